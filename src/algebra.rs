@@ -0,0 +1,283 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::convert::identity;
+use std::marker::PhantomData;
+
+/// # 가환군 (Abelian group)
+/// 결합법칙과 교환법칙을 만족하는 덧셈 `add`, 항등원 `zero`, 역원 `neg`가 있는 타입입니다.
+/// 차이를 저장하는 자료구조(가중치 DSU, Fenwick tree 등)가 값의 타입으로 사용합니다.
+///
+/// * 정수 타입은 보통의 덧셈으로 구현되어 있습니다. 넘침(overflow)은 검사하지 않습니다.
+/// * `f32`, `f64`도 덧셈으로 구현되어 있지만, 반올림 오차 때문에 결합법칙이 근사적으로만 성립합니다.
+/// * 다른 군(예: xor, 모듈러 덧셈)은 새 타입을 만들어 구현합니다.
+pub trait AbelianGroup: Clone {
+    /// 항등원
+    fn zero() -> Self;
+
+    /// 군 연산 `a + b`
+    fn add(a: &Self, b: &Self) -> Self;
+
+    /// 역원 `-a`
+    fn neg(a: &Self) -> Self;
+
+    /// `a - b`
+    fn sub(a: &Self, b: &Self) -> Self {
+        Self::add(a, &Self::neg(b))
+    }
+}
+
+macro_rules! impl_abelian_group {
+    ($zero:literal; $($t:ty),*) => {
+        $(
+            impl AbelianGroup for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn add(a: &Self, b: &Self) -> Self {
+                    a + b
+                }
+
+                fn neg(a: &Self) -> Self {
+                    -a
+                }
+
+                fn sub(a: &Self, b: &Self) -> Self {
+                    a - b
+                }
+            }
+        )*
+    };
+}
+
+impl_abelian_group!(0; i8, i16, i32, i64, i128, isize);
+impl_abelian_group!(0.0; f32, f64);
+
+/// # 모노이드 (Monoid)
+/// 결합법칙을 만족하는 연산 `op`와 항등원 `identity`의 묶음입니다. 값의 타입은 `S`이고, 같은 타입에 여러 연산을
+/// 붙일 수 있도록 모노이드 자체는 값이 없는 표시용 타입(예: [`Sum`], [`Min`])으로 둡니다.
+///
+/// * 교환법칙은 필요 없습니다. 구간을 접는 자료구조는 항상 왼쪽 원소가 `op`의 첫 번째 인자가 되도록 접습니다.
+pub trait Monoid {
+    type S: Clone;
+
+    /// 항등원: 모든 a에 대해 `op(identity, a) == op(a, identity) == a`
+    fn identity() -> Self::S;
+
+    /// 결합법칙을 만족하는 연산
+    fn op(a: &Self::S, b: &Self::S) -> Self::S;
+}
+
+/// 덧셈 모노이드. [`AbelianGroup`]이면 됩니다.
+pub struct Sum<T>(PhantomData<T>);
+
+impl<T: AbelianGroup> Monoid for Sum<T> {
+    type S = T;
+
+    fn identity() -> T {
+        T::zero()
+    }
+
+    fn op(a: &T, b: &T) -> T {
+        T::add(a, b)
+    }
+}
+
+/// 최솟값 모노이드. 항등원은 타입의 최댓값입니다.
+pub struct Min<T>(PhantomData<T>);
+
+/// 최댓값 모노이드. 항등원은 타입의 최솟값입니다.
+pub struct Max<T>(PhantomData<T>);
+
+/// 최대공약수 모노이드. 항등원은 0입니다.
+///
+/// > 부호 있는 타입에서는 결과가 절댓값이라서 음수 a에 대해 `op(0, a) != a`입니다. 값이 0 이상일 때만 쓰세요.
+pub struct Gcd<T>(PhantomData<T>);
+
+macro_rules! impl_integer_monoids {
+    ($($t:ty),*) => {
+        $(
+            impl Monoid for Min<$t> {
+                type S = $t;
+
+                fn identity() -> $t {
+                    <$t>::MAX
+                }
+
+                fn op(a: &$t, b: &$t) -> $t {
+                    *a.min(b)
+                }
+            }
+
+            impl Monoid for Max<$t> {
+                type S = $t;
+
+                fn identity() -> $t {
+                    <$t>::MIN
+                }
+
+                fn op(a: &$t, b: &$t) -> $t {
+                    *a.max(b)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_monoids!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+macro_rules! impl_gcd_monoid {
+    ($($t:ty => $abs:expr),*) => {
+        $(
+            impl Monoid for Gcd<$t> {
+                type S = $t;
+
+                fn identity() -> $t {
+                    0
+                }
+
+                fn op(a: &$t, b: &$t) -> $t {
+                    let (mut a, mut b) = (*a, *b);
+                    while b != 0 {
+                        (a, b) = (b, a % b);
+                    }
+                    $abs(a)
+                }
+            }
+        )*
+    };
+}
+
+impl_gcd_monoid!(
+    i8 => i8::abs, i16 => i16::abs, i32 => i32::abs, i64 => i64::abs, i128 => i128::abs,
+    isize => isize::abs
+);
+impl_gcd_monoid!(
+    u8 => identity, u16 => identity, u32 => identity, u64 => identity, u128 => identity,
+    usize => identity
+);
+
+/// # 모노이드 작용 (lazy segment tree의 mapping)
+/// 모노이드 `M`의 값에 적용하는 함수 `F`들의 집합입니다. 구간 전체에 `F`를 적용한 결과를 구간을 접은 값만으로
+/// 구할 수 있어야 합니다. 즉 `apply(f, op(a, b)) == op(apply(f, a), apply(f, b))`여야 합니다.
+pub trait Action<M: Monoid> {
+    type F: Clone;
+
+    /// 아무것도 바꾸지 않는 함수
+    fn identity_map() -> Self::F;
+
+    /// `f(x)`
+    fn apply(f: &Self::F, x: &M::S) -> M::S;
+
+    /// 합성 `f ∘ g`: g를 먼저 적용하고 그다음 f를 적용한 것과 같은 함수
+    fn compose(f: &Self::F, g: &Self::F) -> Self::F;
+}
+
+/// # 반환 (Semiring)
+/// 덧셈 `add`(결합, 교환, 항등원 `zero`)과 곱셈 `mul`(결합, 항등원 `one`)이 있고 곱셈이 덧셈에 분배되는 타입입니다.
+/// 행렬 곱셈([`Matrix`](crate::math::Matrix))이 원소의 타입으로 사용합니다.
+///
+/// * `zero`는 곱셈에 대해 흡수원이어야 합니다(`mul(zero, a) == zero`).
+/// * 정수 타입과 `f64`는 보통의 덧셈과 곱셈입니다. 넘침은 검사하지 않습니다.
+/// * `bool`은 `(or, and)`라서 행렬 곱이 도달 가능성이 됩니다. [`MinPlus`]는 `(min, +)`라서 행렬 곱이 최단 경로가 됩니다.
+pub trait Semiring: Clone {
+    /// 덧셈의 항등원
+    fn zero() -> Self;
+
+    /// 곱셈의 항등원
+    fn one() -> Self;
+
+    /// `a + b`
+    fn add(a: &Self, b: &Self) -> Self;
+
+    /// `a · b`
+    fn mul(a: &Self, b: &Self) -> Self;
+}
+
+macro_rules! impl_semiring {
+    ($zero:literal, $one:literal; $($t:ty),*) => {
+        $(
+            impl Semiring for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn one() -> Self {
+                    $one
+                }
+
+                fn add(a: &Self, b: &Self) -> Self {
+                    a + b
+                }
+
+                fn mul(a: &Self, b: &Self) -> Self {
+                    a * b
+                }
+            }
+        )*
+    };
+}
+
+impl_semiring!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_semiring!(0.0, 1.0; f32, f64);
+
+impl Semiring for bool {
+    fn zero() -> Self {
+        false
+    }
+
+    fn one() -> Self {
+        true
+    }
+
+    fn add(a: &Self, b: &Self) -> Self {
+        *a || *b
+    }
+
+    fn mul(a: &Self, b: &Self) -> Self {
+        *a && *b
+    }
+}
+
+/// # (min, +) 반환
+/// 덧셈이 min, 곱셈이 +인 값입니다. `zero`는 타입의 최댓값으로 "경로 없음(무한대)"을 뜻하고, `one`은 0입니다.
+///
+/// * 무한대에 무엇을 더해도 무한대입니다. 유한한 값끼리의 합은 넘침을 검사하지 않습니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MinPlus<T>(pub T);
+
+macro_rules! impl_min_plus {
+    ($($t:ty),*) => {
+        $(
+            impl MinPlus<$t> {
+                /// 무한대(경로 없음)
+                pub const INF: Self = MinPlus(<$t>::MAX);
+            }
+
+            impl Semiring for MinPlus<$t> {
+                fn zero() -> Self {
+                    Self::INF
+                }
+
+                fn one() -> Self {
+                    MinPlus(0)
+                }
+
+                fn add(a: &Self, b: &Self) -> Self {
+                    *a.min(b)
+                }
+
+                fn mul(a: &Self, b: &Self) -> Self {
+                    if *a == Self::INF || *b == Self::INF {
+                        Self::INF
+                    } else {
+                        MinPlus(a.0 + b.0)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_min_plus!(i32, i64, i128, u32, u64, u128, usize);
@@ -0,0 +1,4 @@
+mod li_chao;
+pub use li_chao::LiChaoTree;
+mod monotone;
+pub use monotone::ConvexHullTrick;
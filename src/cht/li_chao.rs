@@ -0,0 +1,281 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::ops::RangeInclusive;
+
+/// 자식이 없음을 나타내는 번호. 0번은 루트라서 누구의 자식도 아닙니다.
+const NONE: u32 = 0;
+
+#[derive(Clone, Debug)]
+struct Node {
+    /// 이 노드가 맡은 구간에서 가운데 점 기준으로 가장 좋은 직선 (a, b)
+    line: Option<(i64, i64)>,
+    child: [u32; 2],
+}
+
+/// # Li Chao tree
+/// 정수 x 범위 `lo..=hi` 위에서 직선 `y = ax + b`를 추가하고, 점 x에서 지금까지 추가한 직선 중 가장 작은
+/// (또는 가장 큰) 값을 구합니다. 기울기가 아무 순서로나 들어오는 convex hull trick DP에 씁니다.
+///
+/// * 노드마다 직선을 하나씩 두고, 새 직선과 비교해서 가운데 점에서 진 쪽을 한쪽 자식으로만 내려보냅니다.
+/// * [`insert_segment`](Self::insert_segment)는 x 범위 일부에서만 정의된 선분을 추가합니다.
+/// 선분의 범위를 덮는 노드 O(log U)개에 각각 직선을 넣습니다.
+/// * 노드는 Vec 하나(arena)에 필요할 때만 만들어서, 범위가 `i64::MIN..=i64::MAX`여도 괜찮습니다.
+/// * `ax + b`는 i128로 계산하므로 넘치지 않고, 질의 결과도 i128입니다.
+/// ## 시간복잡도
+/// 직선 추가와 질의 O(log U), 선분 추가 O(log² U). U는 x 범위의 크기
+#[derive(Clone, Debug)]
+pub struct LiChaoTree {
+    lo: i64,
+    hi: i64,
+    /// 최소면 1, 최대면 -1. 값에 곱해서 항상 최소를 구하는 것처럼 다룹니다.
+    sign: i128,
+    nodes: Vec<Node>,
+}
+
+impl LiChaoTree {
+    /// x 범위 `lo..=hi`에서 최솟값을 구하는 트리
+    pub fn new_min(range: RangeInclusive<i64>) -> Self {
+        Self::with_sign(range, 1)
+    }
+
+    /// x 범위 `lo..=hi`에서 최댓값을 구하는 트리
+    pub fn new_max(range: RangeInclusive<i64>) -> Self {
+        Self::with_sign(range, -1)
+    }
+
+    fn with_sign(range: RangeInclusive<i64>, sign: i128) -> Self {
+        let (lo, hi) = (*range.start(), *range.end());
+        assert!(lo <= hi, "empty x range {lo}..={hi}");
+        LiChaoTree {
+            lo,
+            hi,
+            sign,
+            nodes: vec![Node {
+                line: None,
+                child: [NONE; 2],
+            }],
+        }
+    }
+
+    /// 지금까지 만든 노드의 개수
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// 직선 `y = ax + b`를 x 범위 전체에 추가
+    pub fn insert_line(&mut self, a: i64, b: i64) {
+        self.push_down(0, self.lo, self.hi, (a, b));
+    }
+
+    /// 선분 `y = ax + b` (x는 `xs`)를 추가. `xs`가 트리의 x 범위를 벗어난 부분은 무시합니다.
+    pub fn insert_segment(&mut self, a: i64, b: i64, xs: RangeInclusive<i64>) {
+        let (l, r) = ((*xs.start()).max(self.lo), (*xs.end()).min(self.hi));
+        if l > r {
+            return;
+        }
+        let mut stack = vec![(0usize, self.lo, self.hi)];
+        while let Some((v, lo, hi)) = stack.pop() {
+            if r < lo || hi < l {
+                continue;
+            }
+            if l <= lo && hi <= r {
+                self.push_down(v, lo, hi, (a, b));
+                continue;
+            }
+            let mid = midpoint(lo, hi);
+            stack.push((self.child(v, 0), lo, mid));
+            stack.push((self.child(v, 1), mid + 1, hi));
+        }
+    }
+
+    /// 점 x에서 추가한 직선(선분) 중 가장 좋은 값. x를 지나는 것이 없으면 None
+    pub fn query(&self, x: i64) -> Option<i128> {
+        assert!(
+            self.lo <= x && x <= self.hi,
+            "x = {x} out of range {}..={}",
+            self.lo,
+            self.hi
+        );
+        let (mut lo, mut hi) = (self.lo, self.hi);
+        let mut v = 0usize;
+        let mut best: Option<i128> = None;
+        loop {
+            if let Some(line) = self.nodes[v].line {
+                let y = self.eval(line, x);
+                best = Some(best.map_or(y, |b| b.min(y)));
+            }
+            if lo == hi {
+                break;
+            }
+            let mid = midpoint(lo, hi);
+            let side = usize::from(x > mid);
+            if side == 0 {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+            match self.nodes[v].child[side] {
+                NONE => break,
+                c => v = c as usize,
+            }
+        }
+        best.map(|y| y * self.sign)
+    }
+
+    /// sign을 곱한 값. 항상 작을수록 좋습니다.
+    fn eval(&self, (a, b): (i64, i64), x: i64) -> i128 {
+        (a as i128 * x as i128 + b as i128) * self.sign
+    }
+
+    /// 구간 `lo..=hi`를 맡은 노드 v부터 직선을 넣음
+    fn push_down(&mut self, mut v: usize, mut lo: i64, mut hi: i64, mut line: (i64, i64)) {
+        loop {
+            let Some(mut cur) = self.nodes[v].line else {
+                self.nodes[v].line = Some(line);
+                return;
+            };
+            let mid = midpoint(lo, hi);
+            if self.eval(line, mid) < self.eval(cur, mid) {
+                std::mem::swap(&mut cur, &mut line);
+                self.nodes[v].line = Some(cur);
+            }
+            // 이제 cur가 가운데에서 이기고, line은 많아야 한쪽 끝에서만 이깁니다.
+            if lo == hi {
+                return;
+            }
+            if self.eval(line, lo) < self.eval(cur, lo) {
+                v = self.child(v, 0);
+                hi = mid;
+            } else if self.eval(line, hi) < self.eval(cur, hi) {
+                v = self.child(v, 1);
+                lo = mid + 1;
+            } else {
+                return;
+            }
+        }
+    }
+
+    /// v의 자식 번호. 없으면 만듭니다.
+    fn child(&mut self, v: usize, side: usize) -> usize {
+        if self.nodes[v].child[side] == NONE {
+            let id = u32::try_from(self.nodes.len()).expect("too many nodes for u32 indices");
+            self.nodes.push(Node {
+                line: None,
+                child: [NONE; 2],
+            });
+            self.nodes[v].child[side] = id;
+        }
+        self.nodes[v].child[side] as usize
+    }
+}
+
+/// `lo..=hi`를 `lo..=mid`와 `mid + 1..=hi`로 나누는 점 (내림)
+fn midpoint(lo: i64, hi: i64) -> i64 {
+    ((lo as i128 + hi as i128).div_euclid(2)) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn brute(lines: &[(i64, i64, i64, i64)], x: i64, maximize: bool) -> Option<i128> {
+        let values = lines
+            .iter()
+            .filter(|&&(_, _, l, r)| l <= x && x <= r)
+            .map(|&(a, b, _, _)| a as i128 * x as i128 + b as i128);
+        if maximize { values.max() } else { values.min() }
+    }
+
+    #[test]
+    fn random_lines_and_segments_against_brute_force() {
+        let mut rng = XorShift::new(140);
+        for round in 0..200 {
+            let maximize = round % 2 == 1;
+            let lo = rng.range_i64(-50, 50);
+            let hi = lo + rng.range_i64(0, 100);
+            let mut tree = if maximize {
+                LiChaoTree::new_max(lo..=hi)
+            } else {
+                LiChaoTree::new_min(lo..=hi)
+            };
+            let mut lines = Vec::new();
+            for _ in 0..30 {
+                let (a, b) = (rng.range_i64(-20, 20), rng.range_i64(-500, 500));
+                if rng.below(2) == 0 {
+                    tree.insert_line(a, b);
+                    lines.push((a, b, i64::MIN, i64::MAX));
+                } else {
+                    // 트리 범위를 벗어나는 선분도 섞음
+                    let l = rng.range_i64(lo - 10, hi + 10);
+                    let r = rng.range_i64(l - 5, hi + 10);
+                    tree.insert_segment(a, b, l..=r);
+                    lines.push((a, b, l, r));
+                }
+                for _ in 0..5 {
+                    let x = rng.range_i64(lo, hi);
+                    assert_eq!(tree.query(x), brute(&lines, x, maximize));
+                }
+            }
+            for x in lo..=hi {
+                assert_eq!(tree.query(x), brute(&lines, x, maximize));
+            }
+        }
+    }
+
+    #[test]
+    fn segment_covers_only_part_of_range() {
+        let mut tree = LiChaoTree::new_min(0..=10);
+        tree.insert_segment(0, 5, 3..=6);
+        assert_eq!(tree.query(2), None);
+        assert_eq!(tree.query(3), Some(5));
+        assert_eq!(tree.query(6), Some(5));
+        assert_eq!(tree.query(7), None);
+        tree.insert_segment(-1, 7, 5..=20);
+        assert_eq!(tree.query(5), Some(2));
+        assert_eq!(tree.query(4), Some(5));
+        assert_eq!(tree.query(10), Some(-3));
+        tree.insert_line(1, 0);
+        assert_eq!(tree.query(0), Some(0));
+        assert_eq!(tree.query(4), Some(4));
+    }
+
+    #[test]
+    fn monotone_slopes_match_brute_force() {
+        // 기울기가 줄어드는 순서로 들어오는 전형적인 CHT 입력
+        let mut rng = XorShift::new(1400);
+        let mut tree = LiChaoTree::new_min(-1_000_000..=1_000_000);
+        let mut lines = Vec::new();
+        let mut a = 1_000_000;
+        for _ in 0..300 {
+            a -= rng.range_i64(0, 5000);
+            let b = rng.range_i64(-1_000_000_000, 1_000_000_000);
+            tree.insert_line(a, b);
+            lines.push((a, b, i64::MIN, i64::MAX));
+        }
+        for _ in 0..300 {
+            let x = rng.range_i64(-1_000_000, 1_000_000);
+            assert_eq!(tree.query(x), brute(&lines, x, false));
+        }
+    }
+
+    #[test]
+    fn full_i64_range_does_not_overflow() {
+        let mut rng = XorShift::new(14000);
+        let mut tree = LiChaoTree::new_max(i64::MIN..=i64::MAX);
+        let mut lines = Vec::new();
+        for _ in 0..100 {
+            let (a, b) = (rng.next_u64() as i64, rng.next_u64() as i64);
+            tree.insert_line(a, b);
+            lines.push((a, b, i64::MIN, i64::MAX));
+        }
+        for x in [i64::MIN, i64::MAX, 0, -1, 1] {
+            assert_eq!(tree.query(x), brute(&lines, x, true));
+        }
+        for _ in 0..200 {
+            let x = rng.next_u64() as i64;
+            assert_eq!(tree.query(x), brute(&lines, x, true));
+        }
+        // 직선 하나를 넣을 때 노드는 많아야 하나 생김
+        assert!(tree.node_count() <= 1 + lines.len());
+    }
+}
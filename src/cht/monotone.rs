@@ -0,0 +1,271 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Convex hull trick (기울기 정렬)
+/// 직선 `y = ax + b`가 기울기 순서대로 들어올 때, 점 x에서 지금까지 추가한 직선 중 가장 작은(또는 가장 큰) 값을
+/// 구합니다. 기울기가 아무 순서로나 들어오면 [`LiChaoTree`](super::LiChaoTree)를 씁니다.
+///
+/// * 최소 트릭이면 기울기가 줄어드는 순서(같아도 됨), 최대 트릭이면 늘어나는 순서로 넣어야 합니다.
+/// * 필요 없어진 직선은 추가할 때 뒤에서 지워서, 남은 직선들이 아래 껍질(최대면 위 껍질)을 이룹니다.
+/// * [`query`](Self::query)는 이분 탐색이고, 질의하는 x가 줄어들지 않으면 [`query_monotone`](Self::query_monotone)이
+/// 포인터를 앞으로만 옮겨서 amortized O(1)에 답합니다.
+/// * 값은 i128로 계산합니다. 직선이 필요 없는지 판정할 때 차이끼리 곱하므로 `|a|, |b| <= 2^62`이어야 넘치지 않습니다.
+/// ## 시간복잡도
+/// 추가 amortized O(1), `query` O(log n), `query_monotone` amortized O(1)
+#[derive(Clone, Debug)]
+pub struct ConvexHullTrick {
+    /// 최소면 1, 최대면 -1. 직선에 곱해서 항상 최소를 구하는 것처럼 다룹니다.
+    sign: i128,
+    /// sign을 곱한 (a, b). a는 줄어드는 순서
+    lines: Vec<(i128, i128)>,
+    /// `query_monotone`이 마지막으로 고른 직선
+    ptr: usize,
+}
+
+impl ConvexHullTrick {
+    /// 최솟값을 구하는 CHT. 기울기가 줄어드는 순서로 넣어야 합니다.
+    pub fn new_min() -> Self {
+        Self::with_sign(1)
+    }
+
+    /// 최댓값을 구하는 CHT. 기울기가 늘어나는 순서로 넣어야 합니다.
+    pub fn new_max() -> Self {
+        Self::with_sign(-1)
+    }
+
+    fn with_sign(sign: i128) -> Self {
+        ConvexHullTrick {
+            sign,
+            lines: Vec::new(),
+            ptr: 0,
+        }
+    }
+
+    /// 껍질에 남아 있는 직선의 개수
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// 직선이 하나도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// 직선 `y = ax + b` 추가
+    pub fn add_line(&mut self, a: i64, b: i64) {
+        let line = (a as i128 * self.sign, b as i128 * self.sign);
+        if let Some(&(last_a, last_b)) = self.lines.last() {
+            assert!(
+                line.0 <= last_a,
+                "slope {a} is out of order (previous slope {})",
+                last_a * self.sign
+            );
+            if line.0 == last_a {
+                if last_b <= line.1 {
+                    return;
+                }
+                self.lines.pop();
+            }
+        }
+        while self.lines.len() >= 2 {
+            let k = self.lines.len();
+            if !is_useless(self.lines[k - 2], self.lines[k - 1], line) {
+                break;
+            }
+            self.lines.pop();
+        }
+        self.lines.push(line);
+        self.ptr = self.ptr.min(self.lines.len() - 1);
+    }
+
+    /// 점 x에서 가장 좋은 값. 직선이 없으면 None
+    pub fn query(&self, x: i64) -> Option<i128> {
+        if self.lines.is_empty() {
+            return None;
+        }
+        let x = x as i128;
+        // 껍질에서는 i번째가 i + 1번째보다 나쁜 구간이 앞쪽에 모여 있음
+        let (mut lo, mut hi) = (0, self.lines.len() - 1);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if eval(self.lines[mid], x) > eval(self.lines[mid + 1], x) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        Some(eval(self.lines[lo], x) * self.sign)
+    }
+
+    /// 점 x에서 가장 좋은 값. 호출할 때마다 x가 줄어들지 않아야 합니다(직선을 추가한 뒤에도 마찬가지).
+    /// 직선이 없으면 None
+    pub fn query_monotone(&mut self, x: i64) -> Option<i128> {
+        if self.lines.is_empty() {
+            return None;
+        }
+        let x = x as i128;
+        while self.ptr + 1 < self.lines.len()
+            && eval(self.lines[self.ptr], x) >= eval(self.lines[self.ptr + 1], x)
+        {
+            self.ptr += 1;
+        }
+        Some(eval(self.lines[self.ptr], x) * self.sign)
+    }
+}
+
+fn eval((a, b): (i128, i128), x: i128) -> i128 {
+    a * x + b
+}
+
+/// 기울기가 `l1 > l2 > l3` 순서일 때, l1과 l3만으로 l2가 이기는 구간이 없어지는지.
+/// l1과 l3의 교점이 l1과 l2의 교점보다 왼쪽(같아도)에 있는지를 나눗셈 없이 비교합니다.
+fn is_useless(l1: (i128, i128), l2: (i128, i128), l3: (i128, i128)) -> bool {
+    (l3.1 - l1.1) * (l1.0 - l2.0) <= (l2.1 - l1.1) * (l1.0 - l3.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cht::LiChaoTree;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_li_chao_tree() {
+        let mut rng = XorShift::new(141);
+        for round in 0..200 {
+            let maximize = round % 2 == 1;
+            let (mut cht, mut li_chao) = if maximize {
+                (
+                    ConvexHullTrick::new_max(),
+                    LiChaoTree::new_max(-1000..=1000),
+                )
+            } else {
+                (
+                    ConvexHullTrick::new_min(),
+                    LiChaoTree::new_min(-1000..=1000),
+                )
+            };
+            let mut slopes: Vec<i64> = (0..1 + rng.index(40))
+                .map(|_| rng.range_i64(-30, 30))
+                .collect();
+            slopes.sort_unstable();
+            if !maximize {
+                slopes.reverse();
+            }
+            let mut x = -1000;
+            for &a in &slopes {
+                let b = rng.range_i64(-10_000, 10_000);
+                cht.add_line(a, b);
+                li_chao.insert_line(a, b);
+                for _ in 0..3 {
+                    let y = rng.range_i64(-1000, 1000);
+                    assert_eq!(cht.query(y), li_chao.query(y));
+                    x = (x + rng.range_i64(0, 40)).min(1000);
+                    assert_eq!(cht.query_monotone(x), li_chao.query(x));
+                }
+            }
+        }
+        assert_eq!(ConvexHullTrick::new_min().query(0), None);
+    }
+
+    /// APIO 2010 "특별 훈련대": 수열을 연속한 구간으로 나누고, 합이 s인 구간의 점수가 `a s² + b s + c`(a < 0)일 때
+    /// 점수 합의 최댓값. `dp[i] = max_j dp[j] + f(S[i] - S[j])`
+    fn commando_quadratic(x: &[i64], a: i64, b: i64, c: i64) -> i64 {
+        let n = x.len();
+        let mut prefix = vec![0; n + 1];
+        for i in 0..n {
+            prefix[i + 1] = prefix[i] + x[i];
+        }
+        let mut dp = vec![i64::MIN; n + 1];
+        dp[0] = 0;
+        for i in 1..=n {
+            for j in 0..i {
+                let s = prefix[i] - prefix[j];
+                dp[i] = dp[i].max(dp[j] + a * s * s + b * s + c);
+            }
+        }
+        dp[n]
+    }
+
+    fn commando_cht(x: &[i64], a: i64, b: i64, c: i64) -> (i64, i64) {
+        let mut monotone = ConvexHullTrick::new_max();
+        let mut binary = ConvexHullTrick::new_max();
+        let (mut dp_monotone, mut dp_binary) = (0i64, 0i64);
+        let mut s = 0i64;
+        // j = 0
+        monotone.add_line(0, 0);
+        binary.add_line(0, 0);
+        for &v in x {
+            s += v;
+            let rest = a * s * s + b * s + c;
+            dp_monotone = monotone.query_monotone(s).unwrap() as i64 + rest;
+            dp_binary = binary.query(s).unwrap() as i64 + rest;
+            // dp[j] + a S_j² - b S_j - 2a S_j · S_i. S_j가 늘어나고 a < 0이므로 기울기가 늘어남
+            monotone.add_line(-2 * a * s, dp_monotone + a * s * s - b * s);
+            binary.add_line(-2 * a * s, dp_binary + a * s * s - b * s);
+        }
+        (dp_monotone, dp_binary)
+    }
+
+    #[test]
+    fn commando_against_quadratic_dp() {
+        let mut rng = XorShift::new(1410);
+        for round in 0..60 {
+            let n = if round < 50 { 1 + rng.index(30) } else { 2000 };
+            let x: Vec<i64> = (0..n).map(|_| rng.range_i64(1, 100)).collect();
+            let (a, b, c) = (
+                rng.range_i64(-5, -1),
+                rng.range_i64(-100, 10_000),
+                rng.range_i64(-10_000, 10_000),
+            );
+            let expected = commando_quadratic(&x, a, b, c);
+            assert_eq!(commando_cht(&x, a, b, c), (expected, expected));
+        }
+    }
+
+    #[test]
+    fn commando_large_against_li_chao_tree() {
+        let mut rng = XorShift::new(14100);
+        let n = 100_000;
+        let x: Vec<i64> = (0..n).map(|_| rng.range_i64(1, 100)).collect();
+        let (a, b, c) = (-3, 5000, -7);
+        let mut li_chao = LiChaoTree::new_max(0..=100 * n as i64);
+        li_chao.insert_line(0, 0);
+        let (mut dp, mut s) = (0i64, 0i64);
+        for &v in &x {
+            s += v;
+            dp = li_chao.query(s).unwrap() as i64 + a * s * s + b * s + c;
+            li_chao.insert_line(-2 * a * s, dp + a * s * s - b * s);
+        }
+        assert_eq!(commando_cht(&x, a, b, c), (dp, dp));
+    }
+
+    #[test]
+    fn equal_slopes_and_extreme_values() {
+        let mut cht = ConvexHullTrick::new_min();
+        cht.add_line(5, 10);
+        cht.add_line(5, 3);
+        cht.add_line(5, 7);
+        assert_eq!(cht.len(), 1);
+        assert_eq!(cht.query(2), Some(13));
+        let big = 1i64 << 62;
+        let mut cht = ConvexHullTrick::new_max();
+        cht.add_line(-big, big);
+        cht.add_line(0, -big);
+        cht.add_line(big, -big);
+        for x in [-big, -1, 0, 1, big] {
+            let expected = [(-big, big), (0, -big), (big, -big)]
+                .iter()
+                .map(|&(a, b)| a as i128 * x as i128 + b as i128)
+                .max();
+            assert_eq!(cht.query(x), expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "slope 3 is out of order")]
+    fn wrong_slope_order_panics() {
+        let mut cht = ConvexHullTrick::new_min();
+        cht.add_line(1, 0);
+        cht.add_line(3, 0);
+    }
+}
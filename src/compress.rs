@@ -0,0 +1,147 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # 좌표 압축
+/// 각 값의 순위(정렬, 중복 제거한 값들 중 몇 번째인지)와 그 정렬된 값들을 반환합니다.
+/// 값의 범위가 커서 Fenwick tree나 세그먼트 트리의 인덱스로 쓸 수 없을 때 씁니다.
+///
+/// * 같은 값은 같은 순위이고, 순위는 0부터 빈틈없이 붙습니다.
+/// * 나중에 다른 값의 순위를 찾아야 하면 [`Compressor`]를 씁니다.
+/// ## 시간복잡도
+/// O(n log n)
+pub fn compress<T: Ord + Clone>(values: &[T]) -> (Vec<usize>, Vec<T>) {
+    let compressor = Compressor::new(values);
+    let ranks = values.iter().map(|x| compressor.rank(x).unwrap()).collect();
+    (ranks, compressor.values)
+}
+
+/// # 좌표 압축 (i64)
+/// [`compress`]와 같은 결과를 (값, 위치) 쌍을 한 번 정렬해서 이분 탐색 없이 구합니다.
+/// ## 시간복잡도
+/// O(n log n)
+pub fn compress_i64(values: &[i64]) -> (Vec<usize>, Vec<i64>) {
+    let mut order: Vec<(i64, usize)> = values.iter().copied().zip(0..).collect();
+    order.sort_unstable();
+    let mut ranks = vec![0; values.len()];
+    let mut universe: Vec<i64> = Vec::new();
+    for (x, i) in order {
+        if universe.last() != Some(&x) {
+            universe.push(x);
+        }
+        ranks[i] = universe.len() - 1;
+    }
+    (ranks, universe)
+}
+
+/// # 좌표 압축기
+/// 정렬, 중복 제거한 값들을 들고 있다가 값 ↔ 순위를 이분 탐색으로 바꿉니다. 여러 배열(예: 점의 좌표와 질의의 좌표)을
+/// 하나의 좌표계로 압축할 때는 [`from_slices`](Self::from_slices)를 씁니다.
+/// ## 시간복잡도
+/// 생성 O(n log n), 질의 O(log n)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Compressor<T> {
+    values: Vec<T>,
+}
+
+impl<T: Ord + Clone> Compressor<T> {
+    /// values로 생성
+    pub fn new(values: &[T]) -> Self {
+        Self::from_slices(&[values])
+    }
+
+    /// 여러 배열의 값을 모두 모아서 생성
+    pub fn from_slices(slices: &[&[T]]) -> Self {
+        let mut values: Vec<T> = slices.iter().flat_map(|s| s.iter().cloned()).collect();
+        values.sort_unstable();
+        values.dedup();
+        Compressor { values }
+    }
+}
+
+impl<T: Ord> Compressor<T> {
+    /// 서로 다른 값의 개수
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 값이 없는지
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// 정렬, 중복 제거한 값들
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// x의 순위. x가 없으면 None
+    pub fn rank(&self, x: &T) -> Option<usize> {
+        self.values.binary_search(x).ok()
+    }
+
+    /// x 이상인 첫 값의 순위. 모두 x보다 작으면 `len()`. 없는 값으로 구간을 자를 때 씁니다.
+    pub fn lower_bound(&self, x: &T) -> usize {
+        self.values.partition_point(|v| v < x)
+    }
+
+    /// 순위가 rank인 값
+    pub fn value(&self, rank: usize) -> &T {
+        &self.values[rank]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_sorting() {
+        let mut rng = XorShift::new(200);
+        for round in 0..300 {
+            let n = rng.index(50);
+            let spread = [3, 1000, i64::MAX][round % 3];
+            let mut values: Vec<i64> = (0..n).map(|_| rng.range_i64(-spread, spread)).collect();
+            if round % 5 == 0 {
+                values.sort_unstable();
+            }
+            let (ranks, universe) = compress(&values);
+            assert_eq!(compress_i64(&values), (ranks.clone(), universe.clone()));
+            assert!(universe.windows(2).all(|w| w[0] < w[1]));
+            for (i, &x) in values.iter().enumerate() {
+                assert_eq!(universe[ranks[i]], x);
+                // 순위는 x보다 작은 서로 다른 값의 개수
+                assert_eq!(ranks[i], universe.iter().filter(|&&v| v < x).count());
+            }
+            let c = Compressor::new(&values);
+            assert_eq!(c.values(), universe);
+            for _ in 0..10 {
+                let x = rng.range_i64(-spread, spread);
+                assert_eq!(c.rank(&x), universe.iter().position(|&v| v == x));
+                assert_eq!(
+                    c.lower_bound(&x),
+                    universe.iter().filter(|&&v| v < x).count()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn shared_space_and_absent_values() {
+        let points = [10, 30, 30];
+        let queries = [20, 30, 5];
+        let c = Compressor::from_slices(&[&points, &queries]);
+        assert_eq!(c.values(), [5, 10, 20, 30]);
+        assert_eq!(c.rank(&20), Some(2));
+        assert_eq!(c.rank(&25), None);
+        assert_eq!(c.lower_bound(&25), 3);
+        assert_eq!(c.lower_bound(&100), 4);
+        assert_eq!(*c.value(1), 10);
+        let (ranks, universe) = compress(&["b", "a", "b", "c"]);
+        assert_eq!(ranks, vec![1, 0, 1, 2]);
+        assert_eq!(universe, vec!["a", "b", "c"]);
+        let (ranks, universe) = compress::<i32>(&[]);
+        assert!(ranks.is_empty() && universe.is_empty());
+        assert!(Compressor::<u8>::new(&[]).is_empty());
+        assert_eq!(Compressor::new(&[7, 7, 7]).len(), 1);
+    }
+}
@@ -0,0 +1,219 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::Monoid;
+use std::ops::Range;
+
+/// # Disjoint sparse table
+/// 값이 바뀌지 않는 배열에서 구간 `l..r`을 [`Monoid`]로 접은 값을 O(1)에 구합니다.
+/// [`SparseTable`](crate::sparse_table::SparseTable)과 달리 멱등성이 필요 없어서 합, 곱, 행렬 곱에도 쓸 수 있습니다.
+///
+/// * 길이를 2의 거듭제곱으로 늘리고, 단계 h마다 배열을 길이 `2^h`짜리 블록으로 나눕니다. 블록의 가운데를 기준으로
+/// 왼쪽 절반에는 "i부터 가운데까지", 오른쪽 절반에는 "가운데부터 i까지" 접은 값을 저장합니다.
+/// * `l..r`(원소 두 개 이상)은 `l`과 `r - 1`이 처음으로 다른 블록 절반에 들어가는 단계에서 왼쪽 값과 오른쪽 값
+/// 두 개를 합치면 됩니다. 그 단계는 `l ^ (r - 1)`의 가장 높은 비트로 바로 구합니다.
+/// * 교환법칙이 없어도 `a[l], …, a[r - 1]` 순서대로 접힙니다.
+/// * 구간은 0-based 반열림이고, 빈 구간은 항등원입니다.
+/// ## 시간복잡도
+/// 전처리 O(n log n), 질의 O(1)
+pub struct DisjointSparseTable<M: Monoid> {
+    n: usize,
+    /// `table[0]`은 원래 배열, `table[h]`는 단계 h
+    table: Vec<Vec<M::S>>,
+}
+
+impl<M: Monoid> DisjointSparseTable<M> {
+    /// 주어진 배열로 생성
+    pub fn new(values: &[M::S]) -> Self {
+        let n = values.len();
+        let size = n.next_power_of_two().max(2);
+        let mut base = values.to_vec();
+        base.resize(size, M::identity());
+        let mut table = vec![base];
+        let mut h = 1;
+        while (1 << h) <= size {
+            let half = 1 << (h - 1);
+            let base = &table[0];
+            let mut level = vec![M::identity(); size];
+            for mid in (half..size).step_by(2 * half) {
+                level[mid - 1] = base[mid - 1].clone();
+                for i in (mid - half..mid - 1).rev() {
+                    level[i] = M::op(&base[i], &level[i + 1]);
+                }
+                level[mid] = base[mid].clone();
+                for i in mid + 1..mid + half {
+                    level[i] = M::op(&level[i - 1], &base[i]);
+                }
+            }
+            table.push(level);
+            h += 1;
+        }
+        DisjointSparseTable { n, table }
+    }
+
+    /// 배열의 길이
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// 구간 `l..r`을 접은 값
+    pub fn query(&self, range: Range<usize>) -> M::S {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.n,
+            "invalid range {l}..{r} for length {}",
+            self.n
+        );
+        if l == r {
+            return M::identity();
+        }
+        let last = r - 1;
+        if l == last {
+            return self.table[0][l].clone();
+        }
+        let h = (l ^ last).ilog2() as usize + 1;
+        M::op(&self.table[h][l], &self.table[h][last])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::{Max, Sum};
+    use crate::random::XorShift;
+
+    struct Concat;
+
+    impl Monoid for Concat {
+        type S = String;
+
+        fn identity() -> String {
+            String::new()
+        }
+
+        fn op(a: &String, b: &String) -> String {
+            format!("{a}{b}")
+        }
+    }
+
+    const P: u64 = 998_244_353;
+
+    /// 2×2 행렬 곱 (mod P)
+    struct MatMul;
+
+    impl Monoid for MatMul {
+        type S = [[u64; 2]; 2];
+
+        fn identity() -> Self::S {
+            [[1, 0], [0, 1]]
+        }
+
+        fn op(a: &Self::S, b: &Self::S) -> Self::S {
+            let mut c = [[0; 2]; 2];
+            for (i, row) in c.iter_mut().enumerate() {
+                for (j, x) in row.iter_mut().enumerate() {
+                    *x = (a[i][0] * b[0][j] + a[i][1] * b[1][j]) % P;
+                }
+            }
+            c
+        }
+    }
+
+    /// 곱 (mod P)
+    struct ProductMod;
+
+    impl Monoid for ProductMod {
+        type S = u64;
+
+        fn identity() -> u64 {
+            1
+        }
+
+        fn op(a: &u64, b: &u64) -> u64 {
+            a * b % P
+        }
+    }
+
+    fn check_against_fold<M: Monoid>(
+        rng: &mut XorShift,
+        mut gen_value: impl FnMut(&mut XorShift) -> M::S,
+    ) where
+        M::S: PartialEq + std::fmt::Debug,
+    {
+        for _ in 0..50 {
+            let n = rng.index(70);
+            let a: Vec<M::S> = (0..n).map(|_| gen_value(rng)).collect();
+            let table: DisjointSparseTable<M> = DisjointSparseTable::new(&a);
+            for l in 0..=n {
+                for r in l..=n {
+                    let expected = a[l..r].iter().fold(M::identity(), |acc, x| M::op(&acc, x));
+                    assert_eq!(table.query(l..r), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn random_against_brute_force_fold() {
+        let mut rng = XorShift::new(143);
+        check_against_fold::<Sum<i64>>(&mut rng, |r| r.range_i64(-100, 100));
+        check_against_fold::<Max<i32>>(&mut rng, |r| r.range_i64(-100, 100) as i32);
+        check_against_fold::<ProductMod>(&mut rng, |r| r.below(P));
+        check_against_fold::<Concat>(&mut rng, |r| {
+            ((b'a' + r.below(26) as u8) as char).to_string()
+        });
+        check_against_fold::<MatMul>(&mut rng, |r| {
+            [[r.below(5), r.below(5)], [r.below(5), r.below(5)]]
+        });
+    }
+
+    #[test]
+    fn fold_order_is_left_to_right() {
+        let words: Vec<String> = ["ab", "c", "", "def", "g"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let table: DisjointSparseTable<Concat> = DisjointSparseTable::new(&words);
+        assert_eq!(table.query(0..5), "abcdefg");
+        assert_eq!(table.query(1..4), "cdef");
+        assert_eq!(table.query(3..4), "def");
+        assert_eq!(table.query(2..2), "");
+        let fib = [[1, 1], [1, 0]];
+        let shear = [[1, 2], [0, 1]];
+        let table: DisjointSparseTable<MatMul> = DisjointSparseTable::new(&[shear, fib, shear]);
+        let expected = MatMul::op(&MatMul::op(&shear, &fib), &shear);
+        assert_eq!(table.query(0..3), expected);
+        assert_ne!(table.query(0..2), MatMul::op(&fib, &shear));
+        let empty: DisjointSparseTable<Sum<i64>> = DisjointSparseTable::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.query(0..0), 0);
+    }
+
+    #[test]
+    fn many_product_queries() {
+        let mut rng = XorShift::new(1430);
+        let n = 200_000;
+        let values: Vec<u64> = (0..n).map(|_| 1 + rng.below(P - 1)).collect();
+        let table: DisjointSparseTable<ProductMod> = DisjointSparseTable::new(&values);
+        let mut prefix = vec![1u64; n + 1];
+        for i in 0..n {
+            prefix[i + 1] = prefix[i] * values[i] % P;
+        }
+        for q in 0..1_000_000 {
+            let l = rng.index(n);
+            let r = l + 1 + rng.index(n - l);
+            let got = table.query(l..r);
+            // 가끔씩만 직접 확인: 앞부분 곱, 그리고 짧은 구간은 직접 곱해서
+            if q % 1000 == 0 {
+                assert_eq!(table.query(0..r), prefix[r]);
+                let short = l..(l + 20).min(n);
+                let expected = values[short.clone()].iter().fold(1, |acc, x| acc * x % P);
+                assert_eq!(table.query(short), expected);
+            }
+            assert!(got < P);
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod disjoint_set;
+pub use disjoint_set::DisjointSet;
+mod weighted;
+pub use weighted::{Inconsistency, WeightedDsu};
@@ -0,0 +1,223 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Union-Find (Disjoint Set Union)
+/// 원소 0..n을 서로소인 집합들로 나누고, 두 집합을 합치거나 두 원소가 같은 집합인지 묻습니다.
+///
+/// * 크기가 작은 집합의 대표를 큰 집합의 대표 아래에 붙입니다(union by size).
+/// * `find`는 대표를 찾은 뒤 지나온 원소들을 모두 대표에 바로 붙입니다(path compression). 재귀를 쓰지 않으므로
+/// 긴 사슬에서도 스택 오버플로가 나지 않습니다.
+/// * 범위를 벗어난 원소를 넘기면 panic합니다.
+/// ## 시간복잡도
+/// 연산마다 amortized O(α(n))
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// 원소마다 자기 자신만 있는 집합 n개
+    pub fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// 원소 개수
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// 원소가 없는지
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// x가 속한 집합의 대표
+    pub fn find(&mut self, x: usize) -> usize {
+        self.check(x);
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut v = x;
+        while self.parent[v] != root {
+            let next = self.parent[v];
+            self.parent[v] = root;
+            v = next;
+        }
+        root
+    }
+
+    /// a와 b의 집합을 합칩니다. 이미 같은 집합이었으면 false입니다.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.count -= 1;
+        true
+    }
+
+    /// a와 b가 같은 집합인지
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// x가 속한 집합의 원소 개수
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// 집합의 개수
+    pub fn component_count(&self) -> usize {
+        self.count
+    }
+
+    /// 모든 집합. 각 집합은 오름차순이고, 집합끼리는 가장 작은 원소의 순서로 나열합니다.
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let n = self.len();
+        let mut index = vec![usize::MAX; n];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.count);
+        for v in 0..n {
+            let root = self.find(v);
+            if index[root] == usize::MAX {
+                index[root] = groups.len();
+                groups.push(Vec::with_capacity(self.size[root]));
+            }
+            groups[index[root]].push(v);
+        }
+        groups
+    }
+
+    fn check(&self, x: usize) {
+        assert!(
+            x < self.len(),
+            "index {x} out of range for {} elements",
+            self.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use std::collections::VecDeque;
+
+    /// BFS로 구한 연결 요소 번호
+    fn components(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+        let mut adj = vec![Vec::new(); n];
+        for &(u, v) in edges {
+            adj[u].push(v);
+            adj[v].push(u);
+        }
+        let mut comp = vec![usize::MAX; n];
+        let mut count = 0;
+        for s in 0..n {
+            if comp[s] != usize::MAX {
+                continue;
+            }
+            comp[s] = count;
+            let mut queue = VecDeque::from([s]);
+            while let Some(u) = queue.pop_front() {
+                for &v in &adj[u] {
+                    if comp[v] == usize::MAX {
+                        comp[v] = count;
+                        queue.push_back(v);
+                    }
+                }
+            }
+            count += 1;
+        }
+        comp
+    }
+
+    #[test]
+    fn random_graphs_against_bfs() {
+        let mut rng = XorShift::new(126);
+        for _ in 0..200 {
+            let n = 1 + rng.index(40);
+            let m = rng.index(2 * n);
+            let edges: Vec<(usize, usize)> = (0..m).map(|_| (rng.index(n), rng.index(n))).collect();
+            let mut dsu = DisjointSet::new(n);
+            let mut merges = 0;
+            for &(u, v) in &edges {
+                merges += dsu.union(u, v) as usize;
+            }
+            let comp = components(n, &edges);
+            let count = comp.iter().max().unwrap() + 1;
+            assert_eq!(dsu.component_count(), count);
+            assert_eq!(merges, n - count);
+            for _ in 0..50 {
+                let (a, b) = (rng.index(n), rng.index(n));
+                assert_eq!(dsu.same(a, b), comp[a] == comp[b]);
+                let size = comp.iter().filter(|&&c| c == comp[a]).count();
+                assert_eq!(dsu.size_of(a), size);
+            }
+            // BFS 번호도 가장 작은 정점 순서로 붙으므로 groups와 같은 순서
+            let mut expected = vec![Vec::new(); count];
+            for (v, &c) in comp.iter().enumerate() {
+                expected[c].push(v);
+            }
+            assert_eq!(dsu.groups(), expected);
+        }
+    }
+
+    #[test]
+    fn chain_sizes() {
+        let mut dsu = DisjointSet::new(10);
+        assert_eq!(dsu.len(), 10);
+        for i in 1..10 {
+            assert!(dsu.union(i - 1, i));
+            assert_eq!(dsu.size_of(0), i + 1);
+            assert_eq!(dsu.size_of(i), i + 1);
+            assert_eq!(dsu.component_count(), 10 - i);
+        }
+        assert!(!dsu.union(9, 0));
+        assert_eq!(dsu.groups(), vec![(0..10).collect::<Vec<_>>()]);
+        assert!(DisjointSet::new(0).groups().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "index 5 out of range")]
+    fn out_of_range_panics() {
+        DisjointSet::new(5).find(5);
+    }
+
+    #[test]
+    fn million_elements() {
+        let n = 1_000_000;
+        let mut dsu = DisjointSet::new(n);
+        // 크기가 같은 집합끼리 합쳐서 깊이 log n짜리 트리를 만든 뒤 모든 원소를 find
+        let mut step = 1;
+        while step < n {
+            for i in (0..n).step_by(2 * step) {
+                if i + step < n {
+                    dsu.union(i + step, i);
+                }
+            }
+            step *= 2;
+        }
+        assert_eq!(dsu.component_count(), 1);
+        assert_eq!(dsu.size_of(n - 1), n);
+        let root = dsu.find(0);
+        assert!((0..n).all(|v| dsu.find(v) == root));
+        let mut rng = XorShift::new(1260);
+        let mut dsu = DisjointSet::new(n);
+        for _ in 0..n {
+            dsu.union(rng.index(n), rng.index(n));
+        }
+        let total: usize = dsu.groups().iter().map(Vec::len).sum();
+        assert_eq!(total, n);
+    }
+}
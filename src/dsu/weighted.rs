@@ -0,0 +1,257 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::AbelianGroup;
+
+/// [`WeightedDsu::union`]에 이미 알려진 차이와 다른 차이를 넣었을 때의 오류
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inconsistency<W> {
+    /// 이미 알려진 `value[b] - value[a]`
+    pub known: W,
+    /// union으로 넣으려던 `value[b] - value[a]`
+    pub requested: W,
+}
+
+/// # 가중치 Union-Find (potential DSU)
+/// 원소마다 알 수 없는 값 `value[v]`가 있고, "`value[b] - value[a] = w`" 라는 관계를 하나씩 알려줄 때
+/// 같은 집합 안의 임의의 두 원소의 차이를 구합니다. "A는 B보다 w만큼 무겁다" 같은 문제를 바로 풉니다.
+///
+/// * 값의 타입은 [`AbelianGroup`]이면 됩니다. 정수는 덧셈, 새 타입을 만들면 xor 등도 됩니다.
+/// * 원소마다 부모와의 차이 `value[v] - value[parent[v]]`를 저장하고, path compression 때 지나온 차이를 더해서
+/// 대표와의 차이로 바꿉니다. 재귀 없이 두 번 훑어서 처리합니다.
+/// * union by size를 함께 씁니다. 범위를 벗어난 원소를 넘기면 panic합니다.
+/// ## 시간복잡도
+/// 연산마다 amortized O(α(n))
+#[derive(Clone, Debug)]
+pub struct WeightedDsu<W> {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    /// `value[v] - value[parent[v]]`
+    potential: Vec<W>,
+    count: usize,
+}
+
+impl<W: AbelianGroup> WeightedDsu<W> {
+    /// 원소마다 자기 자신만 있는 집합 n개
+    pub fn new(n: usize) -> Self {
+        WeightedDsu {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            potential: vec![W::zero(); n],
+            count: n,
+        }
+    }
+
+    /// 원소 개수
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// 원소가 없는지
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// x가 속한 집합의 대표
+    pub fn find(&mut self, x: usize) -> usize {
+        self.find_with_potential(x).0
+    }
+
+    /// `value[b] - value[a] = w` 를 알려줍니다. 새로 합쳐지면 `Ok(true)`, 이미 같은 집합이고 차이가 w와 같으면
+    /// `Ok(false)`, 차이가 다르면 아무것도 바꾸지 않고 `Err(Inconsistency)`입니다.
+    pub fn union(&mut self, a: usize, b: usize, w: W) -> Result<bool, Inconsistency<W>>
+    where
+        W: PartialEq,
+    {
+        let (ra, pa) = self.find_with_potential(a);
+        let (rb, pb) = self.find_with_potential(b);
+        if ra == rb {
+            let known = W::sub(&pb, &pa);
+            return if known == w {
+                Ok(false)
+            } else {
+                Err(Inconsistency {
+                    known,
+                    requested: w,
+                })
+            };
+        }
+        // value[rb] - value[ra] = w + pa - pb
+        let delta = W::sub(&W::add(&w, &pa), &pb);
+        if self.size[ra] >= self.size[rb] {
+            self.parent[rb] = ra;
+            self.potential[rb] = delta;
+            self.size[ra] += self.size[rb];
+        } else {
+            self.parent[ra] = rb;
+            self.potential[ra] = W::neg(&delta);
+            self.size[rb] += self.size[ra];
+        }
+        self.count -= 1;
+        Ok(true)
+    }
+
+    /// a와 b가 같은 집합이면 `value[b] - value[a]`, 아니면 None
+    pub fn diff(&mut self, a: usize, b: usize) -> Option<W> {
+        let (ra, pa) = self.find_with_potential(a);
+        let (rb, pb) = self.find_with_potential(b);
+        (ra == rb).then(|| W::sub(&pb, &pa))
+    }
+
+    /// a와 b가 같은 집합인지
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// x가 속한 집합의 원소 개수
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// 집합의 개수
+    pub fn component_count(&self) -> usize {
+        self.count
+    }
+
+    /// `(대표, value[x] - value[대표])`
+    fn find_with_potential(&mut self, x: usize) -> (usize, W) {
+        assert!(
+            x < self.len(),
+            "index {x} out of range for {} elements",
+            self.len()
+        );
+        let mut root = x;
+        let mut total = W::zero();
+        while self.parent[root] != root {
+            total = W::add(&total, &self.potential[root]);
+            root = self.parent[root];
+        }
+        // 지나온 원소마다 대표와의 차이 = 남은 차이의 합
+        let mut v = x;
+        let mut rest = total.clone();
+        while self.parent[v] != root {
+            let next = self.parent[v];
+            let own = std::mem::replace(&mut self.potential[v], rest.clone());
+            rest = W::sub(&rest, &own);
+            self.parent[v] = root;
+            v = next;
+        }
+        (root, total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// xor 군: 모든 원소가 자기 자신의 역원
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct Xor(u32);
+
+    impl AbelianGroup for Xor {
+        fn zero() -> Self {
+            Xor(0)
+        }
+
+        fn add(a: &Self, b: &Self) -> Self {
+            Xor(a.0 ^ b.0)
+        }
+
+        fn neg(a: &Self) -> Self {
+            *a
+        }
+    }
+
+    #[test]
+    fn chains_and_contradictions() {
+        let mut dsu: WeightedDsu<i64> = WeightedDsu::new(6);
+        // value = [0, 3, 5, 10, ?, ?]
+        assert_eq!(dsu.union(0, 1, 3), Ok(true));
+        assert_eq!(dsu.union(2, 1, -2), Ok(true));
+        assert_eq!(dsu.union(3, 2, -5), Ok(true));
+        assert_eq!(dsu.diff(0, 3), Some(10));
+        assert_eq!(dsu.diff(3, 0), Some(-10));
+        assert_eq!(dsu.diff(1, 2), Some(2));
+        assert_eq!(dsu.diff(4, 4), Some(0));
+        // 다른 집합
+        assert_eq!(dsu.diff(0, 4), None);
+        assert_eq!(dsu.union(4, 5, 7), Ok(true));
+        assert_eq!(dsu.diff(5, 0), None);
+        // 이미 아는 관계와 같으면 Ok(false), 다르면 오류
+        assert_eq!(dsu.union(3, 0, -10), Ok(false));
+        assert_eq!(
+            dsu.union(0, 3, 11),
+            Err(Inconsistency {
+                known: 10,
+                requested: 11
+            })
+        );
+        assert_eq!(dsu.diff(0, 3), Some(10));
+        // 두 집합을 합치면 양쪽 모두 이어짐: value[4] = 1 → value[5] = 8
+        assert_eq!(dsu.union(2, 4, -4), Ok(true));
+        assert_eq!(dsu.diff(0, 5), Some(8));
+        assert_eq!(dsu.component_count(), 1);
+        assert_eq!(dsu.size_of(5), 6);
+    }
+
+    #[test]
+    fn random_against_hidden_values() {
+        let mut rng = XorShift::new(128);
+        for round in 0..200 {
+            let n = 1 + rng.index(50);
+            let value: Vec<i64> = (0..n).map(|_| rng.range_i64(-1000, 1000)).collect();
+            let mut dsu = WeightedDsu::new(n);
+            // 같은 집합인지는 일반 Union-Find로 확인
+            let mut plain = crate::dsu::DisjointSet::new(n);
+            for _ in 0..3 * n {
+                let (a, b) = (rng.index(n), rng.index(n));
+                let truth = value[b] - value[a];
+                if round % 4 == 0 && rng.below(5) == 0 {
+                    // 틀린 관계: 같은 집합이면 거절되고, 다른 집합이면 그대로 받아들여서 이후 값이 바뀜
+                    let wrong = truth + 1 + rng.range_i64(0, 5);
+                    let result = dsu.union(a, b, wrong);
+                    assert_eq!(result.is_err(), plain.same(a, b));
+                    if result.is_ok() {
+                        plain.union(a, b);
+                        break;
+                    }
+                    continue;
+                }
+                assert_eq!(dsu.union(a, b, truth), Ok(plain.union(a, b)));
+                let (c, d) = (rng.index(n), rng.index(n));
+                let expected = plain.same(c, d).then(|| value[d] - value[c]);
+                assert_eq!(dsu.diff(c, d), expected);
+            }
+            assert_eq!(dsu.component_count(), plain.component_count());
+        }
+    }
+
+    #[test]
+    fn xor_group() {
+        let mut rng = XorShift::new(1280);
+        let n = 300;
+        let value: Vec<u32> = (0..n).map(|_| rng.next_u64() as u32).collect();
+        let mut dsu = WeightedDsu::new(n);
+        for v in 1..n {
+            let u = rng.index(v);
+            assert_eq!(dsu.union(u, v, Xor(value[u] ^ value[v])), Ok(true));
+        }
+        for _ in 0..1000 {
+            let (a, b) = (rng.index(n), rng.index(n));
+            assert_eq!(dsu.diff(a, b), Some(Xor(value[a] ^ value[b])));
+        }
+        assert!(dsu.union(0, 1, Xor(value[0] ^ value[1] ^ 1)).is_err());
+    }
+
+    #[test]
+    fn path_of_unions() {
+        let n = 200_000;
+        let mut dsu: WeightedDsu<i64> = WeightedDsu::new(n);
+        // value[v] = v
+        for v in 1..n {
+            assert_eq!(dsu.union(v - 1, v, 1), Ok(true));
+        }
+        assert_eq!(dsu.diff(0, n - 1), Some(n as i64 - 1));
+        assert_eq!(dsu.diff(n - 1, 12345), Some(12345 - (n as i64 - 1)));
+    }
+}
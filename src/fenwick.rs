@@ -0,0 +1,8 @@
+mod multiset;
+pub use multiset::IndexedMultiset;
+mod point;
+pub use point::Fenwick;
+mod range;
+pub use range::RangeFenwick;
+mod two_d;
+pub use two_d::Fenwick2D;
@@ -0,0 +1,164 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::fenwick::Fenwick;
+
+/// # 순서 통계 Multiset
+/// 미리 정한 값의 집합(universe) 안의 정수를 중복을 허용해서 담고, k번째로 작은 값과 x보다 작은 값의 개수를 구합니다.
+/// "Rust에 order-statistics tree가 있었으면" 하는 자리에 씁니다.
+///
+/// * [`Fenwick`]에 값마다 들어 있는 개수를 저장하고, k번째 값은 [`Fenwick::lower_bound`]로 트리를 내려가며 찾습니다.
+/// * universe는 `new(n)`이면 `0..n`, `with_universe(values)`면 주어진 값들입니다(내부에서 좌표 압축).
+/// * universe 밖의 값을 넣으면 panic합니다. 조회(`count`, `count_less`, `remove_one`)는 아무 값이나 됩니다.
+/// ## 시간복잡도
+/// 연산마다 O(log U) (U = universe 크기), `with_universe`는 O(U log U)
+#[derive(Clone, Debug)]
+pub struct IndexedMultiset {
+    /// 정렬되고 중복 없는 universe
+    keys: Vec<i64>,
+    counts: Fenwick<i64>,
+    len: usize,
+}
+
+impl IndexedMultiset {
+    /// 값 `0..n`을 담을 수 있는 빈 multiset
+    pub fn new(n: usize) -> Self {
+        Self::from_keys((0..n as i64).collect())
+    }
+
+    /// 주어진 값들을 담을 수 있는 빈 multiset. 중복과 순서는 상관없습니다.
+    pub fn with_universe(values: &[i64]) -> Self {
+        let mut keys = values.to_vec();
+        keys.sort_unstable();
+        keys.dedup();
+        Self::from_keys(keys)
+    }
+
+    fn from_keys(keys: Vec<i64>) -> Self {
+        let counts = Fenwick::new(keys.len());
+        IndexedMultiset {
+            keys,
+            counts,
+            len: 0,
+        }
+    }
+
+    /// 들어 있는 원소 개수 (중복 포함)
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 비었는지
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// x를 하나 넣습니다.
+    pub fn insert(&mut self, x: i64) {
+        let i = self
+            .position(x)
+            .unwrap_or_else(|| panic!("value {x} is not in the universe"));
+        self.counts.add(i, 1);
+        self.len += 1;
+    }
+
+    /// x를 하나 뺍니다. x가 없었으면 false입니다.
+    pub fn remove_one(&mut self, x: i64) -> bool {
+        match self.position(x) {
+            Some(i) if self.counts.range_sum(i..i + 1) > 0 => {
+                self.counts.add(i, -1);
+                self.len -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// x의 개수
+    pub fn count(&self, x: i64) -> usize {
+        self.position(x)
+            .map_or(0, |i| self.counts.range_sum(i..i + 1) as usize)
+    }
+
+    /// x보다 작은 원소의 개수
+    pub fn count_less(&self, x: i64) -> usize {
+        self.counts.prefix(self.keys.partition_point(|&k| k < x)) as usize
+    }
+
+    /// k번째(0-based)로 작은 원소. k >= len()이면 None
+    pub fn kth(&self, k: usize) -> Option<i64> {
+        (k < self.len).then(|| self.keys[self.counts.lower_bound(k as i64 + 1)])
+    }
+
+    fn position(&self, x: i64) -> Option<usize> {
+        self.keys.binary_search(&x).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_sorted_vec() {
+        let mut rng = XorShift::new(132);
+        for round in 0..100 {
+            let mut set = if round % 2 == 0 {
+                IndexedMultiset::new(20)
+            } else {
+                let universe: Vec<i64> = (0..15)
+                    .map(|_| rng.range_i64(-1_000_000_000_000, 1_000_000_000_000))
+                    .collect();
+                IndexedMultiset::with_universe(&universe)
+            };
+            let universe = set.keys.clone();
+            let mut model: Vec<i64> = Vec::new();
+            for _ in 0..300 {
+                let x = universe[rng.index(universe.len())];
+                match rng.below(3) {
+                    0 => {
+                        let pos = model.partition_point(|&y| y < x);
+                        model.insert(pos, x);
+                        set.insert(x);
+                    }
+                    1 => {
+                        let removed = model.binary_search(&x).map(|p| model.remove(p));
+                        assert_eq!(set.remove_one(x), removed.is_ok());
+                    }
+                    _ => {
+                        // universe 밖의 값도 조회
+                        let y = x + rng.range_i64(-1, 1);
+                        assert_eq!(set.count(y), model.iter().filter(|&&z| z == y).count());
+                        assert_eq!(set.count_less(y), model.partition_point(|&z| z < y));
+                    }
+                }
+                assert_eq!(set.len(), model.len());
+                let k = rng.index(model.len() + 2);
+                assert_eq!(set.kth(k), model.get(k).copied());
+            }
+        }
+    }
+
+    #[test]
+    fn duplicates() {
+        let mut set = IndexedMultiset::new(10);
+        for x in [3, 3, 3, 7, 0, 7] {
+            set.insert(x);
+        }
+        let all: Vec<i64> = (0..set.len()).map(|k| set.kth(k).unwrap()).collect();
+        assert_eq!(all, vec![0, 3, 3, 3, 7, 7]);
+        assert_eq!(set.count(3), 3);
+        assert_eq!(set.count_less(7), 4);
+        assert!(set.remove_one(3));
+        assert!(!set.remove_one(5));
+        assert!(!set.remove_one(100));
+        assert_eq!(set.kth(3), Some(7));
+        assert_eq!(set.kth(5), None);
+        assert!(IndexedMultiset::new(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "value 10 is not in the universe")]
+    fn insert_outside_universe_panics() {
+        IndexedMultiset::new(10).insert(10);
+    }
+}
@@ -0,0 +1,199 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::AbelianGroup;
+use std::ops::Range;
+
+/// # Fenwick tree (Binary Indexed Tree)
+/// 길이 n인 배열 `a`에 한 점 더하기와 구간 합을 O(log n)에 처리합니다.
+///
+/// * 바깥 API는 모두 0-based이고 구간은 반열림입니다. `prefix(i)`는 `a[0] + … + a[i - 1]`(i개의 합)이고,
+/// `range_sum(l..r)`은 `a[l] + … + a[r - 1]`입니다. 따라서 `prefix(0)`와 빈 구간은 항등원입니다.
+/// * 값의 타입은 [`AbelianGroup`]이면 됩니다(i64, i128, f64, …). 구간 합은 두 prefix의 차이로 구합니다.
+/// * 내부 배열은 1-based이고, `tree[i]`는 `a[i - lowbit(i) .. i]`의 합입니다.
+/// ## 시간복잡도
+/// 생성 O(n), 연산마다 O(log n)
+#[derive(Clone, Debug)]
+pub struct Fenwick<T> {
+    tree: Vec<T>,
+}
+
+impl<T: AbelianGroup> Fenwick<T> {
+    /// 모든 값이 항등원인 길이 n의 배열
+    pub fn new(n: usize) -> Self {
+        Fenwick {
+            tree: vec![T::zero(); n + 1],
+        }
+    }
+
+    /// 주어진 배열로 O(n)에 생성합니다. 각 칸을 자기 구간을 덮는 바로 위 칸에 더해 나갑니다.
+    pub fn from_slice(values: &[T]) -> Self {
+        let n = values.len();
+        let mut tree = Vec::with_capacity(n + 1);
+        tree.push(T::zero());
+        tree.extend_from_slice(values);
+        for i in 1..=n {
+            let j = i + (i & i.wrapping_neg());
+            if j <= n {
+                tree[j] = T::add(&tree[j], &tree[i]);
+            }
+        }
+        Fenwick { tree }
+    }
+
+    /// 배열의 길이
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `a[i] += delta`
+    pub fn add(&mut self, i: usize, delta: T) {
+        let n = self.len();
+        assert!(i < n, "index {i} out of range for length {n}");
+        let mut i = i + 1;
+        while i <= n {
+            self.tree[i] = T::add(&self.tree[i], &delta);
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// 앞에서부터 i개의 합 `a[0] + … + a[i - 1]`. `i == len()`이면 전체 합입니다.
+    pub fn prefix(&self, i: usize) -> T {
+        let n = self.len();
+        assert!(i <= n, "prefix length {i} out of range for length {n}");
+        let mut i = i;
+        let mut sum = T::zero();
+        while i > 0 {
+            sum = T::add(&sum, &self.tree[i]);
+            i &= i - 1;
+        }
+        sum
+    }
+
+    /// 구간 `l..r`의 합. 빈 구간(`l == r`)은 항등원이고, `l > r`이거나 범위를 벗어나면 panic합니다.
+    pub fn range_sum(&self, range: Range<usize>) -> T {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.len(),
+            "invalid range {l}..{r} for length {}",
+            self.len()
+        );
+        T::sub(&self.prefix(r), &self.prefix(l))
+    }
+
+    /// `prefix(i + 1) >= target`인 가장 작은 원소 위치 i. 그런 i가 없으면(전체 합 < target) `len()`입니다.
+    ///
+    /// * 모든 값이 0 이상이어서 prefix가 단조 증가할 때만 의미가 있습니다.
+    /// * `target`이 0 이하면 0입니다.
+    /// * 원소의 개수를 값으로 두면 `lower_bound(k + 1)`이 k번째(0-based)로 작은 원소입니다.
+    /// ## 시간복잡도
+    /// O(log n). 이분 탐색 없이 트리를 위에서부터 내려갑니다.
+    pub fn lower_bound(&self, target: T) -> usize
+    where
+        T: PartialOrd,
+    {
+        let n = self.len();
+        let mut pos = 0;
+        let mut acc = T::zero();
+        let mut step = if n == 0 { 0 } else { 1 << n.ilog2() };
+        while step > 0 {
+            if pos + step <= n {
+                let next = T::add(&acc, &self.tree[pos + step]);
+                if next < target {
+                    pos += step;
+                    acc = next;
+                }
+            }
+            step >>= 1;
+        }
+        pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_updates_against_prefix_array() {
+        let mut rng = XorShift::new(129);
+        for _ in 0..100 {
+            let n = rng.index(60);
+            let mut a: Vec<i64> = (0..n).map(|_| rng.range_i64(-100, 100)).collect();
+            let mut fenwick = if rng.below(2) == 0 {
+                Fenwick::from_slice(&a)
+            } else {
+                let mut f = Fenwick::new(n);
+                for (i, &x) in a.iter().enumerate() {
+                    f.add(i, x);
+                }
+                f
+            };
+            assert_eq!(fenwick.len(), n);
+            for _ in 0..100 {
+                if n > 0 && rng.below(2) == 0 {
+                    let i = rng.index(n);
+                    let delta = rng.range_i64(-100, 100);
+                    a[i] += delta;
+                    fenwick.add(i, delta);
+                }
+                let l = rng.index(n + 1);
+                let r = l + rng.index(n + 1 - l);
+                assert_eq!(fenwick.range_sum(l..r), a[l..r].iter().sum::<i64>());
+                assert_eq!(fenwick.prefix(r), a[..r].iter().sum::<i64>());
+            }
+        }
+    }
+
+    #[test]
+    fn lower_bound_against_linear_scan() {
+        let mut rng = XorShift::new(1290);
+        for _ in 0..100 {
+            let n = rng.index(50);
+            let a: Vec<i128> = (0..n).map(|_| rng.below(4) as i128).collect();
+            let fenwick = Fenwick::from_slice(&a);
+            let total: i128 = a.iter().sum();
+            for target in -1..=total + 1 {
+                let mut sum = 0;
+                let expected = (0..n)
+                    .find(|&i| {
+                        sum += a[i];
+                        sum >= target
+                    })
+                    .unwrap_or(n);
+                assert_eq!(fenwick.lower_bound(target), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn floating_point_values() {
+        let a = [0.5, 1.25, 2.0, 0.25];
+        let mut fenwick = Fenwick::from_slice(&a);
+        assert_eq!(fenwick.prefix(4), 4.0);
+        assert_eq!(fenwick.range_sum(1..3), 3.25);
+        fenwick.add(2, -1.5);
+        assert_eq!(fenwick.range_sum(2..4), 0.75);
+        assert_eq!(fenwick.lower_bound(1.8), 2);
+        assert_eq!(fenwick.lower_bound(10.0), 4);
+    }
+
+    #[test]
+    fn empty_tree() {
+        let fenwick: Fenwick<i64> = Fenwick::new(0);
+        assert!(fenwick.is_empty());
+        assert_eq!(fenwick.prefix(0), 0);
+        assert_eq!(fenwick.range_sum(0..0), 0);
+        assert_eq!(fenwick.lower_bound(1), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of range")]
+    fn add_out_of_range_panics() {
+        Fenwick::<i64>::new(3).add(3, 1);
+    }
+}
@@ -0,0 +1,178 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::AbelianGroup;
+use crate::fenwick::Fenwick;
+use std::ops::{Mul, Range};
+
+/// # 구간 더하기 / 구간 합 Fenwick tree
+/// 길이 n인 배열 `a`에 "구간 `l..r`에 delta 더하기"와 "구간 `l..r`의 합"을 O(log n)에 처리합니다.
+/// lazy segment tree 없이 풀 수 있는 가장 흔한 구간 갱신 문제용입니다.
+///
+/// * API는 [`Fenwick`]과 같은 0-based 반열림 구간입니다. `prefix(i)`는 `a[0] + … + a[i - 1]`입니다.
+/// * Fenwick 두 개(b1, b2)를 둡니다. 구간 `l..r`에 d를 더하면 b1의 l, r에 d, -d를, b2의 l, r에 d·l, -d·r을 더하고,
+/// `prefix(i) = b1.prefix(i) · i - b2.prefix(i)`입니다.
+/// * 값의 타입은 위치와 곱할 수 있는 정수 타입(i64, i128, …)입니다.
+///
+/// > b2에는 `delta × 위치`가 쌓이므로, 실제 합이 i64 안이어도 중간값은 최대 n배까지 커질 수 있습니다.
+/// 값이 크면 `RangeFenwick<i128>`을 사용합니다.
+/// ## 시간복잡도
+/// 생성 O(n), 연산마다 O(log n)
+#[derive(Clone, Debug)]
+pub struct RangeFenwick<T = i64> {
+    b1: Fenwick<T>,
+    b2: Fenwick<T>,
+}
+
+impl<T> RangeFenwick<T>
+where
+    T: AbelianGroup + Mul<Output = T> + TryFrom<usize>,
+{
+    /// 모든 값이 0인 길이 n의 배열
+    pub fn new(n: usize) -> Self {
+        RangeFenwick {
+            b1: Fenwick::new(n),
+            b2: Fenwick::new(n),
+        }
+    }
+
+    /// 주어진 배열로 O(n)에 생성합니다.
+    pub fn from_slice(values: &[T]) -> Self {
+        // 차분 배열 d[i] = a[i] - a[i - 1] 로 b1, d[i]·i 로 b2를 만듭니다.
+        let diff: Vec<T> = (0..values.len())
+            .map(|i| match i {
+                0 => values[0].clone(),
+                _ => T::sub(&values[i], &values[i - 1]),
+            })
+            .collect();
+        let weighted: Vec<T> = diff
+            .iter()
+            .enumerate()
+            .map(|(i, d)| d.clone() * Self::index(i))
+            .collect();
+        RangeFenwick {
+            b1: Fenwick::from_slice(&diff),
+            b2: Fenwick::from_slice(&weighted),
+        }
+    }
+
+    /// 배열의 길이
+    pub fn len(&self) -> usize {
+        self.b1.len()
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 구간 `l..r`의 모든 원소에 delta를 더합니다. 빈 구간이면 아무 일도 없습니다.
+    pub fn add_range(&mut self, range: Range<usize>, delta: T) {
+        let Range { start: l, end: r } = range;
+        let n = self.len();
+        assert!(l <= r && r <= n, "invalid range {l}..{r} for length {n}");
+        if l == r {
+            return;
+        }
+        self.b1.add(l, delta.clone());
+        self.b2.add(l, delta.clone() * Self::index(l));
+        if r < n {
+            self.b1.add(r, T::neg(&delta));
+            self.b2.add(r, T::neg(&delta) * Self::index(r));
+        }
+    }
+
+    /// `a[i]`
+    pub fn get(&self, i: usize) -> T {
+        assert!(
+            i < self.len(),
+            "index {i} out of range for length {}",
+            self.len()
+        );
+        self.range_sum(i..i + 1)
+    }
+
+    /// 앞에서부터 i개의 합 `a[0] + … + a[i - 1]`
+    pub fn prefix(&self, i: usize) -> T {
+        T::sub(&(self.b1.prefix(i) * Self::index(i)), &self.b2.prefix(i))
+    }
+
+    /// 구간 `l..r`의 합. 빈 구간은 0이고, `l > r`이거나 범위를 벗어나면 panic합니다.
+    pub fn range_sum(&self, range: Range<usize>) -> T {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.len(),
+            "invalid range {l}..{r} for length {}",
+            self.len()
+        );
+        T::sub(&self.prefix(r), &self.prefix(l))
+    }
+
+    fn index(i: usize) -> T {
+        T::try_from(i)
+            .ok()
+            .expect("index does not fit in the value type")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_plain_vec() {
+        let mut rng = XorShift::new(130);
+        for round in 0..30 {
+            let n = if round < 25 {
+                rng.index(30)
+            } else {
+                1000 + rng.index(3000)
+            };
+            let mut a: Vec<i64> = (0..n).map(|_| rng.range_i64(-1000, 1000)).collect();
+            let mut fenwick = if round % 2 == 0 {
+                RangeFenwick::from_slice(&a)
+            } else {
+                let mut f = RangeFenwick::new(n);
+                for (i, &x) in a.iter().enumerate() {
+                    f.add_range(i..i + 1, x);
+                }
+                f
+            };
+            for _ in 0..300 {
+                let l = rng.index(n + 1);
+                let r = l + rng.index(n + 1 - l);
+                if rng.below(2) == 0 {
+                    let delta = rng.range_i64(-1_000_000, 1_000_000);
+                    fenwick.add_range(l..r, delta);
+                    for x in &mut a[l..r] {
+                        *x += delta;
+                    }
+                } else {
+                    assert_eq!(fenwick.range_sum(l..r), a[l..r].iter().sum::<i64>());
+                }
+            }
+            // 전체 구간
+            fenwick.add_range(0..n, -7);
+            for x in &mut a {
+                *x -= 7;
+            }
+            assert_eq!(fenwick.range_sum(0..n), a.iter().sum::<i64>());
+            for (i, &x) in a.iter().enumerate() {
+                assert_eq!(fenwick.get(i), x);
+            }
+        }
+    }
+
+    #[test]
+    fn wide_accumulation_with_i128() {
+        // 합은 i64를 넘지만 i128로는 정확
+        let n = 1 << 20;
+        let mut fenwick: RangeFenwick<i128> = RangeFenwick::new(n);
+        let big = i64::MAX as i128;
+        fenwick.add_range(0..n, big);
+        fenwick.add_range(n / 2..n, -big);
+        assert_eq!(fenwick.range_sum(0..n), big * (n / 2) as i128);
+        assert_eq!(fenwick.prefix(1), big);
+        assert_eq!(fenwick.get(n - 1), 0);
+        assert!(RangeFenwick::<i64>::new(0).is_empty());
+    }
+}
@@ -0,0 +1,195 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::AbelianGroup;
+use std::ops::Range;
+
+/// # 2차원 Fenwick tree
+/// rows × cols 격자에 한 칸 더하기와 직사각형 합을 O(log rows · log cols)에 처리합니다.
+///
+/// * 구간은 [`Fenwick`](crate::fenwick::Fenwick)과 같은 0-based 반열림입니다.
+/// `sum_rect(r1..r2, c1..c2)`는 `r1 <= r < r2`, `c1 <= c < c2`인 칸의 합입니다.
+/// * 내부 배열은 `(rows + 1) × (cols + 1)` 크기의 1-based 배열 하나입니다. 2000 × 2000 격자도 바로 만들 수 있습니다.
+/// ## 시간복잡도
+/// 생성 O(rows · cols), 연산마다 O(log rows · log cols)
+#[derive(Clone, Debug)]
+pub struct Fenwick2D<T> {
+    rows: usize,
+    cols: usize,
+    tree: Vec<T>,
+}
+
+impl<T: AbelianGroup> Fenwick2D<T> {
+    /// 모든 칸이 항등원인 rows × cols 격자
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Fenwick2D {
+            rows,
+            cols,
+            tree: vec![T::zero(); (rows + 1) * (cols + 1)],
+        }
+    }
+
+    /// 행렬로 O(rows · cols)에 생성합니다. 모든 행의 길이가 같아야 합니다.
+    /// 행마다 1차원과 같은 방법으로 만든 뒤, 열 방향으로 한 번 더 같은 방법을 적용합니다.
+    pub fn from_matrix(matrix: &[Vec<T>]) -> Self {
+        let rows = matrix.len();
+        let cols = matrix.first().map_or(0, Vec::len);
+        let mut fenwick = Self::new(rows, cols);
+        let w = cols + 1;
+        for (r, row) in matrix.iter().enumerate() {
+            assert_eq!(row.len(), cols, "matrix rows must have the same length");
+            fenwick.tree[(r + 1) * w + 1..(r + 2) * w].clone_from_slice(row);
+        }
+        let tree = &mut fenwick.tree;
+        for i in 1..=rows {
+            for j in 1..=cols {
+                let nj = j + (j & j.wrapping_neg());
+                if nj <= cols {
+                    tree[i * w + nj] = T::add(&tree[i * w + nj], &tree[i * w + j]);
+                }
+            }
+        }
+        for i in 1..=rows {
+            let ni = i + (i & i.wrapping_neg());
+            if ni <= rows {
+                for j in 1..=cols {
+                    tree[ni * w + j] = T::add(&tree[ni * w + j], &tree[i * w + j]);
+                }
+            }
+        }
+        fenwick
+    }
+
+    /// 행의 개수
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 열의 개수
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// `a[r][c] += delta`
+    pub fn add(&mut self, r: usize, c: usize, delta: T) {
+        assert!(
+            r < self.rows && c < self.cols,
+            "cell ({r}, {c}) out of range for {}x{} grid",
+            self.rows,
+            self.cols
+        );
+        let w = self.cols + 1;
+        let mut i = r + 1;
+        while i <= self.rows {
+            let mut j = c + 1;
+            while j <= self.cols {
+                self.tree[i * w + j] = T::add(&self.tree[i * w + j], &delta);
+                j += j & j.wrapping_neg();
+            }
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// 위쪽 r개 행, 왼쪽 c개 열의 합 (`sum_rect(0..r, 0..c)`)
+    pub fn prefix(&self, r: usize, c: usize) -> T {
+        assert!(
+            r <= self.rows && c <= self.cols,
+            "prefix ({r}, {c}) out of range for {}x{} grid",
+            self.rows,
+            self.cols
+        );
+        let w = self.cols + 1;
+        let mut sum = T::zero();
+        let mut i = r;
+        while i > 0 {
+            let mut j = c;
+            while j > 0 {
+                sum = T::add(&sum, &self.tree[i * w + j]);
+                j &= j - 1;
+            }
+            i &= i - 1;
+        }
+        sum
+    }
+
+    /// 직사각형 `rows × cols`의 합. 빈 구간이면 항등원이고, 뒤집힌 구간이나 범위를 벗어나면 panic합니다.
+    pub fn sum_rect(&self, rows: Range<usize>, cols: Range<usize>) -> T {
+        let Range { start: r1, end: r2 } = rows;
+        let Range { start: c1, end: c2 } = cols;
+        assert!(
+            r1 <= r2 && c1 <= c2,
+            "invalid rectangle {r1}..{r2} x {c1}..{c2}"
+        );
+        let outer = T::add(&self.prefix(r2, c2), &self.prefix(r1, c1));
+        let sides = T::add(&self.prefix(r1, c2), &self.prefix(r2, c1));
+        T::sub(&outer, &sides)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 매번 새로 계산하는 2차원 prefix 합
+    fn prefix_sums(a: &[Vec<i64>]) -> Vec<Vec<i64>> {
+        let rows = a.len();
+        let cols = a.first().map_or(0, Vec::len);
+        let mut p = vec![vec![0; cols + 1]; rows + 1];
+        for r in 0..rows {
+            for c in 0..cols {
+                p[r + 1][c + 1] = a[r][c] + p[r][c + 1] + p[r + 1][c] - p[r][c];
+            }
+        }
+        p
+    }
+
+    #[test]
+    fn random_against_prefix_sums() {
+        let mut rng = XorShift::new(131);
+        for round in 0..60 {
+            let rows = 1 + rng.index(15);
+            let cols = 1 + rng.index(15);
+            let mut a: Vec<Vec<i64>> = (0..rows)
+                .map(|_| (0..cols).map(|_| rng.range_i64(-50, 50)).collect())
+                .collect();
+            let mut fenwick = if round % 2 == 0 {
+                Fenwick2D::from_matrix(&a)
+            } else {
+                let mut f = Fenwick2D::new(rows, cols);
+                for (r, row) in a.iter().enumerate() {
+                    for (c, &x) in row.iter().enumerate() {
+                        f.add(r, c, x);
+                    }
+                }
+                f
+            };
+            for _ in 0..50 {
+                let (r, c) = (rng.index(rows), rng.index(cols));
+                let delta = rng.range_i64(-50, 50);
+                a[r][c] += delta;
+                fenwick.add(r, c, delta);
+                let p = prefix_sums(&a);
+                let r1 = rng.index(rows + 1);
+                let r2 = r1 + rng.index(rows + 1 - r1);
+                let c1 = rng.index(cols + 1);
+                let c2 = c1 + rng.index(cols + 1 - c1);
+                let expected = p[r2][c2] - p[r1][c2] - p[r2][c1] + p[r1][c1];
+                assert_eq!(fenwick.sum_rect(r1..r2, c1..c2), expected);
+                // 전체와 한 칸
+                assert_eq!(fenwick.sum_rect(0..rows, 0..cols), p[rows][cols]);
+                assert_eq!(fenwick.sum_rect(r..r + 1, c..c + 1), a[r][c]);
+            }
+        }
+    }
+
+    #[test]
+    fn large_grid() {
+        let (rows, cols) = (2000, 2000);
+        let matrix = vec![vec![1i64; cols]; rows];
+        let mut fenwick = Fenwick2D::from_matrix(&matrix);
+        assert_eq!(fenwick.sum_rect(0..rows, 0..cols), 4_000_000);
+        fenwick.add(1999, 1999, -1);
+        assert_eq!(fenwick.sum_rect(1000..2000, 1000..2000), 999_999);
+        assert_eq!(fenwick.sum_rect(5..5, 0..cols), 0);
+        assert_eq!((fenwick.rows(), fenwick.cols()), (rows, cols));
+    }
+}
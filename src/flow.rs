@@ -0,0 +1,4 @@
+mod dinic;
+pub use dinic::{EdgeId, MaxFlow};
+mod min_cost;
+pub use min_cost::MinCostFlow;
@@ -0,0 +1,350 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::flow::EdgeId;
+use crate::heap::{Heap, MinHeap};
+
+/// # MinCostFlow
+/// 간선마다 용량과 단위 비용이 있는 방향 그래프에서, 정해진 양까지의 유량을 최소 비용으로 흘립니다.
+///
+/// * successive shortest path: 잔여 그래프에서 비용이 가장 싼 s-t 경로로 유량을 늘리는 과정을 반복합니다.
+/// * Johnson potential로 잔여 간선의 비용을 0 이상으로 바꾸고, 최단 경로는 crate의 [`MinHeap`]을 쓰는 Dijkstra로 구합니다.
+/// * 원래 간선의 비용은 음수여도 됩니다. 처음 한 번 Bellman-Ford로 potential을 초기화합니다.
+/// 단 s에서 도달 가능한 음수 사이클이 있으면 panic합니다.
+/// ## 시간복잡도
+/// 증가 경로 하나마다 O(E log E)이고, 증가 횟수는 최대 유량 이하입니다.
+/// > 비용의 합(유량 × 경로 비용)은 i64 범위 안이어야 합니다.
+pub struct MinCostFlow {
+    graph: Vec<Vec<usize>>,
+    // 간선 2k는 k번째로 추가한 정방향 간선, 2k+1은 그 역방향 간선
+    to: Vec<usize>,
+    cap: Vec<i64>,
+    cost: Vec<i64>,
+}
+
+impl MinCostFlow {
+    /// 정점 n개, 간선이 없는 그래프 생성
+    pub fn new(n: usize) -> Self {
+        MinCostFlow {
+            graph: vec![Vec::new(); n],
+            to: Vec::new(),
+            cap: Vec::new(),
+            cost: Vec::new(),
+        }
+    }
+
+    /// 정점 개수
+    pub fn len(&self) -> usize {
+        self.graph.len()
+    }
+
+    /// 정점이 하나도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    /// u → v 방향, 용량 cap, 단위 비용 cost인 간선 추가
+    pub fn add_edge(&mut self, u: usize, v: usize, cap: i64, cost: i64) -> EdgeId {
+        let n = self.len();
+        assert!(
+            u < n && v < n,
+            "edge ({u}, {v}) out of range for {n} vertices"
+        );
+        assert!(cap >= 0, "capacity must be non-negative, got {cap}");
+        let id = self.to.len();
+        self.graph[u].push(id);
+        self.to.push(v);
+        self.cap.push(cap);
+        self.cost.push(cost);
+        self.graph[v].push(id + 1);
+        self.to.push(u);
+        self.cap.push(0);
+        self.cost.push(-cost);
+        EdgeId::from_index(id / 2)
+    }
+
+    /// 간선에 현재 흐르는 유량
+    pub fn edge_flow(&self, id: EdgeId) -> i64 {
+        self.cap[2 * id.index() + 1]
+    }
+
+    /// s에서 t로 최대 `flow_limit`만큼 흘렸을 때의 `(유량, 최소 비용)`
+    pub fn flow(&mut self, s: usize, t: usize, flow_limit: i64) -> (i64, i64) {
+        *self.min_cost_flow(s, t, flow_limit).last().unwrap()
+    }
+
+    /// s에서 t로 최대 `flow_limit`만큼 흘리면서, 유량에 따른 최소 비용 함수의 꺾이는 점들을 반환합니다.
+    ///
+    /// * 결과는 `(0, 0)`에서 시작하고, 마지막 원소가 최종 `(유량, 비용)`입니다.
+    /// * 최소 비용은 유량에 대해 볼록한 구간별 일차함수이므로, 점 사이의 유량에 대한 비용은 선형 보간으로 구할 수 있습니다.
+    /// * 기울기가 같은 연속 구간은 하나로 합칩니다.
+    pub fn min_cost_flow(&mut self, s: usize, t: usize, flow_limit: i64) -> Vec<(i64, i64)> {
+        let n = self.len();
+        assert!(s < n && t < n, "source {s} or sink {t} out of range");
+        assert_ne!(s, t, "source and sink must differ");
+        let mut potential = self.bellman_ford(s);
+        let mut frontier = vec![(0i64, 0i64)];
+        let (mut flow, mut total_cost) = (0i64, 0i64);
+        let mut prev_edge = vec![usize::MAX; n];
+        let mut dist = vec![i64::MAX; n];
+
+        while flow < flow_limit {
+            if !self.dijkstra(s, t, &potential, &mut dist, &mut prev_edge) {
+                break;
+            }
+            for v in 0..n {
+                if dist[v] != i64::MAX {
+                    potential[v] += dist[v];
+                }
+            }
+            // 경로의 병목 용량과 실제 비용
+            let mut push = flow_limit - flow;
+            let mut path_cost = 0;
+            let mut v = t;
+            while v != s {
+                let e = prev_edge[v];
+                push = push.min(self.cap[e]);
+                path_cost += self.cost[e];
+                v = self.to[e ^ 1];
+            }
+            let mut v = t;
+            while v != s {
+                let e = prev_edge[v];
+                self.cap[e] -= push;
+                self.cap[e ^ 1] += push;
+                v = self.to[e ^ 1];
+            }
+            flow += push;
+            total_cost += push * path_cost;
+
+            // 직전 구간과 기울기가 같으면 합침
+            if frontier.len() >= 2 {
+                let (f1, c1) = frontier[frontier.len() - 2];
+                let (f2, c2) = frontier[frontier.len() - 1];
+                if (c2 - c1) as i128 * (flow - f2) as i128
+                    == (total_cost - c2) as i128 * (f2 - f1) as i128
+                {
+                    frontier.pop();
+                }
+            }
+            frontier.push((flow, total_cost));
+        }
+        frontier
+    }
+
+    /// 비용이 음수인 간선이 있어도 되도록 Bellman-Ford로 초기 potential 계산.
+    /// s에서 도달할 수 없는 정점의 potential은 0으로 둡니다.
+    fn bellman_ford(&self, s: usize) -> Vec<i64> {
+        let n = self.len();
+        let mut dist = vec![i64::MAX; n];
+        dist[s] = 0;
+        for round in 0..n {
+            let mut updated = false;
+            for u in 0..n {
+                if dist[u] == i64::MAX {
+                    continue;
+                }
+                for &e in &self.graph[u] {
+                    let v = self.to[e];
+                    if self.cap[e] > 0 && dist[u] + self.cost[e] < dist[v] {
+                        dist[v] = dist[u] + self.cost[e];
+                        updated = true;
+                    }
+                }
+            }
+            if !updated {
+                break;
+            }
+            assert!(round + 1 < n, "negative cycle reachable from source");
+        }
+        dist.into_iter()
+            .map(|d| if d == i64::MAX { 0 } else { d })
+            .collect()
+    }
+
+    /// potential으로 보정한 비용(항상 0 이상)으로 Dijkstra. t에 도달했는지 반환
+    fn dijkstra(
+        &self,
+        s: usize,
+        t: usize,
+        potential: &[i64],
+        dist: &mut [i64],
+        prev_edge: &mut [usize],
+    ) -> bool {
+        dist.iter_mut().for_each(|d| *d = i64::MAX);
+        dist[s] = 0;
+        let mut heap: MinHeap<(i64, usize)> = MinHeap::new();
+        heap.push((0, s));
+        while let Some((d, u)) = heap.pop() {
+            if d > dist[u] {
+                continue;
+            }
+            for &e in &self.graph[u] {
+                if self.cap[e] == 0 {
+                    continue;
+                }
+                let v = self.to[e];
+                let nd = d + self.cost[e] + potential[u] - potential[v];
+                if nd < dist[v] {
+                    dist[v] = nd;
+                    prev_edge[v] = e;
+                    heap.push((nd, v));
+                }
+            }
+        }
+        dist[t] != i64::MAX
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::hungarian;
+    use crate::random::XorShift;
+
+    /// 각 간선의 유량을 0..=cap으로 모두 시도해서, limit 이하 최대 유량의 최소 비용을 구함
+    fn brute_force(
+        n: usize,
+        edges: &[(usize, usize, i64, i64)],
+        s: usize,
+        t: usize,
+        limit: i64,
+    ) -> (i64, i64) {
+        let mut best = (0i64, 0i64);
+        let mut flows = vec![0i64; edges.len()];
+        loop {
+            let mut balance = vec![0i64; n];
+            let mut cost = 0;
+            for (&(u, v, _, c), &f) in edges.iter().zip(&flows) {
+                balance[u] -= f;
+                balance[v] += f;
+                cost += f * c;
+            }
+            let value = balance[t];
+            let conserved = (0..n).all(|v| v == s || v == t || balance[v] == 0);
+            let better = value > best.0 || (value == best.0 && cost < best.1);
+            if conserved && (0..=limit).contains(&value) && better {
+                best = (value, cost);
+            }
+            // 다음 조합
+            let mut i = 0;
+            while i < edges.len() && flows[i] == edges[i].2 {
+                flows[i] = 0;
+                i += 1;
+            }
+            if i == edges.len() {
+                break;
+            }
+            flows[i] += 1;
+        }
+        best
+    }
+
+    #[test]
+    fn small_known_network() {
+        let mut mcf = MinCostFlow::new(4);
+        let e01 = mcf.add_edge(0, 1, 2, 1);
+        mcf.add_edge(0, 2, 1, 2);
+        mcf.add_edge(1, 2, 1, 1);
+        mcf.add_edge(1, 3, 1, 3);
+        mcf.add_edge(2, 3, 2, 1);
+        let frontier = mcf.min_cost_flow(0, 3, 10);
+        // 경로 비용 3 (0-1-2-3), 3 (0-2-3), 4 (0-1-3)
+        assert_eq!(frontier, vec![(0, 0), (2, 6), (3, 10)]);
+        assert_eq!(mcf.edge_flow(e01), 2);
+    }
+
+    #[test]
+    fn flow_limit_smaller_than_max_flow() {
+        let mut mcf = MinCostFlow::new(3);
+        mcf.add_edge(0, 1, 5, 2);
+        mcf.add_edge(1, 2, 5, 3);
+        mcf.add_edge(0, 2, 5, 10);
+        assert_eq!(mcf.flow(0, 2, 3), (3, 15));
+
+        let mut mcf = MinCostFlow::new(3);
+        mcf.add_edge(0, 1, 5, 2);
+        mcf.add_edge(1, 2, 5, 3);
+        mcf.add_edge(0, 2, 5, 10);
+        assert_eq!(mcf.min_cost_flow(0, 2, 7), vec![(0, 0), (5, 25), (7, 45)]);
+
+        // 도달 불가
+        let mut mcf = MinCostFlow::new(3);
+        mcf.add_edge(0, 1, 5, 2);
+        assert_eq!(mcf.flow(0, 2, 3), (0, 0));
+    }
+
+    #[test]
+    fn negative_costs() {
+        let edges = [
+            (0, 1, 1, -5),
+            (0, 2, 1, 1),
+            (1, 3, 1, 2),
+            (2, 3, 1, -4),
+            (1, 2, 1, -1),
+        ];
+        let mut mcf = MinCostFlow::new(4);
+        for &(u, v, cap, cost) in &edges {
+            mcf.add_edge(u, v, cap, cost);
+        }
+        // 0-1-2-3 (비용 -10) 하나만 흘리는 것보다 0-1-3, 0-2-3 두 개가 더 많은 유량
+        assert_eq!(mcf.flow(0, 3, 2), (2, -6));
+        assert_eq!(brute_force(4, &edges, 0, 3, 2), (2, -6));
+    }
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(107);
+        for _ in 0..300 {
+            let n = 2 + rng.index(4);
+            let m = 1 + rng.index(6);
+            // 음수 사이클이 없도록 u < v인 간선만 사용
+            let edges: Vec<(usize, usize, i64, i64)> = (0..m)
+                .map(|_| {
+                    let u = rng.index(n - 1);
+                    let v = u + 1 + rng.index(n - 1 - u);
+                    (u, v, rng.range_i64(0, 2), rng.range_i64(-5, 10))
+                })
+                .collect();
+            let limit = rng.range_i64(0, 5);
+            let mut mcf = MinCostFlow::new(n);
+            for &(u, v, cap, cost) in &edges {
+                mcf.add_edge(u, v, cap, cost);
+            }
+            let frontier = mcf.min_cost_flow(0, n - 1, limit);
+            assert_eq!(
+                *frontier.last().unwrap(),
+                brute_force(n, &edges, 0, n - 1, limit)
+            );
+            // 기울기는 증가해야 함 (볼록)
+            for w in frontier.windows(3) {
+                let s1 = (w[1].1 - w[0].1) as i128 * (w[2].0 - w[1].0) as i128;
+                let s2 = (w[2].1 - w[1].1) as i128 * (w[1].0 - w[0].0) as i128;
+                assert!(s1 < s2);
+            }
+        }
+    }
+
+    #[test]
+    fn assignment_equals_hungarian() {
+        let mut rng = XorShift::new(1070);
+        for _ in 0..50 {
+            let n = 1 + rng.index(10);
+            let m = n + rng.index(5);
+            let cost: Vec<Vec<i64>> = (0..n)
+                .map(|_| (0..m).map(|_| rng.range_i64(-100, 100)).collect())
+                .collect();
+            let s = n + m;
+            let t = s + 1;
+            let mut mcf = MinCostFlow::new(t + 1);
+            for (i, row) in cost.iter().enumerate() {
+                mcf.add_edge(s, i, 1, 0);
+                for (j, &c) in row.iter().enumerate() {
+                    mcf.add_edge(i, n + j, 1, c);
+                }
+            }
+            for j in 0..m {
+                mcf.add_edge(n + j, t, 1, 0);
+            }
+            assert_eq!(mcf.flow(s, t, n as i64), (n as i64, hungarian(&cost).0));
+        }
+    }
+}
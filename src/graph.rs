@@ -0,0 +1,102 @@
+#![allow(clippy::doc_lazy_continuation)]
+mod biconnected;
+pub use biconnected::{BlockCutTree, articulation_points, block_cut_tree, bridges};
+mod bipartite;
+pub use bipartite::bipartite_coloring;
+mod dag_paths;
+pub use dag_paths::{DagPaths, dag_longest_paths, dag_shortest_paths};
+mod eulerian;
+pub use eulerian::{eulerian_path, eulerian_path_edges};
+mod functional;
+pub use functional::{FgComponents, FunctionalGraph, PathFold, functional_graph_components};
+mod hungarian;
+pub use hungarian::{hungarian, hungarian_maximize};
+mod k_shortest;
+pub use k_shortest::k_shortest_paths;
+mod matching;
+pub use matching::{Matching, hopcroft_karp, maximum_independent_set, minimum_vertex_cover};
+mod scc;
+pub use scc::strongly_connected_components;
+mod stoer_wagner;
+pub use stoer_wagner::global_min_cut;
+mod topo;
+pub use topo::topological_sort;
+mod tsp;
+pub use tsp::{NO_EDGE, tsp, tsp_path};
+mod two_sat;
+pub use two_sat::TwoSat;
+
+/// # Graph
+/// 정점 0..n, 무방향 간선으로 이루어진 그래프 (인접 리스트).
+///
+/// * 간선에는 추가된 순서대로 0부터 id가 붙습니다. 중복 간선(multi edge)과 self-loop를 모두 허용합니다.
+/// * 인접 리스트의 원소는 `(이웃 정점, 간선 id)` 입니다. 간선 하나는 양 끝점의 리스트에 한 번씩 들어가므로,
+/// self-loop `(v, v)`는 `v`의 리스트에 두 번 들어갑니다.
+#[derive(Clone, Debug)]
+pub struct Graph {
+    adj: Vec<Vec<(usize, usize)>>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl Graph {
+    /// 간선이 없는 정점 n개짜리 그래프 생성
+    pub fn new(n: usize) -> Self {
+        Graph {
+            adj: vec![Vec::new(); n],
+            edges: Vec::new(),
+        }
+    }
+
+    /// 간선 목록으로 그래프 생성
+    pub fn from_edges(n: usize, edges: &[(usize, usize)]) -> Self {
+        let mut graph = Self::new(n);
+        for &(u, v) in edges {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    /// 무방향 간선 추가. 추가된 간선의 id를 반환합니다.
+    pub fn add_edge(&mut self, u: usize, v: usize) -> usize {
+        let n = self.len();
+        assert!(
+            u < n && v < n,
+            "edge ({u}, {v}) out of range for {n} vertices"
+        );
+        let id = self.edges.len();
+        self.edges.push((u, v));
+        self.adj[u].push((v, id));
+        self.adj[v].push((u, id));
+        id
+    }
+
+    /// 정점 개수
+    pub fn len(&self) -> usize {
+        self.adj.len()
+    }
+
+    /// 정점이 하나도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.adj.is_empty()
+    }
+
+    /// 간선 개수
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// 추가된 순서대로의 간선 목록
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// v에 붙은 `(이웃 정점, 간선 id)` 목록
+    pub fn adjacent(&self, v: usize) -> &[(usize, usize)] {
+        &self.adj[v]
+    }
+
+    /// v의 이웃 정점들
+    pub fn neighbors(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adj[v].iter().map(|&(to, _)| to)
+    }
+}
@@ -0,0 +1,384 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::graph::Graph;
+
+/// # 단절점 (articulation point)
+/// 제거하면 연결 요소의 수가 늘어나는 정점들을 오름차순으로 반환합니다.
+///
+/// DFS 트리에서 자식 c의 low(c)(c의 서브트리에서 back edge 하나로 갈 수 있는 가장 작은 방문 번호)가
+/// 부모 v의 방문 번호 이상이면 v는 단절점입니다. 단 DFS 루트는 자식이 둘 이상일 때만 단절점입니다.
+/// ## 시간복잡도
+/// O(V + E)
+pub fn articulation_points(graph: &Graph) -> Vec<usize> {
+    let dfs = low_link(graph);
+    (0..graph.len()).filter(|&v| dfs.articulation[v]).collect()
+}
+
+/// # 단절선 (bridge)
+/// 제거하면 연결 요소의 수가 늘어나는 간선들의 id를 오름차순으로 반환합니다.
+/// 간선 id로 부모 간선만 건너뛰므로, 중복 간선은 서로의 우회로가 되어 단절선이 아닙니다.
+/// ## 시간복잡도
+/// O(V + E)
+pub fn bridges(graph: &Graph) -> Vec<usize> {
+    let dfs = low_link(graph);
+    (0..graph.edge_count()).filter(|&e| dfs.bridge[e]).collect()
+}
+
+/// # Block-cut tree
+/// [`block_cut_tree`]의 결과입니다.
+/// * `blocks[b]`: b번째 이중 연결 요소(block)에 속한 간선 id (오름차순)
+/// * `block_vertices[b]`: b번째 block의 정점 (오름차순)
+/// * `is_cut[v]`: v가 단절점인지. 단절점은 두 개 이상의 block에 속하는 정점과 같습니다.
+/// * `tree`: block-cut tree의 인접 리스트. 노드 `0..blocks.len()`은 block이고,
+/// 그 뒤의 노드는 단절점 하나씩입니다. 간선은 block과 그 block에 속한 단절점 사이에만 있으므로 이분 그래프이고,
+/// 원래 그래프의 연결 요소마다 트리 하나가 됩니다.
+/// * `node_of[v]`: 정점 v를 나타내는 트리 노드. 단절점이면 자기 노드, 아니면 v가 속한 유일한 block입니다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockCutTree {
+    pub blocks: Vec<Vec<usize>>,
+    pub block_vertices: Vec<Vec<usize>>,
+    pub is_cut: Vec<bool>,
+    pub tree: Vec<Vec<usize>>,
+    pub node_of: Vec<usize>,
+}
+
+/// # 이중 연결 요소와 block-cut tree
+/// 무방향 그래프의 간선을 이중 연결 요소(block)로 나누고, block과 단절점으로 이루어진 트리를 만듭니다.
+///
+/// * 두 간선이 같은 block에 있는 것은 둘을 함께 지나는 단순 사이클이 있는 것과 같습니다. 단절선은 간선 하나짜리 block입니다.
+/// * 중복 간선은 같은 block에 들어갑니다.
+/// * self-loop는 이중 연결성과 관계없으므로 그 정점이 속한 block(단절점이면 번호가 가장 작은 block)에 넣습니다.
+/// * 간선이 없는(self-loop만 있는) 정점은 그 정점 하나로 이루어진 block이 됩니다. 그래서 모든 정점이 어떤 노드에 대응합니다.
+///
+/// "u에서 w로 가는 모든 경로가 x를 지나는가"는 x가 단절점이고 트리에서 `node_of[u]`-`node_of[w]` 경로가
+/// x의 노드를 지나는지와 같습니다 (u, w, x가 서로 다르고 u, w가 연결되어 있을 때).
+///
+/// DFS는 명시적인 스택으로 구현되어 있어서 깊은 그래프에서도 스택 오버플로가 나지 않습니다.
+/// ## 시간복잡도
+/// O(V + E)
+pub fn block_cut_tree(graph: &Graph) -> BlockCutTree {
+    let n = graph.len();
+    let dfs = low_link(graph);
+    let mut blocks = dfs.blocks;
+    let block_vertices: Vec<Vec<usize>> = blocks
+        .iter()
+        .zip(&dfs.block_roots)
+        .map(|(edges, &root)| {
+            let mut vs = vec![root];
+            for &e in edges {
+                let (u, v) = graph.edges()[e];
+                vs.push(u);
+                vs.push(v);
+            }
+            vs.sort_unstable();
+            vs.dedup();
+            vs
+        })
+        .collect();
+
+    let mut count = vec![0usize; n];
+    let mut first_block = vec![usize::MAX; n];
+    for (b, vs) in block_vertices.iter().enumerate() {
+        for &v in vs {
+            count[v] += 1;
+            if first_block[v] == usize::MAX {
+                first_block[v] = b;
+            }
+        }
+    }
+    let is_cut: Vec<bool> = count.iter().map(|&c| c >= 2).collect();
+
+    for (e, &(u, v)) in graph.edges().iter().enumerate() {
+        if u == v {
+            blocks[first_block[u]].push(e);
+        }
+    }
+    for edges in &mut blocks {
+        edges.sort_unstable();
+    }
+
+    let mut node_of = first_block;
+    let mut tree = vec![Vec::new(); blocks.len()];
+    for v in 0..n {
+        if is_cut[v] {
+            node_of[v] = tree.len();
+            tree.push(Vec::new());
+        }
+    }
+    for (b, vs) in block_vertices.iter().enumerate() {
+        for &v in vs.iter() {
+            if is_cut[v] {
+                tree[b].push(node_of[v]);
+                tree[node_of[v]].push(b);
+            }
+        }
+    }
+    BlockCutTree {
+        blocks,
+        block_vertices,
+        is_cut,
+        tree,
+        node_of,
+    }
+}
+
+struct LowLink {
+    articulation: Vec<bool>,
+    bridge: Vec<bool>,
+    /// self-loop를 뺀 간선들의 block. 간선이 없는 DFS 루트는 빈 block입니다.
+    blocks: Vec<Vec<usize>>,
+    /// 각 block의 정점 하나 (빈 block의 정점을 알기 위해)
+    block_roots: Vec<usize>,
+}
+
+/// 간선 스택을 쓰는 반복 Tarjan DFS
+fn low_link(graph: &Graph) -> LowLink {
+    let n = graph.len();
+    let mut tin = vec![usize::MAX; n];
+    let mut low = vec![0usize; n];
+    let mut articulation = vec![false; n];
+    let mut bridge = vec![false; graph.edge_count()];
+    let mut blocks = Vec::new();
+    let mut block_roots = Vec::new();
+    let mut edge_stack = Vec::new();
+    let mut timer = 0;
+    for root in 0..n {
+        if tin[root] != usize::MAX {
+            continue;
+        }
+        tin[root] = timer;
+        low[root] = timer;
+        timer += 1;
+        let mut root_children = 0;
+        // (정점, 들어올 때 쓴 간선, 다음에 볼 인접 위치)
+        let mut stack = vec![(root, usize::MAX, 0usize)];
+        while let Some(&mut (v, parent_edge, ref mut i)) = stack.last_mut() {
+            if let Some(&(to, id)) = graph.adjacent(v).get(*i) {
+                *i += 1;
+                if id == parent_edge || to == v {
+                    continue;
+                }
+                if tin[to] == usize::MAX {
+                    edge_stack.push(id);
+                    tin[to] = timer;
+                    low[to] = timer;
+                    timer += 1;
+                    if v == root {
+                        root_children += 1;
+                    }
+                    stack.push((to, id, 0));
+                } else if tin[to] < tin[v] {
+                    // 조상으로 가는 back edge. 반대쪽에서 본 경우(tin[to] > tin[v])는 이미 처리됨
+                    edge_stack.push(id);
+                    low[v] = low[v].min(tin[to]);
+                }
+                continue;
+            }
+            stack.pop();
+            let Some(&(p, _, _)) = stack.last() else {
+                break;
+            };
+            low[p] = low[p].min(low[v]);
+            if low[v] >= tin[p] {
+                if p != root {
+                    articulation[p] = true;
+                }
+                let mut block = Vec::new();
+                while let Some(e) = edge_stack.pop() {
+                    block.push(e);
+                    if e == parent_edge {
+                        break;
+                    }
+                }
+                blocks.push(block);
+                block_roots.push(p);
+            }
+            if low[v] > tin[p] {
+                bridge[parent_edge] = true;
+            }
+        }
+        if root_children >= 2 {
+            articulation[root] = true;
+        }
+        if root_children == 0 {
+            blocks.push(Vec::new());
+            block_roots.push(root);
+        }
+    }
+    LowLink {
+        articulation,
+        bridge,
+        blocks,
+        block_roots,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// removed 정점과 removed_edge 간선을 뺀 그래프의 연결 요소 번호
+    fn components(
+        graph: &Graph,
+        removed: Option<usize>,
+        removed_edge: Option<usize>,
+    ) -> Vec<usize> {
+        let n = graph.len();
+        let mut comp = vec![usize::MAX; n];
+        let mut count = 0;
+        for s in 0..n {
+            if comp[s] != usize::MAX || Some(s) == removed {
+                continue;
+            }
+            comp[s] = count;
+            let mut stack = vec![s];
+            while let Some(u) = stack.pop() {
+                for &(v, id) in graph.adjacent(u) {
+                    if Some(v) != removed && Some(id) != removed_edge && comp[v] == usize::MAX {
+                        comp[v] = count;
+                        stack.push(v);
+                    }
+                }
+            }
+            count += 1;
+        }
+        comp
+    }
+
+    fn count_components(comp: &[usize]) -> usize {
+        let mut c: Vec<usize> = comp.iter().copied().filter(|&x| x != usize::MAX).collect();
+        c.sort_unstable();
+        c.dedup();
+        c.len()
+    }
+
+    /// 트리에서 a-b 경로가 노드 x를 지나는지
+    fn tree_path_contains(tree: &[Vec<usize>], a: usize, b: usize, x: usize) -> bool {
+        let mut parent = vec![usize::MAX; tree.len()];
+        parent[a] = a;
+        let mut stack = vec![a];
+        while let Some(u) = stack.pop() {
+            for &v in &tree[u] {
+                if parent[v] == usize::MAX {
+                    parent[v] = u;
+                    stack.push(v);
+                }
+            }
+        }
+        let mut v = b;
+        loop {
+            if v == x {
+                return true;
+            }
+            if v == a {
+                return false;
+            }
+            v = parent[v];
+        }
+    }
+
+    fn random_graph(rng: &mut XorShift, n: usize, m: usize) -> Graph {
+        let edges: Vec<(usize, usize)> = (0..m).map(|_| (rng.index(n), rng.index(n))).collect();
+        Graph::from_edges(n, &edges)
+    }
+
+    #[test]
+    fn hand_checked_graphs() {
+        // 삼각형 0-1-2와 삼각형 2-3-4가 2에서 만나고, 4-5 다리, 6은 고립
+        let g = Graph::from_edges(7, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5)]);
+        assert_eq!(articulation_points(&g), vec![2, 4]);
+        assert_eq!(bridges(&g), vec![6]);
+        let bct = block_cut_tree(&g);
+        let mut blocks = bct.blocks.clone();
+        blocks.sort();
+        assert_eq!(blocks, vec![vec![], vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        assert_eq!(bct.tree.len(), 4 + 2);
+        assert_eq!(bct.block_vertices[bct.node_of[6]], vec![6]);
+        assert!(bct.tree[bct.node_of[6]].is_empty());
+
+        // 중복 간선은 단절선이 아님
+        let g = Graph::from_edges(3, &[(0, 1), (0, 1), (1, 2)]);
+        assert_eq!(bridges(&g), vec![2]);
+        assert_eq!(articulation_points(&g), vec![1]);
+        assert_eq!(block_cut_tree(&g).blocks.len(), 2);
+
+        // self-loop는 block을 늘리지 않음
+        let g = Graph::from_edges(3, &[(0, 0), (0, 1), (1, 1), (2, 2)]);
+        let bct = block_cut_tree(&g);
+        assert!(articulation_points(&g).is_empty());
+        assert_eq!(bct.blocks.len(), 2);
+        assert_eq!(bct.blocks[bct.node_of[0]], vec![0, 1, 2]);
+        assert_eq!(bct.blocks[bct.node_of[2]], vec![3]);
+    }
+
+    #[test]
+    fn random_graphs_against_brute_force() {
+        let mut rng = XorShift::new(123);
+        for _ in 0..300 {
+            let n = 1 + rng.index(10);
+            let m = rng.index(2 * n + 1);
+            let g = random_graph(&mut rng, n, m);
+            let base = count_components(&components(&g, None, None));
+            let cuts: Vec<usize> = (0..n)
+                .filter(|&v| {
+                    // v를 지우면 v 자신의 요소가 사라지므로, v가 고립이면 1개 줄어듦
+                    let isolated = g.neighbors(v).all(|w| w == v);
+                    let after = count_components(&components(&g, Some(v), None));
+                    after > base - usize::from(isolated)
+                })
+                .collect();
+            assert_eq!(articulation_points(&g), cuts);
+            let expected_bridges: Vec<usize> = (0..m)
+                .filter(|&e| count_components(&components(&g, None, Some(e))) > base)
+                .collect();
+            assert_eq!(bridges(&g), expected_bridges);
+
+            let bct = block_cut_tree(&g);
+            // 모든 간선이 정확히 하나의 block에
+            let mut seen = vec![0; m];
+            for block in &bct.blocks {
+                for &e in block {
+                    seen[e] += 1;
+                }
+            }
+            assert!(seen.iter().all(|&c| c == 1));
+            let cut_from_tree: Vec<usize> = (0..n).filter(|&v| bct.is_cut[v]).collect();
+            assert_eq!(cut_from_tree, cuts);
+            // 트리: 노드 수 - 간선 수 = 연결 요소 수
+            let tree_edges: usize = bct.tree.iter().map(Vec::len).sum::<usize>() / 2;
+            assert_eq!(bct.tree.len() - tree_edges, base);
+
+            // "u에서 w로 가는 모든 경로가 x를 지나는가"
+            let comp = components(&g, None, None);
+            for u in 0..n {
+                for w in 0..n {
+                    for x in 0..n {
+                        if u == w || u == x || w == x || comp[u] != comp[w] {
+                            continue;
+                        }
+                        let without = components(&g, Some(x), None);
+                        let forced = without[u] != without[w];
+                        let by_tree = bct.is_cut[x]
+                            && tree_path_contains(
+                                &bct.tree,
+                                bct.node_of[u],
+                                bct.node_of[w],
+                                bct.node_of[x],
+                            );
+                        assert_eq!(forced, by_tree, "u={u} w={w} x={x}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn deep_path_does_not_overflow() {
+        let n = 200_000;
+        let edges: Vec<(usize, usize)> = (1..n).map(|v| (v - 1, v)).collect();
+        let g = Graph::from_edges(n, &edges);
+        assert_eq!(bridges(&g).len(), n - 1);
+        assert_eq!(articulation_points(&g).len(), n - 2);
+        assert_eq!(block_cut_tree(&g).blocks.len(), n - 1);
+    }
+}
@@ -0,0 +1,201 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::graph::Graph;
+use std::collections::VecDeque;
+
+/// # 이분 그래프 판정 (2-coloring)
+/// 무방향 그래프의 각 정점에 색 0/1을 칠해서, 모든 간선의 양 끝 색이 다르도록 만듭니다.
+///
+/// * 성공하면 정점별 색을 담은 `Ok(color)`를 반환합니다. 연결 요소마다 따로 칠하며, 각 요소에서 가장 번호가 작은 정점이 색 0입니다.
+/// * 실패하면 홀수 길이 사이클을 `Err(cycle)`로 반환합니다. `cycle`의 연속한 두 정점과 마지막-첫 정점은 간선으로 이어져 있고,
+/// 길이는 항상 홀수입니다. self-loop `(v, v)`는 길이 1짜리 사이클 `[v]`가 됩니다.
+///
+/// BFS로 칠하다가 같은 색끼리 이어진 간선 (u, v)를 만나면, BFS 트리에서 u와 v의 공통 조상까지 올라가 사이클을 만듭니다.
+/// BFS 트리에서 u와 v의 깊이 홀짝이 같으므로 그 사이클의 길이는 홀수입니다.
+/// ## 시간복잡도
+/// O(V + E)
+pub fn bipartite_coloring(graph: &Graph) -> Result<Vec<u8>, Vec<usize>> {
+    let n = graph.len();
+    let mut color: Vec<Option<u8>> = vec![None; n];
+    let mut parent = vec![usize::MAX; n];
+    let mut depth = vec![0usize; n];
+    let mut queue = VecDeque::new();
+
+    for start in 0..n {
+        if color[start].is_some() {
+            continue;
+        }
+        color[start] = Some(0);
+        queue.push_back(start);
+        while let Some(u) = queue.pop_front() {
+            let cu = color[u].unwrap();
+            for v in graph.neighbors(u) {
+                match color[v] {
+                    None => {
+                        color[v] = Some(1 - cu);
+                        parent[v] = u;
+                        depth[v] = depth[u] + 1;
+                        queue.push_back(v);
+                    }
+                    Some(cv) if cv == cu => {
+                        return Err(odd_cycle(u, v, &parent, &depth));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    Ok(color.into_iter().map(|c| c.unwrap()).collect())
+}
+
+/// 같은 색인 간선 (u, v)로 BFS 트리 위의 홀수 사이클을 복원합니다.
+/// 결과는 u → ... → lca → ... → v 순서입니다.
+fn odd_cycle(u: usize, v: usize, parent: &[usize], depth: &[usize]) -> Vec<usize> {
+    let mut left = vec![u];
+    let mut right = vec![v];
+    let (mut a, mut b) = (u, v);
+    while depth[a] > depth[b] {
+        a = parent[a];
+        left.push(a);
+    }
+    while depth[b] > depth[a] {
+        b = parent[b];
+        right.push(b);
+    }
+    while a != b {
+        a = parent[a];
+        b = parent[b];
+        left.push(a);
+        right.push(b);
+    }
+    // lca가 left, right 양쪽 끝에 한 번씩 있으므로 right 쪽에서 제거
+    right.pop();
+    right.reverse();
+    left.extend(right);
+    left
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 반환된 색이 올바른 2-coloring인지
+    fn assert_valid_coloring(graph: &Graph, color: &[u8]) {
+        assert_eq!(color.len(), graph.len());
+        for &(u, v) in graph.edges() {
+            assert_ne!(color[u], color[v], "edge ({u}, {v}) has same color");
+        }
+    }
+
+    /// 반환된 사이클이 실제 그래프의 홀수 사이클인지
+    fn assert_odd_cycle(graph: &Graph, cycle: &[usize]) {
+        assert_eq!(cycle.len() % 2, 1, "cycle {cycle:?} is not odd");
+        for i in 0..cycle.len() {
+            let a = cycle[i];
+            let b = cycle[(i + 1) % cycle.len()];
+            assert!(
+                graph.neighbors(a).any(|x| x == b),
+                "cycle {cycle:?} uses missing edge ({a}, {b})"
+            );
+        }
+        // 단순 사이클이어야 함
+        let mut sorted = cycle.to_vec();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), cycle.len());
+    }
+
+    /// 작은 n에 대해 모든 색칠을 시도하는 brute force
+    fn brute_force_bipartite(graph: &Graph) -> bool {
+        let n = graph.len();
+        (0u32..1 << n).any(|mask| {
+            graph
+                .edges()
+                .iter()
+                .all(|&(u, v)| (mask >> u & 1) != (mask >> v & 1))
+        })
+    }
+
+    #[test]
+    fn tree_and_even_cycle_are_bipartite() {
+        // 트리
+        let tree = Graph::from_edges(7, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (5, 6)]);
+        let color = bipartite_coloring(&tree).unwrap();
+        assert_valid_coloring(&tree, &color);
+        assert_eq!(color[0], 0);
+
+        // 길이 6 사이클
+        let cycle = Graph::from_edges(6, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]);
+        let color = bipartite_coloring(&cycle).unwrap();
+        assert_valid_coloring(&cycle, &color);
+
+        // 중복 간선이 있어도 이분 그래프
+        let multi = Graph::from_edges(2, &[(0, 1), (1, 0), (0, 1)]);
+        assert_eq!(bipartite_coloring(&multi), Ok(vec![0, 1]));
+    }
+
+    #[test]
+    fn odd_cycle_is_reported() {
+        let triangle = Graph::from_edges(3, &[(0, 1), (1, 2), (2, 0)]);
+        let cycle = bipartite_coloring(&triangle).unwrap_err();
+        assert_eq!(cycle.len(), 3);
+        assert_odd_cycle(&triangle, &cycle);
+
+        // 꼬리가 달린 길이 5 사이클
+        let graph = Graph::from_edges(
+            8,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 3),
+                (3, 4),
+                (4, 5),
+                (5, 6),
+                (6, 2),
+                (6, 7),
+            ],
+        );
+        let cycle = bipartite_coloring(&graph).unwrap_err();
+        assert_odd_cycle(&graph, &cycle);
+    }
+
+    #[test]
+    fn self_loop_is_not_bipartite() {
+        let graph = Graph::from_edges(3, &[(0, 1), (2, 2)]);
+        assert_eq!(bipartite_coloring(&graph), Err(vec![2]));
+    }
+
+    #[test]
+    fn components_are_colored_independently() {
+        // 간선 없는 그래프, 빈 그래프
+        assert_eq!(bipartite_coloring(&Graph::new(3)), Ok(vec![0, 0, 0]));
+        assert_eq!(bipartite_coloring(&Graph::new(0)), Ok(vec![]));
+
+        let graph = Graph::from_edges(6, &[(0, 1), (2, 3), (3, 4), (5, 4)]);
+        let color = bipartite_coloring(&graph).unwrap();
+        assert_valid_coloring(&graph, &color);
+        // 각 요소의 첫 정점은 색 0
+        assert_eq!((color[0], color[2]), (0, 0));
+    }
+
+    #[test]
+    fn random_graphs_match_brute_force() {
+        let mut rng = XorShift::new(103);
+        for _ in 0..500 {
+            let n = 1 + rng.index(10);
+            let m = rng.index(n * 2);
+            let edges: Vec<(usize, usize)> = (0..m).map(|_| (rng.index(n), rng.index(n))).collect();
+            let graph = Graph::from_edges(n, &edges);
+            match bipartite_coloring(&graph) {
+                Ok(color) => {
+                    assert!(brute_force_bipartite(&graph));
+                    assert_valid_coloring(&graph, &color);
+                }
+                Err(cycle) => {
+                    assert!(!brute_force_bipartite(&graph));
+                    assert_odd_cycle(&graph, &cycle);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::graph::topological_sort;
+
+/// DAG 경로 DP의 결과
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DagPaths {
+    /// 출발점에서의 거리. 도달할 수 없으면 None
+    pub dist: Vec<Option<i64>>,
+    /// 최적 경로에서 직전 정점. 출발점과 도달할 수 없는 정점은 None
+    pub parent: Vec<Option<usize>>,
+}
+
+impl DagPaths {
+    /// 출발점에서 v까지의 최적 경로 (양 끝 포함). 도달할 수 없으면 None
+    pub fn path_to(&self, v: usize) -> Option<Vec<usize>> {
+        self.dist[v]?;
+        let mut path = vec![v];
+        let mut u = v;
+        while let Some(p) = self.parent[u] {
+            path.push(p);
+            u = p;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// # DAG 최단 경로
+/// 방향 간선 `(u, v, 가중치)`로 이루어진 DAG에서 src로부터 모든 정점까지의 최단 거리를 구합니다.
+/// [`topological_sort`]로 정렬한 뒤 그 순서대로 간선을 완화하므로, 음수 가중치도 그대로 됩니다.
+///
+/// * 그래프에 사이클이 있으면(src에서 도달할 수 없는 곳이라도) 그 사이클을 `Err(cycle)`로 반환합니다.
+/// * 거리가 같은 경로가 여러 개면 먼저 완화된 간선을 parent로 둡니다.
+///
+/// > 거리는 i64 범위 안이어야 합니다.
+/// ## 시간복잡도
+/// O(V + E)
+pub fn dag_shortest_paths(
+    n: usize,
+    edges: &[(usize, usize, i64)],
+    src: usize,
+) -> Result<DagPaths, Vec<usize>> {
+    relax_in_order(n, edges, src, |candidate, current| candidate < current)
+}
+
+/// # DAG 최장 경로
+/// [`dag_shortest_paths`]와 같지만 가중치 합이 가장 큰 경로를 구합니다. 작업 의존 관계에서 임계 경로(critical path)를
+/// 구할 때 사용합니다. 사이클이 있으면 `Err(cycle)`입니다.
+/// ## 시간복잡도
+/// O(V + E)
+pub fn dag_longest_paths(
+    n: usize,
+    edges: &[(usize, usize, i64)],
+    src: usize,
+) -> Result<DagPaths, Vec<usize>> {
+    relax_in_order(n, edges, src, |candidate, current| candidate > current)
+}
+
+fn relax_in_order(
+    n: usize,
+    edges: &[(usize, usize, i64)],
+    src: usize,
+    better: impl Fn(i64, i64) -> bool,
+) -> Result<DagPaths, Vec<usize>> {
+    assert!(src < n, "src {src} out of range for {n} vertices");
+    let mut adj: Vec<Vec<(usize, i64)>> = vec![Vec::new(); n];
+    for &(u, v, w) in edges {
+        assert!(
+            u < n && v < n,
+            "edge ({u}, {v}) out of range for {n} vertices"
+        );
+        adj[u].push((v, w));
+    }
+    let targets: Vec<Vec<usize>> = adj
+        .iter()
+        .map(|list| list.iter().map(|&(v, _)| v).collect())
+        .collect();
+    let order = topological_sort(&targets)?;
+
+    let mut dist = vec![None; n];
+    let mut parent = vec![None; n];
+    dist[src] = Some(0);
+    for u in order {
+        let Some(du) = dist[u] else {
+            continue;
+        };
+        for &(v, w) in &adj[u] {
+            let candidate = du + w;
+            if dist[v].is_none_or(|dv| better(candidate, dv)) {
+                dist[v] = Some(candidate);
+                parent[v] = Some(u);
+            }
+        }
+    }
+    Ok(DagPaths { dist, parent })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn bellman_ford(n: usize, edges: &[(usize, usize, i64)], src: usize) -> Vec<Option<i64>> {
+        let mut dist = vec![None; n];
+        dist[src] = Some(0);
+        for _ in 0..n {
+            for &(u, v, w) in edges {
+                if let Some(du) = dist[u]
+                    && dist[v].is_none_or(|dv| du + w < dv)
+                {
+                    dist[v] = Some(du + w);
+                }
+            }
+        }
+        dist
+    }
+
+    fn random_dag(rng: &mut XorShift, n: usize) -> Vec<(usize, usize, i64)> {
+        let mut perm: Vec<usize> = (0..n).collect();
+        for i in (1..n).rev() {
+            perm.swap(i, rng.index(i + 1));
+        }
+        let m = rng.index(3 * n);
+        (0..m)
+            .filter_map(|_| {
+                let (a, b) = (rng.index(n), rng.index(n));
+                let w = rng.range_i64(-20, 20);
+                (a != b).then(|| (perm[a.min(b)], perm[a.max(b)], w))
+            })
+            .collect()
+    }
+
+    fn assert_paths(edges: &[(usize, usize, i64)], src: usize, paths: &DagPaths) {
+        for v in 0..paths.dist.len() {
+            let Some(path) = paths.path_to(v) else {
+                assert_eq!(paths.dist[v], None);
+                continue;
+            };
+            assert_eq!(path[0], src);
+            assert_eq!(*path.last().unwrap(), v);
+            // 경로의 각 간선 가중치는 parent 관계로 거리 차이와 같아야 함
+            for w in path.windows(2) {
+                let (a, b) = (w[0], w[1]);
+                let diff = paths.dist[b].unwrap() - paths.dist[a].unwrap();
+                assert!(edges.contains(&(a, b, diff)));
+            }
+        }
+    }
+
+    #[test]
+    fn random_against_bellman_ford() {
+        let mut rng = XorShift::new(125);
+        for _ in 0..300 {
+            let n = 1 + rng.index(25);
+            let edges = random_dag(&mut rng, n);
+            let src = rng.index(n);
+            let shortest = dag_shortest_paths(n, &edges, src).unwrap();
+            assert_eq!(shortest.dist, bellman_ford(n, &edges, src));
+            assert_paths(&edges, src, &shortest);
+
+            // 최장 경로 = 가중치를 뒤집은 최단 경로
+            let negated: Vec<(usize, usize, i64)> =
+                edges.iter().map(|&(u, v, w)| (u, v, -w)).collect();
+            let longest = dag_longest_paths(n, &edges, src).unwrap();
+            let expected: Vec<Option<i64>> = dag_shortest_paths(n, &negated, src)
+                .unwrap()
+                .dist
+                .into_iter()
+                .map(|d| d.map(|d| -d))
+                .collect();
+            assert_eq!(longest.dist, expected);
+            assert_paths(&edges, src, &longest);
+        }
+    }
+
+    #[test]
+    fn critical_path_and_cycles() {
+        // 0 → 1 → 3, 0 → 2 → 3: 최장 0-2-3 (7), 최단 0-1-3 (3)
+        let edges = [(0, 1, 1), (1, 3, 2), (0, 2, 4), (2, 3, 3), (4, 3, 100)];
+        let longest = dag_longest_paths(5, &edges, 0).unwrap();
+        assert_eq!(longest.dist, vec![Some(0), Some(1), Some(4), Some(7), None]);
+        assert_eq!(longest.path_to(3), Some(vec![0, 2, 3]));
+        assert_eq!(longest.path_to(4), None);
+        let shortest = dag_shortest_paths(5, &edges, 0).unwrap();
+        assert_eq!(shortest.path_to(3), Some(vec![0, 1, 3]));
+        assert_eq!(shortest.path_to(0), Some(vec![0]));
+
+        // src에서 도달할 수 없는 사이클도 오류
+        let edges = [(0, 1, 1), (2, 3, 1), (3, 2, 1)];
+        let mut cycle = dag_shortest_paths(4, &edges, 0).unwrap_err();
+        cycle.sort_unstable();
+        assert_eq!(cycle, vec![2, 3]);
+        assert_eq!(dag_longest_paths(1, &[(0, 0, 5)], 0), Err(vec![0]));
+    }
+}
@@ -0,0 +1,264 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # 오일러 경로 (정점 순서)
+/// 모든 간선을 정확히 한 번씩 지나는 경로를 정점 순서로 반환합니다. 간선 목록은 `(u, v)`이고,
+/// `directed`가 false면 무방향 간선으로 봅니다. 없으면 None입니다.
+///
+/// * 오일러 회로(시작 = 끝)가 존재하면 항상 회로를 반환합니다.
+/// * 중복 간선과 self-loop를 지원합니다. 결과의 길이는 간선 수 + 1입니다.
+/// * 간선이 하나도 없으면 빈 Vec을 반환합니다.
+///
+/// 중복 간선이 있으면 정점 순서만으로는 어떤 간선을 썼는지 알 수 없으므로, 그럴 때는 [`eulerian_path_edges`]를 사용합니다.
+pub fn eulerian_path(n: usize, edges: &[(usize, usize)], directed: bool) -> Option<Vec<usize>> {
+    hierholzer(n, edges, directed).map(|(vertices, _)| vertices)
+}
+
+/// # 오일러 경로 (간선 순서)
+/// [`eulerian_path`]와 같지만 지나간 간선의 번호(`edges`에서의 위치)를 순서대로 반환합니다.
+/// 무방향 그래프에서 간선 `(u, v)`를 v → u 방향으로 지났을 수 있으므로, 방향이 필요하면 정점 순서 버전과 함께 사용합니다.
+pub fn eulerian_path_edges(
+    n: usize,
+    edges: &[(usize, usize)],
+    directed: bool,
+) -> Option<Vec<usize>> {
+    hierholzer(n, edges, directed).map(|(_, edge_ids)| edge_ids)
+}
+
+/// 차수 조건을 확인하고 시작점을 고른 뒤 Hierholzer 알고리즘을 명시적인 스택으로 수행합니다.
+/// 모든 간선을 다 썼는지 확인하는 것으로 연결성 검사를 대신합니다.
+/// ## 시간복잡도
+/// O(V + E)
+fn hierholzer(
+    n: usize,
+    edges: &[(usize, usize)],
+    directed: bool,
+) -> Option<(Vec<usize>, Vec<usize>)> {
+    if edges.is_empty() {
+        return Some((Vec::new(), Vec::new()));
+    }
+    let mut adj: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    // 방향 그래프: out - in, 무방향 그래프: 차수
+    let mut balance = vec![0i64; n];
+    for (id, &(u, v)) in edges.iter().enumerate() {
+        assert!(
+            u < n && v < n,
+            "edge ({u}, {v}) out of range for {n} vertices"
+        );
+        adj[u].push((v, id));
+        if directed {
+            balance[u] += 1;
+            balance[v] -= 1;
+        } else {
+            adj[v].push((u, id));
+            balance[u] += 1;
+            balance[v] += 1;
+        }
+    }
+
+    let start = if directed {
+        let plus: Vec<usize> = (0..n).filter(|&v| balance[v] > 0).collect();
+        let minus = (0..n).filter(|&v| balance[v] < 0).count();
+        match plus.as_slice() {
+            [] => (0..n).find(|&v| !adj[v].is_empty())?,
+            [s] if balance[*s] == 1 && minus == 1 => *s,
+            _ => return None,
+        }
+    } else {
+        let odd: Vec<usize> = (0..n).filter(|&v| balance[v] % 2 == 1).collect();
+        match odd.as_slice() {
+            [] => (0..n).find(|&v| !adj[v].is_empty())?,
+            [s, _] => *s,
+            _ => return None,
+        }
+    };
+
+    let mut used = vec![false; edges.len()];
+    let mut ptr = vec![0usize; n];
+    let mut vertices = Vec::with_capacity(edges.len() + 1);
+    let mut edge_ids = Vec::with_capacity(edges.len());
+    // (정점, 그 정점으로 들어올 때 쓴 간선)
+    let mut stack = vec![(start, usize::MAX)];
+    while let Some(&(u, e)) = stack.last() {
+        while ptr[u] < adj[u].len() && used[adj[u][ptr[u]].1] {
+            ptr[u] += 1;
+        }
+        if ptr[u] < adj[u].len() {
+            let (v, id) = adj[u][ptr[u]];
+            ptr[u] += 1;
+            used[id] = true;
+            stack.push((v, id));
+        } else {
+            stack.pop();
+            vertices.push(u);
+            if e != usize::MAX {
+                edge_ids.push(e);
+            }
+        }
+    }
+    if edge_ids.len() != edges.len() {
+        // 간선이 있는 정점들이 연결되어 있지 않음
+        return None;
+    }
+    vertices.reverse();
+    edge_ids.reverse();
+    Some((vertices, edge_ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 정점 순서와 간선 순서가 서로 맞고 모든 간선을 한 번씩 쓰는지 확인
+    fn assert_valid(n: usize, edges: &[(usize, usize)], directed: bool) {
+        let vertices = eulerian_path(n, edges, directed).expect("path must exist");
+        let ids = eulerian_path_edges(n, edges, directed).expect("path must exist");
+        assert_eq!(vertices.len(), edges.len() + 1);
+        assert_eq!(ids.len(), edges.len());
+        let mut seen = vec![false; edges.len()];
+        for (i, &id) in ids.iter().enumerate() {
+            assert!(!seen[id], "edge {id} used twice");
+            seen[id] = true;
+            let (u, v) = edges[id];
+            let (a, b) = (vertices[i], vertices[i + 1]);
+            if directed {
+                assert_eq!((a, b), (u, v));
+            } else {
+                assert!((a, b) == (u, v) || (a, b) == (v, u));
+            }
+        }
+    }
+
+    /// 간선을 모두 쓰는 순서를 백트래킹으로 찾음
+    fn brute_force_exists(n: usize, edges: &[(usize, usize)], directed: bool) -> bool {
+        fn go(
+            u: usize,
+            used: &mut Vec<bool>,
+            left: usize,
+            edges: &[(usize, usize)],
+            d: bool,
+        ) -> bool {
+            if left == 0 {
+                return true;
+            }
+            for i in 0..edges.len() {
+                if used[i] {
+                    continue;
+                }
+                let (a, b) = edges[i];
+                let next = if a == u {
+                    Some(b)
+                } else if !d && b == u {
+                    Some(a)
+                } else {
+                    None
+                };
+                if let Some(v) = next {
+                    used[i] = true;
+                    if go(v, used, left - 1, edges, d) {
+                        return true;
+                    }
+                    used[i] = false;
+                }
+            }
+            false
+        }
+        (0..n).any(|s| {
+            go(
+                s,
+                &mut vec![false; edges.len()],
+                edges.len(),
+                edges,
+                directed,
+            )
+        })
+    }
+
+    #[test]
+    fn known_cases() {
+        // 무방향 사각형 + 대각선: 홀수 차수 정점 1, 3에서 시작/끝
+        let edges = [(0, 1), (1, 2), (2, 3), (3, 0), (1, 3)];
+        let path = eulerian_path(4, &edges, false).unwrap();
+        assert_eq!(path[0], 1);
+        assert_eq!(*path.last().unwrap(), 3);
+        assert_valid(4, &edges, false);
+
+        // 회로가 있으면 회로를 반환
+        let edges = [(0, 1), (1, 2), (2, 0)];
+        let path = eulerian_path(3, &edges, true).unwrap();
+        assert_eq!(path, vec![0, 1, 2, 0]);
+
+        // 방향 경로
+        let edges = [(2, 0), (0, 1), (1, 2), (2, 3)];
+        let path = eulerian_path(4, &edges, true).unwrap();
+        assert_eq!(path[0], 2);
+        assert_eq!(*path.last().unwrap(), 3);
+
+        // self-loop와 중복 간선
+        let edges = [(0, 0), (0, 1), (0, 1), (1, 1)];
+        assert_valid(2, &edges, false);
+        assert_valid(2, &[(0, 0), (0, 1), (1, 0), (1, 1)], true);
+
+        assert_eq!(eulerian_path(3, &[], false), Some(vec![]));
+    }
+
+    #[test]
+    fn rejects_bad_degrees_and_disconnected() {
+        // 홀수 차수 정점 4개 (별)
+        assert_eq!(eulerian_path(4, &[(0, 1), (0, 2), (0, 3)], false), None);
+        // 방향: 출차수 초과 정점 2개
+        assert_eq!(eulerian_path(3, &[(0, 1), (0, 2)], true), None);
+        // 삼각형 두 개로 분리 (차수 조건은 만족)
+        let edges = [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)];
+        assert_eq!(eulerian_path(6, &edges, false), None);
+        assert_eq!(eulerian_path(6, &edges, true), None);
+        // 간선이 없는 고립 정점은 상관없음
+        assert!(eulerian_path(5, &[(1, 2), (2, 3)], false).is_some());
+    }
+
+    #[test]
+    fn random_eulerian_multigraphs() {
+        let mut rng = XorShift::new(109);
+        for round in 0..200 {
+            let n = 1 + rng.index(8);
+            let len = 1 + rng.index(30);
+            // 임의의 walk를 만들고 간선 순서를 섞음. 짝수 round는 닫힌 walk
+            let mut walk = vec![rng.index(n)];
+            for _ in 0..len {
+                walk.push(rng.index(n));
+            }
+            if round % 2 == 0 {
+                walk.push(walk[0]);
+            }
+            let mut edges: Vec<(usize, usize)> = walk.windows(2).map(|w| (w[0], w[1])).collect();
+            for i in (1..edges.len()).rev() {
+                edges.swap(i, rng.index(i + 1));
+            }
+            for directed in [true, false] {
+                assert_valid(n, &edges, directed);
+                if round % 2 == 0 {
+                    let path = eulerian_path(n, &edges, directed).unwrap();
+                    assert_eq!(path[0], *path.last().unwrap(), "circuit expected");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn random_small_against_brute_force() {
+        let mut rng = XorShift::new(1090);
+        for _ in 0..500 {
+            let n = 1 + rng.index(5);
+            let edges: Vec<(usize, usize)> = (0..1 + rng.index(6))
+                .map(|_| (rng.index(n), rng.index(n)))
+                .collect();
+            for directed in [true, false] {
+                let exists = brute_force_exists(n, &edges, directed);
+                assert_eq!(eulerian_path(n, &edges, directed).is_some(), exists);
+                if exists {
+                    assert_valid(n, &edges, directed);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,406 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Functional graph
+/// 모든 정점의 나가는 간선이 정확히 하나인 그래프 `v → next[v]`에서 k번 이동한 결과를 구합니다.
+///
+/// * `up[j][v]`는 v에서 2^j번 이동한 정점입니다. 테이블의 높이는 log n입니다.
+/// * n번 이동하면 반드시 사이클 위에 있으므로, k ≥ n이면 n번 이동한 뒤 남은 횟수를 사이클 길이로 나눈 나머지만큼 이동합니다.
+/// 그래서 k가 10^18 정도로 커도 테이블을 키우지 않습니다.
+/// * 경로 위 값을 접어야 하면 [`FunctionalGraph::path_fold`]로 최대 이동 횟수 K에 맞는 테이블을 따로 만듭니다.
+/// ## 시간복잡도
+/// 전처리 O(n log n), 질의 O(log n)
+pub struct FunctionalGraph {
+    next: Vec<usize>,
+    up: Vec<Vec<usize>>,
+    /// 각 정점이 속한 컴포넌트의 사이클 길이
+    cycle_len: Vec<usize>,
+}
+
+impl FunctionalGraph {
+    /// `next[v]`: v에서 한 번 이동한 정점
+    pub fn new(next: Vec<usize>) -> Self {
+        let n = next.len();
+        for (v, &w) in next.iter().enumerate() {
+            assert!(w < n, "next[{v}] = {w} out of range for {n} vertices");
+        }
+        let levels = ((u64::BITS - (n as u64).leading_zeros()) as usize).max(1);
+        let up = doubling(&next, levels);
+        let components = functional_graph_components(&next);
+        let cycle_len = (0..n)
+            .map(|v| components.cycles[components.comp[v]].len())
+            .collect();
+        FunctionalGraph {
+            next,
+            up,
+            cycle_len,
+        }
+    }
+
+    /// 정점 개수
+    pub fn len(&self) -> usize {
+        self.next.len()
+    }
+
+    /// 정점이 하나도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.next.is_empty()
+    }
+
+    /// v에서 한 번 이동한 정점
+    pub fn next(&self, v: usize) -> usize {
+        self.next[v]
+    }
+
+    /// v에서 k번 이동한 정점
+    pub fn kth(&self, v: usize, k: u64) -> usize {
+        let n = self.len() as u64;
+        if k < n {
+            return jump(&self.up, v, k);
+        }
+        let w = jump(&self.up, v, n);
+        jump(&self.up, w, (k - n) % self.cycle_len[w] as u64)
+    }
+
+    /// # 꼬리와 사이클 길이
+    /// v에서 출발해서 처음으로 사이클에 들어가기까지의 이동 횟수(tail)와 그 사이클의 길이를 Brent 알고리즘으로 구합니다.
+    /// v가 사이클 위에 있으면 tail은 0입니다. 추가 메모리 없이 O(tail + cycle)
+    pub fn cycle_info(&self, v: usize) -> (usize, usize) {
+        let next = &self.next;
+        // 사이클 길이: 2의 거듭제곱 길이 구간마다 기준점을 옮기며 기준점으로 돌아오는 때를 찾음
+        let mut power = 1;
+        let mut cycle = 1;
+        let mut tortoise = v;
+        let mut hare = next[v];
+        while tortoise != hare {
+            if power == cycle {
+                tortoise = hare;
+                power *= 2;
+                cycle = 0;
+            }
+            hare = next[hare];
+            cycle += 1;
+        }
+        // cycle만큼 앞서 출발한 포인터와 동시에 움직여서 처음 만나는 곳이 사이클의 시작
+        let mut tortoise = v;
+        let mut hare = v;
+        for _ in 0..cycle {
+            hare = next[hare];
+        }
+        let mut tail = 0;
+        while tortoise != hare {
+            tortoise = next[tortoise];
+            hare = next[hare];
+            tail += 1;
+        }
+        (tail, cycle)
+    }
+
+    /// # 경로 위 값 접기
+    /// `values[v]`와 결합법칙을 만족하는 연산 `op`(항등원 `identity`)로, k ≤ `max_k`번 이동하는 동안 지나는 값을 접는
+    /// [`PathFold`]를 만듭니다. 메모리 O(n log max_k)
+    pub fn path_fold<T: Clone, F: Fn(&T, &T) -> T>(
+        &self,
+        values: &[T],
+        identity: T,
+        op: F,
+        max_k: u64,
+    ) -> PathFold<T, F> {
+        let n = self.len();
+        assert_eq!(values.len(), n, "need one value per vertex");
+        let levels = ((u64::BITS - max_k.leading_zeros()) as usize).max(1);
+        let up = doubling(&self.next, levels);
+        let mut agg = vec![values.to_vec()];
+        for j in 1..levels {
+            let prev = &agg[j - 1];
+            let next: Vec<T> = (0..n).map(|v| op(&prev[v], &prev[up[j - 1][v]])).collect();
+            agg.push(next);
+        }
+        PathFold {
+            up,
+            agg,
+            identity,
+            op,
+            max_k,
+        }
+    }
+}
+
+/// # 경로 위 값 접기
+/// [`FunctionalGraph::path_fold`]로 만듭니다.
+pub struct PathFold<T, F> {
+    up: Vec<Vec<usize>>,
+    agg: Vec<Vec<T>>,
+    identity: T,
+    op: F,
+    max_k: u64,
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> PathFold<T, F> {
+    /// v에서 k번 이동한 정점과, 그동안 지난 k개 정점(v 포함, 도착 정점 제외)의 값을 경로 순서대로 접은 결과
+    pub fn kth_with(&self, v: usize, k: u64) -> (usize, T) {
+        assert!(k <= self.max_k, "k = {k} exceeds max_k = {}", self.max_k);
+        let mut v = v;
+        let mut acc = self.identity.clone();
+        for j in 0..self.up.len() {
+            if k >> j & 1 == 1 {
+                acc = (self.op)(&acc, &self.agg[j][v]);
+                v = self.up[j][v];
+            }
+        }
+        (v, acc)
+    }
+}
+
+fn doubling(next: &[usize], levels: usize) -> Vec<Vec<usize>> {
+    let mut up = vec![next.to_vec()];
+    for j in 1..levels {
+        let prev = &up[j - 1];
+        let row: Vec<usize> = prev.iter().map(|&w| prev[w]).collect();
+        up.push(row);
+    }
+    up
+}
+
+/// k < 2^levels
+fn jump(up: &[Vec<usize>], mut v: usize, k: u64) -> usize {
+    for (j, row) in up.iter().enumerate() {
+        if k >> j & 1 == 1 {
+            v = row[v];
+        }
+    }
+    v
+}
+
+/// # Functional graph의 컴포넌트 구조
+/// [`functional_graph_components`]의 결과입니다. 컴포넌트마다 사이클이 정확히 하나 있습니다.
+/// * `comp[v]`: v가 속한 컴포넌트 번호 (0부터)
+/// * `cycles[c]`: 컴포넌트 c의 사이클. `next`를 따라가는 순서이고, self-loop는 길이 1인 사이클입니다.
+/// * `dist[v]`: v에서 사이클에 처음 닿을 때까지의 이동 횟수 (사이클 위면 0)
+/// * `entry[v]`: v에서 처음 닿는 사이클 위 정점 (사이클 위면 v)
+/// * `cycle_index[v]`: `entry[v]`가 `cycles[comp[v]]`에서 차지하는 위치
+///
+/// k ≥ `dist[v]`이면 v에서 k번 이동한 정점은
+/// `cycles[comp[v]][(cycle_index[v] + k - dist[v]) % cycles[comp[v]].len()]`입니다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FgComponents {
+    pub comp: Vec<usize>,
+    pub cycles: Vec<Vec<usize>>,
+    pub dist: Vec<usize>,
+    pub entry: Vec<usize>,
+    pub cycle_index: Vec<usize>,
+}
+
+/// # Functional graph 컴포넌트 분해
+/// `v → next[v]` 그래프의 각 컴포넌트와 그 사이클, 사이클까지의 거리를 구합니다.
+///
+/// 방문하지 않은 정점에서 출발해 이미 본 정점을 만날 때까지 따라갑니다.
+/// 이번 경로 위의 정점을 만나면 새 사이클이고, 이전에 끝난 정점을 만나면 그 정점의 컴포넌트에 붙습니다.
+/// 경로는 뒤에서부터 거리를 채우므로 재귀가 없습니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn functional_graph_components(next: &[usize]) -> FgComponents {
+    let n = next.len();
+    // 0: 방문 전, 1: 지금 따라가는 중, 2: 끝남
+    let mut state = vec![0u8; n];
+    let mut comp = vec![0usize; n];
+    let mut cycles: Vec<Vec<usize>> = Vec::new();
+    let mut dist = vec![0usize; n];
+    let mut entry = vec![0usize; n];
+    let mut cycle_index = vec![0usize; n];
+    let mut path = Vec::new();
+    for s in 0..n {
+        let mut v = s;
+        while state[v] == 0 {
+            assert!(
+                next[v] < n,
+                "next[{v}] = {} out of range for {n} vertices",
+                next[v]
+            );
+            state[v] = 1;
+            path.push(v);
+            v = next[v];
+        }
+        let mut tail_len = path.len();
+        if state[v] == 1 {
+            let i = path.iter().rposition(|&u| u == v).unwrap();
+            let id = cycles.len();
+            for (j, &u) in path[i..].iter().enumerate() {
+                comp[u] = id;
+                entry[u] = u;
+                cycle_index[u] = j;
+            }
+            cycles.push(path[i..].to_vec());
+            tail_len = i;
+        }
+        for &u in path[..tail_len].iter().rev() {
+            let w = next[u];
+            comp[u] = comp[w];
+            dist[u] = dist[w] + 1;
+            entry[u] = entry[w];
+            cycle_index[u] = cycle_index[w];
+        }
+        for u in path.drain(..) {
+            state[u] = 2;
+        }
+    }
+    FgComponents {
+        comp,
+        cycles,
+        dist,
+        entry,
+        cycle_index,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn random_function(rng: &mut XorShift, n: usize) -> Vec<usize> {
+        (0..n).map(|_| rng.index(n)).collect()
+    }
+
+    fn naive_kth(next: &[usize], mut v: usize, k: u64) -> usize {
+        for _ in 0..k {
+            v = next[v];
+        }
+        v
+    }
+
+    #[test]
+    fn rho_shapes() {
+        // 0 → 1 → 2 → 3 → 4 → 2, 5 → 5, 6 → 0
+        let next = vec![1, 2, 3, 4, 2, 5, 0];
+        let fg = FunctionalGraph::new(next);
+        assert_eq!(fg.cycle_info(0), (2, 3));
+        assert_eq!(fg.cycle_info(6), (3, 3));
+        assert_eq!(fg.cycle_info(3), (0, 3));
+        assert_eq!(fg.cycle_info(5), (0, 1));
+        // 0에서 10^18번: 2번 이동 후 사이클(2, 3, 4)에서 (10^18 - 2) % 3 = 2번 더
+        assert_eq!(fg.kth(0, 1_000_000_000_000_000_000), 4);
+        assert_eq!(fg.kth(5, u64::MAX), 5);
+
+        // 한 바퀴짜리 큰 사이클
+        let n = 1000;
+        let fg = FunctionalGraph::new((0..n).map(|v| (v + 1) % n).collect());
+        assert_eq!(fg.cycle_info(17), (0, n));
+        assert_eq!(fg.kth(0, 10u64.pow(18) + 7), (10usize.pow(18) + 7) % n);
+    }
+
+    #[test]
+    fn random_kth_against_stepping() {
+        let mut rng = XorShift::new(116);
+        for _ in 0..100 {
+            let n = 1 + rng.index(50);
+            let next = random_function(&mut rng, n);
+            let fg = FunctionalGraph::new(next.clone());
+            for _ in 0..50 {
+                let v = rng.index(n);
+                let k = rng.below(4 * n as u64);
+                assert_eq!(fg.kth(v, k), naive_kth(&next, v, k));
+                // 꼬리와 사이클 확인: tail번 이동한 정점은 cycle번 이동하면 제자리
+                let (tail, cycle) = fg.cycle_info(v);
+                let w = naive_kth(&next, v, tail as u64);
+                assert_eq!(naive_kth(&next, w, cycle as u64), w);
+                assert!((1..cycle).all(|c| naive_kth(&next, w, c as u64) != w));
+                if tail > 0 {
+                    let before = naive_kth(&next, v, tail as u64 - 1);
+                    assert_ne!(naive_kth(&next, before, cycle as u64), before);
+                }
+                // 아주 큰 k
+                let big = rng.next_u64();
+                let expected = naive_kth(&next, w, (big - tail as u64) % cycle as u64);
+                assert_eq!(fg.kth(v, big), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn path_sum_against_simulation() {
+        let mut rng = XorShift::new(1160);
+        for _ in 0..50 {
+            let n = 1 + rng.index(40);
+            let next = random_function(&mut rng, n);
+            let values: Vec<i64> = (0..n).map(|_| rng.range_i64(-100, 100)).collect();
+            let fg = FunctionalGraph::new(next.clone());
+            let max_k = 500;
+            let fold = fg.path_fold(&values, 0i64, |a, b| a + b, max_k);
+            // 경로 순서가 중요한 연산: 지난 정점 번호를 이어 붙인 문자열
+            let labels: Vec<String> = (0..n).map(|v| format!("{v},")).collect();
+            let concat = fg.path_fold(&labels, String::new(), |a, b| a.clone() + b, max_k);
+            for _ in 0..30 {
+                let v = rng.index(n);
+                let k = rng.below(max_k + 1);
+                let (mut u, mut sum, mut text) = (v, 0, String::new());
+                for _ in 0..k {
+                    sum += values[u];
+                    text += &labels[u];
+                    u = next[u];
+                }
+                assert_eq!(fold.kth_with(v, k), (u, sum));
+                assert_eq!(concat.kth_with(v, k), (u, text));
+            }
+        }
+    }
+
+    #[test]
+    fn components_of_hand_built_shapes() {
+        // 0 → 1 → 2 → 0, 3 → 1, 4 → 4, 5 → 4, 6 → 5
+        let c = functional_graph_components(&[1, 2, 0, 1, 4, 4, 5]);
+        assert_eq!(c.cycles, vec![vec![0, 1, 2], vec![4]]);
+        assert_eq!(c.comp, vec![0, 0, 0, 0, 1, 1, 1]);
+        assert_eq!(c.dist, vec![0, 0, 0, 1, 0, 1, 2]);
+        assert_eq!(c.entry, vec![0, 1, 2, 1, 4, 4, 4]);
+        assert_eq!(c.cycle_index, vec![0, 1, 2, 1, 0, 0, 0]);
+        assert!(functional_graph_components(&[]).cycles.is_empty());
+    }
+
+    #[test]
+    fn random_large_components() {
+        let mut rng = XorShift::new(117);
+        for round in 0..4 {
+            let n = 100_000;
+            // 짝수 round는 완전 무작위, 홀수 round는 꼬리가 긴 모양 (대부분 v → v - 1)
+            let next: Vec<usize> = (0..n)
+                .map(|v| {
+                    if round % 2 == 1 && v > 0 && rng.below(1000) != 0 {
+                        v - 1
+                    } else {
+                        rng.index(n)
+                    }
+                })
+                .collect();
+            let c = functional_graph_components(&next);
+            for (id, cycle) in c.cycles.iter().enumerate() {
+                for (j, &u) in cycle.iter().enumerate() {
+                    assert_eq!(next[u], cycle[(j + 1) % cycle.len()]);
+                    assert_eq!((c.comp[u], c.dist[u], c.entry[u]), (id, 0, u));
+                    assert_eq!(c.cycle_index[u], j);
+                }
+            }
+            for v in 0..n {
+                assert_eq!(c.comp[v], c.comp[next[v]]);
+                if c.dist[v] > 0 {
+                    // 한 칸 가면 거리가 1 줄고 같은 곳으로 들어감
+                    assert_eq!(c.dist[next[v]] + 1, c.dist[v]);
+                    assert_eq!(c.entry[next[v]], c.entry[v]);
+                } else {
+                    assert_eq!(c.entry[v], v);
+                }
+                let cycle = &c.cycles[c.comp[v]];
+                assert_eq!(cycle[c.cycle_index[v]], c.entry[v]);
+            }
+            // 몇몇 정점은 직접 따라가서 확인
+            let fg = FunctionalGraph::new(next.clone());
+            for _ in 0..200 {
+                let v = rng.index(n);
+                let k = c.dist[v] as u64 + rng.below(1_000_000_000);
+                let cycle = &c.cycles[c.comp[v]];
+                let steps = (c.cycle_index[v] as u64 + k - c.dist[v] as u64) % cycle.len() as u64;
+                assert_eq!(fg.kth(v, k), cycle[steps as usize]);
+                assert_eq!(fg.cycle_info(v), (c.dist[v], cycle.len()));
+            }
+        }
+    }
+}
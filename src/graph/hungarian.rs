@@ -0,0 +1,191 @@
+/// # Hungarian 알고리즘 (최소 비용 할당)
+/// n×m 비용 행렬(n ≤ m)에서 각 행에 서로 다른 열을 하나씩 배정할 때, 비용의 합이 **최소**가 되는 배정을 구합니다.
+/// 반환값은 `(최소 비용, 행별로 배정된 열)`입니다.
+///
+/// * 직사각형 행렬(n < m)을 지원하며, 이 경우 일부 열은 배정되지 않습니다.
+/// * 음수 비용도 됩니다. 단 비용의 절댓값 합이 i64 범위를 넘지 않아야 합니다.
+/// * 최대화가 필요하면 [`hungarian_maximize`]를 사용합니다.
+///
+/// 행을 하나씩 추가하면서 potential(쌍대 변수)을 유지하고, Dijkstra처럼 가장 싼 증가 경로를 찾아 배정을 늘립니다.
+/// ## 시간복잡도
+/// O(n²m)
+pub fn hungarian(cost: &[Vec<i64>]) -> (i64, Vec<usize>) {
+    let n = cost.len();
+    if n == 0 {
+        return (0, Vec::new());
+    }
+    let m = cost[0].len();
+    assert!(n <= m, "hungarian needs rows <= columns, got {n}x{m}");
+    for row in cost {
+        assert_eq!(row.len(), m, "cost matrix rows must have equal length");
+    }
+
+    const INF: i64 = i64::MAX / 4;
+    // 1-indexed. 열 0은 "아직 배정되지 않은 가상의 열"로 사용
+    let mut u = vec![0i64; n + 1];
+    let mut v = vec![0i64; m + 1];
+    // row_of[j]: 열 j에 배정된 행 (0이면 없음)
+    let mut row_of = vec![0usize; m + 1];
+    let mut way = vec![0usize; m + 1];
+
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut j0 = 0;
+        let mut min_v = vec![INF; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = row_of[j0];
+            let mut delta = INF;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let reduced = cost[i0 - 1][j - 1] - u[i0] - v[j];
+                if reduced < min_v[j] {
+                    min_v[j] = reduced;
+                    way[j] = j0;
+                }
+                if min_v[j] < delta {
+                    delta = min_v[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[row_of[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_v[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if row_of[j0] == 0 {
+                break;
+            }
+        }
+        // 증가 경로를 따라 배정 갱신
+        while j0 != 0 {
+            let j1 = way[j0];
+            row_of[j0] = row_of[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assignment = vec![0usize; n];
+    for j in 1..=m {
+        if row_of[j] != 0 {
+            assignment[row_of[j] - 1] = j - 1;
+        }
+    }
+    let total = (0..n).map(|i| cost[i][assignment[i]]).sum();
+    (total, assignment)
+}
+
+/// # Hungarian 알고리즘 (최대 이익 할당)
+/// 비용의 부호를 뒤집어 [`hungarian`]을 호출합니다. 반환값은 `(최대 합, 행별로 배정된 열)`입니다.
+pub fn hungarian_maximize(profit: &[Vec<i64>]) -> (i64, Vec<usize>) {
+    let negated: Vec<Vec<i64>> = profit
+        .iter()
+        .map(|row| row.iter().map(|&x| -x).collect())
+        .collect();
+    let (total, assignment) = hungarian(&negated);
+    (-total, assignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 행마다 서로 다른 열을 고르는 모든 경우를 시도
+    fn brute_force(cost: &[Vec<i64>]) -> i64 {
+        fn go(i: usize, used: u32, cost: &[Vec<i64>]) -> i64 {
+            if i == cost.len() {
+                return 0;
+            }
+            let mut best = i64::MAX;
+            for j in 0..cost[i].len() {
+                if used >> j & 1 == 0 {
+                    best = best.min(cost[i][j] + go(i + 1, used | 1 << j, cost));
+                }
+            }
+            best
+        }
+        go(0, 0, cost)
+    }
+
+    fn brute_force_max(cost: &[Vec<i64>]) -> i64 {
+        let negated: Vec<Vec<i64>> = cost
+            .iter()
+            .map(|r| r.iter().map(|&x| -x).collect())
+            .collect();
+        -brute_force(&negated)
+    }
+
+    fn assert_valid(cost: &[Vec<i64>], total: i64, assignment: &[usize]) {
+        assert_eq!(assignment.len(), cost.len());
+        let mut cols = assignment.to_vec();
+        cols.sort();
+        cols.dedup();
+        assert_eq!(cols.len(), assignment.len(), "columns must be distinct");
+        let sum: i64 = assignment
+            .iter()
+            .enumerate()
+            .map(|(i, &j)| cost[i][j])
+            .sum();
+        assert_eq!(sum, total);
+    }
+
+    #[test]
+    fn textbook_instance() {
+        let cost = vec![
+            vec![9, 2, 7, 8],
+            vec![6, 4, 3, 7],
+            vec![5, 8, 1, 8],
+            vec![7, 6, 9, 4],
+        ];
+        let (total, assignment) = hungarian(&cost);
+        assert_eq!(total, 13);
+        assert_eq!(assignment, vec![1, 0, 2, 3]);
+
+        let (total, assignment) = hungarian_maximize(&cost);
+        assert_eq!(total, brute_force_max(&cost));
+        assert_valid(&cost, total, &assignment);
+    }
+
+    #[test]
+    fn degenerate_shapes() {
+        assert_eq!(hungarian(&[]), (0, vec![]));
+        assert_eq!(hungarian(&[vec![5]]), (5, vec![0]));
+        // 1×m: 가장 작은 열 선택
+        assert_eq!(hungarian(&[vec![4, -2, 7, -2, 3]]).0, -2);
+        assert_eq!(hungarian_maximize(&[vec![4, -2, 7, -2, 3]]), (7, vec![2]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn more_rows_than_columns_panics() {
+        hungarian(&[vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(105);
+        for _ in 0..200 {
+            let n = 1 + rng.index(8);
+            let m = n + rng.index(2);
+            let cost: Vec<Vec<i64>> = (0..n)
+                .map(|_| (0..m).map(|_| rng.range_i64(-50, 100)).collect())
+                .collect();
+            let (total, assignment) = hungarian(&cost);
+            assert_eq!(total, brute_force(&cost));
+            assert_valid(&cost, total, &assignment);
+
+            let (total, assignment) = hungarian_maximize(&cost);
+            assert_eq!(total, brute_force_max(&cost));
+            assert_valid(&cost, total, &assignment);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::heap::{Heap, MinHeap};
+
+/// # K번째 최단 경로 (walk)
+/// 방향 그래프 `adj[u] = [(v, 가중치)]`에서 src → dst 로 가는 경로 중 비용이 작은 것부터 최대 k개를
+/// `(비용, 정점 순서)`로 반환합니다. 정점과 간선을 여러 번 지나는 walk도 서로 다른 경로로 셉니다.
+///
+/// * crate의 [`MinHeap`]에 "지금까지 만든 부분 경로"를 넣고, 가장 싼 것을 꺼내 한 간선씩 늘립니다.
+/// 각 정점은 최대 k번만 꺼내고, 그 뒤로 꺼낸 부분 경로는 버립니다. 어떤 정점을 지나는 k개의 최단 경로는
+/// 그 정점까지의 k개의 최단 부분 경로만으로 만들 수 있기 때문입니다.
+/// * 비용이 같은 경로는 부분 경로가 만들어진 순서대로 나옵니다. 그래서 결과는 항상 같습니다.
+/// * src == dst이면 간선이 없는 경로 `(0, [src])`가 첫 번째입니다.
+/// * dst에 도달할 수 없거나 경로가 k개보다 적으면 있는 만큼만 반환합니다.
+/// * 정점 순서만 반환하므로, 같은 두 정점 사이의 중복 간선은 정점 순서가 같은 경로가 여러 번 나옵니다.
+///
+/// > 가중치 합은 u64 범위 안이어야 합니다.
+/// ## 시간복잡도
+/// O(k · E log(k · E)), 메모리 O(k · E)
+pub fn k_shortest_paths(
+    n: usize,
+    adj: &[Vec<(usize, u64)>],
+    src: usize,
+    dst: usize,
+    k: usize,
+) -> Vec<(u64, Vec<usize>)> {
+    assert_eq!(adj.len(), n, "adjacency list must have {n} vertices");
+    assert!(
+        src < n && dst < n,
+        "src {src} or dst {dst} out of range for {n} vertices"
+    );
+    // 부분 경로 트리: (마지막 정점, 직전 부분 경로의 번호). 시작은 usize::MAX
+    let mut nodes: Vec<(usize, usize)> = vec![(src, usize::MAX)];
+    let mut heap: MinHeap<(u64, usize)> = MinHeap::new();
+    heap.push((0, 0));
+    let mut popped = vec![0usize; n];
+    let mut result = Vec::new();
+    while result.len() < k {
+        let Some((cost, id)) = heap.pop() else {
+            break;
+        };
+        let u = nodes[id].0;
+        if popped[u] == k {
+            continue;
+        }
+        popped[u] += 1;
+        if u == dst {
+            result.push((cost, walk(&nodes, id)));
+        }
+        for &(v, w) in &adj[u] {
+            if popped[v] < k {
+                nodes.push((v, id));
+                heap.push((cost + w, nodes.len() - 1));
+            }
+        }
+    }
+    result
+}
+
+fn walk(nodes: &[(usize, usize)], mut id: usize) -> Vec<usize> {
+    let mut path = Vec::new();
+    while id != usize::MAX {
+        path.push(nodes[id].0);
+        id = nodes[id].1;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// src에서 출발해 비용이 bound 이하인 모든 walk 중 dst에서 끝나는 것의 비용 (오름차순)
+    fn brute_force(adj: &[Vec<(usize, u64)>], src: usize, dst: usize, bound: u64) -> Vec<u64> {
+        let mut costs = Vec::new();
+        let mut stack = vec![(src, 0u64)];
+        while let Some((u, c)) = stack.pop() {
+            if u == dst {
+                costs.push(c);
+            }
+            for &(v, w) in &adj[u] {
+                if c + w <= bound {
+                    stack.push((v, c + w));
+                }
+            }
+        }
+        costs.sort_unstable();
+        costs
+    }
+
+    fn assert_walk(adj: &[Vec<(usize, u64)>], src: usize, dst: usize, cost: u64, path: &[usize]) {
+        assert_eq!(path.first(), Some(&src));
+        assert_eq!(path.last(), Some(&dst));
+        let total: u64 = path
+            .windows(2)
+            .map(|e| {
+                adj[e[0]]
+                    .iter()
+                    .find(|&&(v, _)| v == e[1])
+                    .expect("consecutive vertices must be adjacent")
+                    .1
+            })
+            .sum();
+        assert_eq!(total, cost);
+    }
+
+    #[test]
+    fn random_small_against_enumeration() {
+        let mut rng = XorShift::new(124);
+        let bound = 14;
+        for _ in 0..200 {
+            let n = 1 + rng.index(5);
+            // 같은 (u, v) 간선은 하나만 두어서 정점 순서로 비용이 정해지게 함
+            let mut adj = vec![Vec::new(); n];
+            for list in adj.iter_mut() {
+                for v in 0..n {
+                    if rng.below(3) == 0 {
+                        list.push((v, 2 + rng.below(4)));
+                    }
+                }
+            }
+            let (src, dst) = (rng.index(n), rng.index(n));
+            let k = 1 + rng.index(12);
+            let result = k_shortest_paths(n, &adj, src, dst, k);
+            assert!(result.len() <= k);
+            assert!(result.windows(2).all(|w| w[0].0 <= w[1].0));
+            for (cost, path) in &result {
+                assert_walk(&adj, src, dst, *cost, path);
+            }
+            let expected = brute_force(&adj, src, dst, bound);
+            let found: Vec<u64> = result.iter().map(|r| r.0).filter(|&c| c <= bound).collect();
+            assert_eq!(found, expected[..k.min(expected.len())]);
+            // 서로 다른 walk
+            let mut paths: Vec<&Vec<usize>> = result.iter().map(|r| &r.1).collect();
+            paths.sort();
+            paths.dedup();
+            assert_eq!(paths.len(), result.len());
+        }
+    }
+
+    #[test]
+    fn ties_are_deterministic() {
+        // 0 → 1 → 3, 0 → 2 → 3 모두 비용 2, 3 → 0 으로 돌아가는 비용 0 간선
+        let adj = vec![
+            vec![(1, 1), (2, 1)],
+            vec![(3, 1)],
+            vec![(3, 1)],
+            vec![(0, 0)],
+        ];
+        let result = k_shortest_paths(4, &adj, 0, 3, 4);
+        assert_eq!(
+            result,
+            vec![
+                (2, vec![0, 1, 3]),
+                (2, vec![0, 2, 3]),
+                (4, vec![0, 1, 3, 0, 1, 3]),
+                (4, vec![0, 1, 3, 0, 2, 3]),
+            ]
+        );
+        assert_eq!(k_shortest_paths(4, &adj, 0, 3, 4), result);
+    }
+
+    #[test]
+    fn terminates_when_paths_run_out() {
+        // 도달 불가능
+        let adj = vec![vec![(1, 3)], vec![(0, 2)], vec![]];
+        assert!(k_shortest_paths(3, &adj, 0, 2, 5).is_empty());
+        // DAG에서 경로가 k개보다 적음
+        let adj = vec![vec![(1, 1), (2, 5)], vec![(2, 1)], vec![]];
+        assert_eq!(
+            k_shortest_paths(3, &adj, 0, 2, 10),
+            vec![(2, vec![0, 1, 2]), (5, vec![0, 2])]
+        );
+        // src == dst, 비용 0 사이클
+        let adj = vec![vec![(0, 0)]];
+        assert_eq!(
+            k_shortest_paths(1, &adj, 0, 0, 3),
+            vec![(0, vec![0]), (0, vec![0, 0]), (0, vec![0, 0, 0])]
+        );
+        assert!(k_shortest_paths(1, &adj, 0, 0, 0).is_empty());
+    }
+}
@@ -0,0 +1,297 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::collections::VecDeque;
+
+/// 이분 매칭 결과.
+/// * `left[u]`: 왼쪽 정점 u와 매칭된 오른쪽 정점 (없으면 None)
+/// * `right[v]`: 오른쪽 정점 v와 매칭된 왼쪽 정점 (없으면 None)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matching {
+    pub size: usize,
+    pub left: Vec<Option<usize>>,
+    pub right: Vec<Option<usize>>,
+}
+
+/// # Hopcroft–Karp 최대 이분 매칭
+/// 왼쪽 정점 0..n_left, 오른쪽 정점 0..n_right 사이의 간선 `(왼쪽, 오른쪽)` 목록으로 최대 매칭을 구합니다.
+/// 중복 간선이나 간선이 없는 정점이 있어도 됩니다.
+///
+/// 한 단계마다 BFS로 매칭되지 않은 왼쪽 정점들로부터의 레벨을 구하고,
+/// 레벨 그래프 위에서 DFS로 서로 겹치지 않는 최단 증가 경로들을 한꺼번에 찾습니다.
+/// 단계 수가 O(√V)이므로 전체 시간복잡도는 O(E√V)입니다.
+/// > DFS는 명시적인 스택으로 구현되어 있어서 경로가 길어도 스택 오버플로가 나지 않습니다.
+pub fn hopcroft_karp(n_left: usize, n_right: usize, edges: &[(usize, usize)]) -> Matching {
+    let adj = left_adjacency(n_left, n_right, edges);
+    let mut left: Vec<Option<usize>> = vec![None; n_left];
+    let mut right: Vec<Option<usize>> = vec![None; n_right];
+    let mut dist = vec![usize::MAX; n_left];
+    let mut it = vec![0usize; n_left];
+    let mut size = 0;
+
+    loop {
+        // BFS: 매칭되지 않은 왼쪽 정점들로부터 레벨 계산
+        let mut queue = VecDeque::new();
+        for u in 0..n_left {
+            if left[u].is_none() {
+                dist[u] = 0;
+                queue.push_back(u);
+            } else {
+                dist[u] = usize::MAX;
+            }
+        }
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            for &v in &adj[u] {
+                match right[v] {
+                    None => found = true,
+                    Some(w) if dist[w] == usize::MAX => {
+                        dist[w] = dist[u] + 1;
+                        queue.push_back(w);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if !found {
+            break;
+        }
+
+        // DFS: 레벨 그래프 위에서 증가 경로 찾기
+        it.iter_mut().for_each(|x| *x = 0);
+        for root in 0..n_left {
+            if left[root].is_some() {
+                continue;
+            }
+            let mut stack = vec![root];
+            while let Some(&u) = stack.last() {
+                if it[u] == adj[u].len() {
+                    // u에서는 더 이상 증가 경로가 없으므로 이번 단계에서 제외
+                    dist[u] = usize::MAX;
+                    stack.pop();
+                    continue;
+                }
+                let v = adj[u][it[u]];
+                match right[v] {
+                    None => {
+                        // 스택의 각 정점을 현재 보고 있는 간선으로 다시 매칭
+                        for &s in &stack {
+                            let t = adj[s][it[s]];
+                            left[s] = Some(t);
+                            right[t] = Some(s);
+                        }
+                        size += 1;
+                        break;
+                    }
+                    Some(w) if dist[w] == dist[u] + 1 => stack.push(w),
+                    Some(_) => it[u] += 1,
+                }
+            }
+        }
+    }
+
+    Matching { size, left, right }
+}
+
+/// # 최소 버텍스 커버 (König 정리)
+/// `matching`이 `edges`에 대한 최대 매칭일 때, 모든 간선의 한쪽 끝 이상을 포함하는 가장 작은 정점 집합을 구합니다.
+/// 반환값은 `(왼쪽 정점 목록, 오른쪽 정점 목록)`이며 각각 오름차순이고, 크기의 합은 `matching.size`와 같습니다.
+///
+/// 매칭되지 않은 왼쪽 정점들에서 교대 경로(왼→오는 매칭이 아닌 간선, 오→왼은 매칭 간선)로 도달 가능한 집합을 Z라 하면,
+/// (L \ Z) ∪ (R ∩ Z)가 최소 버텍스 커버입니다.
+pub fn minimum_vertex_cover(
+    matching: &Matching,
+    edges: &[(usize, usize)],
+) -> (Vec<usize>, Vec<usize>) {
+    let (left_reach, right_reach) = alternating_reach(matching, edges);
+    let left = (0..left_reach.len()).filter(|&u| !left_reach[u]).collect();
+    let right = (0..right_reach.len()).filter(|&v| right_reach[v]).collect();
+    (left, right)
+}
+
+/// # 최대 독립 집합
+/// 최소 버텍스 커버의 여집합입니다. 어떤 간선도 양 끝이 모두 포함되지 않으며,
+/// 크기의 합은 `n_left + n_right - matching.size`입니다. 반환 형태는 [`minimum_vertex_cover`]와 같습니다.
+pub fn maximum_independent_set(
+    matching: &Matching,
+    edges: &[(usize, usize)],
+) -> (Vec<usize>, Vec<usize>) {
+    let (left_reach, right_reach) = alternating_reach(matching, edges);
+    let left = (0..left_reach.len()).filter(|&u| left_reach[u]).collect();
+    let right = (0..right_reach.len())
+        .filter(|&v| !right_reach[v])
+        .collect();
+    (left, right)
+}
+
+/// 매칭되지 않은 왼쪽 정점에서 교대 경로로 도달 가능한 정점 표시
+fn alternating_reach(matching: &Matching, edges: &[(usize, usize)]) -> (Vec<bool>, Vec<bool>) {
+    let n_left = matching.left.len();
+    let n_right = matching.right.len();
+    let adj = left_adjacency(n_left, n_right, edges);
+    let mut left_reach = vec![false; n_left];
+    let mut right_reach = vec![false; n_right];
+    let mut queue = VecDeque::new();
+    for (u, partner) in matching.left.iter().enumerate() {
+        if partner.is_none() {
+            left_reach[u] = true;
+            queue.push_back(u);
+        }
+    }
+    while let Some(u) = queue.pop_front() {
+        for &v in &adj[u] {
+            if right_reach[v] || matching.left[u] == Some(v) {
+                continue;
+            }
+            right_reach[v] = true;
+            if let Some(w) = matching.right[v]
+                && !left_reach[w]
+            {
+                left_reach[w] = true;
+                queue.push_back(w);
+            }
+        }
+    }
+    (left_reach, right_reach)
+}
+
+fn left_adjacency(n_left: usize, n_right: usize, edges: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let mut adj = vec![Vec::new(); n_left];
+    for &(u, v) in edges {
+        assert!(
+            u < n_left && v < n_right,
+            "edge ({u}, {v}) out of range for {n_left}x{n_right} bipartite graph"
+        );
+        adj[u].push(v);
+    }
+    adj
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 왼쪽 정점을 하나씩 보면서 사용한 오른쪽 정점을 bitmask로 들고 가는 brute force
+    fn brute_force_matching(n_left: usize, n_right: usize, edges: &[(usize, usize)]) -> usize {
+        fn go(u: usize, used: u32, adj: &[Vec<usize>]) -> usize {
+            if u == adj.len() {
+                return 0;
+            }
+            let mut best = go(u + 1, used, adj);
+            for &v in &adj[u] {
+                if used >> v & 1 == 0 {
+                    best = best.max(1 + go(u + 1, used | 1 << v, adj));
+                }
+            }
+            best
+        }
+        go(0, 0, &left_adjacency(n_left, n_right, edges))
+    }
+
+    fn assert_valid_matching(m: &Matching, edges: &[(usize, usize)]) {
+        let mut count = 0;
+        for (u, &v) in m.left.iter().enumerate() {
+            if let Some(v) = v {
+                assert_eq!(m.right[v], Some(u));
+                assert!(edges.contains(&(u, v)), "({u}, {v}) is not an edge");
+                count += 1;
+            }
+        }
+        assert_eq!(m.right.iter().flatten().count(), count);
+        assert_eq!(m.size, count);
+        // 양 끝이 모두 비어있는 간선이 없어야 함 (maximal)
+        for &(u, v) in edges {
+            assert!(m.left[u].is_some() || m.right[v].is_some());
+        }
+    }
+
+    fn assert_cover_and_independent(m: &Matching, edges: &[(usize, usize)]) {
+        let (cl, cr) = minimum_vertex_cover(m, edges);
+        assert_eq!(cl.len() + cr.len(), m.size);
+        for &(u, v) in edges {
+            assert!(
+                cl.contains(&u) || cr.contains(&v),
+                "edge ({u}, {v}) not covered"
+            );
+        }
+        let (il, ir) = maximum_independent_set(m, edges);
+        assert_eq!(il.len() + ir.len(), m.left.len() + m.right.len() - m.size);
+        for &(u, v) in edges {
+            assert!(!(il.contains(&u) && ir.contains(&v)));
+        }
+    }
+
+    #[test]
+    fn small_known_cases() {
+        let edges = [(0, 0), (0, 1), (1, 0), (2, 1), (2, 2)];
+        let m = hopcroft_karp(3, 3, &edges);
+        assert_eq!(m.size, 3);
+        assert_valid_matching(&m, &edges);
+        assert_cover_and_independent(&m, &edges);
+
+        // 별 모양: 왼쪽 하나에 오른쪽 여럿
+        let edges = [(0, 0), (0, 1), (0, 2)];
+        let m = hopcroft_karp(1, 3, &edges);
+        assert_eq!(m.size, 1);
+        assert_eq!(minimum_vertex_cover(&m, &edges), (vec![0], vec![]));
+    }
+
+    #[test]
+    fn empty_sides_and_duplicate_edges() {
+        let m = hopcroft_karp(0, 0, &[]);
+        assert_eq!(m.size, 0);
+        assert_eq!(minimum_vertex_cover(&m, &[]), (vec![], vec![]));
+
+        let m = hopcroft_karp(3, 0, &[]);
+        assert_eq!(m.left, vec![None; 3]);
+        assert_eq!(maximum_independent_set(&m, &[]), (vec![0, 1, 2], vec![]));
+
+        let edges = [(1, 1), (1, 1), (1, 1)];
+        let m = hopcroft_karp(3, 2, &edges);
+        assert_eq!(m.size, 1);
+        assert_eq!(m.left, vec![None, Some(1), None]);
+        assert_cover_and_independent(&m, &edges);
+    }
+
+    #[test]
+    fn random_small_against_brute_force() {
+        let mut rng = XorShift::new(104);
+        for _ in 0..500 {
+            let n_left = rng.index(7);
+            let n_right = rng.index(7);
+            let m = if n_left * n_right == 0 {
+                0
+            } else {
+                rng.index(n_left * n_right + 3)
+            };
+            let edges: Vec<(usize, usize)> = (0..m)
+                .map(|_| (rng.index(n_left), rng.index(n_right)))
+                .collect();
+            let matching = hopcroft_karp(n_left, n_right, &edges);
+            assert_eq!(matching.size, brute_force_matching(n_left, n_right, &edges));
+            assert_valid_matching(&matching, &edges);
+            assert_cover_and_independent(&matching, &edges);
+        }
+    }
+
+    #[test]
+    fn random_large_is_valid_and_maximum() {
+        let mut rng = XorShift::new(1040);
+        for _ in 0..5 {
+            let n_left = 500 + rng.index(500);
+            let n_right = 500 + rng.index(500);
+            let edges: Vec<(usize, usize)> = (0..3000)
+                .map(|_| (rng.index(n_left), rng.index(n_right)))
+                .collect();
+            let matching = hopcroft_karp(n_left, n_right, &edges);
+            assert_valid_matching(&matching, &edges);
+            // 크기가 같은 버텍스 커버가 존재하면 최대 매칭임이 보장됨
+            let (cl, cr) = minimum_vertex_cover(&matching, &edges);
+            assert_eq!(cl.len() + cr.len(), matching.size);
+            let mut in_left = vec![false; n_left];
+            let mut in_right = vec![false; n_right];
+            cl.iter().for_each(|&u| in_left[u] = true);
+            cr.iter().for_each(|&v| in_right[v] = true);
+            assert!(edges.iter().all(|&(u, v)| in_left[u] || in_right[v]));
+        }
+    }
+}
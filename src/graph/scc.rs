@@ -0,0 +1,138 @@
+/// # 강한 연결 요소 (Tarjan)
+/// 방향 그래프의 인접 리스트 `adj`(`adj[u]`는 u에서 나가는 간선의 도착 정점들)로 강한 연결 요소를 구합니다.
+/// 반환값은 `(요소 개수, 정점별 요소 번호)`입니다.
+///
+/// * 요소 번호는 축약 그래프의 위상 순서입니다. 즉 간선 u → v가 서로 다른 요소를 잇는다면 `comp[u] < comp[v]`입니다.
+/// * DFS는 명시적인 스택으로 구현되어 있어서 깊은 그래프에서도 스택 오버플로가 나지 않습니다.
+/// ## 시간복잡도
+/// O(V + E)
+pub fn strongly_connected_components(adj: &[Vec<usize>]) -> (usize, Vec<usize>) {
+    const UNVISITED: usize = usize::MAX;
+    let n = adj.len();
+    let mut index = vec![UNVISITED; n];
+    let mut low = vec![0usize; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut comp = vec![UNVISITED; n];
+    let mut count = 0;
+    let mut next_index = 0;
+    // (정점, 다음에 볼 간선 위치)
+    let mut call: Vec<(usize, usize)> = Vec::new();
+
+    for root in 0..n {
+        if index[root] != UNVISITED {
+            continue;
+        }
+        index[root] = next_index;
+        low[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        call.push((root, 0));
+
+        while let Some(&mut (v, ref mut i)) = call.last_mut() {
+            if *i < adj[v].len() {
+                let w = adj[v][*i];
+                *i += 1;
+                if index[w] == UNVISITED {
+                    index[w] = next_index;
+                    low[w] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    call.push((w, 0));
+                } else if on_stack[w] {
+                    low[v] = low[v].min(index[w]);
+                }
+                continue;
+            }
+            call.pop();
+            if let Some(&(parent, _)) = call.last() {
+                low[parent] = low[parent].min(low[v]);
+            }
+            if low[v] == index[v] {
+                loop {
+                    let w = stack.pop().unwrap();
+                    on_stack[w] = false;
+                    comp[w] = count;
+                    if w == v {
+                        break;
+                    }
+                }
+                count += 1;
+            }
+        }
+    }
+
+    // Tarjan은 위상 순서의 역순으로 요소를 완성하므로 번호를 뒤집음
+    for c in comp.iter_mut() {
+        *c = count - 1 - *c;
+    }
+    (count, comp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn reachability(adj: &[Vec<usize>]) -> Vec<Vec<bool>> {
+        let n = adj.len();
+        let mut reach = vec![vec![false; n]; n];
+        for (s, row) in reach.iter_mut().enumerate() {
+            let mut stack = vec![s];
+            row[s] = true;
+            while let Some(u) = stack.pop() {
+                for &v in &adj[u] {
+                    if !row[v] {
+                        row[v] = true;
+                        stack.push(v);
+                    }
+                }
+            }
+        }
+        reach
+    }
+
+    #[test]
+    fn small_graph() {
+        // 0 ⇄ 1 → 2 ⇄ 3, 4 단독
+        let adj = vec![vec![1], vec![0, 2], vec![3], vec![2], vec![]];
+        let (count, comp) = strongly_connected_components(&adj);
+        assert_eq!(count, 3);
+        assert_eq!(comp[0], comp[1]);
+        assert_eq!(comp[2], comp[3]);
+        assert!(comp[1] < comp[2]);
+        assert_eq!(strongly_connected_components(&[]), (0, vec![]));
+    }
+
+    #[test]
+    fn random_against_reachability() {
+        let mut rng = XorShift::new(108);
+        for _ in 0..300 {
+            let n = 1 + rng.index(12);
+            let mut adj = vec![Vec::new(); n];
+            for _ in 0..rng.index(n * 3) {
+                adj[rng.index(n)].push(rng.index(n));
+            }
+            let (count, comp) = strongly_connected_components(&adj);
+            let reach = reachability(&adj);
+            for u in 0..n {
+                assert!(comp[u] < count);
+                for v in 0..n {
+                    assert_eq!(comp[u] == comp[v], reach[u][v] && reach[v][u]);
+                }
+                for &v in &adj[u] {
+                    assert!(comp[u] <= comp[v], "not topological order");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn long_path_does_not_overflow() {
+        let n = 200_000;
+        let adj: Vec<Vec<usize>> = (0..n).map(|v| vec![(v + 1) % n]).collect();
+        assert_eq!(strongly_connected_components(&adj).0, 1);
+    }
+}
@@ -0,0 +1,207 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::heap::{Heap, MaxHeap};
+use std::collections::BTreeMap;
+
+/// # Stoer–Wagner 전역 최소 컷
+/// 가중치 있는 무방향 그래프의 정점을 두 개의 비어 있지 않은 집합으로 나눌 때, 사이를 잇는 간선 가중치 합의 최솟값과
+/// 한쪽 집합(정점 번호 오름차순)을 반환합니다. 모든 s-t 쌍에 대해 최대 유량을 구하지 않아도 됩니다.
+///
+/// * 한 단계(phase)마다 집합 A에 "A와 가장 강하게 연결된 정점"을 하나씩 넣는 순서(maximum adjacency ordering)를 만듭니다.
+/// 마지막에 들어간 정점 t와 그 직전 정점 s에 대해, t 하나만 떼어내는 컷이 최소 s-t 컷입니다.
+/// 그 뒤 s와 t를 합치고 다음 단계로 넘어갑니다.
+/// * 가장 강하게 연결된 정점은 crate의 [`MaxHeap`]에 `(연결 가중치, 정점)`을 넣고, 낡은 원소는 꺼낼 때 버려서 고릅니다.
+/// * 연결되어 있지 않으면 컷은 0이고, 반환하는 집합은 연결 요소들의 합집합입니다.
+/// * 중복 간선은 가중치가 더해지고, self-loop는 무시합니다.
+///
+/// > 정점이 2개 이상이어야 합니다. 가중치 합은 u64 범위 안이어야 합니다.
+/// ## 시간복잡도
+/// O(V · E log V)
+pub fn global_min_cut(n: usize, edges: &[(usize, usize, u64)]) -> (u64, Vec<usize>) {
+    assert!(n >= 2, "global min cut needs at least 2 vertices, got {n}");
+    // 합쳐진 정점끼리의 가중치. 결과가 실행마다 같도록 BTreeMap을 씁니다.
+    let mut adj: Vec<BTreeMap<usize, u64>> = vec![BTreeMap::new(); n];
+    for &(u, v, w) in edges {
+        assert!(
+            u < n && v < n,
+            "edge ({u}, {v}) out of range for {n} vertices"
+        );
+        if u != v {
+            *adj[u].entry(v).or_insert(0) += w;
+            *adj[v].entry(u).or_insert(0) += w;
+        }
+    }
+    // members[v]: 대표 정점 v로 합쳐진 원래 정점들
+    let mut members: Vec<Vec<usize>> = (0..n).map(|v| vec![v]).collect();
+    let mut active: Vec<usize> = (0..n).collect();
+    let mut best = (u64::MAX, Vec::new());
+
+    let mut key = vec![0u64; n];
+    let mut in_a = vec![false; n];
+    while active.len() > 1 {
+        for &v in &active {
+            key[v] = 0;
+            in_a[v] = false;
+        }
+        let mut heap: MaxHeap<(u64, usize)> = MaxHeap::new();
+        for &v in &active {
+            heap.push((0, v));
+        }
+        let (mut s, mut t) = (usize::MAX, usize::MAX);
+        let mut added = 0;
+        while added < active.len() {
+            let (k, u) = heap.pop().expect("every active vertex is in the heap");
+            if in_a[u] || k != key[u] {
+                continue;
+            }
+            in_a[u] = true;
+            added += 1;
+            s = t;
+            t = u;
+            for (&v, &w) in &adj[u] {
+                if !in_a[v] {
+                    key[v] += w;
+                    heap.push((key[v], v));
+                }
+            }
+        }
+        if key[t] < best.0 {
+            best = (key[t], members[t].clone());
+        }
+
+        // t를 s에 합침
+        let moved = std::mem::take(&mut members[t]);
+        members[s].extend(moved);
+        let t_adj = std::mem::take(&mut adj[t]);
+        for (v, w) in t_adj {
+            adj[v].remove(&t);
+            if v != s {
+                *adj[s].entry(v).or_insert(0) += w;
+                *adj[v].entry(s).or_insert(0) += w;
+            }
+        }
+        active.retain(|&v| v != t);
+    }
+    best.1.sort_unstable();
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flow::MaxFlow;
+    use crate::random::XorShift;
+
+    fn cut_weight(n: usize, edges: &[(usize, usize, u64)], side: &[usize]) -> u64 {
+        let mut in_side = vec![false; n];
+        for &v in side {
+            in_side[v] = true;
+        }
+        edges
+            .iter()
+            .filter(|&&(u, v, _)| in_side[u] != in_side[v])
+            .map(|&(_, _, w)| w)
+            .sum()
+    }
+
+    fn assert_valid(n: usize, edges: &[(usize, usize, u64)], cut: u64, side: &[usize]) {
+        assert!(
+            !side.is_empty() && side.len() < n,
+            "side must be a proper subset"
+        );
+        assert!(side.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(cut_weight(n, edges, side), cut);
+    }
+
+    fn random_edges(
+        rng: &mut XorShift,
+        n: usize,
+        m: usize,
+        max_w: u64,
+    ) -> Vec<(usize, usize, u64)> {
+        (0..m)
+            .map(|_| (rng.index(n), rng.index(n), rng.below(max_w + 1)))
+            .collect()
+    }
+
+    #[test]
+    fn known_cases() {
+        // Stoer–Wagner 논문의 예제 그래프 (정점 1..8 → 0..7). 최소 컷 4 = {2, 3, 6, 7}
+        let edges = [
+            (0, 1, 2),
+            (0, 4, 3),
+            (1, 2, 3),
+            (1, 4, 2),
+            (1, 5, 2),
+            (2, 3, 4),
+            (2, 6, 2),
+            (3, 6, 2),
+            (3, 7, 2),
+            (4, 5, 3),
+            (5, 6, 1),
+            (6, 7, 3),
+        ];
+        let (cut, side) = global_min_cut(8, &edges);
+        assert_eq!(cut, 4);
+        assert_valid(8, &edges, cut, &side);
+        let mut other: Vec<usize> = (0..8).filter(|v| !side.contains(v)).collect();
+        other.sort_unstable();
+        assert!(side == vec![2, 3, 6, 7] || other == vec![2, 3, 6, 7]);
+
+        // 연결되어 있지 않으면 0
+        let edges = [(0, 1, 5), (2, 3, 7), (3, 4, 1)];
+        let (cut, side) = global_min_cut(5, &edges);
+        assert_eq!(cut, 0);
+        assert_valid(5, &edges, cut, &side);
+        let (cut, side) = global_min_cut(2, &[]);
+        assert_eq!(cut, 0);
+        assert_eq!(side.len(), 1);
+        // self-loop와 중복 간선
+        assert_eq!(global_min_cut(2, &[(0, 0, 9), (0, 1, 2), (1, 0, 3)]).0, 5);
+    }
+
+    #[test]
+    fn random_small_against_subsets() {
+        let mut rng = XorShift::new(121);
+        for _ in 0..200 {
+            let n = 2 + rng.index(11);
+            let m = rng.index(3 * n);
+            let edges = random_edges(&mut rng, n, m, 10);
+            // 정점 0을 포함하지 않는 쪽을 모두 시도
+            let expected = (1..1u32 << (n - 1))
+                .map(|mask| {
+                    let side: Vec<usize> = (1..n).filter(|&v| mask >> (v - 1) & 1 == 1).collect();
+                    cut_weight(n, &edges, &side)
+                })
+                .min()
+                .unwrap();
+            let (cut, side) = global_min_cut(n, &edges);
+            assert_eq!(cut, expected);
+            assert_valid(n, &edges, cut, &side);
+        }
+    }
+
+    #[test]
+    fn medium_against_max_flow() {
+        let mut rng = XorShift::new(1210);
+        for _ in 0..10 {
+            let n = 20 + rng.index(30);
+            let m = n + rng.index(4 * n);
+            let edges = random_edges(&mut rng, n, m, 1000);
+            // 전역 최소 컷은 어떤 t에 대해 최소 0-t 컷
+            let expected = (1..n)
+                .map(|t| {
+                    let mut flow = MaxFlow::new(n);
+                    for &(u, v, w) in &edges {
+                        flow.add_edge(u, v, w as i64);
+                        flow.add_edge(v, u, w as i64);
+                    }
+                    flow.max_flow(0, t) as u64
+                })
+                .min()
+                .unwrap();
+            let (cut, side) = global_min_cut(n, &edges);
+            assert_eq!(cut, expected);
+            assert_valid(n, &edges, cut, &side);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::collections::VecDeque;
+
+/// # 위상 정렬 (Kahn)
+/// 방향 그래프의 인접 리스트 `adj`(`adj[u]`는 u에서 나가는 간선의 도착 정점들)의 정점을,
+/// 모든 간선 u → v에 대해 u가 v보다 먼저 오도록 나열합니다.
+///
+/// * 성공하면 정점 순서를 `Ok(order)`로 반환합니다. 진입 차수가 0이 된 정점을 큐에 넣는 순서대로 꺼냅니다.
+/// * 사이클이 있으면 그 중 하나를 `Err(cycle)`로 반환합니다. `cycle`의 연속한 두 정점과 마지막-첫 정점은 간선
+/// `cycle[i] → cycle[i + 1]`로 이어져 있습니다. self-loop `(v, v)`는 `[v]`가 됩니다.
+/// ## 시간복잡도
+/// O(V + E)
+pub fn topological_sort(adj: &[Vec<usize>]) -> Result<Vec<usize>, Vec<usize>> {
+    let n = adj.len();
+    let mut indegree = vec![0usize; n];
+    for list in adj {
+        for &v in list {
+            assert!(v < n, "edge target {v} out of range for {n} vertices");
+            indegree[v] += 1;
+        }
+    }
+    let mut queue: VecDeque<usize> = (0..n).filter(|&v| indegree[v] == 0).collect();
+    let mut order = Vec::with_capacity(n);
+    while let Some(u) = queue.pop_front() {
+        order.push(u);
+        for &v in &adj[u] {
+            indegree[v] -= 1;
+            if indegree[v] == 0 {
+                queue.push_back(v);
+            }
+        }
+    }
+    if order.len() == n {
+        Ok(order)
+    } else {
+        Err(find_cycle(adj, &indegree))
+    }
+}
+
+/// 남은 정점(진입 차수 > 0)은 모두 남은 정점에서 들어오는 간선이 있으므로, 거꾸로 따라가면 사이클을 만납니다.
+fn find_cycle(adj: &[Vec<usize>], indegree: &[usize]) -> Vec<usize> {
+    let n = adj.len();
+    let mut pred = vec![usize::MAX; n];
+    for (u, list) in adj.iter().enumerate() {
+        if indegree[u] > 0 {
+            for &v in list {
+                if indegree[v] > 0 {
+                    pred[v] = u;
+                }
+            }
+        }
+    }
+    let start = (0..n).find(|&v| indegree[v] > 0).unwrap();
+    let mut seen = vec![false; n];
+    let mut v = start;
+    while !seen[v] {
+        seen[v] = true;
+        v = pred[v];
+    }
+    let mut cycle = vec![v];
+    let mut u = pred[v];
+    while u != v {
+        cycle.push(u);
+        u = pred[u];
+    }
+    cycle.reverse();
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn assert_cycle(adj: &[Vec<usize>], cycle: &[usize]) {
+        assert!(!cycle.is_empty());
+        for (i, &u) in cycle.iter().enumerate() {
+            let v = cycle[(i + 1) % cycle.len()];
+            assert!(adj[u].contains(&v), "{u} -> {v} is not an edge");
+        }
+        let mut sorted = cycle.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), cycle.len());
+    }
+
+    #[test]
+    fn small_cases() {
+        assert_eq!(topological_sort(&[]), Ok(vec![]));
+        let adj = vec![vec![2], vec![2], vec![3], vec![]];
+        assert_eq!(topological_sort(&adj), Ok(vec![0, 1, 2, 3]));
+        assert_eq!(topological_sort(&[vec![0]]), Err(vec![0]));
+        let adj = vec![vec![1], vec![2], vec![3], vec![1]];
+        let cycle = topological_sort(&adj).unwrap_err();
+        assert_cycle(&adj, &cycle);
+        assert_eq!(cycle.len(), 3);
+    }
+
+    #[test]
+    fn random_graphs() {
+        let mut rng = XorShift::new(125);
+        for round in 0..300 {
+            let n = 1 + rng.index(30);
+            let m = rng.index(2 * n);
+            // 절반은 순열 순서를 따르는 DAG, 나머지는 아무 간선
+            let mut perm: Vec<usize> = (0..n).collect();
+            for i in (1..n).rev() {
+                perm.swap(i, rng.index(i + 1));
+            }
+            let mut adj = vec![Vec::new(); n];
+            for _ in 0..m {
+                let (a, b) = (rng.index(n), rng.index(n));
+                if round % 2 == 0 {
+                    if a != b {
+                        adj[perm[a.min(b)]].push(perm[a.max(b)]);
+                    }
+                } else {
+                    adj[a].push(b);
+                }
+            }
+            match topological_sort(&adj) {
+                Ok(order) => {
+                    let mut position = vec![usize::MAX; n];
+                    for (i, &v) in order.iter().enumerate() {
+                        position[v] = i;
+                    }
+                    assert!(position.iter().all(|&p| p < n));
+                    for (u, list) in adj.iter().enumerate() {
+                        for &v in list {
+                            assert!(position[u] < position[v]);
+                        }
+                    }
+                }
+                Err(cycle) => {
+                    assert_ne!(round % 2, 0, "a DAG must be sortable");
+                    assert_cycle(&adj, &cycle);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,270 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// 간선이 없음을 나타내는 거리
+pub const NO_EDGE: u64 = u64::MAX;
+
+/// # 외판원 문제 (Held–Karp)
+/// 거리 행렬 `dist[u][v]`(u → v, 없으면 [`NO_EDGE`] = `u64::MAX`)에서 모든 정점을 한 번씩 방문하고
+/// 출발점으로 돌아오는 가장 짧은 순회의 비용과 방문 순서를 구합니다. 순회가 없으면 None입니다.
+///
+/// * 방문 순서는 정점 0에서 시작하는 길이 n의 Vec이고, 마지막 정점에서 0으로 돌아가는 간선까지 비용에 포함됩니다.
+/// * 방향 그래프(비대칭 행렬)도 됩니다. 대각 성분은 무시합니다.
+/// * n = 1이면 `(0, [0])`, n = 0이면 `(0, [])`입니다.
+/// * 정점 0은 항상 처음이므로 나머지 n - 1개 정점의 집합만 bitmask로 둡니다.
+///
+/// > 비용의 합이 u64를 넘지 않는다고 가정합니다.
+/// ## 시간복잡도
+/// O(2^n · n²), 메모리 O(2^n · n). n은 20 이하여야 하고, 넘으면 panic합니다.
+pub fn tsp(dist: &[Vec<u64>]) -> Option<(u64, Vec<usize>)> {
+    let n = check_square(dist);
+    if n <= 1 {
+        return Some((0, (0..n).collect()));
+    }
+    let vertices: Vec<usize> = (1..n).collect();
+    let table = held_karp(dist, &vertices, |v| dist[0][v]);
+    let (cost, last) = table.best_end(|v| dist[v][0])?;
+    let mut tour = vec![0];
+    tour.extend(table.path(last));
+    Some((cost, tour))
+}
+
+/// # 해밀턴 경로
+/// [`tsp`]와 같지만 출발점으로 돌아오지 않습니다. 시작 정점과 끝 정점은 자유롭게 고릅니다.
+/// 모든 정점을 한 번씩 지나는 가장 짧은 경로의 비용과 정점 순서를 반환하고, 없으면 None입니다.
+pub fn tsp_path(dist: &[Vec<u64>]) -> Option<(u64, Vec<usize>)> {
+    let n = check_square(dist);
+    if n <= 1 {
+        return Some((0, (0..n).collect()));
+    }
+    let vertices: Vec<usize> = (0..n).collect();
+    let table = held_karp(dist, &vertices, |_| 0);
+    let (cost, last) = table.best_end(|_| 0)?;
+    Some((cost, table.path(last)))
+}
+
+fn check_square(dist: &[Vec<u64>]) -> usize {
+    let n = dist.len();
+    assert!(n <= 20, "n = {n} is too large for bitmask DP");
+    for row in dist {
+        assert_eq!(row.len(), n, "distance matrix must be square");
+    }
+    n
+}
+
+struct Table<'a> {
+    vertices: &'a [usize],
+    /// `cost[mask * m + i]`: mask의 정점을 모두 지나고 vertices[i]에서 끝나는 최소 비용
+    cost: Vec<u64>,
+    /// 직전 정점의 위치. 첫 정점이면 u8::MAX
+    prev: Vec<u8>,
+}
+
+/// vertices의 부분집합 mask마다, 그 정점들을 모두 지나고 각 정점에서 끝나는 최소 비용.
+/// `start(v)`는 v에서 경로를 시작하는 비용입니다.
+fn held_karp<'a>(
+    dist: &[Vec<u64>],
+    vertices: &'a [usize],
+    start: impl Fn(usize) -> u64,
+) -> Table<'a> {
+    let m = vertices.len();
+    let full = 1u32 << m;
+    let mut cost = vec![NO_EDGE; full as usize * m];
+    let mut prev = vec![u8::MAX; full as usize * m];
+    for (i, &v) in vertices.iter().enumerate() {
+        cost[(1usize << i) * m + i] = start(v);
+    }
+    for mask in 1..full {
+        for i in 0..m {
+            let here = cost[mask as usize * m + i];
+            if mask >> i & 1 == 0 || here == NO_EDGE {
+                continue;
+            }
+            let u = vertices[i];
+            for (j, &v) in vertices.iter().enumerate() {
+                if mask >> j & 1 == 1 || dist[u][v] == NO_EDGE {
+                    continue;
+                }
+                let next = (mask | 1 << j) as usize * m + j;
+                let candidate = here + dist[u][v];
+                if candidate < cost[next] {
+                    cost[next] = candidate;
+                    prev[next] = i as u8;
+                }
+            }
+        }
+    }
+    Table {
+        vertices,
+        cost,
+        prev,
+    }
+}
+
+impl Table<'_> {
+    /// 모두 방문한 뒤 `finish(v)`를 더했을 때 가장 싼 (비용, 끝 정점 위치)
+    fn best_end(&self, finish: impl Fn(usize) -> u64) -> Option<(u64, usize)> {
+        let m = self.vertices.len();
+        let full = (1usize << m) - 1;
+        (0..m)
+            .filter_map(|i| {
+                let c = self.cost[full * m + i];
+                let f = finish(self.vertices[i]);
+                (c != NO_EDGE && f != NO_EDGE).then(|| (c + f, i))
+            })
+            .min()
+    }
+
+    /// 끝 정점 위치에서 거꾸로 따라가서 방문 순서를 복원
+    fn path(&self, last: usize) -> Vec<usize> {
+        let m = self.vertices.len();
+        let mut mask = (1usize << m) - 1;
+        let mut i = last;
+        let mut order = Vec::with_capacity(m);
+        loop {
+            order.push(self.vertices[i]);
+            let p = self.prev[mask * m + i];
+            if p == u8::MAX {
+                break;
+            }
+            mask ^= 1 << i;
+            i = p as usize;
+        }
+        order.reverse();
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn cost_of(dist: &[Vec<u64>], order: &[usize], cycle: bool) -> Option<u64> {
+        let mut total = 0;
+        let mut steps: Vec<(usize, usize)> = order.windows(2).map(|w| (w[0], w[1])).collect();
+        if cycle && order.len() > 1 {
+            steps.push((order[order.len() - 1], order[0]));
+        }
+        for (u, v) in steps {
+            if dist[u][v] == NO_EDGE {
+                return None;
+            }
+            total += dist[u][v];
+        }
+        Some(total)
+    }
+
+    fn next_permutation(a: &mut [usize]) -> bool {
+        let Some(i) = (1..a.len()).rev().find(|&i| a[i - 1] < a[i]) else {
+            return false;
+        };
+        let j = (i..a.len()).rev().find(|&j| a[j] > a[i - 1]).unwrap();
+        a.swap(i - 1, j);
+        a[i..].reverse();
+        true
+    }
+
+    fn brute_force(dist: &[Vec<u64>], cycle: bool) -> Option<u64> {
+        let n = dist.len();
+        let mut best = None;
+        if cycle {
+            // 정점 0을 고정하고 나머지를 순열로
+            let mut rest: Vec<usize> = (1..n).collect();
+            loop {
+                let mut order = vec![0];
+                order.extend_from_slice(&rest);
+                if let Some(c) = cost_of(dist, &order, true) {
+                    best = Some(best.map_or(c, |b: u64| b.min(c)));
+                }
+                if !next_permutation(&mut rest) {
+                    break;
+                }
+            }
+        } else {
+            let mut order: Vec<usize> = (0..n).collect();
+            loop {
+                if let Some(c) = cost_of(dist, &order, false) {
+                    best = Some(best.map_or(c, |b: u64| b.min(c)));
+                }
+                if !next_permutation(&mut order) {
+                    break;
+                }
+            }
+        }
+        best
+    }
+
+    fn check(dist: &[Vec<u64>]) {
+        let n = dist.len();
+        for cycle in [true, false] {
+            let result = if cycle { tsp(dist) } else { tsp_path(dist) };
+            assert_eq!(result.as_ref().map(|r| r.0), brute_force(dist, cycle));
+            if let Some((cost, order)) = result {
+                let mut sorted = order.clone();
+                sorted.sort_unstable();
+                assert_eq!(sorted, (0..n).collect::<Vec<_>>());
+                assert_eq!(cost_of(dist, &order, cycle), Some(cost));
+                if cycle {
+                    assert_eq!(order[0], 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn degenerate_sizes() {
+        assert_eq!(tsp(&[]), Some((0, vec![])));
+        assert_eq!(tsp(&[vec![7]]), Some((0, vec![0])));
+        assert_eq!(tsp_path(&[vec![7]]), Some((0, vec![0])));
+        let two = vec![vec![0, 3], vec![5, 0]];
+        assert_eq!(tsp(&two), Some((8, vec![0, 1])));
+        assert_eq!(tsp_path(&two), Some((3, vec![0, 1])));
+        let one_way = vec![vec![0, NO_EDGE], vec![5, 0]];
+        assert_eq!(tsp(&one_way), None);
+        assert_eq!(tsp_path(&one_way), Some((5, vec![1, 0])));
+    }
+
+    #[test]
+    fn random_against_permutations() {
+        let mut rng = XorShift::new(120);
+        for round in 0..200 {
+            let n = if round < 196 {
+                1 + rng.index(7)
+            } else {
+                8 + round % 2
+            };
+            let symmetric = round % 2 == 0;
+            let raw: Vec<Vec<u64>> = (0..n)
+                .map(|_| {
+                    (0..n)
+                        .map(|_| {
+                            if rng.below(5) == 0 {
+                                NO_EDGE
+                            } else {
+                                rng.below(100)
+                            }
+                        })
+                        .collect()
+                })
+                .collect();
+            let dist: Vec<Vec<u64>> = (0..n)
+                .map(|u| {
+                    (0..n)
+                        .map(|v| match (u == v, symmetric) {
+                            (true, _) => 0,
+                            (false, true) => raw[u.min(v)][u.max(v)],
+                            (false, false) => raw[u][v],
+                        })
+                        .collect()
+                })
+                .collect();
+            check(&dist);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "too large")]
+    fn more_than_twenty_vertices_panics() {
+        tsp(&vec![vec![1; 21]; 21]);
+    }
+}
@@ -0,0 +1,260 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::graph::strongly_connected_components;
+
+/// # 2-SAT
+/// 변수 n개에 대해 "두 리터럴 중 적어도 하나는 참" 꼴의 절(clause)들을 모두 만족하는 할당을 찾습니다.
+///
+/// ## 리터럴 표기
+/// 리터럴은 **1-based 부호 있는 정수**입니다. `k`는 "x_k가 참", `-k`는 "x_k가 거짓"이며 k는 1..=n입니다.
+/// 0은 리터럴이 아닙니다.
+///
+/// 절 (a ∨ b)를 함의 ¬a → b, ¬b → a로 바꾼 함의 그래프에서 강한 연결 요소를 구합니다.
+/// x와 ¬x가 같은 요소에 있으면 만족 불가능하고, 아니면 위상 순서에서 더 뒤에 있는 쪽을 참으로 둡니다.
+/// ## 시간복잡도
+/// O(n + 절 개수)
+pub struct TwoSat {
+    n: usize,
+    // 정점 2i는 x_(i+1), 2i+1은 ¬x_(i+1). at_most_one이 만드는 보조 변수는 i >= n
+    adj: Vec<Vec<usize>>,
+}
+
+impl TwoSat {
+    /// 변수 n개짜리 빈 식 생성
+    pub fn new(n: usize) -> Self {
+        TwoSat {
+            n,
+            adj: vec![Vec::new(); 2 * n],
+        }
+    }
+
+    /// 절 (x ∨ y) 추가. `add_clause(x, x)`는 x를 참으로 강제합니다.
+    pub fn add_clause(&mut self, x: i32, y: i32) {
+        let (a, b) = (self.node(x), self.node(y));
+        self.adj[a ^ 1].push(b);
+        self.adj[b ^ 1].push(a);
+    }
+
+    /// 함의 x → y 추가. (¬x ∨ y)와 같습니다.
+    pub fn add_implication(&mut self, x: i32, y: i32) {
+        self.add_clause(-x, y);
+    }
+
+    /// 주어진 리터럴 중 참인 것이 많아야 하나가 되도록 제약 추가.
+    /// 보조 변수를 이용한 prefix 인코딩으로 O(k)개의 절만 추가합니다. 보조 변수는 [`solve`](Self::solve) 결과에 포함되지 않습니다.
+    pub fn at_most_one(&mut self, literals: &[i32]) {
+        if literals.len() <= 1 {
+            return;
+        }
+        // 보조 변수 s_i: "literals[0..=i] 중 참이 있다"
+        let mut prev: Option<usize> = None;
+        for &lit in literals {
+            let l = self.node(lit);
+            let s = self.new_aux();
+            // l → s
+            self.add_node_clause(l ^ 1, s);
+            if let Some(p) = prev {
+                // s_(i-1) → s_i, s_(i-1) → ¬l
+                self.add_node_clause(p ^ 1, s);
+                self.add_node_clause(p ^ 1, l ^ 1);
+            }
+            prev = Some(s);
+        }
+    }
+
+    /// 만족하는 할당이 있으면 `x_1..x_n`의 값을 0-based 벡터로 반환합니다 (`result[k - 1]`이 x_k).
+    pub fn solve(&self) -> Option<Vec<bool>> {
+        let (_, comp) = strongly_connected_components(&self.adj);
+        let total = self.adj.len() / 2;
+        let mut result = Vec::with_capacity(self.n);
+        for i in 0..total {
+            let (t, f) = (comp[2 * i], comp[2 * i + 1]);
+            if t == f {
+                return None;
+            }
+            if i < self.n {
+                result.push(t > f);
+            }
+        }
+        Some(result)
+    }
+
+    /// 리터럴을 함의 그래프의 정점 번호로
+    fn node(&self, lit: i32) -> usize {
+        let k = lit.unsigned_abs() as usize;
+        assert!(
+            lit != 0 && k <= self.n,
+            "literal {lit} out of range for {} variables",
+            self.n
+        );
+        if lit > 0 {
+            2 * (k - 1)
+        } else {
+            2 * (k - 1) + 1
+        }
+    }
+
+    fn new_aux(&mut self) -> usize {
+        let id = self.adj.len();
+        self.adj.push(Vec::new());
+        self.adj.push(Vec::new());
+        id
+    }
+
+    fn add_node_clause(&mut self, a: usize, b: usize) {
+        self.adj[a ^ 1].push(b);
+        self.adj[b ^ 1].push(a);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn lit_value(assignment: &[bool], lit: i32) -> bool {
+        let v = assignment[lit.unsigned_abs() as usize - 1];
+        if lit > 0 { v } else { !v }
+    }
+
+    fn satisfies(assignment: &[bool], clauses: &[(i32, i32)]) -> bool {
+        clauses
+            .iter()
+            .all(|&(x, y)| lit_value(assignment, x) || lit_value(assignment, y))
+    }
+
+    fn brute_force(n: usize, clauses: &[(i32, i32)]) -> bool {
+        (0u32..1 << n).any(|mask| {
+            let assignment: Vec<bool> = (0..n).map(|i| mask >> i & 1 == 1).collect();
+            satisfies(&assignment, clauses)
+        })
+    }
+
+    fn random_lit(rng: &mut XorShift, n: usize) -> i32 {
+        let k = 1 + rng.index(n) as i32;
+        if rng.below(2) == 0 { k } else { -k }
+    }
+
+    #[test]
+    fn unsatisfiable() {
+        let mut ts = TwoSat::new(1);
+        ts.add_clause(1, 1);
+        ts.add_clause(-1, -1);
+        assert_eq!(ts.solve(), None);
+
+        // x1 → x2 → ¬x1, ¬x1 → x1
+        let mut ts = TwoSat::new(2);
+        ts.add_implication(1, 2);
+        ts.add_implication(2, -1);
+        ts.add_clause(1, 1);
+        assert_eq!(ts.solve(), None);
+    }
+
+    #[test]
+    fn forced_chain() {
+        let n = 1000;
+        let mut ts = TwoSat::new(n);
+        ts.add_clause(1, 1);
+        for k in 1..n as i32 {
+            ts.add_implication(k, k + 1);
+        }
+        assert_eq!(ts.solve(), Some(vec![true; n]));
+
+        // 마지막을 거짓으로 강제하면 거꾸로 모두 거짓
+        let mut ts = TwoSat::new(n);
+        ts.add_clause(-(n as i32), -(n as i32));
+        for k in 1..n as i32 {
+            ts.add_implication(k, k + 1);
+        }
+        assert_eq!(ts.solve(), Some(vec![false; n]));
+    }
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(1080);
+        for _ in 0..500 {
+            let n = 1 + rng.index(10);
+            let m = rng.index(3 * n);
+            let clauses: Vec<(i32, i32)> = (0..m)
+                .map(|_| (random_lit(&mut rng, n), random_lit(&mut rng, n)))
+                .collect();
+            let mut ts = TwoSat::new(n);
+            for &(x, y) in &clauses {
+                ts.add_clause(x, y);
+            }
+            match ts.solve() {
+                Some(assignment) => {
+                    assert_eq!(assignment.len(), n);
+                    assert!(satisfies(&assignment, &clauses));
+                }
+                None => assert!(!brute_force(n, &clauses)),
+            }
+        }
+    }
+
+    #[test]
+    fn at_most_one_against_brute_force() {
+        let mut rng = XorShift::new(1081);
+        for _ in 0..300 {
+            let n = 1 + rng.index(8);
+            let clauses: Vec<(i32, i32)> = (0..rng.index(2 * n))
+                .map(|_| (random_lit(&mut rng, n), random_lit(&mut rng, n)))
+                .collect();
+            let group: Vec<i32> = (0..1 + rng.index(4))
+                .map(|_| random_lit(&mut rng, n))
+                .collect();
+            let mut ts = TwoSat::new(n);
+            for &(x, y) in &clauses {
+                ts.add_clause(x, y);
+            }
+            ts.at_most_one(&group);
+
+            let valid = |a: &[bool]| {
+                satisfies(a, &clauses) && group.iter().filter(|&&l| lit_value(a, l)).count() <= 1
+            };
+            let expected = (0u32..1 << n)
+                .any(|mask| valid(&(0..n).map(|i| mask >> i & 1 == 1).collect::<Vec<_>>()));
+            match ts.solve() {
+                Some(assignment) => {
+                    assert_eq!(assignment.len(), n);
+                    assert!(valid(&assignment));
+                }
+                None => assert!(!expected),
+            }
+        }
+    }
+
+    /// 정답 할당을 먼저 정하고, 그 할당이 만족하는 절만 m개 추가한 식
+    fn planted_instance(rng: &mut XorShift, n: usize, m: usize) -> (TwoSat, Vec<(i32, i32)>) {
+        let planted: Vec<bool> = (0..n).map(|_| rng.below(2) == 0).collect();
+        let mut ts = TwoSat::new(n);
+        let mut clauses = Vec::with_capacity(m);
+        while clauses.len() < m {
+            let (x, y) = (random_lit(rng, n), random_lit(rng, n));
+            if lit_value(&planted, x) || lit_value(&planted, y) {
+                ts.add_clause(x, y);
+                clauses.push((x, y));
+            }
+        }
+        (ts, clauses)
+    }
+
+    #[test]
+    fn large_planted_instance() {
+        let mut rng = XorShift::new(1082);
+        let (ts, clauses) = planted_instance(&mut rng, 100_000, 1_000_000);
+        let assignment = ts.solve().expect("planted instance is satisfiable");
+        assert!(satisfies(&assignment, &clauses));
+    }
+
+    #[test]
+    #[ignore]
+    fn million_clauses_timing() {
+        let mut rng = XorShift::new(1083);
+        let (ts, clauses) = planted_instance(&mut rng, 1_000_000, 1_000_000);
+        let start = std::time::Instant::now();
+        let assignment = ts.solve().expect("planted instance is satisfiable");
+        let elapsed = start.elapsed();
+        assert!(satisfies(&assignment, &clauses));
+        println!("solved 10^6 clauses in {elapsed:?}");
+    }
+}
@@ -0,0 +1,265 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::collections::VecDeque;
+
+const DIRS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const DIRS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+fn neighbors(
+    r: usize,
+    c: usize,
+    rows: usize,
+    cols: usize,
+    dirs: &'static [(isize, isize)],
+) -> impl Iterator<Item = (usize, usize)> {
+    dirs.iter().filter_map(move |&(dr, dc)| {
+        let nr = r.checked_add_signed(dr)?;
+        let nc = c.checked_add_signed(dc)?;
+        (nr < rows && nc < cols).then_some((nr, nc))
+    })
+}
+
+/// `rows × cols` 격자 안에 있는 상하좌우 이웃 (위, 오른쪽, 아래, 왼쪽 순서)
+pub fn neighbors4(
+    r: usize,
+    c: usize,
+    rows: usize,
+    cols: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    neighbors(r, c, rows, cols, &DIRS4)
+}
+
+/// `rows × cols` 격자 안에 있는 8방향 이웃 (위에서부터 시계 방향)
+pub fn neighbors8(
+    r: usize,
+    c: usize,
+    rows: usize,
+    cols: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    neighbors(r, c, rows, cols, &DIRS8)
+}
+
+/// # 격자 BFS
+/// `passable(r, c)`가 true인 칸만 지나면서, 여러 출발점 중 가장 가까운 곳까지의 거리를 모든 칸에 대해 구합니다.
+///
+/// * `diag`가 true면 대각선 이웃도 거리 1로 봅니다.
+/// * 출발점은 `passable`과 관계없이 거리 0입니다. 그래서 벽을 출발점으로 두면 "가장 가까운 벽까지의 거리"가 됩니다.
+/// * 도달할 수 없는 칸은 None입니다.
+/// ## 시간복잡도
+/// O(rows · cols)
+pub fn grid_bfs(
+    rows: usize,
+    cols: usize,
+    passable: impl Fn(usize, usize) -> bool,
+    sources: &[(usize, usize)],
+    diag: bool,
+) -> Vec<Vec<Option<u32>>> {
+    let dirs: &'static [(isize, isize)] = if diag { &DIRS8 } else { &DIRS4 };
+    let mut dist = vec![vec![None; cols]; rows];
+    let mut queue = VecDeque::new();
+    for &(r, c) in sources {
+        assert!(
+            r < rows && c < cols,
+            "source ({r}, {c}) out of range for {rows}x{cols} grid"
+        );
+        if dist[r][c].is_none() {
+            dist[r][c] = Some(0);
+            queue.push_back((r, c));
+        }
+    }
+    while let Some((r, c)) = queue.pop_front() {
+        let d = dist[r][c].unwrap();
+        for (nr, nc) in neighbors(r, c, rows, cols, dirs) {
+            if dist[nr][nc].is_none() && passable(nr, nc) {
+                dist[nr][nc] = Some(d + 1);
+                queue.push_back((nr, nc));
+            }
+        }
+    }
+    dist
+}
+
+/// # 격자 연결 요소
+/// `passable`한 칸들을 이웃 관계로 묶어서 `(요소 개수, 칸별 요소 번호)`를 반환합니다.
+/// 번호는 행 우선 순서로 처음 나타나는 순서대로 0부터 붙고, 지나갈 수 없는 칸은 None입니다.
+pub fn flood_fill_labels(
+    rows: usize,
+    cols: usize,
+    passable: impl Fn(usize, usize) -> bool,
+    diag: bool,
+) -> (usize, Vec<Vec<Option<usize>>>) {
+    let dirs: &'static [(isize, isize)] = if diag { &DIRS8 } else { &DIRS4 };
+    let mut label = vec![vec![None; cols]; rows];
+    let mut count = 0;
+    let mut stack = Vec::new();
+    for r in 0..rows {
+        for c in 0..cols {
+            if label[r][c].is_some() || !passable(r, c) {
+                continue;
+            }
+            label[r][c] = Some(count);
+            stack.push((r, c));
+            while let Some((ur, uc)) = stack.pop() {
+                for (nr, nc) in neighbors(ur, uc, rows, cols, dirs) {
+                    if label[nr][nc].is_none() && passable(nr, nc) {
+                        label[nr][nc] = Some(count);
+                        stack.push((nr, nc));
+                    }
+                }
+            }
+            count += 1;
+        }
+    }
+    (count, label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn parse(maze: &[&str]) -> Vec<Vec<u8>> {
+        maze.iter().map(|row| row.bytes().collect()).collect()
+    }
+
+    #[test]
+    fn neighbors_are_clipped() {
+        let corner: Vec<_> = neighbors4(0, 0, 3, 4).collect();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+        assert_eq!(neighbors8(0, 0, 3, 4).count(), 3);
+        assert_eq!(neighbors8(1, 1, 3, 4).count(), 8);
+        assert_eq!(neighbors4(0, 0, 1, 1).count(), 0);
+    }
+
+    #[test]
+    fn hand_drawn_maze() {
+        let maze = parse(&["S.#.", ".##.", "...."]);
+        let dist = grid_bfs(3, 4, |r, c| maze[r][c] != b'#', &[(0, 0)], false);
+        assert_eq!(dist[0][3], Some(7));
+        assert_eq!(dist[2][3], Some(5));
+        assert_eq!(dist[0][2], None);
+        // 대각선을 허용하면 (1, 0) → (2, 1) → ... 가 짧아짐
+        let dist = grid_bfs(3, 4, |r, c| maze[r][c] != b'#', &[(0, 0)], true);
+        assert_eq!(dist[2][3], Some(4));
+        assert_eq!(dist[0][3], Some(5));
+
+        let (count, labels) = flood_fill_labels(3, 4, |r, c| maze[r][c] != b'#', false);
+        assert_eq!(count, 1);
+        assert_eq!(labels[1][1], None);
+        let walls = parse(&["#.#", "###", ".#."]);
+        let (count, labels) = flood_fill_labels(3, 3, |r, c| walls[r][c] == b'.', false);
+        assert_eq!(count, 3);
+        assert_eq!(labels[2][2], Some(2));
+        assert_eq!(
+            flood_fill_labels(3, 3, |r, c| walls[r][c] == b'.', true).0,
+            3
+        );
+        assert_eq!(
+            flood_fill_labels(3, 3, |r, c| walls[r][c] == b'#', true).0,
+            1
+        );
+    }
+
+    #[test]
+    fn distance_to_nearest_wall() {
+        let maze = parse(&["#....", ".....", "....#"]);
+        let walls: Vec<(usize, usize)> = (0..3)
+            .flat_map(|r| (0..5).map(move |c| (r, c)))
+            .filter(|&(r, c)| maze[r][c] == b'#')
+            .collect();
+        let dist = grid_bfs(3, 5, |_, _| true, &walls, false);
+        assert_eq!(dist[0][0], Some(0));
+        assert_eq!(dist[1][2], Some(3));
+        assert_eq!(dist[0][4], Some(2));
+    }
+
+    #[test]
+    fn fully_blocked() {
+        let dist = grid_bfs(4, 4, |_, _| false, &[(2, 1)], true);
+        for (r, row) in dist.iter().enumerate() {
+            for (c, &d) in row.iter().enumerate() {
+                assert_eq!(d, ((r, c) == (2, 1)).then_some(0));
+            }
+        }
+        assert_eq!(flood_fill_labels(4, 4, |_, _| false, false).0, 0);
+        assert!(grid_bfs(0, 0, |_, _| true, &[], false).is_empty());
+    }
+
+    #[test]
+    fn random_grids_against_graph_bfs() {
+        let mut rng = XorShift::new(119);
+        for round in 0..100 {
+            let rows = 1 + rng.index(12);
+            let cols = 1 + rng.index(12);
+            let diag = round % 2 == 1;
+            let open: Vec<Vec<bool>> = (0..rows)
+                .map(|_| (0..cols).map(|_| rng.below(10) < 7).collect())
+                .collect();
+            // 인접 리스트로 바꿔서 일반 BFS와 비교
+            let id = |r: usize, c: usize| r * cols + c;
+            let mut adj = vec![Vec::new(); rows * cols];
+            for r in 0..rows {
+                for c in 0..cols {
+                    for dr in -1isize..=1 {
+                        for dc in -1isize..=1 {
+                            if (dr, dc) == (0, 0) || (!diag && dr != 0 && dc != 0) {
+                                continue;
+                            }
+                            let (nr, nc) = (r as isize + dr, c as isize + dc);
+                            if nr < 0 || nc < 0 || nr >= rows as isize || nc >= cols as isize {
+                                continue;
+                            }
+                            let (nr, nc) = (nr as usize, nc as usize);
+                            if open[nr][nc] {
+                                adj[id(r, c)].push(id(nr, nc));
+                            }
+                        }
+                    }
+                }
+            }
+            let sources: Vec<(usize, usize)> = (0..1 + rng.index(3))
+                .map(|_| (rng.index(rows), rng.index(cols)))
+                .collect();
+            let mut expected = vec![None; rows * cols];
+            let mut queue = VecDeque::new();
+            for &(r, c) in &sources {
+                expected[id(r, c)] = Some(0u32);
+                queue.push_back(id(r, c));
+            }
+            while let Some(u) = queue.pop_front() {
+                for &v in &adj[u] {
+                    if expected[v].is_none() {
+                        expected[v] = Some(expected[u].unwrap() + 1);
+                        queue.push_back(v);
+                    }
+                }
+            }
+            let dist = grid_bfs(rows, cols, |r, c| open[r][c], &sources, diag);
+            let (count, labels) = flood_fill_labels(rows, cols, |r, c| open[r][c], diag);
+            for r in 0..rows {
+                for c in 0..cols {
+                    assert_eq!(dist[r][c], expected[id(r, c)]);
+                    assert_eq!(labels[r][c].is_some(), open[r][c]);
+                    // 이웃한 열린 칸은 같은 번호
+                    for &v in &adj[id(r, c)] {
+                        if open[r][c] {
+                            assert_eq!(labels[r][c], labels[v / cols][v % cols]);
+                        }
+                    }
+                }
+            }
+            let mut seen: Vec<usize> = labels.iter().flatten().flatten().copied().collect();
+            seen.sort_unstable();
+            seen.dedup();
+            assert_eq!(seen, (0..count).collect::<Vec<_>>());
+        }
+    }
+}
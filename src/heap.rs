@@ -288,7 +288,7 @@ mod tests {
 
         // 이 함수는 "H가 MinHeap인지 MaxHeap인지"를 모르므로
         // 그냥 정렬해 두 케이스 중 하나와 일치하는지만 본다.
-        let mut asc = vec!["alpha", "bravo", "charlie", "delta"];
+        let asc = vec!["alpha", "bravo", "charlie", "delta"];
         let mut desc = asc.clone();
         desc.reverse();
         assert!(out == asc || out == desc);
@@ -373,8 +373,8 @@ mod tests {
 
         for i in 0..1000 {
             let v = (i * 37) % 997; // 다양한 값
-            maxh.push(v as i32);
-            minh.push(v as i32);
+            maxh.push(v);
+            minh.push(v);
         }
         assert_eq!(maxh.len(), 1000);
         assert_eq!(minh.len(), 1000);
@@ -389,7 +389,7 @@ mod tests {
             min_out.push(x);
         }
 
-        let mut sorted: Vec<i32> = (0..1000).map(|i| ((i * 37) % 997) as i32).collect();
+        let mut sorted: Vec<i32> = (0..1000).map(|i| (i * 37) % 997).collect();
         sorted.sort();
 
         let mut sorted_desc = sorted.clone();
@@ -0,0 +1,306 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::treap::Priorities;
+use std::ops::Range;
+
+/// 자식이 없음을 나타내는 번호
+const NIL: u32 = u32::MAX;
+
+/// (lo, hi, 넣은 순서)
+type Key = (i64, i64, u64);
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    /// 지운 노드는 None
+    item: Option<(i64, i64, T)>,
+    key: Key,
+    /// 서브트리에서 가장 큰 hi
+    max_hi: i64,
+    priority: u64,
+    child: [u32; 2],
+}
+
+/// # Interval set
+/// 구간 `(lo, hi, 값)`을 넣고 빼면서, 점 x를 포함하는 구간과 주어진 구간과 겹치는 구간을 모두 찾습니다.
+///
+/// * 구간은 모두 반열림 `lo..hi`입니다(`lo < hi`). 점 x는 `lo <= x < hi`일 때 구간에 포함되고, 두 구간은 공통
+/// 부분이 있을 때만 겹칩니다. 그래서 끝이 맞닿은 `1..3`과 `3..5`는 겹치지 않습니다.
+/// * `(lo, hi, 넣은 순서)`를 키로 하는 treap에 서브트리의 최대 hi를 더한 interval tree입니다.
+/// 최대 hi가 질의의 시작 이하인 서브트리와, lo가 질의의 끝 이상인 노드의 오른쪽 서브트리를 건너뜁니다.
+/// * 결과는 `(lo, hi, 넣은 순서)` 순서로 정렬되어 있습니다. 같은 구간을 여러 번 넣을 수 있습니다.
+/// ## 시간복잡도
+/// 넣기/빼기 기댓값 O(log n), 질의 기댓값 O(log n + 답의 개수 · log n)
+#[derive(Clone, Debug)]
+pub struct IntervalSet<T> {
+    nodes: Vec<Node<T>>,
+    free: Vec<u32>,
+    root: u32,
+    len: usize,
+    next_id: u64,
+    priorities: Priorities,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> IntervalSet<T> {
+    /// 빈 집합
+    pub fn new() -> Self {
+        IntervalSet {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            len: 0,
+            next_id: 0,
+            priorities: Priorities::new(0x9E37_79B9_7F4A_7C15),
+        }
+    }
+
+    /// 구간의 개수
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 비었는지
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 구간 `lo..hi`와 값을 넣음
+    pub fn insert(&mut self, lo: i64, hi: i64, value: T) {
+        assert!(lo < hi, "empty interval {lo}..{hi}");
+        let key = (lo, hi, self.next_id);
+        self.next_id += 1;
+        let node = Node {
+            item: Some((lo, hi, value)),
+            key,
+            max_hi: hi,
+            priority: self.priorities.next(),
+            child: [NIL; 2],
+        };
+        let id = if let Some(id) = self.free.pop() {
+            self.nodes[id as usize] = node;
+            id
+        } else {
+            let id = u32::try_from(self.nodes.len()).expect("too many nodes for u32 indices");
+            assert!(id != NIL, "too many nodes for u32 indices");
+            self.nodes.push(node);
+            id
+        };
+        let (left, right) = self.split(self.root, key);
+        let left = self.merge(left, id);
+        self.root = self.merge(left, right);
+        self.len += 1;
+    }
+
+    /// 구간 `lo..hi`를 하나(가장 먼저 넣은 것) 빼고 그 값을 반환. 없으면 None
+    pub fn remove(&mut self, lo: i64, hi: i64) -> Option<T> {
+        // (lo, hi, 넣은 순서) 중 가장 앞선 것을 찾음
+        let mut found = None;
+        let mut v = self.root;
+        while v != NIL {
+            let node = &self.nodes[v as usize];
+            if (node.key.0, node.key.1) < (lo, hi) {
+                v = node.child[1];
+            } else {
+                if (node.key.0, node.key.1) == (lo, hi) {
+                    found = Some(node.key);
+                }
+                v = node.child[0];
+            }
+        }
+        let key = found?;
+        let (left, rest) = self.split(self.root, key);
+        let (middle, right) = self.split(rest, (key.0, key.1, key.2 + 1));
+        self.root = self.merge(left, right);
+        self.free.push(middle);
+        self.len -= 1;
+        self.nodes[middle as usize]
+            .item
+            .take()
+            .map(|(_, _, value)| value)
+    }
+
+    /// 점 x를 포함하는(`lo <= x < hi`) 구간
+    pub fn stab(&self, x: i64) -> Vec<&(i64, i64, T)> {
+        self.collect(x, x)
+    }
+
+    /// 구간 `l..r`과 겹치는(`lo < r`이고 `l < hi`) 구간. 빈 구간이면 아무것도 없습니다.
+    pub fn overlapping(&self, range: Range<i64>) -> Vec<&(i64, i64, T)> {
+        let Range { start: l, end: r } = range;
+        if l >= r {
+            return Vec::new();
+        }
+        self.collect(l, r - 1)
+    }
+
+    /// `lo <= last`이고 `first < hi`인 구간을 키 순서대로
+    fn collect(&self, first: i64, last: i64) -> Vec<&(i64, i64, T)> {
+        let mut result = Vec::new();
+        // (노드, 노드 자신을 꺼낼 차례인지)
+        let mut stack = vec![(self.root, false)];
+        while let Some((v, emit)) = stack.pop() {
+            if v == NIL {
+                continue;
+            }
+            let node = &self.nodes[v as usize];
+            if emit {
+                let item = node.item.as_ref().unwrap();
+                if first < item.1 {
+                    result.push(item);
+                }
+                continue;
+            }
+            if node.max_hi <= first {
+                continue;
+            }
+            if node.key.0 <= last {
+                stack.push((node.child[1], false));
+                stack.push((v, true));
+            }
+            stack.push((node.child[0], false));
+        }
+        result
+    }
+
+    fn max_hi(&self, v: u32) -> i64 {
+        if v == NIL {
+            i64::MIN
+        } else {
+            self.nodes[v as usize].max_hi
+        }
+    }
+
+    fn update(&mut self, v: u32) {
+        let [l, r] = self.nodes[v as usize].child;
+        let max_hi = self.nodes[v as usize]
+            .key
+            .1
+            .max(self.max_hi(l))
+            .max(self.max_hi(r));
+        self.nodes[v as usize].max_hi = max_hi;
+    }
+
+    /// v를 (키가 key보다 작은 것, 나머지)로 나눔
+    fn split(&mut self, v: u32, key: Key) -> (u32, u32) {
+        if v == NIL {
+            return (NIL, NIL);
+        }
+        if self.nodes[v as usize].key < key {
+            let (l, r) = self.split(self.nodes[v as usize].child[1], key);
+            self.nodes[v as usize].child[1] = l;
+            self.update(v);
+            (v, r)
+        } else {
+            let (l, r) = self.split(self.nodes[v as usize].child[0], key);
+            self.nodes[v as usize].child[0] = r;
+            self.update(v);
+            (l, v)
+        }
+    }
+
+    /// a의 모든 키가 b의 키보다 작을 때 둘을 합침
+    fn merge(&mut self, a: u32, b: u32) -> u32 {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if self.nodes[a as usize].priority > self.nodes[b as usize].priority {
+            let right = self.merge(self.nodes[a as usize].child[1], b);
+            self.nodes[a as usize].child[1] = right;
+            self.update(a);
+            a
+        } else {
+            let left = self.merge(a, self.nodes[b as usize].child[0]);
+            self.nodes[b as usize].child[0] = left;
+            self.update(b);
+            b
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_linear_scan() {
+        let mut rng = XorShift::new(151);
+        for _ in 0..50 {
+            let mut set = IntervalSet::new();
+            // (lo, hi, 넣은 순서)
+            let mut model: Vec<(i64, i64, usize)> = Vec::new();
+            for step in 0..300 {
+                let lo = rng.range_i64(-30, 30);
+                let hi = lo + rng.range_i64(1, 15);
+                match rng.below(4) {
+                    0 | 1 => {
+                        set.insert(lo, hi, step);
+                        model.push((lo, hi, step));
+                    }
+                    2 => {
+                        // 모델에 있는 구간을 지우는 경우가 많도록 골라 씀
+                        let (lo, hi) = match model.get(rng.index(model.len() + 1)) {
+                            Some(&(a, b, _)) if rng.below(4) > 0 => (a, b),
+                            _ => (lo, hi),
+                        };
+                        let expected = model
+                            .iter()
+                            .position(|&(a, b, _)| (a, b) == (lo, hi))
+                            .map(|i| model.remove(i).2);
+                        assert_eq!(set.remove(lo, hi), expected);
+                    }
+                    _ => {
+                        let mut sorted = model.clone();
+                        sorted.sort_unstable();
+                        let x = rng.range_i64(-35, 50);
+                        let expected: Vec<_> = sorted
+                            .iter()
+                            .filter(|&&(a, b, _)| a <= x && x < b)
+                            .collect();
+                        assert_eq!(set.stab(x), expected);
+                        let (l, r) = (lo, lo + rng.range_i64(0, 10));
+                        let expected: Vec<_> = sorted
+                            .iter()
+                            .filter(|&&(a, b, _)| l < r && a < r && l < b)
+                            .collect();
+                        assert_eq!(set.overlapping(l..r), expected);
+                    }
+                }
+                assert_eq!(set.len(), model.len());
+            }
+        }
+    }
+
+    #[test]
+    fn endpoints_and_nesting() {
+        let mut set = IntervalSet::new();
+        set.insert(1, 3, 'a');
+        set.insert(3, 5, 'b');
+        set.insert(0, 10, 'c');
+        set.insert(4, 5, 'd');
+        let payloads = |v: Vec<&(i64, i64, char)>| v.iter().map(|t| t.2).collect::<String>();
+        // 끝점은 반열림
+        assert_eq!(payloads(set.stab(3)), "cb");
+        assert_eq!(payloads(set.stab(1)), "ca");
+        assert_eq!(payloads(set.stab(10)), "");
+        assert_eq!(payloads(set.stab(4)), "cbd");
+        // 맞닿기만 하면 겹치지 않음
+        assert_eq!(payloads(set.overlapping(5..7)), "c");
+        assert_eq!(payloads(set.overlapping(2..4)), "cab");
+        assert_eq!(payloads(set.overlapping(2..2)), "");
+        assert_eq!(set.remove(0, 10), Some('c'));
+        assert_eq!(set.remove(0, 10), None);
+        assert_eq!(payloads(set.stab(6)), "");
+        let empty: IntervalSet<()> = IntervalSet::new();
+        assert!(empty.is_empty());
+        assert!(empty.stab(0).is_empty());
+        assert!(empty.overlapping(i64::MIN..i64::MAX).is_empty());
+    }
+}
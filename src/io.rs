@@ -0,0 +1,5 @@
+#![allow(clippy::doc_lazy_continuation)]
+mod scanner;
+pub use scanner::Scanner;
+mod writer;
+pub use writer::Writer;
@@ -0,0 +1,242 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::fmt::Debug;
+use std::io::Read;
+use std::str::FromStr;
+
+/// # 입력 스캐너
+/// 입력 전체를 한 번에 바이트 버퍼로 읽어 두고, 공백으로 나뉜 토큰을 차례로 꺼내 `FromStr`로 바꿉니다.
+///
+/// * 토큰은 ASCII 공백(스페이스, 탭, `\n`, `\r` 등)으로 나뉘므로 CRLF 입력도 그대로 읽습니다.
+/// * 토큰마다 할당하지 않고 버퍼의 조각을 바로 파싱합니다. `&str`로 바꿀 때의 UTF-8 검사는 ASCII 토큰이면 아주
+/// 빠르게 끝납니다.
+/// * 입력이 끝났는데 읽으려 하거나 파싱에 실패하면 문제가 된 토큰을 담아 panic합니다.
+/// * 입력을 끝까지 읽은 뒤에 시작하므로 인터랙티브 문제에는 쓸 수 없습니다.
+/// ## 시간복잡도
+/// 토큰마다 O(토큰 길이)
+#[derive(Clone, Debug)]
+pub struct Scanner {
+    buf: Vec<u8>,
+    pos: usize,
+    /// 토큰을 읽은 뒤 그 줄의 나머지를 아직 `next_line`으로 읽지 않았는지
+    line_open: bool,
+}
+
+impl Scanner {
+    /// reader를 끝까지 읽어서 생성
+    pub fn new(mut reader: impl Read) -> Self {
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).expect("failed to read input");
+        Scanner {
+            buf,
+            pos: 0,
+            line_open: false,
+        }
+    }
+
+    /// 표준 입력 전체로 생성
+    pub fn stdin() -> Self {
+        Self::new(std::io::stdin().lock())
+    }
+
+    /// 다음 토큰을 T로. 입력이 끝났으면 panic
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<T: FromStr>(&mut self) -> T
+    where
+        T::Err: Debug,
+    {
+        self.try_next().expect("unexpected end of input")
+    }
+
+    /// 다음 토큰을 T로. 입력이 끝났으면 None
+    pub fn try_next<T: FromStr>(&mut self) -> Option<T>
+    where
+        T::Err: Debug,
+    {
+        let token = self.next_token()?;
+        let s = std::str::from_utf8(token)
+            .unwrap_or_else(|_| panic!("token {token:?} is not valid UTF-8"));
+        Some(
+            s.parse()
+                .unwrap_or_else(|e| panic!("failed to parse token {s:?}: {e:?}")),
+        )
+    }
+
+    /// 다음 토큰 n개
+    pub fn next_vec<T: FromStr>(&mut self, n: usize) -> Vec<T>
+    where
+        T::Err: Debug,
+    {
+        (0..n).map(|_| self.next()).collect()
+    }
+
+    /// 다음 토큰 두 개
+    pub fn next_pair<A: FromStr, B: FromStr>(&mut self) -> (A, B)
+    where
+        A::Err: Debug,
+        B::Err: Debug,
+    {
+        let a = self.next();
+        (a, self.next())
+    }
+
+    /// 다음 토큰의 바이트. `#.#.` 같은 격자의 한 줄을 읽을 때 씁니다.
+    pub fn next_bytes(&mut self) -> Vec<u8> {
+        self.next_token().expect("unexpected end of input").to_vec()
+    }
+
+    /// 토큰 rows개를 바이트 격자로
+    pub fn next_grid(&mut self, rows: usize) -> Vec<Vec<u8>> {
+        (0..rows).map(|_| self.next_bytes()).collect()
+    }
+
+    /// `rows × cols`개의 토큰을 행 우선으로 읽은 격자
+    pub fn next_matrix<T: FromStr>(&mut self, rows: usize, cols: usize) -> Vec<Vec<T>>
+    where
+        T::Err: Debug,
+    {
+        (0..rows).map(|_| self.next_vec(cols)).collect()
+    }
+
+    /// 현재 위치부터 줄 끝까지(`\n`과 `\r\n`은 뺌). 토큰을 읽은 직후라면 그 줄의 남은 부분(보통 빈 문자열)이므로,
+    /// 다음 줄 전체를 원하면 한 번 더 부릅니다. 줄바꿈 없이 끝나는 입력의 마지막 토큰 뒤에서도 빈 문자열이고, 그 밖에
+    /// 입력이 끝났으면 panic
+    pub fn next_line(&mut self) -> &str {
+        let line_open = std::mem::take(&mut self.line_open);
+        if self.pos == self.buf.len() {
+            // 줄바꿈 없이 끝나는 입력의 마지막 토큰 뒤
+            assert!(line_open, "unexpected end of input");
+            return "";
+        }
+        let rest = &self.buf[self.pos..];
+        let len = rest.iter().position(|&b| b == b'\n');
+        self.pos += len.map_or(rest.len(), |len| len + 1);
+        let mut line = &rest[..len.unwrap_or(rest.len())];
+        if let Some(stripped) = line.strip_suffix(b"\r") {
+            line = stripped;
+        }
+        std::str::from_utf8(line).unwrap_or_else(|_| panic!("line {line:?} is not valid UTF-8"))
+    }
+
+    /// 다음 토큰
+    fn next_token(&mut self) -> Option<&[u8]> {
+        while self.pos < self.buf.len() && self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        if self.pos == self.buf.len() {
+            return None;
+        }
+        let start = self.pos;
+        while self.pos < self.buf.len() && !self.buf[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        self.line_open = true;
+        Some(&self.buf[start..self.pos])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn mixed_whitespace_and_types() {
+        let input = "3  -5\t\r\n  12345678901234\n\n  hello 2.5\r\n-0 x\r\n  ";
+        let mut sc = Scanner::new(input.as_bytes());
+        assert_eq!(sc.next::<usize>(), 3);
+        assert_eq!(sc.next::<i32>(), -5);
+        assert_eq!(sc.next::<u64>(), 12_345_678_901_234);
+        assert_eq!(sc.next::<String>(), "hello");
+        assert_eq!(sc.next::<f64>(), 2.5);
+        assert_eq!(sc.next_pair::<i64, char>(), (0, 'x'));
+        assert_eq!(sc.try_next::<i64>(), None);
+        let mut sc = Scanner::new("가나 é 1".as_bytes());
+        assert_eq!(sc.next::<String>(), "가나");
+        assert_eq!(sc.next::<char>(), 'é');
+        assert_eq!(sc.next::<u8>(), 1);
+        assert_eq!(sc.try_next::<i64>(), None);
+        assert_eq!(sc.try_next::<i64>(), None);
+    }
+
+    #[test]
+    fn vectors_grids_and_lines() {
+        let input = "2 3\r\n1 2 3\r\n-4 5 -6\r\n#.#\n.#.\nfirst line\r\nsecond  line \nlast";
+        let mut sc = Scanner::new(input.as_bytes());
+        let (rows, cols) = sc.next_pair::<usize, usize>();
+        assert_eq!(
+            sc.next_matrix::<i64>(rows, cols),
+            vec![vec![1, 2, 3], vec![-4, 5, -6]]
+        );
+        assert_eq!(sc.next_grid(2), vec![b"#.#".to_vec(), b".#.".to_vec()]);
+        // 토큰 바로 뒤의 줄 끝
+        assert_eq!(sc.next_line(), "");
+        assert_eq!(sc.next_line(), "first line");
+        assert_eq!(sc.next_line(), "second  line ");
+        assert_eq!(sc.next_line(), "last");
+        let mut sc = Scanner::new("4\n1 2 3 4\n".as_bytes());
+        let n = sc.next();
+        assert_eq!(sc.next_vec::<u8>(n), vec![1, 2, 3, 4]);
+        assert_eq!(sc.next_line(), "");
+        assert_eq!(sc.try_next::<String>(), None);
+        // 줄바꿈 없이 끝나는 입력
+        let mut sc = Scanner::new("abc".as_bytes());
+        assert_eq!(sc.next::<String>(), "abc");
+        assert_eq!(sc.next_line(), "");
+        let mut sc = Scanner::new("x y".as_bytes());
+        assert_eq!(sc.next::<char>(), 'x');
+        assert_eq!(sc.next_line(), " y");
+    }
+
+    #[test]
+    #[should_panic(expected = "\"12a\"")]
+    fn parse_failure_shows_token() {
+        Scanner::new("7 12a".as_bytes()).next_vec::<i32>(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "not valid UTF-8")]
+    fn invalid_utf8_token_panics() {
+        Scanner::new(&[b'1', b' ', 0xff, b'a'][..]).next_vec::<String>(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected end of input")]
+    fn line_after_end_panics() {
+        let mut sc = Scanner::new("abc".as_bytes());
+        sc.next::<String>();
+        sc.next_line();
+        sc.next_line();
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected end of input")]
+    fn line_of_empty_input_panics() {
+        Scanner::new("".as_bytes()).next_line();
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected end of input")]
+    fn end_of_input_panics() {
+        Scanner::new(" \n ".as_bytes()).next::<i32>();
+    }
+
+    #[test]
+    #[ignore]
+    fn million_numbers() {
+        let mut rng = XorShift::new(198);
+        let values: Vec<i64> = (0..1_000_000)
+            .map(|_| rng.range_i64(-1_000_000_000_000_000_000, 1_000_000_000_000_000_000))
+            .collect();
+        let mut input = String::new();
+        for (i, x) in values.iter().enumerate() {
+            input.push_str(&x.to_string());
+            input.push_str(if i % 10 == 9 { "\r\n" } else { " " });
+        }
+        let start = std::time::Instant::now();
+        let mut sc = Scanner::new(input.as_bytes());
+        let read: Vec<i64> = sc.next_vec(values.len());
+        let elapsed = start.elapsed();
+        assert_eq!(read, values);
+        println!("read 10^6 numbers in {elapsed:?}");
+    }
+}
@@ -0,0 +1,122 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::fmt::Display;
+use std::io::{BufWriter, Stdout, Write};
+
+/// # 출력 버퍼
+/// 출력을 `BufWriter`에 모아 두었다가 한꺼번에 씁니다. 줄마다 `println!`을 부르면 매번 stdout을 잠그고 비워서
+/// 10^6줄 출력이 시간 초과가 나는데, 이를 피합니다.
+///
+/// * `write_join`은 중간 String을 만들지 않고 원소를 하나씩 바로 씁니다.
+/// * 버퍼는 `flush`를 부르거나 Writer가 drop될 때 비워집니다. drop에서는 쓰기 오류를 알 수 없으므로 마지막에
+/// `flush`를 부르는 것이 안전합니다.
+/// * 인터랙티브 문제에서는 질의를 쓴 뒤 응답을 읽기 전에 반드시 `flush`해야 합니다. 또 [`Scanner`](super::Scanner)는
+/// 입력 끝까지 읽은 뒤에 시작하므로, 응답은 `std::io::stdin().read_line`처럼 한 줄씩 읽어야 합니다.
+/// * 쓰기에 실패하면 panic합니다.
+#[derive(Debug)]
+pub struct Writer<W: Write> {
+    out: BufWriter<W>,
+}
+
+impl Writer<Stdout> {
+    /// 표준 출력에 쓰는 Writer
+    pub fn stdout() -> Self {
+        Self::new(std::io::stdout())
+    }
+}
+
+impl<W: Write> Writer<W> {
+    /// inner에 쓰는 Writer
+    pub fn new(inner: W) -> Self {
+        Writer {
+            out: BufWriter::new(inner),
+        }
+    }
+
+    /// x를 씀
+    pub fn w(&mut self, x: impl Display) {
+        write!(self.out, "{x}").expect("failed to write output");
+    }
+
+    /// x와 줄바꿈을 씀
+    pub fn wln(&mut self, x: impl Display) {
+        writeln!(self.out, "{x}").expect("failed to write output");
+    }
+
+    /// 원소 사이에 sep을 넣어 쓰고 줄바꿈. 비었으면 줄바꿈만 씁니다.
+    pub fn write_join<T: Display>(&mut self, iter: impl IntoIterator<Item = T>, sep: &str) {
+        for (i, x) in iter.into_iter().enumerate() {
+            if i > 0 {
+                self.w(sep);
+            }
+            self.w(x);
+        }
+        self.wln("");
+    }
+
+    /// `Yes` 또는 `No`와 줄바꿈
+    pub fn write_yes_no(&mut self, yes: bool) {
+        self.wln(if yes { "Yes" } else { "No" });
+    }
+
+    /// 버퍼의 내용을 모두 내보냄
+    pub fn flush(&mut self) {
+        self.out.flush().expect("failed to flush output");
+    }
+
+    /// 버퍼를 비우고 안쪽의 writer를 반환
+    pub fn into_inner(self) -> W {
+        self.out
+            .into_inner()
+            .unwrap_or_else(|e| panic!("failed to flush output: {}", e.error()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(f: impl FnOnce(&mut Writer<Vec<u8>>)) -> String {
+        let mut writer = Writer::new(Vec::new());
+        f(&mut writer);
+        String::from_utf8(writer.into_inner()).unwrap()
+    }
+
+    #[test]
+    fn joins_exact_bytes() {
+        assert_eq!(output(|w| w.write_join(Vec::<i64>::new(), " ")), "\n");
+        assert_eq!(output(|w| w.write_join([-7], " ")), "-7\n");
+        assert_eq!(output(|w| w.write_join([1, -2, 3], ", ")), "1, -2, 3\n");
+        assert_eq!(output(|w| w.write_join(["a", "b"], "")), "ab\n");
+        let long: Vec<i64> = (-50_000..50_000).collect();
+        let expected = long
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+            + "\n";
+        assert_eq!(output(|w| w.write_join(&long, " ")), expected);
+    }
+
+    #[test]
+    fn lines_and_yes_no() {
+        let text = output(|w| {
+            w.w(1);
+            w.w(' ');
+            w.wln(-2.5);
+            w.write_yes_no(true);
+            w.write_yes_no(false);
+            w.wln("");
+            w.flush();
+            w.wln(format_args!("{}-{}", 3, 4));
+        });
+        assert_eq!(text, "1 -2.5\nYes\nNo\n\n3-4\n");
+    }
+
+    #[test]
+    fn flush_reaches_the_sink() {
+        let mut writer = Writer::new(Vec::new());
+        writer.wln(42);
+        writer.flush();
+        assert_eq!(writer.out.get_ref(), b"42\n");
+    }
+}
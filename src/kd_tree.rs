@@ -0,0 +1,265 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::heap::{Heap, MaxHeap};
+use std::ops::RangeInclusive;
+
+/// # 2차원 KD-tree
+/// 점 집합에서 가장 가까운 점, 가까운 점 k개, 직사각형 안의 점 개수를 구합니다.
+///
+/// * 점 배열을 x, y 좌표로 번갈아 가며 중앙값에서 나눠 균형 잡힌 트리를 배열 안에 만듭니다.
+/// 구간 `lo..hi`를 맡은 노드는 가운데 `(lo + hi) / 2`의 점이고, 노드마다 서브트리를 감싸는 직사각형을 저장합니다.
+/// * 최근접 탐색은 질의 점에 가까운 자식부터 보고, 감싸는 직사각형까지의 거리가 지금까지의 k번째 후보보다 멀면
+/// 그 서브트리를 건너뜁니다. 후보는 [`MaxHeap`]에 `(거리², 점 번호)`로 둡니다.
+/// * 거리는 유클리드 거리의 제곱(u64)이고, 거리가 같으면 점 번호가 작은 쪽이 앞섭니다. 같은 위치의 점이 여러 개
+/// 있어도 각각 따로 셉니다. 좌표의 절댓값이 10^9 이하이면 넘치지 않습니다.
+/// * 점 번호는 `build`에 넘긴 배열에서의 위치입니다. 트리 순회는 재귀 없이 스택으로 합니다.
+/// ## 시간복잡도
+/// 생성 O(n log n), 직사각형 질의 최악 O(√n + 답), 최근접은 무작위 점에서 기댓값 O(log n)
+#[derive(Clone, Debug)]
+pub struct KdTree2D {
+    /// 트리 순서로 놓은 (x, y, 원래 번호)
+    points: Vec<(i64, i64, usize)>,
+    /// `bbox[mid]`: 가운데가 mid인 노드의 서브트리를 감싸는 `[min_x, max_x, min_y, max_y]`
+    bbox: Vec<[i64; 4]>,
+}
+
+impl KdTree2D {
+    /// 점 배열로 생성
+    pub fn build(points: &[(i64, i64)]) -> Self {
+        let n = points.len();
+        let mut order: Vec<(i64, i64, usize)> = points
+            .iter()
+            .enumerate()
+            .map(|(i, &(x, y))| (x, y, i))
+            .collect();
+        let mut bbox = vec![[0; 4]; n];
+        // (구간 시작, 구간 끝, x로 나누는지)
+        let mut stack = vec![(0, n, true)];
+        while let Some((lo, hi, by_x)) = stack.pop() {
+            if lo >= hi {
+                continue;
+            }
+            let part = &mut order[lo..hi];
+            let mut b = [i64::MAX, i64::MIN, i64::MAX, i64::MIN];
+            for &(x, y, _) in part.iter() {
+                b = [b[0].min(x), b[1].max(x), b[2].min(y), b[3].max(y)];
+            }
+            let mid = (lo + hi) / 2;
+            if by_x {
+                part.select_nth_unstable_by_key(mid - lo, |p| (p.0, p.1));
+            } else {
+                part.select_nth_unstable_by_key(mid - lo, |p| (p.1, p.0));
+            }
+            bbox[mid] = b;
+            stack.push((lo, mid, !by_x));
+            stack.push((mid + 1, hi, !by_x));
+        }
+        KdTree2D {
+            points: order,
+            bbox,
+        }
+    }
+
+    /// 점의 개수
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// 점이 없는지
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// 질의 점에서 가장 가까운 점의 (번호, 거리²). 점이 없으면 None
+    pub fn nearest(&self, query: (i64, i64)) -> Option<(usize, u64)> {
+        self.k_nearest(query, 1).first().copied()
+    }
+
+    /// 질의 점에서 가까운 점 k개의 (번호, 거리²)를 가까운 순서로. 점이 k개보다 적으면 모두 반환합니다.
+    pub fn k_nearest(&self, query: (i64, i64), k: usize) -> Vec<(usize, u64)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut best: MaxHeap<(u64, usize)> = MaxHeap::new();
+        let mut stack = vec![(0, self.len())];
+        while let Some((lo, hi)) = stack.pop() {
+            if lo >= hi {
+                continue;
+            }
+            let mid = (lo + hi) / 2;
+            if best.len() == k && box_dist(&self.bbox[mid], query) > best.peek().unwrap().0 {
+                continue;
+            }
+            let (x, y, id) = self.points[mid];
+            best.push((dist(x, y, query), id));
+            if best.len() > k {
+                best.pop();
+            }
+            // 가까운 쪽을 나중에 넣어서 먼저 봄
+            let child_dist = |lo: usize, hi: usize| {
+                if lo < hi {
+                    box_dist(&self.bbox[(lo + hi) / 2], query)
+                } else {
+                    u64::MAX
+                }
+            };
+            if child_dist(lo, mid) <= child_dist(mid + 1, hi) {
+                stack.push((mid + 1, hi));
+                stack.push((lo, mid));
+            } else {
+                stack.push((lo, mid));
+                stack.push((mid + 1, hi));
+            }
+        }
+        let mut result = Vec::with_capacity(best.len());
+        while let Some((d, id)) = best.pop() {
+            result.push((id, d));
+        }
+        result.reverse();
+        result
+    }
+
+    /// `xs × ys` 직사각형(경계 포함) 안의 점 개수
+    pub fn count_in_rect(&self, xs: RangeInclusive<i64>, ys: RangeInclusive<i64>) -> usize {
+        let (x1, x2, y1, y2) = (*xs.start(), *xs.end(), *ys.start(), *ys.end());
+        let mut count = 0;
+        let mut stack = vec![(0, self.len())];
+        while let Some((lo, hi)) = stack.pop() {
+            if lo >= hi {
+                continue;
+            }
+            let mid = (lo + hi) / 2;
+            let [min_x, max_x, min_y, max_y] = self.bbox[mid];
+            if max_x < x1 || x2 < min_x || max_y < y1 || y2 < min_y {
+                continue;
+            }
+            if x1 <= min_x && max_x <= x2 && y1 <= min_y && max_y <= y2 {
+                count += hi - lo;
+                continue;
+            }
+            let (x, y, _) = self.points[mid];
+            if x1 <= x && x <= x2 && y1 <= y && y <= y2 {
+                count += 1;
+            }
+            stack.push((lo, mid));
+            stack.push((mid + 1, hi));
+        }
+        count
+    }
+}
+
+fn dist(x: i64, y: i64, (qx, qy): (i64, i64)) -> u64 {
+    let (dx, dy) = (x.abs_diff(qx), y.abs_diff(qy));
+    dx * dx + dy * dy
+}
+
+/// 질의 점에서 직사각형까지 거리²의 하한
+fn box_dist(&[min_x, max_x, min_y, max_y]: &[i64; 4], (qx, qy): (i64, i64)) -> u64 {
+    let gap = |q: i64, lo: i64, hi: i64| {
+        if q < lo {
+            lo.abs_diff(q)
+        } else if q > hi {
+            q.abs_diff(hi)
+        } else {
+            0
+        }
+    };
+    let (dx, dy) = (gap(qx, min_x, max_x), gap(qy, min_y, max_y));
+    dx * dx + dy * dy
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn brute_k_nearest(points: &[(i64, i64)], q: (i64, i64), k: usize) -> Vec<(usize, u64)> {
+        let mut all: Vec<(u64, usize)> = points
+            .iter()
+            .enumerate()
+            .map(|(i, &(x, y))| (dist(x, y, q), i))
+            .collect();
+        all.sort_unstable();
+        all.into_iter().take(k).map(|(d, i)| (i, d)).collect()
+    }
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(150);
+        for round in 0..200 {
+            let n = rng.index(80);
+            // 같은 점과 한 직선 위의 점이 자주 나오도록 좌표 범위를 바꿈
+            let spread = [3, 50, 1_000_000_000][round % 3];
+            let points: Vec<(i64, i64)> = (0..n)
+                .map(|_| match round % 4 {
+                    0 => (rng.range_i64(-spread, spread), 7),
+                    _ => (
+                        rng.range_i64(-spread, spread),
+                        rng.range_i64(-spread, spread),
+                    ),
+                })
+                .collect();
+            let tree = KdTree2D::build(&points);
+            for _ in 0..30 {
+                let q = (
+                    rng.range_i64(-spread, spread),
+                    rng.range_i64(-spread, spread),
+                );
+                let k = rng.index(n + 3);
+                assert_eq!(tree.k_nearest(q, k), brute_k_nearest(&points, q, k));
+                assert_eq!(
+                    tree.nearest(q),
+                    brute_k_nearest(&points, q, 1).first().copied()
+                );
+                let (a, b) = (
+                    rng.range_i64(-spread, spread),
+                    rng.range_i64(-spread, spread),
+                );
+                let (c, d) = (
+                    rng.range_i64(-spread, spread),
+                    rng.range_i64(-spread, spread),
+                );
+                let (x1, x2, y1, y2) = (a.min(b), a.max(b), c.min(d), c.max(d));
+                let expected = points
+                    .iter()
+                    .filter(|&&(x, y)| x1 <= x && x <= x2 && y1 <= y && y <= y2)
+                    .count();
+                assert_eq!(tree.count_in_rect(x1..=x2, y1..=y2), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn duplicates_and_small_cases() {
+        let points = [(1, 1), (1, 1), (1, 1), (5, 5), (-2, 3)];
+        let tree = KdTree2D::build(&points);
+        assert_eq!(tree.nearest((1, 1)), Some((0, 0)));
+        assert_eq!(tree.k_nearest((1, 2), 3), vec![(0, 1), (1, 1), (2, 1)]);
+        assert_eq!(tree.k_nearest((0, 0), 10).len(), 5);
+        assert_eq!(tree.count_in_rect(1..=1, 1..=1), 3);
+        assert_eq!(
+            tree.count_in_rect(i64::MIN..=i64::MAX, i64::MIN..=i64::MAX),
+            5
+        );
+        let empty = KdTree2D::build(&[]);
+        assert_eq!(empty.nearest((0, 0)), None);
+        assert_eq!(empty.count_in_rect(0..=10, 0..=10), 0);
+    }
+
+    #[test]
+    fn many_points() {
+        let mut rng = XorShift::new(1500);
+        let points: Vec<(i64, i64)> = (0..100_000)
+            .map(|_| (rng.range_i64(0, 1_000_000), rng.range_i64(0, 1_000_000)))
+            .collect();
+        let tree = KdTree2D::build(&points);
+        for _ in 0..20 {
+            let q = (rng.range_i64(0, 1_000_000), rng.range_i64(0, 1_000_000));
+            let expected = (0..points.len())
+                .map(|i| (dist(points[i].0, points[i].1, q), i))
+                .min()
+                .map(|(d, i)| (i, d));
+            assert_eq!(tree.nearest(q), expected);
+        }
+        assert_eq!(tree.count_in_rect(0..=1_000_000, 0..=1_000_000), 100_000);
+    }
+}
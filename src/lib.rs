@@ -1 +1,29 @@
+pub mod algebra;
+pub mod cht;
+pub mod compress;
+pub mod disjoint_sparse_table;
+pub mod dsu;
+pub mod fenwick;
+pub mod flow;
+pub mod graph;
+pub mod grid;
 pub mod heap;
+pub mod interval_set;
+pub mod io;
+pub mod kd_tree;
+pub mod lis;
+pub mod lru_cache;
+pub mod math;
+pub mod mo;
+pub mod prefix_sum;
+#[cfg(test)]
+pub(crate) mod random;
+pub mod range_map;
+pub mod search;
+pub mod segtree;
+pub mod sparse_table;
+pub mod string;
+pub mod treap;
+pub mod tree;
+pub mod wavelet_tree;
+pub mod xor;
@@ -0,0 +1,155 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # 최장 증가 부분 수열 (길이)
+/// 순증가(`a[i] < a[j]`)하는 가장 긴 부분 수열의 길이를 구합니다.
+/// ## 시간복잡도
+/// O(n log n)
+pub fn lis_length<T: Ord>(a: &[T]) -> usize {
+    patience(a, true).tails.len()
+}
+
+/// # 최장 증가 부분 수열 (복원)
+/// 순증가하는 가장 긴 부분 수열 하나의 위치들을 오름차순으로 반환합니다.
+///
+/// * 길이가 k인 증가 부분 수열의 끝 값 중 가장 작은 것을 `tails[k - 1]`에 두는 patience sorting입니다.
+/// 각 원소는 들어간 칸의 바로 앞 칸 원소를 부모로 기억하고, 마지막 칸에서 부모를 따라가 복원합니다.
+/// * 같은 값을 허용하려면 [`longest_non_decreasing`]을 씁니다. 예를 들어 `[2, 2, 2]`의 답은 1과 3입니다.
+/// ## 시간복잡도
+/// O(n log n)
+pub fn lis_indices<T: Ord>(a: &[T]) -> Vec<usize> {
+    patience(a, true).reconstruct()
+}
+
+/// # 최장 비감소 부분 수열 (복원)
+/// [`lis_indices`]와 같지만 같은 값이 이어져도 되는(`a[i] <= a[j]`) 가장 긴 부분 수열의 위치들을 반환합니다.
+/// ## 시간복잡도
+/// O(n log n)
+pub fn longest_non_decreasing<T: Ord>(a: &[T]) -> Vec<usize> {
+    patience(a, false).reconstruct()
+}
+
+/// # 위치별 최장 증가 부분 수열
+/// `result[i]`는 `a[i]`로 끝나는 순증가 부분 수열의 최대 길이입니다. 양쪽 방향의 결과를 합쳐서 "i를 꼭 지나는 LIS"
+/// 같은 DP를 만들 때 씁니다.
+/// ## 시간복잡도
+/// O(n log n)
+pub fn lis_ending_at<T: Ord>(a: &[T]) -> Vec<usize> {
+    patience(a, true).ending_at
+}
+
+struct Patience {
+    /// `tails[k]`: 길이 k + 1인 부분 수열의 끝 중 값이 가장 작은 것의 위치
+    tails: Vec<usize>,
+    /// 각 위치로 끝나는 부분 수열에서 바로 앞 원소의 위치
+    parent: Vec<Option<usize>>,
+    ending_at: Vec<usize>,
+}
+
+impl Patience {
+    fn reconstruct(&self) -> Vec<usize> {
+        let mut result = Vec::with_capacity(self.tails.len());
+        let mut cur = self.tails.last().copied();
+        while let Some(i) = cur {
+            result.push(i);
+            cur = self.parent[i];
+        }
+        result.reverse();
+        result
+    }
+}
+
+fn patience<T: Ord>(a: &[T], strict: bool) -> Patience {
+    let mut tails: Vec<usize> = Vec::new();
+    let mut parent = vec![None; a.len()];
+    let mut ending_at = vec![0; a.len()];
+    for (i, x) in a.iter().enumerate() {
+        // 순증가면 x 이상인 첫 칸, 비감소면 x보다 큰 첫 칸을 바꿈
+        let k = tails.partition_point(|&j| if strict { a[j] < *x } else { a[j] <= *x });
+        parent[i] = k.checked_sub(1).map(|p| tails[p]);
+        ending_at[i] = k + 1;
+        if k == tails.len() {
+            tails.push(i);
+        } else {
+            tails[k] = i;
+        }
+    }
+    Patience {
+        tails,
+        parent,
+        ending_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 모든 부분집합을 보고 구한 (순증가 LIS 길이, 비감소 길이)
+    fn brute(a: &[i64]) -> (usize, usize) {
+        let mut best = (0, 0);
+        for mask in 0u32..1 << a.len() {
+            let picked: Vec<i64> = (0..a.len())
+                .filter(|&i| (mask >> i) & 1 == 1)
+                .map(|i| a[i])
+                .collect();
+            let len = picked.len();
+            if picked.windows(2).all(|w| w[0] < w[1]) {
+                best.0 = best.0.max(len);
+            }
+            if picked.windows(2).all(|w| w[0] <= w[1]) {
+                best.1 = best.1.max(len);
+            }
+        }
+        best
+    }
+
+    fn assert_valid(a: &[i64], indices: &[usize], strict: bool) {
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+        assert!(indices.windows(2).all(|w| if strict {
+            a[w[0]] < a[w[1]]
+        } else {
+            a[w[0]] <= a[w[1]]
+        }));
+    }
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(206);
+        for round in 0..500 {
+            let n = rng.index(16);
+            let spread = [2, 5, 100][round % 3];
+            let a: Vec<i64> = (0..n).map(|_| rng.range_i64(-spread, spread)).collect();
+            let (strict, non_strict) = brute(&a);
+            assert_eq!(lis_length(&a), strict);
+            let lis = lis_indices(&a);
+            assert_eq!(lis.len(), strict);
+            assert_valid(&a, &lis, true);
+            let lnds = longest_non_decreasing(&a);
+            assert_eq!(lnds.len(), non_strict);
+            assert_valid(&a, &lnds, false);
+            let ending = lis_ending_at(&a);
+            for i in 0..n {
+                // a[i]로 끝나는 LIS = a[..=i] 중 a[i] 미만인 값들의 LIS + 1
+                let prefix: Vec<i64> = a[..i].iter().copied().filter(|&x| x < a[i]).collect();
+                assert_eq!(ending[i], brute(&prefix).0 + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn decreasing_equal_and_empty() {
+        let decreasing = [5, 4, 3, 2, 1];
+        assert_eq!(lis_length(&decreasing), 1);
+        assert_eq!(lis_ending_at(&decreasing), vec![1; 5]);
+        assert_eq!(longest_non_decreasing(&decreasing).len(), 1);
+        let equal = [7; 6];
+        assert_eq!(lis_indices(&equal).len(), 1);
+        assert_eq!(longest_non_decreasing(&equal), vec![0, 1, 2, 3, 4, 5]);
+        let empty: [i32; 0] = [];
+        assert_eq!(lis_length(&empty), 0);
+        assert!(lis_indices(&empty).is_empty());
+        assert_eq!(lis_indices(&[3, 1, 4, 1, 5, 9, 2, 6]).len(), 4);
+        assert_eq!(lis_length(&["b", "a", "c"]), 2);
+    }
+}
@@ -0,0 +1,237 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// 이전/다음 노드가 없음을 나타내는 번호
+const NIL: u32 = u32::MAX;
+
+#[derive(Clone, Debug)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    prev: u32,
+    next: u32,
+}
+
+/// # LRU cache
+/// 용량이 정해진 키-값 저장소입니다. 가득 찬 상태에서 새 키를 넣으면 가장 오래 쓰지 않은 항목을 내보냅니다.
+///
+/// * 키에서 노드 번호로 가는 `HashMap`과, 노드 배열 위의 이중 연결 리스트(앞이 가장 최근)로 만듭니다.
+/// 내보낸 노드의 자리는 새 항목이 그대로 씁니다.
+/// * [`get`](Self::get)과 [`put`](Self::put)은 항목을 가장 최근으로 올리고, [`peek`](Self::peek)은 올리지 않습니다.
+/// * 키를 맵과 노드에 모두 두기 때문에 `K: Clone`이 필요합니다.
+/// ## 시간복잡도
+/// 연산마다 기댓값 O(1)
+#[derive(Clone, Debug)]
+pub struct LruCache<K, V> {
+    capacity: usize,
+    map: HashMap<K, u32>,
+    nodes: Vec<Node<K, V>>,
+    /// 가장 최근에 쓴 노드
+    head: u32,
+    /// 가장 오래 쓰지 않은 노드
+    tail: u32,
+}
+
+impl<K: Eq + Hash + Clone, V> LruCache<K, V> {
+    /// 항목을 최대 capacity개 담는 빈 캐시
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity < NIL as usize,
+            "capacity {capacity} too large for u32 indices"
+        );
+        LruCache {
+            capacity,
+            map: HashMap::with_capacity(capacity),
+            nodes: Vec::with_capacity(capacity),
+            head: NIL,
+            tail: NIL,
+        }
+    }
+
+    /// 담을 수 있는 항목의 최대 개수
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 항목의 개수
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// 비었는지
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// key의 값을 가장 최근으로 올리고 반환. 없으면 None
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let id = *self.map.get(key)?;
+        self.detach(id);
+        self.push_front(id);
+        Some(&self.nodes[id as usize].value)
+    }
+
+    /// key의 값. 순서는 바꾸지 않습니다.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|&id| &self.nodes[id as usize].value)
+    }
+
+    /// key의 값을 value로 하고 가장 최근으로 올림. 새 키 때문에 항목을 내보냈으면 그 `(키, 값)`을 반환합니다.
+    /// 이미 있는 키는 값만 바꾸고 None을 반환하고, 용량이 0이면 넣은 항목을 그대로 돌려줍니다.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(&id) = self.map.get(&key) {
+            self.nodes[id as usize].value = value;
+            self.detach(id);
+            self.push_front(id);
+            return None;
+        }
+        if self.capacity == 0 {
+            return Some((key, value));
+        }
+        if self.map.len() < self.capacity {
+            let id = self.nodes.len() as u32;
+            self.nodes.push(Node {
+                key: key.clone(),
+                value,
+                prev: NIL,
+                next: NIL,
+            });
+            self.map.insert(key, id);
+            self.push_front(id);
+            return None;
+        }
+        // 가장 오래된 노드 자리에 새 항목을 씀
+        let id = self.tail;
+        self.detach(id);
+        let node = &mut self.nodes[id as usize];
+        let old_key = std::mem::replace(&mut node.key, key.clone());
+        let old_value = std::mem::replace(&mut node.value, value);
+        self.map.remove(&old_key);
+        self.map.insert(key, id);
+        self.push_front(id);
+        Some((old_key, old_value))
+    }
+
+    /// 리스트에서 id를 뺌
+    fn detach(&mut self, id: u32) {
+        let Node { prev, next, .. } = self.nodes[id as usize];
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.nodes[prev as usize].next = next;
+        }
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.nodes[next as usize].prev = prev;
+        }
+    }
+
+    /// id를 리스트 맨 앞(가장 최근)에 붙임
+    fn push_front(&mut self, id: u32) {
+        self.nodes[id as usize].prev = NIL;
+        self.nodes[id as usize].next = self.head;
+        if self.head == NIL {
+            self.tail = id;
+        } else {
+            self.nodes[self.head as usize].prev = id;
+        }
+        self.head = id;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 앞이 가장 오래된 항목인 단순 모델
+    struct Model {
+        capacity: usize,
+        entries: Vec<(u64, u64)>,
+    }
+
+    impl Model {
+        fn get(&mut self, key: u64) -> Option<u64> {
+            let i = self.entries.iter().position(|&(k, _)| k == key)?;
+            let entry = self.entries.remove(i);
+            self.entries.push(entry);
+            Some(entry.1)
+        }
+
+        fn put(&mut self, key: u64, value: u64) -> Option<(u64, u64)> {
+            if let Some(i) = self.entries.iter().position(|&(k, _)| k == key) {
+                self.entries.remove(i);
+                self.entries.push((key, value));
+                return None;
+            }
+            self.entries.push((key, value));
+            if self.entries.len() > self.capacity {
+                return Some(self.entries.remove(0));
+            }
+            None
+        }
+    }
+
+    #[test]
+    fn random_against_vec_model() {
+        let mut rng = XorShift::new(155);
+        for round in 0..100 {
+            let capacity = round % 7;
+            let mut cache = LruCache::new(capacity);
+            let mut model = Model {
+                capacity,
+                entries: Vec::new(),
+            };
+            for _ in 0..500 {
+                let key = rng.below(10);
+                match rng.below(3) {
+                    0 => assert_eq!(cache.get(&key).copied(), model.get(key)),
+                    1 => {
+                        let value = rng.below(1000);
+                        assert_eq!(cache.put(key, value), model.put(key, value));
+                    }
+                    _ => {
+                        let expected = model
+                            .entries
+                            .iter()
+                            .find(|&&(k, _)| k == key)
+                            .map(|&(_, v)| v);
+                        assert_eq!(cache.peek(&key).copied(), expected);
+                    }
+                }
+                assert_eq!(cache.len(), model.entries.len());
+            }
+        }
+    }
+
+    #[test]
+    fn eviction_order_follows_gets() {
+        let mut cache = LruCache::new(3);
+        assert_eq!(cache.put("a", 1), None);
+        assert_eq!(cache.put("b", 2), None);
+        assert_eq!(cache.put("c", 3), None);
+        // a를 쓰면 b가 가장 오래된 항목
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert_eq!(cache.put("d", 4), Some(("b", 2)));
+        // peek은 순서를 바꾸지 않음
+        assert_eq!(cache.peek(&"c"), Some(&3));
+        assert_eq!(cache.put("e", 5), Some(("c", 3)));
+        // 이미 있는 키는 값만 바꾸고 가장 최근으로
+        assert_eq!(cache.put("a", 10), None);
+        assert_eq!(cache.put("f", 6), Some(("d", 4)));
+        assert_eq!(cache.get(&"a"), Some(&10));
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn zero_capacity_keeps_nothing() {
+        let mut cache = LruCache::new(0);
+        assert_eq!(cache.put(1, 'x'), Some((1, 'x')));
+        assert_eq!(cache.get(&1), None);
+        assert!(cache.is_empty());
+        assert_eq!(cache.capacity(), 0);
+    }
+}
@@ -0,0 +1,53 @@
+mod big_uint;
+pub use big_uint::{BigUInt, ParseBigUIntError};
+mod combinatorics;
+pub use combinatorics::Combinatorics;
+mod crt;
+pub use crt::crt;
+mod discrete_log;
+pub use discrete_log::discrete_log;
+mod dyn_mod_int;
+pub use dyn_mod_int::DynModInt;
+mod fft;
+pub use fft::{convolution_f64, convolution_mod};
+mod floor_sum;
+pub use floor_sum::floor_sum;
+mod gauss;
+pub use gauss::{
+    LinearSolution, determinant_mod_p, rank_mod_p, solve_linear_f64, solve_linear_mod_p, solve_xor,
+};
+mod gcd;
+pub use gcd::{ext_gcd, gcd, lcm, mod_inverse};
+mod lagrange;
+pub use lagrange::{lagrange_interpolate, lagrange_interpolate_points};
+mod linear_recurrence;
+pub use linear_recurrence::{berlekamp_massey, linear_recurrence_kth};
+mod linear_sieve;
+pub use linear_sieve::{SieveTables, linear_sieve};
+mod lucas;
+pub use lucas::{comb_mod_small_prime, comb_mod_square_free};
+mod matrix;
+pub use matrix::Matrix;
+mod mod_int;
+pub use mod_int::{ModInt, ModInt998244353, ModInt1000000007};
+mod modular;
+pub use modular::{add_mod, mul_mod, pow_mod, sub_mod};
+mod ntt;
+pub use ntt::{convolution, convolution_ntt};
+mod pollard_rho;
+pub use pollard_rho::{factorize_u64, is_prime_u64};
+mod poly;
+pub use poly::Poly;
+mod primitive_root;
+pub use primitive_root::primitive_root;
+mod root;
+pub use root::{ikth_root, is_perfect_power, is_perfect_square, isqrt};
+mod sieve;
+pub use sieve::Sieve;
+mod sos;
+pub use sos::{subset_mobius, subset_sum_convolution, subset_zeta, superset_mobius, superset_zeta};
+mod walsh_hadamard;
+pub use walsh_hadamard::{
+    and_convolution, and_convolution_mod, or_convolution, or_convolution_mod, xor_convolution,
+    xor_convolution_mod,
+};
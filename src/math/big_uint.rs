@@ -0,0 +1,434 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul};
+use std::str::FromStr;
+
+/// 자릿수 하나(limb)의 밑
+const BASE: u32 = 1_000_000_000;
+/// 짧은 쪽이 이 길이 이하면 Karatsuba 대신 O(nm) 곱셈
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// # 임의 정밀도 부호 없는 정수
+/// 1000자리 정수의 덧셈, 곱셈처럼 u128을 넘는 값을 다룰 때 쓰는 작은 큰 수 타입입니다. 덧셈, 뺄셈(`checked_sub`),
+/// 곱셈, 비교, u64로 나눈 몫과 나머지, 10진수 문자열 변환을 지원합니다.
+///
+/// * 밑이 `10^9`인 자릿수(u32)를 낮은 자리부터 저장하고, 가장 높은 자리가 0이 아니도록 유지합니다. 0은 자릿수가
+/// 없습니다. 밑이 10의 거듭제곱이라서 출력은 자릿수를 9자리씩 이어 붙이기만 하면 됩니다.
+/// * 곱셈은 짧은 쪽이 32자리(약 290자리 10진수)를 넘으면 Karatsuba로 합니다. 길이 차이가 크면 긴 쪽을 짧은 쪽
+/// 길이로 잘라 곱합니다.
+/// ## 시간복잡도
+/// 덧셈, 뺄셈, 비교, u64 나눗셈 O(n), 곱셈 O(n^1.58), 변환 O(n)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigUInt {
+    limbs: Vec<u32>,
+}
+
+/// [`BigUInt`]의 문자열이 10진수가 아님
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseBigUIntError;
+
+impl fmt::Display for ParseBigUIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid decimal digits for BigUInt")
+    }
+}
+
+impl std::error::Error for ParseBigUIntError {}
+
+impl BigUInt {
+    /// 0
+    pub fn new() -> Self {
+        BigUInt { limbs: Vec::new() }
+    }
+
+    /// 0인지
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// `self - other`. 결과가 음수이면 None
+    pub fn checked_sub(&self, other: &BigUInt) -> Option<BigUInt> {
+        if *self < *other {
+            return None;
+        }
+        Some(Self::from_limbs(sub_limbs(&self.limbs, &other.limbs)))
+    }
+
+    /// `(self / d, self % d)`. d가 0이면 panic
+    pub fn div_rem_u64(&self, d: u64) -> (BigUInt, u64) {
+        assert!(d != 0, "division by zero");
+        let mut quotient = vec![0u32; self.limbs.len()];
+        let mut rem = 0u128;
+        for (q, &limb) in quotient.iter_mut().zip(&self.limbs).rev() {
+            let cur = rem * BASE as u128 + limb as u128;
+            *q = (cur / d as u128) as u32;
+            rem = cur % d as u128;
+        }
+        (Self::from_limbs(quotient), rem as u64)
+    }
+
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        BigUInt { limbs }
+    }
+}
+
+/// a + b
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+    for (i, &x) in long.iter().enumerate() {
+        let mut sum = x + short.get(i).copied().unwrap_or(0) + carry;
+        carry = (sum >= BASE) as u32;
+        if carry == 1 {
+            sum -= BASE;
+        }
+        result.push(sum);
+    }
+    if carry == 1 {
+        result.push(1);
+    }
+    result
+}
+
+/// a - b (a >= b)
+fn sub_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let y = b.get(i).copied().unwrap_or(0) + borrow;
+        if x >= y {
+            result.push(x - y);
+            borrow = 0;
+        } else {
+            result.push(x + BASE - y);
+            borrow = 1;
+        }
+    }
+    debug_assert!(borrow == 0);
+    result
+}
+
+fn schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let cur = result[i + j] as u64 + x as u64 * y as u64 + carry;
+            result[i + j] = (cur % BASE as u64) as u32;
+            carry = cur / BASE as u64;
+        }
+        let mut k = i + b.len();
+        while carry > 0 {
+            let cur = result[k] as u64 + carry;
+            result[k] = (cur % BASE as u64) as u32;
+            carry = cur / BASE as u64;
+            k += 1;
+        }
+    }
+    result
+}
+
+/// 끝의 0을 지우지 않은 a · b (길이 a.len() + b.len())
+fn mul_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if short.is_empty() {
+        return vec![0; long.len()];
+    }
+    if short.len() <= KARATSUBA_THRESHOLD {
+        return schoolbook(long, short);
+    }
+    if long.len() >= 2 * short.len() {
+        // 긴 쪽을 짧은 쪽 길이의 조각으로 잘라 곱하고 자리를 맞춰 더함
+        let mut result = vec![0u32; long.len() + short.len()];
+        for (c, chunk) in long.chunks(short.len()).enumerate() {
+            let part = mul_limbs(chunk, short);
+            add_into(&mut result[c * short.len()..], &part);
+        }
+        return result;
+    }
+    // a = a1 · B^h + a0, b = b1 · B^h + b0
+    let h = long.len() / 2;
+    let (a0, a1) = long.split_at(h);
+    let (b0, b1) = short.split_at(h.min(short.len()));
+    let z0 = mul_limbs(a0, b0);
+    let z2 = mul_limbs(a1, b1);
+    let mut z1 = mul_limbs(&add_limbs(a0, a1), &add_limbs(b0, b1));
+    // z1 - z0 - z2 >= 0
+    trim(&mut z1);
+    let mut z1 = sub_limbs(&z1, &trimmed(&z0));
+    trim(&mut z1);
+    let mut z1 = sub_limbs(&z1, &trimmed(&z2));
+    trim(&mut z1);
+    let mut result = vec![0u32; long.len() + short.len()];
+    add_into(&mut result, &z0);
+    add_into(&mut result[h..], &z1);
+    add_into(&mut result[2 * h..], &z2);
+    result
+}
+
+fn trim(v: &mut Vec<u32>) {
+    while v.last() == Some(&0) {
+        v.pop();
+    }
+}
+
+fn trimmed(v: &[u32]) -> Vec<u32> {
+    let mut v = v.to_vec();
+    trim(&mut v);
+    v
+}
+
+/// dst += src. 결과가 dst에 들어가야 하고, 넘치는 0은 버림
+fn add_into(dst: &mut [u32], src: &[u32]) {
+    let mut carry = 0;
+    let mut i = 0;
+    while i < src.len() || carry > 0 {
+        if i >= dst.len() {
+            debug_assert!(src[i..].iter().all(|&x| x == 0) && carry == 0);
+            return;
+        }
+        let mut sum = dst[i] + src.get(i).copied().unwrap_or(0) + carry;
+        carry = (sum >= BASE) as u32;
+        if carry == 1 {
+            sum -= BASE;
+        }
+        dst[i] = sum;
+        i += 1;
+    }
+}
+
+impl From<u64> for BigUInt {
+    fn from(mut x: u64) -> Self {
+        let mut limbs = Vec::new();
+        while x > 0 {
+            limbs.push((x % BASE as u64) as u32);
+            x /= BASE as u64;
+        }
+        BigUInt { limbs }
+    }
+}
+
+impl From<u128> for BigUInt {
+    fn from(mut x: u128) -> Self {
+        let mut limbs = Vec::new();
+        while x > 0 {
+            limbs.push((x % BASE as u128) as u32);
+            x /= BASE as u128;
+        }
+        BigUInt { limbs }
+    }
+}
+
+impl FromStr for BigUInt {
+    type Err = ParseBigUIntError;
+
+    /// 10진수 문자열. 앞의 0은 허용하고, 빈 문자열과 숫자가 아닌 문자는 오류입니다.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
+            return Err(ParseBigUIntError);
+        }
+        let limbs = bytes
+            .rchunks(9)
+            .map(|chunk| chunk.iter().fold(0, |acc, &c| acc * 10 + (c - b'0') as u32))
+            .collect();
+        Ok(Self::from_limbs(limbs))
+    }
+}
+
+impl fmt::Display for BigUInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((last, rest)) = self.limbs.split_last() else {
+            return f.write_str("0");
+        };
+        let mut s = last.to_string();
+        for limb in rest.iter().rev() {
+            s.push_str(&format!("{limb:09}"));
+        }
+        f.pad(&s)
+    }
+}
+
+impl Ord for BigUInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigUInt {
+    type Output = BigUInt;
+
+    fn add(self, rhs: Self) -> BigUInt {
+        BigUInt::from_limbs(add_limbs(&self.limbs, &rhs.limbs))
+    }
+}
+
+impl Add for BigUInt {
+    type Output = BigUInt;
+
+    fn add(self, rhs: Self) -> BigUInt {
+        &self + &rhs
+    }
+}
+
+impl Mul for &BigUInt {
+    type Output = BigUInt;
+
+    fn mul(self, rhs: Self) -> BigUInt {
+        BigUInt::from_limbs(mul_limbs(&self.limbs, &rhs.limbs))
+    }
+}
+
+impl Mul for BigUInt {
+    type Output = BigUInt;
+
+    fn mul(self, rhs: Self) -> BigUInt {
+        &self * &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn big(s: &str) -> BigUInt {
+        s.parse().unwrap()
+    }
+
+    fn random_big(rng: &mut XorShift, digits: usize) -> BigUInt {
+        let s: String = (0..digits.max(1))
+            .map(|_| char::from(b'0' + rng.below(10) as u8))
+            .collect();
+        big(&s)
+    }
+
+    #[test]
+    fn against_u128() {
+        let mut rng = XorShift::new(191);
+        for round in 0..20_000 {
+            // 자릿수 경계(10^9의 배수 근처)의 값을 자주 만듦
+            let pick = |rng: &mut XorShift| match round % 3 {
+                0 => rng.next_u64(),
+                1 => 10u64.pow(rng.below(20) as u32).wrapping_sub(rng.below(3)),
+                _ => rng.below(2_000_000_000),
+            };
+            let (x, y) = (pick(&mut rng), pick(&mut rng));
+            let (bx, by) = (BigUInt::from(x), BigUInt::from(y));
+            assert_eq!((&bx + &by).to_string(), (x as u128 + y as u128).to_string());
+            assert_eq!((&bx * &by).to_string(), (x as u128 * y as u128).to_string());
+            assert_eq!(bx.cmp(&by), x.cmp(&y));
+            let expected = x.checked_sub(y).map(BigUInt::from);
+            assert_eq!(bx.checked_sub(&by), expected);
+            let d = pick(&mut rng).max(1);
+            let (q, r) = BigUInt::from(x as u128 * y as u128).div_rem_u64(d);
+            let product = x as u128 * y as u128;
+            assert_eq!(
+                (q, r),
+                (
+                    BigUInt::from(product / d as u128),
+                    (product % d as u128) as u64
+                )
+            );
+            assert_eq!(big(&x.to_string()), bx);
+        }
+    }
+
+    #[test]
+    fn parse_and_print() {
+        for s in [
+            "0",
+            "1",
+            "999999999",
+            "1000000000",
+            "123456789012345678901234567890",
+        ] {
+            assert_eq!(big(s).to_string(), s);
+        }
+        assert_eq!(big("0000").to_string(), "0");
+        assert_eq!(big("000123").to_string(), "123");
+        assert!(big("0").is_zero());
+        assert_eq!("".parse::<BigUInt>(), Err(ParseBigUIntError));
+        assert_eq!("12a".parse::<BigUInt>(), Err(ParseBigUIntError));
+        assert_eq!("-1".parse::<BigUInt>(), Err(ParseBigUIntError));
+        assert_eq!(format!("{:>5}", BigUInt::from(42u64)), "   42");
+        // 자릿수 경계에서 받아내림과 올림
+        let b = big("1000000000000000000");
+        assert_eq!(
+            b.checked_sub(&BigUInt::from(1u64)).unwrap().to_string(),
+            "999999999999999999"
+        );
+        assert_eq!((&big("999999999999999999") + &BigUInt::from(1u64)), b);
+        assert_eq!(b.checked_sub(&b), Some(BigUInt::new()));
+    }
+
+    #[test]
+    fn factorials_and_powers() {
+        let mut fact = BigUInt::from(1u64);
+        for i in 1..=100u64 {
+            fact = &fact * &BigUInt::from(i);
+        }
+        let s = fact.to_string();
+        assert_eq!(s.len(), 158);
+        assert!(s.starts_with("93326215443944152681699238856266700490715968264381621468592963895217599993229915608941463976156518286253697920827223758251185210916864"));
+        assert!(s.ends_with(&"0".repeat(24)));
+        assert_eq!(s.bytes().map(|c| (c - b'0') as u32).sum::<u32>(), 648);
+        let mut power = BigUInt::from(1u64);
+        for _ in 0..1000 {
+            power = &power + &power;
+        }
+        let digits = power.to_string();
+        assert_eq!(digits.len(), 302);
+        assert_eq!(digits.bytes().map(|c| (c - b'0') as u32).sum::<u32>(), 1366);
+        // 2^1000 = (2^500)² = (2^1)^1000
+        let mut half = BigUInt::from(1u64);
+        for _ in 0..500 {
+            half = &half * &BigUInt::from(2u64);
+        }
+        assert_eq!(&half * &half, power);
+        let (q, r) = power.div_rem_u64(1 << 60);
+        assert_eq!(r, 0);
+        assert_eq!(&q * &BigUInt::from(1u64 << 60), power);
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook() {
+        let mut rng = XorShift::new(1910);
+        for _ in 0..30 {
+            let (da, db) = (1 + rng.index(3000), 1 + rng.index(3000));
+            let a = random_big(&mut rng, da);
+            let b = random_big(&mut rng, db);
+            let expected = BigUInt::from_limbs(schoolbook(&a.limbs, &b.limbs));
+            assert_eq!(&a * &b, expected);
+            assert_eq!(&b * &a, expected);
+            // (a + b)² = a² + 2ab + b²
+            let sum = &a + &b;
+            let two = BigUInt::from(2u64);
+            assert_eq!(
+                &sum * &sum,
+                &(&(&a * &a) + &(&two * &expected)) + &(&b * &b)
+            );
+        }
+        let a = random_big(&mut rng, 10_000);
+        let b = random_big(&mut rng, 10_000);
+        assert_eq!(
+            (&a * &b).to_string().len() >= 19_999,
+            !a.is_zero() && !b.is_zero()
+        );
+        assert!((&a * &BigUInt::new()).is_zero());
+    }
+}
@@ -0,0 +1,182 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::ModInt;
+
+/// # 이항계수 표
+/// `0..=max_n`의 팩토리얼과 팩토리얼의 역원을 미리 구해서 nCr, nPr 등을 O(1)에 구합니다.
+///
+/// * 법 M은 `max_n`보다 큰 소수여야 합니다. 그래야 모든 팩토리얼의 역원이 있습니다.
+/// * 인자는 i64입니다. `k < 0`, `n < 0`, `k > n`처럼 경우의 수가 없는 입력은 0을 반환하므로 `comb(n - 1, k)`를
+/// 경계 검사 없이 쓸 수 있습니다. 표 밖의 n은 panic합니다.
+/// * 역원 팩토리얼은 `fact[max_n]`의 역원 하나만 구하고 `inv_fact[i - 1] = inv_fact[i] · i`로 내려갑니다.
+/// ## 시간복잡도
+/// 생성 O(max_n + log M), 질의 O(1)
+#[derive(Clone, Debug)]
+pub struct Combinatorics<const M: u32> {
+    fact: Vec<ModInt<M>>,
+    inv_fact: Vec<ModInt<M>>,
+}
+
+impl<const M: u32> Combinatorics<M> {
+    /// `0..=max_n`의 표를 만듦
+    pub fn new(max_n: usize) -> Self {
+        assert!(
+            (max_n as u64) < M as u64,
+            "max_n {max_n} must be smaller than the modulus {M}"
+        );
+        let mut fact = Vec::with_capacity(max_n + 1);
+        fact.push(ModInt::new(1));
+        for i in 1..=max_n {
+            fact.push(fact[i - 1] * ModInt::from(i));
+        }
+        let mut inv_fact = vec![ModInt::new(0); max_n + 1];
+        inv_fact[max_n] = fact[max_n].inv();
+        for i in (1..=max_n).rev() {
+            inv_fact[i - 1] = inv_fact[i] * ModInt::from(i);
+        }
+        Combinatorics { fact, inv_fact }
+    }
+
+    /// 표에 있는 가장 큰 n
+    pub fn max_n(&self) -> usize {
+        self.fact.len() - 1
+    }
+
+    /// `n!`. n < 0이면 0
+    pub fn fact(&self, n: i64) -> ModInt<M> {
+        if n < 0 {
+            return ModInt::new(0);
+        }
+        self.fact[self.index(n)]
+    }
+
+    /// `1 / n!`. n < 0이면 0
+    pub fn inv_fact(&self, n: i64) -> ModInt<M> {
+        if n < 0 {
+            return ModInt::new(0);
+        }
+        self.inv_fact[self.index(n)]
+    }
+
+    /// nCk: n개에서 k개를 고르는 경우의 수
+    pub fn comb(&self, n: i64, k: i64) -> ModInt<M> {
+        if n < 0 || k < 0 || k > n {
+            return ModInt::new(0);
+        }
+        self.fact(n) * self.inv_fact(k) * self.inv_fact(n - k)
+    }
+
+    /// nPk: n개에서 k개를 골라 늘어놓는 경우의 수
+    pub fn perm(&self, n: i64, k: i64) -> ModInt<M> {
+        if n < 0 || k < 0 || k > n {
+            return ModInt::new(0);
+        }
+        self.fact(n) * self.inv_fact(n - k)
+    }
+
+    /// nHk: n종류에서 중복을 허락해 k개를 고르는 경우의 수 `C(n + k - 1, k)`. `nH0 = 1`입니다.
+    pub fn multiset_comb(&self, n: i64, k: i64) -> ModInt<M> {
+        if n < 0 || k < 0 {
+            return ModInt::new(0);
+        }
+        if k == 0 {
+            return ModInt::new(1);
+        }
+        self.comb(n + k - 1, k)
+    }
+
+    /// n번째 카탈랑 수 `C(2n, n) / (n + 1)`. 표에 2n까지 있어야 합니다.
+    pub fn catalan(&self, n: i64) -> ModInt<M> {
+        if n < 0 {
+            return ModInt::new(0);
+        }
+        self.comb(2 * n, n) - self.comb(2 * n, n + 1)
+    }
+
+    fn index(&self, n: i64) -> usize {
+        let n = n as usize;
+        assert!(
+            n <= self.max_n(),
+            "{n} is beyond the table size {}",
+            self.max_n()
+        );
+        n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::ModInt998244353;
+
+    type Mint = ModInt998244353;
+
+    #[test]
+    fn against_exact_binomials() {
+        let table = Combinatorics::<998_244_353>::new(300);
+        // u128 파스칼 삼각형 (n <= 120이면 넘치지 않음)
+        let mut row = vec![1u128];
+        for n in 0..=120i64 {
+            for k in 0..=n {
+                assert_eq!(
+                    table.comb(n, k),
+                    Mint::new((row[k as usize] % 998_244_353) as u64)
+                );
+            }
+            let mut next = vec![1u128; row.len() + 1];
+            for k in 1..row.len() {
+                next[k] = row[k - 1] + row[k];
+            }
+            row = next;
+        }
+        for n in 0..=300i64 {
+            for k in 0..=n {
+                assert_eq!(table.comb(n, k), table.comb(n, n - k));
+                if n > 0 && k > 0 {
+                    assert_eq!(
+                        table.comb(n, k),
+                        table.comb(n - 1, k - 1) + table.comb(n - 1, k)
+                    );
+                }
+                assert_eq!(table.perm(n, k), table.comb(n, k) * table.fact(k));
+            }
+        }
+    }
+
+    #[test]
+    fn conventions_and_small_values() {
+        let table = Combinatorics::<1_000_000_007>::new(40);
+        let zero = ModInt::new(0);
+        assert_eq!(table.comb(3, 4), zero);
+        assert_eq!(table.comb(-1, 0), zero);
+        assert_eq!(table.comb(3, -1), zero);
+        assert_eq!(table.perm(2, 3), zero);
+        assert_eq!(table.comb(0, 0), ModInt::new(1));
+        assert_eq!(table.perm(5, 2), ModInt::new(20));
+        assert_eq!(table.multiset_comb(3, 2), ModInt::new(6));
+        assert_eq!(table.multiset_comb(0, 0), ModInt::new(1));
+        assert_eq!(table.multiset_comb(0, 3), zero);
+        let catalan: Vec<u32> = (0..10).map(|n| table.catalan(n).value()).collect();
+        assert_eq!(catalan, [1, 1, 2, 5, 14, 42, 132, 429, 1430, 4862]);
+        assert_eq!(table.fact(20), ModInt::new(2_432_902_008_176_640_000));
+        assert_eq!(table.fact(10) * table.inv_fact(10), ModInt::new(1));
+    }
+
+    #[test]
+    fn large_table() {
+        let table = Combinatorics::<998_244_353>::new(1_000_000);
+        assert_eq!(table.max_n(), 1_000_000);
+        assert_eq!(
+            table.fact(1_000_000) * table.inv_fact(1_000_000),
+            Mint::new(1)
+        );
+        // C(10^6, 1) = 10^6
+        assert_eq!(table.comb(1_000_000, 1), Mint::new(1_000_000));
+        assert_eq!(table.comb(1_000_000, 999_999), Mint::new(1_000_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "beyond the table")]
+    fn outside_table_panics() {
+        Combinatorics::<998_244_353>::new(10).comb(11, 1);
+    }
+}
@@ -0,0 +1,111 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::ext_gcd;
+
+/// # 중국인의 나머지 정리
+/// 연립 합동식 `x ≡ r_i (mod m_i)`의 해를 `(x, lcm)`으로 반환합니다. 해는 `x + lcm · t` 꼴이 전부이고,
+/// `0 <= x < lcm`입니다. 해가 없으면 None입니다.
+///
+/// * 법끼리 서로소가 아니어도 됩니다. 두 식을 합칠 때 `g = gcd(m_1, m_2)`가 `r_2 - r_1`을 나누지 않으면 모순이고,
+/// 나누면 법이 `lcm(m_1, m_2)`인 식 하나가 됩니다.
+/// * 중간 계산은 i128로 하므로 넘치지 않습니다. 최종 lcm이 i64 범위(`< 2^63`)를 넘으면 panic합니다.
+/// * 법은 1 이상이어야 하고, 나머지는 음수이거나 법 이상이어도 됩니다. 식이 없으면 `(0, 1)`입니다.
+/// ## 시간복잡도
+/// O(k · log max m)
+pub fn crt(residues: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut lcm) = (0i128, 1i128);
+    for &(r, m) in residues {
+        assert!(m >= 1, "modulus {m} must be positive");
+        let (r, m) = ((r as i128).rem_euclid(m as i128), m as i128);
+        // gcd(lcm mod m, m) = gcd(lcm, m)이고, p는 lcm / g의 (mod m / g) 역원
+        let (g, p, _) = ext_gcd((lcm % m) as i64, m as i64);
+        let (g, p) = (g as i128, p as i128);
+        if (r - x) % g != 0 {
+            return None;
+        }
+        // x + lcm · t ≡ r (mod m) ⇔ (lcm / g) · t ≡ (r - x) / g (mod m / g)
+        let step = m / g;
+        let t = ((r - x) / g % step * p).rem_euclid(step);
+        x += lcm * t;
+        lcm *= step;
+        assert!(lcm <= i64::MAX as i128, "lcm of moduli overflows i64");
+        x = x.rem_euclid(lcm);
+    }
+    Some((x as i64, lcm as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{gcd, lcm};
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_consistent_systems() {
+        let mut rng = XorShift::new(182);
+        for round in 0..3000 {
+            let k = rng.index(6);
+            // 공통 인수가 많은 법을 만듦
+            let common = [1, 6, 1 << 20, 999_983][round % 4];
+            let mut moduli = Vec::new();
+            let mut total = 1u64;
+            while moduli.len() < k {
+                let m = common * (1 + rng.below(30)) as i64;
+                if let Some(next) = lcm(total, m as u64).filter(|&l| l < 1 << 62) {
+                    total = next;
+                    moduli.push(m);
+                } else {
+                    break;
+                }
+            }
+            let answer = rng.range_i64(0, total as i64 - 1);
+            let residues: Vec<(i64, i64)> = moduli
+                .iter()
+                .map(|&m| (answer.rem_euclid(m) + m * rng.range_i64(-3, 3), m))
+                .collect();
+            let (x, l) = crt(&residues).unwrap();
+            assert_eq!((x, l as u64), (answer, total));
+            for &(r, m) in &residues {
+                assert_eq!(x.rem_euclid(m), r.rem_euclid(m));
+            }
+        }
+    }
+
+    #[test]
+    fn inconsistent_systems() {
+        let mut rng = XorShift::new(1820);
+        for _ in 0..3000 {
+            let (a, b) = (1 + rng.range_i64(0, 50), 1 + rng.range_i64(0, 50));
+            let (ra, rb) = (rng.range_i64(0, a - 1), rng.range_i64(0, b - 1));
+            let g = gcd(a as u64, b as u64) as i64;
+            let consistent = (ra - rb) % g == 0;
+            assert_eq!(crt(&[(ra, a), (rb, b)]).is_some(), consistent);
+            // 가능한 x를 직접 찾아서 비교
+            let l = a / g * b;
+            let expected = (0..l).find(|x| x % a == ra && x % b == rb);
+            assert_eq!(crt(&[(ra, a), (rb, b)]).map(|(x, _)| x), expected);
+        }
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+    }
+
+    #[test]
+    fn single_and_large_moduli() {
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(-1, 7)]), Some((6, 7)));
+        assert_eq!(crt(&[(5, 1)]), Some((0, 1)));
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // 큰 공통 인수를 가진 법
+        let p = 1_000_000_007i64;
+        assert_eq!(crt(&[(3, 2 * p), (p + 3, 3 * p)]), Some((4 * p + 3, 6 * p)));
+        assert_eq!(crt(&[(3, 2 * p), (4, 3 * p)]), None);
+        // lcm이 i64 끝에 가까운 경우
+        let q = 998_244_353i64;
+        assert_eq!(crt(&[(p - 1, p), (q - 1, q)]), Some((p * q - 1, p * q)));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows i64")]
+    fn lcm_overflow_panics() {
+        crt(&[(0, 1 << 40), (0, (1 << 40) - 1)]);
+    }
+}
@@ -0,0 +1,112 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::{gcd, mul_mod, pow_mod};
+use std::collections::HashMap;
+
+/// # 이산 로그 (baby-step giant-step)
+/// `a^x ≡ b (mod m)`인 가장 작은 `x >= 0`을 구합니다. 없으면 None입니다. `a^0 = 1`로 보므로 `b ≡ 1`이면 0입니다.
+///
+/// * a와 m이 서로소가 아니면 `g = gcd(a, m)`으로 식 전체를 나누는 일을 서로소가 될 때까지 되풀이합니다. 나눌 때마다
+/// 답이 1 늘고, b가 g로 나누어떨어지지 않으면 해가 없습니다. 나누기 전에 지금까지의 계수가 b와 같은지 봐서 작은
+/// 답을 놓치지 않습니다.
+/// * 서로소가 되면 `n = ⌈√m⌉`으로 `k · a^(i·n) ≡ b · a^j`를 HashMap으로 찾습니다. i를 작은 것부터, 같은 i에서는 j를
+/// 큰 것부터 보므로 처음 찾은 답이 가장 작습니다.
+/// * m은 1 이상이어야 하고, a, b는 m 이상이어도 됩니다. `m = 1`이면 0입니다.
+/// ## 시간복잡도
+/// 기댓값 O(√m + log² m)
+pub fn discrete_log(a: u64, b: u64, m: u64) -> Option<u64> {
+    assert!(m >= 1, "modulus must be positive");
+    let (mut a, mut b, mut m) = (a % m, b % m, m);
+    // k · a^x ≡ b (mod m)을 풀고 답에 added를 더함
+    let (mut k, mut added) = (1 % m, 0);
+    loop {
+        if k == b {
+            return Some(added);
+        }
+        let g = gcd(a, m);
+        if g == 1 {
+            break;
+        }
+        if !b.is_multiple_of(g) {
+            return None;
+        }
+        (b, m) = (b / g, m / g);
+        k = mul_mod(k, a / g, m);
+        a %= m;
+        added += 1;
+    }
+    let n = m.isqrt() + 1;
+    let mut baby = HashMap::with_capacity(n as usize);
+    let mut cur = b;
+    for j in 0..n {
+        // 같은 값이면 큰 j가 남음
+        baby.insert(cur, j);
+        cur = mul_mod(cur, a, m);
+    }
+    let giant = pow_mod(a, n, m);
+    let mut cur = k;
+    for i in 1..=n {
+        cur = mul_mod(cur, giant, m);
+        if let Some(&j) = baby.get(&cur) {
+            return Some(i * n - j + added);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 거듭제곱은 log m 이내에 주기에 들어가고 주기는 m 이하라서 `0..=m + 64`만 보면 됨
+    fn brute(a: u64, b: u64, m: u64) -> Option<u64> {
+        let mut cur = 1 % m;
+        for x in 0..=m + 64 {
+            if cur == b % m {
+                return Some(x);
+            }
+            cur = cur * (a % m) % m;
+        }
+        None
+    }
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(183);
+        for round in 0..3000 {
+            let m = 1 + rng.below(if round % 2 == 0 { 100 } else { 10_000 });
+            let a = rng.below(2 * m);
+            // 답이 있는 경우가 많도록 반은 a의 거듭제곱으로 b를 만듦
+            let b = if rng.below(2) == 0 {
+                pow_mod(a, rng.below(2 * m), m)
+            } else {
+                rng.below(m)
+            };
+            assert_eq!(discrete_log(a, b, m), brute(a, b, m), "{a} {b} {m}");
+        }
+    }
+
+    #[test]
+    fn textbook_cases() {
+        assert_eq!(discrete_log(2, 3, 5), Some(3));
+        assert_eq!(discrete_log(3, 13, 17), Some(4));
+        assert_eq!(discrete_log(5, 1, 7), Some(0));
+        assert_eq!(discrete_log(2, 0, 7), None);
+        // 서로소가 아닌 법
+        assert_eq!(discrete_log(2, 0, 8), Some(3));
+        assert_eq!(discrete_log(2, 4, 12), Some(2));
+        assert_eq!(discrete_log(2, 8, 12), Some(3));
+        assert_eq!(discrete_log(2, 3, 12), None);
+        assert_eq!(discrete_log(6, 0, 36), Some(2));
+        assert_eq!(discrete_log(0, 0, 10), Some(1));
+        assert_eq!(discrete_log(0, 1, 10), Some(0));
+        assert_eq!(discrete_log(0, 5, 10), None);
+        assert_eq!(discrete_log(7, 3, 1), Some(0));
+        // 큰 소수 법
+        let p = 1_000_000_007;
+        assert_eq!(
+            discrete_log(5, pow_mod(5, 123_456_789, p), p),
+            Some(123_456_789)
+        );
+    }
+}
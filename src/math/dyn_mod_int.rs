@@ -0,0 +1,324 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::ext_gcd;
+use crate::algebra::Semiring;
+use std::cell::Cell;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Barrett reduction: `im = ⌈2^64 / m⌉`로 `z / m`을 곱셈과 시프트로 어림합니다.
+#[derive(Clone, Copy, Debug)]
+struct Barrett {
+    m: u32,
+    im: u64,
+}
+
+impl Barrett {
+    fn new(m: u32) -> Self {
+        assert!(m >= 1, "modulus must be positive");
+        Barrett {
+            m,
+            im: (u64::MAX / m as u64).wrapping_add(1),
+        }
+    }
+
+    /// `a * b mod m` (`a, b < m`)
+    fn mul(self, a: u32, b: u32) -> u32 {
+        let z = a as u64 * b as u64;
+        let x = ((z as u128 * self.im as u128) >> 64) as u64;
+        let y = x.wrapping_mul(self.m as u64);
+        // 어림한 몫은 실제 몫보다 많아야 1 큼
+        (z.wrapping_sub(y)
+            .wrapping_add(if z < y { self.m as u64 } else { 0 })) as u32
+    }
+}
+
+thread_local! {
+    static CONTEXT: Cell<Barrett> = Cell::new(Barrett::new(998_244_353));
+}
+
+fn context() -> Barrett {
+    CONTEXT.with(Cell::get)
+}
+
+/// # DynModInt
+/// 법을 실행 중에 정하는 [`ModInt`](super::ModInt)입니다. 입력으로 법이 주어지고 소수가 아닐 수도 있는 문제에 씁니다.
+///
+/// * 법은 값마다 들고 다니지 않고 스레드마다 하나씩 둡니다. 처음에는 998244353이고,
+/// [`set_modulus`](Self::set_modulus)로 바꿉니다. 법을 바꾸면 그 전에 만든 값은 뜻이 없어지므로 다시 만들어야
+/// 합니다. 같은 스레드에서 두 법을 섞어 쓸 수 없습니다.
+/// * 곱셈은 Barrett reduction으로 하드웨어 나눗셈 없이 합니다. 법은 `1..2^32`입니다.
+/// * 나눗셈과 `inv`는 확장 유클리드로 역원을 구하고, 역원이 없으면 panic합니다.
+/// ## 시간복잡도
+/// 덧셈/뺄셈/곱셈 O(1), 나눗셈/`inv` O(log m), `pow` O(log e)
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DynModInt {
+    value: u32,
+}
+
+impl DynModInt {
+    /// 이 스레드의 법을 m으로 바꿈. 이미 만든 값은 새 법에서 쓰면 안 됩니다.
+    pub fn set_modulus(m: u32) {
+        CONTEXT.with(|c| c.set(Barrett::new(m)));
+    }
+
+    /// 이 스레드의 법
+    pub fn modulus() -> u32 {
+        context().m
+    }
+
+    /// x mod m
+    pub fn new(x: u64) -> Self {
+        DynModInt {
+            value: (x % context().m as u64) as u32,
+        }
+    }
+
+    /// `0..m`의 값
+    pub fn value(self) -> u32 {
+        self.value
+    }
+
+    /// `self^e`. `0^0 = 1`입니다(법이 1이면 0).
+    pub fn pow(self, mut e: u64) -> Self {
+        let barrett = context();
+        let (mut base, mut result) = (self.value, 1 % barrett.m);
+        while e > 0 {
+            if e & 1 == 1 {
+                result = barrett.mul(result, base);
+            }
+            base = barrett.mul(base, base);
+            e >>= 1;
+        }
+        DynModInt { value: result }
+    }
+
+    /// 곱셈 역원. 없으면 panic
+    pub fn inv(self) -> Self {
+        self.checked_inv()
+            .unwrap_or_else(|| panic!("{} has no inverse modulo {}", self.value, Self::modulus()))
+    }
+
+    /// 곱셈 역원. `gcd(self, m) != 1`이면 None
+    pub fn checked_inv(self) -> Option<Self> {
+        let m = Self::modulus() as i64;
+        let (g, x, _) = ext_gcd(self.value as i64, m);
+        (g == 1).then(|| Self::from(x))
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for DynModInt {
+                fn from(x: $t) -> Self {
+                    Self::new(x as u64)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for DynModInt {
+                fn from(x: $t) -> Self {
+                    Self::new((x as i64).rem_euclid(Self::modulus() as i64) as u64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+impl Add for DynModInt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let m = Self::modulus() as u64;
+        let sum = self.value as u64 + rhs.value as u64;
+        DynModInt {
+            value: if sum >= m { sum - m } else { sum } as u32,
+        }
+    }
+}
+
+impl Sub for DynModInt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let value = if self.value >= rhs.value {
+            self.value - rhs.value
+        } else {
+            self.value + (Self::modulus() - rhs.value)
+        };
+        DynModInt { value }
+    }
+}
+
+impl Mul for DynModInt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        DynModInt {
+            value: context().mul(self.value, rhs.value),
+        }
+    }
+}
+
+impl Div for DynModInt {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl Neg for DynModInt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::default() - self
+    }
+}
+
+macro_rules! impl_assign {
+    ($($trait:ident, $method:ident, $op:ident);*) => {
+        $(
+            impl $trait for DynModInt {
+                fn $method(&mut self, rhs: Self) {
+                    *self = self.$op(rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_assign!(
+    AddAssign, add_assign, add;
+    SubAssign, sub_assign, sub;
+    MulAssign, mul_assign, mul;
+    DivAssign, div_assign, div
+);
+
+impl Sum for DynModInt {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a DynModInt> for DynModInt {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for DynModInt {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a DynModInt> for DynModInt {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl Semiring for DynModInt {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn add(a: &Self, b: &Self) -> Self {
+        *a + *b
+    }
+
+    fn mul(a: &Self, b: &Self) -> Self {
+        *a * *b
+    }
+}
+
+impl fmt::Display for DynModInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl fmt::Debug for DynModInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::gcd;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_moduli_against_u128_arithmetic() {
+        let mut rng = XorShift::new(175);
+        for round in 0..300 {
+            let m = match round % 4 {
+                0 => 1 + rng.below(20) as u32,
+                1 => 2 * (1 + rng.below(1 << 30)) as u32,
+                2 => u32::MAX - rng.below(100) as u32,
+                _ => 1 + rng.below(u32::MAX as u64) as u32,
+            };
+            DynModInt::set_modulus(m);
+            assert_eq!(DynModInt::modulus(), m);
+            let m128 = m as u128;
+            for _ in 0..100 {
+                let (x, y) = (rng.next_u64(), rng.next_u64());
+                let (a, b) = (DynModInt::new(x), DynModInt::new(y));
+                let (x, y) = (x as u128 % m128, y as u128 % m128);
+                assert_eq!((a * b).value() as u128, x * y % m128);
+                assert_eq!((a + b).value() as u128, (x + y) % m128);
+                assert_eq!((a - b).value() as u128, (x + m128 - y) % m128);
+                let e = rng.below(1000);
+                let mut expected = 1 % m128;
+                for _ in 0..e % 20 {
+                    expected = expected * x % m128;
+                }
+                assert_eq!(a.pow(e % 20).value() as u128, expected);
+                match a.checked_inv() {
+                    Some(inv) => assert_eq!((a * inv).value() as u128, 1 % m128),
+                    None => assert_ne!(gcd(a.value() as u64, m as u64), 1),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn conversions_and_reset() {
+        DynModInt::set_modulus(10);
+        assert_eq!(DynModInt::from(-3i64).value(), 7);
+        assert_eq!((DynModInt::from(3u32) / DynModInt::from(7u32)).value(), 9);
+        let sum: DynModInt = (1..=4u32).map(DynModInt::from).sum();
+        let product: DynModInt = (1..=4u32).map(DynModInt::from).product();
+        assert_eq!((sum.value(), product.value()), (0, 4));
+        assert_eq!(DynModInt::from(4u32).checked_inv(), None);
+        // 법을 바꾸면 새로 만든 값부터 새 법을 따름
+        DynModInt::set_modulus(1);
+        assert_eq!(DynModInt::from(5u32).pow(0).value(), 0);
+        DynModInt::set_modulus(1_000_000_007);
+        assert_eq!(DynModInt::from(-1i32).to_string(), "1000000006");
+    }
+
+    #[test]
+    #[should_panic(expected = "has no inverse")]
+    fn dividing_by_non_invertible_panics() {
+        DynModInt::set_modulus(6);
+        let _ = DynModInt::from(1u32) / DynModInt::from(2u32);
+    }
+}
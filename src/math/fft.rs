@@ -0,0 +1,292 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::{ModInt, convolution_ntt, mod_inverse};
+use std::f64::consts::PI;
+use std::ops::{Add, Mul, Sub};
+
+/// 이 길이 이하면 O(nm) 곱셈이 더 빠름
+const NAIVE_THRESHOLD: usize = 32;
+
+/// `convolution_mod`이 쓰는 NTT 소수
+const P1: u32 = 754_974_721;
+const P2: u32 = 167_772_161;
+const P3: u32 = 469_762_049;
+
+#[derive(Clone, Copy, Debug)]
+struct Complex {
+    re: f64,
+    im: f64,
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Complex {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Complex {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Complex {
+            re: self.re * rhs.re - self.im * rhs.im,
+            im: self.re * rhs.im + self.im * rhs.re,
+        }
+    }
+}
+
+/// # FFT 합성곱 (f64)
+/// `c[k] = Σ a[i] · b[k - i]`인 길이 `n + m - 1`의 c를 복소수 FFT로 구합니다. 어느 한쪽이 비었으면 빈 배열입니다.
+///
+/// * 단위근은 단계마다 곱해서 만들지 않고 `cos`, `sin`으로 하나씩 구해서 오차가 쌓이지 않게 합니다.
+/// * 실수부에 a, 허수부에 b를 넣어 변환 한 번으로 두 변환을 얻습니다. 이때 오차가 큰 쪽의 제곱을 따르지 않도록, 먼저
+/// a에 2^-k, b에 2^k를 곱해 두 최댓값을 비슷하게 맞춥니다. 2의 거듭제곱이라 반올림이 없고 결과도 그대로입니다.
+/// * 오차는 대략 `max|a| · max|b| · max(n, m) · 10^-15`입니다. 정수 입력이면 `max|a| · max|b| · max(n, m)`이
+/// `10^14` 정도까지는 반올림해서 정확한 정수를 얻을 수 있고, 그보다 크면 [`convolution_mod`]나
+/// [`convolution`](super::convolution)을 쓰세요.
+/// ## 시간복잡도
+/// O((n + m) log(n + m))
+pub fn convolution_f64(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= NAIVE_THRESHOLD {
+        let mut c = vec![0.0; len];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[i + j] += x * y;
+            }
+        }
+        return c;
+    }
+    let size = len.next_power_of_two();
+    let k = (exponent(a) - exponent(b)) / 2;
+    let (scale_a, scale_b) = (2f64.powi(-k), 2f64.powi(k));
+    // 실수부에 a, 허수부에 b를 넣으면 변환 한 번으로 두 변환을 얻음: (x + iy)² = x² - y² + 2ixy
+    let mut f = vec![Complex { re: 0.0, im: 0.0 }; size];
+    for (i, &x) in a.iter().enumerate() {
+        f[i].re = x * scale_a;
+    }
+    for (i, &y) in b.iter().enumerate() {
+        f[i].im = y * scale_b;
+    }
+    let roots: Vec<Complex> = (0..size / 2)
+        .map(|k| {
+            let angle = 2.0 * PI * k as f64 / size as f64;
+            Complex {
+                re: angle.cos(),
+                im: angle.sin(),
+            }
+        })
+        .collect();
+    fft(&mut f, &roots, false);
+    for x in f.iter_mut() {
+        *x = *x * *x;
+    }
+    fft(&mut f, &roots, true);
+    // 허수부가 2 · (a * b)
+    f[..len].iter().map(|x| x.im / (2 * size) as f64).collect()
+}
+
+/// 절댓값이 가장 큰 원소의 이진 지수. 0이거나 유한하지 않으면 0으로 두어 크기를 맞추지 않음
+fn exponent(a: &[f64]) -> i32 {
+    let max = a.iter().fold(0.0f64, |m, &x| m.max(x.abs()));
+    if max.is_normal() {
+        ((max.to_bits() >> 52) & 0x7ff) as i32 - 1023
+    } else {
+        0
+    }
+}
+
+/// 길이가 2의 거듭제곱인 a의 변환. 역변환은 길이로 나누지 않음
+fn fft(a: &mut [Complex], roots: &[Complex], invert: bool) {
+    let n = a.len();
+    let shift = usize::BITS - n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let mut half = 1;
+    while half < n {
+        let step = n / (2 * half);
+        for block in a.chunks_exact_mut(2 * half) {
+            let (lo, hi) = block.split_at_mut(half);
+            for (k, (x, y)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+                let mut w = roots[k * step];
+                if invert {
+                    w.im = -w.im;
+                }
+                let (u, v) = (*x, *y * w);
+                *x = u + v;
+                *y = u - v;
+            }
+        }
+        half *= 2;
+    }
+}
+
+/// # 임의 법 합성곱
+/// NTT를 쓸 수 없는 법(예: 10^9 + 7)으로 [`convolution`](super::convolution)을 구합니다.
+///
+/// * 세 NTT 소수 754974721, 167772161, 469762049로 각각 합성곱을 구하고 Garner 알고리즘(CRT)으로 실제 정수값을
+/// 복원한 뒤 m으로 나눕니다. 세 소수의 곱이 약 `5.9 · 10^25`라서 `min(n, k) · (m - 1)^2`이 이보다 작으면
+/// 정확합니다. 그래서 `m <= 2^31`이어야 하고, 결과 길이는 `2^24` 이하여야 합니다.
+/// * 입력은 m 이상이어도 되고, 결과는 `0..m`입니다.
+/// ## 시간복잡도
+/// O((n + k) log(n + k)), NTT 9번
+pub fn convolution_mod(a: &[u64], b: &[u64], m: u64) -> Vec<u64> {
+    assert!(
+        (1..=1 << 31).contains(&m),
+        "modulus {m} must be in 1..=2^31"
+    );
+    let a: Vec<u64> = a.iter().map(|&x| x % m).collect();
+    let b: Vec<u64> = b.iter().map(|&x| x % m).collect();
+    let c1 = convolution_with::<P1>(&a, &b);
+    let c2 = convolution_with::<P2>(&a, &b);
+    let c3 = convolution_with::<P3>(&a, &b);
+    let (p1, p2, p3) = (P1 as u64, P2 as u64, P3 as u64);
+    let inv_p1 = mod_inverse(p1 as i64, p2 as i64).unwrap() as u64;
+    let inv_p1p2 = mod_inverse((p1 * p2 % p3) as i64, p3 as i64).unwrap() as u64;
+    c1.iter()
+        .zip(&c2)
+        .zip(&c3)
+        .map(|((&x1, &x2), &x3)| {
+            // x = t1 + t2 · p1 + t3 · p1 · p2
+            let t1 = x1;
+            let t2 = (x2 + p2 - t1 % p2) % p2 * inv_p1 % p2;
+            let partial = (t1 + t2 * p1) % p3;
+            let t3 = (x3 + p3 - partial) % p3 * inv_p1p2 % p3;
+            let x = t1 as u128 + t2 as u128 * p1 as u128 + t3 as u128 * (p1 * p2) as u128;
+            (x % m as u128) as u64
+        })
+        .collect()
+}
+
+fn convolution_with<const P: u32>(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let a: Vec<ModInt<P>> = a.iter().map(|&x| ModInt::new(x)).collect();
+    let b: Vec<ModInt<P>> = b.iter().map(|&x| ModInt::new(x)).collect();
+    convolution_ntt(&a, &b)
+        .into_iter()
+        .map(|x| x.value() as u64)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn wildcard_matching_against_brute_force() {
+        let mut rng = XorShift::new(188);
+        for _ in 0..100 {
+            // 0은 와일드카드, 나머지는 문자
+            let text: Vec<f64> = (0..rng.index(300)).map(|_| rng.below(4) as f64).collect();
+            let pattern: Vec<f64> = (0..1 + rng.index(60))
+                .map(|_| rng.below(4) as f64)
+                .collect();
+            if pattern.len() > text.len() {
+                continue;
+            }
+            // Σ p·t·(p - t)² = Σ p³t - 2p²t² + pt³
+            let reversed: Vec<f64> = pattern.iter().rev().copied().collect();
+            let pow = |v: &[f64], e: i32| v.iter().map(|x| x.powi(e)).collect::<Vec<f64>>();
+            let x = convolution_f64(&pow(&reversed, 3), &text);
+            let y = convolution_f64(&pow(&reversed, 2), &pow(&text, 2));
+            let z = convolution_f64(&reversed, &pow(&text, 3));
+            let m = pattern.len();
+            for i in 0..=text.len() - m {
+                let k = i + m - 1;
+                let score = x[k] - 2.0 * y[k] + z[k];
+                let expected = pattern
+                    .iter()
+                    .zip(&text[i..])
+                    .all(|(&p, &t)| p == 0.0 || t == 0.0 || p == t);
+                assert_eq!(score.abs() < 0.5, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn arbitrary_modulus_against_i128() {
+        let mut rng = XorShift::new(1880);
+        for round in 0..100 {
+            let m = [1_000_000_007, 1 << 31, 998_244_353, 2, 1][round % 5];
+            let limit = if round < 90 { 100 } else { 2000 };
+            let a: Vec<u64> = (0..rng.index(limit)).map(|_| rng.next_u64()).collect();
+            let b: Vec<u64> = (0..rng.index(limit))
+                .map(|_| m - 1 - rng.below(3).min(m - 1))
+                .collect();
+            let expected: Vec<u64> = if a.is_empty() || b.is_empty() {
+                Vec::new()
+            } else {
+                let mut c = vec![0i128; a.len() + b.len() - 1];
+                for (i, &x) in a.iter().enumerate() {
+                    for (j, &y) in b.iter().enumerate() {
+                        c[i + j] = (c[i + j] + (x % m) as i128 * (y % m) as i128) % m as i128;
+                    }
+                }
+                c.into_iter().map(|x| x as u64).collect()
+            };
+            assert_eq!(convolution_mod(&a, &b, m), expected);
+        }
+    }
+
+    #[test]
+    fn magnitude_near_precision_limit() {
+        let mut rng = XorShift::new(1881);
+        // max|a| · max|b| · n ≈ 3 · 10^4 · 3 · 10^4 · 10^5 = 9 · 10^13
+        let n = 100_000;
+        let a: Vec<i64> = (0..n).map(|_| 30_000 - rng.range_i64(0, 10)).collect();
+        let b: Vec<i64> = (0..n).map(|_| -30_000 + rng.range_i64(0, 10)).collect();
+        let to_f64 = |v: &[i64]| v.iter().map(|&x| x as f64).collect::<Vec<f64>>();
+        let c = convolution_f64(&to_f64(&a), &to_f64(&b));
+        for _ in 0..20 {
+            let k = rng.index(2 * n - 1);
+            let exact: i64 = (k.saturating_sub(n - 1)..=k.min(n - 1))
+                .map(|i| a[i] * b[k - i])
+                .sum();
+            assert_eq!(c[k].round() as i64, exact);
+        }
+        // 가운데 계수는 가장 큼
+        let exact: i64 = (0..n).map(|i| a[i] * b[n - 1 - i]).sum();
+        assert_eq!(c[n - 1].round() as i64, exact);
+        assert!(convolution_f64(&[], &[1.0]).is_empty());
+    }
+
+    #[test]
+    fn balancing_unequal_magnitudes() {
+        let mut rng = XorShift::new(1882);
+        // max|a| · max|b| · n ≈ 2^30 · 3 · 2^12은 한계 안. 크기를 맞추지 않으면 max|a|² · n ≈ 2^72라서 틀림
+        let n: usize = 1 << 12;
+        let a: Vec<i64> = (0..n).map(|_| rng.range_i64(0, 1 << 30)).collect();
+        let b: Vec<i64> = (0..n).map(|_| rng.range_i64(0, 3)).collect();
+        let fa: Vec<f64> = a.iter().map(|&x| x as f64).collect();
+        let fb: Vec<f64> = b.iter().map(|&x| x as f64).collect();
+        let c = convolution_f64(&fa, &fb);
+        for k in (0..2 * n - 1).step_by(97).chain([n - 1]) {
+            let exact: i64 = (k.saturating_sub(n - 1)..=k.min(n - 1))
+                .map(|i| a[i] * b[k - i])
+                .sum();
+            assert_eq!(c[k].round() as i64, exact);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # floor sum
+/// `Σ_{i=0}^{n-1} ⌊(a·i + b) / m⌋`를 구합니다. 직선 아래의 격자점 개수, `Σ (a·i + b) mod m` 같은 값을 이 식으로 바꿔
+/// 풉니다.
+///
+/// * a, b가 음수여도 됩니다. 먼저 `a = q_a·m + r_a`(`0 <= r_a < m`, 내림 나눗셈)로 나눠서 `q_a·n(n-1)/2`를 따로 더하고
+/// (b도 같음), 남은 `0 <= a, b < m`인 경우는 유클리드 호제법처럼 a와 m을 바꿔 가며 줄입니다.
+/// * `n < 2^32`, `1 <= m < 2^32`이어야 합니다. 이 범위에서 중간값은 i128로 넘치지 않고, 결과가 i64를 넘으면
+/// panic합니다.
+/// ## 시간복잡도
+/// O(log m)
+pub fn floor_sum(n: u64, m: u64, a: i64, b: i64) -> i64 {
+    assert!(n < 1 << 32, "n = {n} must be less than 2^32");
+    assert!((1..1 << 32).contains(&m), "m = {m} must be in 1..2^32");
+    let (n, m) = (n as i128, m as i128);
+    let (a, b) = (a as i128, b as i128);
+    let (qa, ra) = (a.div_euclid(m), a.rem_euclid(m));
+    let (qb, rb) = (b.div_euclid(m), b.rem_euclid(m));
+    let total = qa * (n * (n - 1) / 2)
+        + qb * n
+        + unsigned(n as u128, m as u128, ra as u128, rb as u128) as i128;
+    i64::try_from(total).expect("floor_sum overflows i64")
+}
+
+/// `0 <= a, b`인 경우
+fn unsigned(mut n: u128, mut m: u128, mut a: u128, mut b: u128) -> u128 {
+    let mut total = 0;
+    loop {
+        if a >= m {
+            total += n * n.saturating_sub(1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            total += n * (b / m);
+            b %= m;
+        }
+        // 직선 y = (a·x + b) / m 아래의 점을 x와 y를 바꿔서 셈
+        let y_max = a * n + b;
+        if y_max < m {
+            return total;
+        }
+        (n, b) = (y_max / m, y_max % m);
+        (m, a) = (a, m);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn brute(n: u64, m: u64, a: i64, b: i64) -> i128 {
+        (0..n as i128)
+            .map(|i| (a as i128 * i + b as i128).div_euclid(m as i128))
+            .sum()
+    }
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(193);
+        for round in 0..20_000 {
+            let n = rng.below(if round % 2 == 0 { 20 } else { 300 });
+            let m = 1 + rng.below([5, 1000, (1 << 32) - 1][round % 3]);
+            let limit = [10, 1_000_000, 1 << 40][round % 3];
+            let (a, b) = (rng.range_i64(-limit, limit), rng.range_i64(-limit, limit));
+            assert_eq!(
+                floor_sum(n, m, a, b) as i128,
+                brute(n, m, a, b),
+                "{n} {m} {a} {b}"
+            );
+        }
+    }
+
+    #[test]
+    fn edges() {
+        assert_eq!(floor_sum(0, 7, 3, 5), 0);
+        assert_eq!(floor_sum(0, 1, i64::MIN, i64::MIN), 0);
+        assert_eq!(floor_sum(5, 1, 2, 3), 2 * 10 + 3 * 5);
+        assert_eq!(floor_sum(4, 10, 6, 3), 3);
+        assert_eq!(floor_sum(3, 2, -1, 0), 0 - 1 - 1);
+        // 큰 a, b
+        for (n, m, a, b) in [
+            (3, 1, i64::MAX / 4, i64::MIN / 4),
+            (2, (1 << 32) - 1, i64::MIN, i64::MAX),
+            (1000, 3, -(1 << 40), 1 << 52),
+        ] {
+            assert_eq!(floor_sum(n, m, a, b) as i128, brute(n, m, a, b));
+        }
+        // (2^32 - 1)개의 ⌊(i · (2^32 - 1) + 0) / (2^32 - 1)⌋ = Σ i
+        let n = (1u64 << 32) - 1;
+        assert_eq!(
+            floor_sum(n, n, n as i64, 0) as i128,
+            n as i128 * (n as i128 - 1) / 2
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows i64")]
+    fn overflow_panics() {
+        floor_sum((1 << 32) - 1, 1, i64::MAX, 0);
+    }
+}
@@ -0,0 +1,452 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::ModInt;
+use crate::algebra::Semiring;
+use std::ops::{Div, Mul, Sub};
+
+/// # 연립일차방정식의 해
+/// `A·x = b`를 푼 결과입니다. 해가 있으면 자유 변수를 모두 0으로 둔 해를 하나 담습니다.
+///
+/// * 변수의 개수는 A의 첫 행의 길이라서, 식이 하나도 없으면 변수도 없는 것으로 봅니다.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinearSolution<T> {
+    /// 해가 없음
+    Inconsistent { rank: usize },
+    /// 해가 하나뿐임 (`rank == 변수의 개수`)
+    Unique(Vec<T>),
+    /// 해가 무수히 많음. 해 공간의 차원은 `변수의 개수 - rank`입니다.
+    Infinite { rank: usize, particular: Vec<T> },
+}
+
+impl<T> LinearSolution<T> {
+    /// 계수 행렬 A의 rank
+    pub fn rank(&self) -> usize {
+        match self {
+            LinearSolution::Inconsistent { rank } | LinearSolution::Infinite { rank, .. } => *rank,
+            LinearSolution::Unique(x) => x.len(),
+        }
+    }
+
+    /// 해가 있으면 그중 하나
+    pub fn solution(&self) -> Option<&[T]> {
+        match self {
+            LinearSolution::Inconsistent { .. } => None,
+            LinearSolution::Unique(x) | LinearSolution::Infinite { particular: x, .. } => Some(x),
+        }
+    }
+}
+
+/// # 가우스 소거법 (f64)
+/// `A·x = b`를 부분 피벗(열에서 절댓값이 가장 큰 원소)으로 풉니다. A는 `n × m`이고 b의 길이는 n입니다.
+///
+/// * 절댓값이 eps 이하인 값은 0으로 봅니다. 그래서 거의 특이한 행렬은 rank가 작게 나오고, 남은 식의 우변이 eps보다
+/// 크면 해가 없다고 판단합니다. 계수의 크기가 1 근처이면 eps는 `1e-9` 정도가 알맞습니다.
+/// ## 시간복잡도
+/// O(n · m · min(n, m))
+pub fn solve_linear_f64(a: Vec<Vec<f64>>, b: Vec<f64>, eps: f64) -> LinearSolution<f64> {
+    solve(a, b, |x| x.abs() <= eps, f64::abs)
+}
+
+/// # 가우스 소거법 (mod p)
+/// [`solve_linear_f64`]와 같지만 원소가 [`ModInt`]입니다. 법 M은 소수여야 합니다(0이 아닌 원소의 역원이 있어야 함).
+/// ## 시간복잡도
+/// O(n · m · min(n, m) + rank · log M)
+pub fn solve_linear_mod_p<const M: u32>(
+    a: Vec<Vec<ModInt<M>>>,
+    b: Vec<ModInt<M>>,
+) -> LinearSolution<ModInt<M>> {
+    solve(a, b, |x| x.value() == 0, |x| (x.value() != 0) as u8 as f64)
+}
+
+/// n × n 행렬의 행렬식 (M은 소수). 빈 행렬의 행렬식은 1입니다.
+pub fn determinant_mod_p<const M: u32>(mut a: Vec<Vec<ModInt<M>>>) -> ModInt<M> {
+    let n = a.len();
+    assert!(
+        a.iter().all(|row| row.len() == n),
+        "determinant of a non-square matrix"
+    );
+    let mut det = ModInt::new(1);
+    for col in 0..n {
+        let Some(pivot) = (col..n).find(|&r| a[r][col].value() != 0) else {
+            return ModInt::new(0);
+        };
+        if pivot != col {
+            a.swap(pivot, col);
+            det = -det;
+        }
+        det *= a[col][col];
+        let inv = a[col][col].inv();
+        let (top, bottom) = a.split_at_mut(col + 1);
+        let pivot_row = &top[col];
+        for row in bottom {
+            let factor = row[col] * inv;
+            if factor.value() == 0 {
+                continue;
+            }
+            for (v, &p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *v -= factor * p;
+            }
+        }
+    }
+    det
+}
+
+/// `n × m` 행렬의 rank (M은 소수)
+pub fn rank_mod_p<const M: u32>(a: Vec<Vec<ModInt<M>>>) -> usize {
+    let n = a.len();
+    solve(
+        a,
+        vec![ModInt::new(0); n],
+        |x| x.value() == 0,
+        |x| (x.value() != 0) as u8 as f64,
+    )
+    .rank()
+}
+
+/// # 가우스 소거법 (GF(2))
+/// 덧셈이 XOR인 `A·x = b`를 풉니다. 행을 u64 비트셋으로 바꿔서 행 연산을 64개씩 합니다.
+/// ## 시간복잡도
+/// O(n · m · min(n, m) / 64)
+pub fn solve_xor(a: Vec<Vec<bool>>, b: Vec<bool>) -> LinearSolution<bool> {
+    let (n, m) = check_shape(&a, b.len());
+    let words = (m + 1).div_ceil(64);
+    let mut rows: Vec<Vec<u64>> = a
+        .iter()
+        .zip(&b)
+        .map(|(row, &rhs)| {
+            let mut bits = vec![0u64; words];
+            for (j, _) in row.iter().enumerate().filter(|&(_, &x)| x) {
+                bits[j / 64] |= 1 << (j % 64);
+            }
+            if rhs {
+                bits[m / 64] |= 1 << (m % 64);
+            }
+            bits
+        })
+        .collect();
+    let bit = |row: &[u64], j: usize| (row[j / 64] >> (j % 64)) & 1 == 1;
+    let mut pivots = Vec::new();
+    for col in 0..m {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..n).find(|&r| bit(&rows[r], col)) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let (above, rest) = rows.split_at_mut(rank);
+        let (pivot_row, below) = rest.split_first_mut().unwrap();
+        for row in above.iter_mut().chain(below) {
+            if bit(row, col) {
+                for (w, p) in row.iter_mut().zip(pivot_row.iter()) {
+                    *w ^= p;
+                }
+            }
+        }
+        pivots.push(col);
+    }
+    let rank = pivots.len();
+    if rows[rank..].iter().any(|row| bit(row, m)) {
+        return LinearSolution::Inconsistent { rank };
+    }
+    let mut x = vec![false; m];
+    for (row, &col) in rows.iter().zip(&pivots) {
+        x[col] = bit(row, m);
+    }
+    finish(x, rank, m)
+}
+
+fn check_shape<T>(a: &[Vec<T>], b_len: usize) -> (usize, usize) {
+    let (n, m) = (a.len(), a.first().map_or(0, Vec::len));
+    assert!(
+        a.iter().all(|row| row.len() == m),
+        "rows of the coefficient matrix have different lengths"
+    );
+    assert!(
+        b_len == n,
+        "right-hand side has length {b_len} but the matrix has {n} rows"
+    );
+    (n, m)
+}
+
+fn finish<T>(x: Vec<T>, rank: usize, m: usize) -> LinearSolution<T> {
+    if rank == m {
+        LinearSolution::Unique(x)
+    } else {
+        LinearSolution::Infinite {
+            rank,
+            particular: x,
+        }
+    }
+}
+
+/// 첨가 행렬을 기약 행 사다리꼴로 만들어 풂. 피벗은 score가 가장 큰 원소
+fn solve<T>(
+    a: Vec<Vec<T>>,
+    b: Vec<T>,
+    is_zero: impl Fn(T) -> bool,
+    score: impl Fn(T) -> f64,
+) -> LinearSolution<T>
+where
+    T: Semiring + Copy + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    let (n, m) = check_shape(&a, b.len());
+    let mut rows: Vec<Vec<T>> = a
+        .into_iter()
+        .zip(b)
+        .map(|(mut row, rhs)| {
+            row.push(rhs);
+            row
+        })
+        .collect();
+    let mut pivots = Vec::new();
+    for col in 0..m {
+        let rank = pivots.len();
+        let Some(pivot) =
+            (rank..n).max_by(|&r, &s| score(rows[r][col]).total_cmp(&score(rows[s][col])))
+        else {
+            break;
+        };
+        if is_zero(rows[pivot][col]) {
+            continue;
+        }
+        rows.swap(rank, pivot);
+        let inv = T::one() / rows[rank][col];
+        for v in rows[rank].iter_mut() {
+            *v = *v * inv;
+        }
+        let (above, rest) = rows.split_at_mut(rank);
+        let (pivot_row, below) = rest.split_first_mut().unwrap();
+        for row in above.iter_mut().chain(below) {
+            let factor = row[col];
+            if is_zero(factor) {
+                continue;
+            }
+            for (v, &p) in row.iter_mut().zip(pivot_row.iter()) {
+                *v = *v - factor * p;
+            }
+        }
+        pivots.push(col);
+    }
+    let rank = pivots.len();
+    if rows[rank..].iter().any(|row| !is_zero(row[m])) {
+        return LinearSolution::Inconsistent { rank };
+    }
+    let mut x = vec![T::zero(); m];
+    for (row, &col) in rows.iter().zip(&pivots) {
+        x[col] = row[m];
+    }
+    finish(x, rank, m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    type Mint = ModInt<13>;
+
+    /// 무작위 n × m 행렬. rank를 낮추려고 앞 행들의 조합인 행을 섞음
+    fn random_mod_matrix(rng: &mut XorShift, n: usize, m: usize) -> Vec<Vec<Mint>> {
+        let mut a: Vec<Vec<Mint>> = Vec::new();
+        for i in 0..n {
+            let row = if i >= 2 && rng.below(3) == 0 {
+                let (c, d) = (Mint::new(rng.below(13)), Mint::new(rng.below(13)));
+                (0..m).map(|j| c * a[0][j] + d * a[i - 1][j]).collect()
+            } else {
+                (0..m).map(|_| Mint::new(rng.below(13))).collect()
+            };
+            a.push(row);
+        }
+        a
+    }
+
+    fn mul_vec<T: Semiring>(a: &[Vec<T>], x: &[T]) -> Vec<T> {
+        a.iter()
+            .map(|row| {
+                row.iter()
+                    .zip(x)
+                    .fold(T::zero(), |acc, (p, q)| T::add(&acc, &T::mul(p, q)))
+            })
+            .collect()
+    }
+
+    /// 모든 x를 넣어 보고 해의 개수를 셈
+    fn brute_mod(a: &[Vec<Mint>], b: &[Mint], m: usize) -> usize {
+        let mut count = 0;
+        let mut x = vec![Mint::new(0); m];
+        for code in 0..13usize.pow(m as u32) {
+            let mut c = code;
+            for v in x.iter_mut() {
+                *v = Mint::from(c % 13);
+                c /= 13;
+            }
+            if mul_vec(a, &x) == b {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn mod_p_against_brute_force() {
+        let mut rng = XorShift::new(186);
+        for _ in 0..300 {
+            let (n, m) = (1 + rng.index(4), 1 + rng.index(3));
+            let a = random_mod_matrix(&mut rng, n, m);
+            let b: Vec<Mint> = if rng.below(2) == 0 {
+                let x: Vec<Mint> = (0..m).map(|_| Mint::new(rng.below(13))).collect();
+                mul_vec(&a, &x)
+            } else {
+                (0..n).map(|_| Mint::new(rng.below(13))).collect()
+            };
+            let count = brute_mod(&a, &b, m);
+            let result = solve_linear_mod_p(a.clone(), b.clone());
+            let rank = result.rank();
+            assert_eq!(rank_mod_p(a.clone()), rank);
+            match &result {
+                LinearSolution::Inconsistent { .. } => assert_eq!(count, 0),
+                LinearSolution::Unique(x) => {
+                    assert_eq!(count, 1);
+                    assert_eq!(mul_vec(&a, x), b);
+                }
+                LinearSolution::Infinite { particular, .. } => {
+                    assert_eq!(count, 13usize.pow((m - rank) as u32));
+                    assert_eq!(mul_vec(&a, particular), b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn determinant_against_permutation_expansion() {
+        fn brute_det(a: &[Vec<Mint>]) -> Mint {
+            let n = a.len();
+            if n == 0 {
+                return Mint::new(1);
+            }
+            // 첫 행으로 여인수 전개
+            (0..n).fold(Mint::new(0), |acc, j| {
+                let minor: Vec<Vec<Mint>> = a[1..]
+                    .iter()
+                    .map(|row| (0..n).filter(|&c| c != j).map(|c| row[c]).collect())
+                    .collect();
+                let term = a[0][j] * brute_det(&minor);
+                if j % 2 == 0 { acc + term } else { acc - term }
+            })
+        }
+        let mut rng = XorShift::new(1860);
+        for _ in 0..300 {
+            let n = rng.index(6);
+            let a = random_mod_matrix(&mut rng, n, n);
+            assert_eq!(determinant_mod_p(a.clone()), brute_det(&a));
+        }
+        let big: Vec<Vec<ModInt<998_244_353>>> = (0..3)
+            .map(|i| {
+                (0..3)
+                    .map(|j| ModInt::from(i * 3 + j + (i == j) as u32))
+                    .collect()
+            })
+            .collect();
+        // [[1, 1, 2], [3, 5, 5], [6, 7, 9]]
+        assert_eq!(determinant_mod_p(big), ModInt::from(-5i32));
+    }
+
+    #[test]
+    fn f64_constructed_solutions() {
+        let mut rng = XorShift::new(1861);
+        for _ in 0..300 {
+            let n = 1 + rng.index(8);
+            let a: Vec<Vec<f64>> = (0..n)
+                .map(|_| {
+                    (0..n)
+                        .map(|_| rng.range_i64(-100, 100) as f64 / 10.0)
+                        .collect()
+                })
+                .collect();
+            let x: Vec<f64> = (0..n).map(|_| rng.range_i64(-50, 50) as f64).collect();
+            let b = mul_vec(&a, &x);
+            match solve_linear_f64(a.clone(), b.clone(), 1e-9) {
+                LinearSolution::Unique(got) => {
+                    for (g, e) in got.iter().zip(&x) {
+                        assert!((g - e).abs() < 1e-6, "{got:?} {x:?}");
+                    }
+                }
+                // 무작위 행렬이 특이할 수도 있지만, 해가 있으니 모순은 아님
+                other => {
+                    let got = other.solution().unwrap();
+                    for (g, e) in mul_vec(&a, got).iter().zip(&b) {
+                        assert!((g - e).abs() < 1e-6);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn f64_singular_and_inconsistent() {
+        // 두 번째 식이 첫 번째 식의 2배
+        let a = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+        assert_eq!(
+            solve_linear_f64(a.clone(), vec![3.0, 7.0], 1e-9),
+            LinearSolution::Inconsistent { rank: 1 }
+        );
+        let result = solve_linear_f64(a, vec![3.0, 6.0], 1e-9);
+        assert_eq!(result.rank(), 1);
+        let x = result.solution().unwrap();
+        assert!((x[0] + 2.0 * x[1] - 3.0).abs() < 1e-9);
+        // 거의 특이한 행렬은 eps에 따라 판단이 달라짐
+        let near = vec![vec![1.0, 1.0], vec![1.0, 1.0 + 1e-12]];
+        assert_eq!(
+            solve_linear_f64(near.clone(), vec![1.0, 1.0], 1e-9).rank(),
+            1
+        );
+        assert_eq!(solve_linear_f64(near, vec![1.0, 1.0], 1e-15).rank(), 2);
+        // 식보다 변수가 많음
+        let wide = solve_linear_f64(vec![vec![1.0, 1.0, 1.0]], vec![6.0], 1e-9);
+        assert!(matches!(wide, LinearSolution::Infinite { rank: 1, .. }));
+        assert_eq!(
+            solve_linear_f64(vec![], vec![], 1e-9),
+            LinearSolution::Unique(vec![])
+        );
+    }
+
+    #[test]
+    fn xor_against_brute_force() {
+        let mut rng = XorShift::new(1862);
+        for round in 0..500 {
+            let (n, m) = if round < 400 {
+                (rng.index(8), 1 + rng.index(10))
+            } else {
+                (1 + rng.index(100), 60 + rng.index(80))
+            };
+            let a: Vec<Vec<bool>> = (0..n)
+                .map(|_| (0..m).map(|_| rng.below(3) == 0).collect())
+                .collect();
+            let b: Vec<bool> = (0..n).map(|_| rng.below(2) == 0).collect();
+            let satisfies = |x: &[bool]| {
+                a.iter().zip(&b).all(|(row, &rhs)| {
+                    row.iter().zip(x).filter(|&(&p, &q)| p && q).count() % 2 == rhs as usize
+                })
+            };
+            let result = solve_xor(a.clone(), b.clone());
+            if let Some(x) = result.solution() {
+                assert!(satisfies(x));
+            }
+            if m <= 10 {
+                let count = (0..1u32 << m)
+                    .filter(|mask| {
+                        satisfies(&(0..m).map(|j| (mask >> j) & 1 == 1).collect::<Vec<_>>())
+                    })
+                    .count();
+                match result {
+                    LinearSolution::Inconsistent { .. } => assert_eq!(count, 0),
+                    other => assert_eq!(count, 1 << (m - other.rank())),
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "right-hand side has length 1 but the matrix has 2 rows")]
+    fn shape_mismatch_panics() {
+        solve_xor(vec![vec![true], vec![false]], vec![true]);
+    }
+}
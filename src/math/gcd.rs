@@ -0,0 +1,144 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # 최대공약수 (binary GCD)
+/// 나눗셈 대신 시프트와 뺄셈만 씁니다. `gcd(0, 0) = 0`입니다.
+/// ## 시간복잡도
+/// O(log max(a, b))
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return a | b;
+    }
+    let shift = (a | b).trailing_zeros();
+    let (mut a, mut b) = (a >> a.trailing_zeros(), b);
+    // a는 항상 홀수
+    while b != 0 {
+        b >>= b.trailing_zeros();
+        if a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+    }
+    a << shift
+}
+
+/// 최소공배수. u64를 넘으면 None이고, 어느 한쪽이 0이면 0입니다.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// # 확장 유클리드 호제법
+/// `a·x + b·y = g`인 `(g, x, y)`를 반환합니다. `g = gcd(|a|, |b|) >= 0`입니다.
+///
+/// * 반복문으로 구한 해라서 `|x| <= |b| / g`, `|y| <= |a| / g`로 절댓값이 가장 작은 쪽의 해입니다(a나 b가 0인
+/// 경우 제외). `a = b = 0`이면 `(0, 1, 0)`입니다.
+/// * 음수 입력도 됩니다. 다만 `i64::MIN`은 절댓값이 i64를 넘어서 받지 않습니다.
+/// ## 시간복잡도
+/// O(log min(|a|, |b|))
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    assert!(
+        a != i64::MIN && b != i64::MIN,
+        "ext_gcd does not accept i64::MIN"
+    );
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `a·x ≡ 1 (mod m)`인 `x`를 `0..m`에서. a와 m이 서로소가 아니면 None (`m >= 1`)
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    assert!(m >= 1, "modulus must be positive, got {m}");
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn euclid(a: u64, b: u64) -> u64 {
+        if b == 0 { a } else { euclid(b, a % b) }
+    }
+
+    #[test]
+    fn gcd_and_lcm_against_euclid() {
+        let mut rng = XorShift::new(173);
+        for round in 0..10_000 {
+            let (a, b) = match round % 3 {
+                0 => (rng.below(100), rng.below(100)),
+                1 => (rng.next_u64(), rng.next_u64()),
+                // 2의 거듭제곱을 많이 공유하는 값
+                _ => (
+                    rng.below(1 << 20) << rng.below(40),
+                    rng.below(1 << 20) << rng.below(40),
+                ),
+            };
+            let g = euclid(a, b);
+            assert_eq!(gcd(a, b), g);
+            let expected = if g == 0 {
+                Some(0)
+            } else {
+                u64::try_from(a as u128 / g as u128 * b as u128).ok()
+            };
+            assert_eq!(lcm(a, b), expected);
+        }
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm(1 << 32, 1 << 32), Some(1 << 32));
+        assert_eq!(lcm(1 << 32, 3 << 32), Some(3 << 32));
+    }
+
+    #[test]
+    fn bezout_identity_for_random_pairs() {
+        let mut rng = XorShift::new(1730);
+        for round in 0..10_000 {
+            let limit = if round % 2 == 0 { 50 } else { 1 << 62 };
+            let (a, b) = (rng.range_i64(-limit, limit), rng.range_i64(-limit, limit));
+            let (g, x, y) = ext_gcd(a, b);
+            assert_eq!(g as u64, gcd(a.unsigned_abs(), b.unsigned_abs()));
+            assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+            if a != 0 && b != 0 {
+                assert!(x.unsigned_abs() <= (b / g).unsigned_abs());
+                assert!(y.unsigned_abs() <= (a / g).unsigned_abs());
+            }
+        }
+        assert_eq!(ext_gcd(0, 0), (0, 1, 0));
+        assert_eq!(ext_gcd(0, -5), (5, 0, -1));
+        assert_eq!(ext_gcd(-4, 6), (2, 1, 1));
+    }
+
+    #[test]
+    fn inverses_across_moduli() {
+        let mut rng = XorShift::new(1731);
+        for m in 1..300 {
+            for _ in 0..20 {
+                let a = rng.range_i64(-1000, 1000);
+                match mod_inverse(a, m) {
+                    Some(x) => {
+                        assert!((0..m).contains(&x));
+                        assert_eq!((a * x).rem_euclid(m), 1 % m);
+                    }
+                    None => assert_ne!(gcd(a.unsigned_abs(), m as u64), 1),
+                }
+            }
+        }
+        assert_eq!(mod_inverse(3, 1), Some(0));
+        assert_eq!(mod_inverse(6, 9), None);
+        let p = 1_000_000_007;
+        let x = mod_inverse(-2, p).unwrap();
+        assert_eq!((-2 * x).rem_euclid(p), 1);
+    }
+}
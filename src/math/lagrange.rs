@@ -0,0 +1,155 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::ModInt;
+
+/// # 라그랑주 보간 (표본점 0..k)
+/// `ys[i] = f(i)`(`0 <= i < k`)인 k - 1차 이하 다항식 f의 `f(x)`를 구합니다. 거듭제곱의 합처럼 답이 n에 대한
+/// 다항식인 문제에서 작은 n의 값만 구해 두고 `n = 10^18`의 값을 구할 때 씁니다.
+///
+/// * 표본점이 연속이라서 분모가 `i! · (k - 1 - i)! · (-1)^(k-1-i)`이고, 분자는 `x - j`의 앞부분 곱과 뒷부분 곱으로
+/// 구합니다.
+/// * 다항식의 값은 x mod M에만 달려 있으므로, `x mod M`이 표본점이면 그 표본을 그대로 반환합니다.
+/// * 법 M은 k보다 큰 소수여야 합니다. ys가 비었으면 0입니다.
+/// ## 시간복잡도
+/// O(k + log M)
+pub fn lagrange_interpolate<const M: u32>(ys: &[ModInt<M>], x: u64) -> ModInt<M> {
+    let k = ys.len();
+    assert!(
+        (k as u64) < M as u64,
+        "{k} samples need a modulus larger than {M}"
+    );
+    if k == 0 {
+        return ModInt::new(0);
+    }
+    let r = x % M as u64;
+    if r < k as u64 {
+        return ys[r as usize];
+    }
+    let x = ModInt::<M>::new(r);
+    // prefix[i] = Π_{j<i} (x - j), suffix[i] = Π_{j>=i} (x - j)
+    let mut prefix = vec![ModInt::new(1); k + 1];
+    for j in 0..k {
+        prefix[j + 1] = prefix[j] * (x - ModInt::from(j));
+    }
+    let mut suffix = vec![ModInt::new(1); k + 1];
+    for j in (0..k).rev() {
+        suffix[j] = suffix[j + 1] * (x - ModInt::from(j));
+    }
+    let mut fact = vec![ModInt::<M>::new(1); k];
+    for i in 1..k {
+        fact[i] = fact[i - 1] * ModInt::from(i);
+    }
+    let inv_last = fact[k - 1].inv();
+    let mut inv_fact = vec![inv_last; k];
+    for i in (1..k).rev() {
+        inv_fact[i - 1] = inv_fact[i] * ModInt::from(i);
+    }
+    ys.iter()
+        .enumerate()
+        .map(|(i, &y)| {
+            let term = y * prefix[i] * suffix[i + 1] * inv_fact[i] * inv_fact[k - 1 - i];
+            if (k - 1 - i) % 2 == 1 { -term } else { term }
+        })
+        .sum()
+}
+
+/// # 라그랑주 보간 (임의의 표본점)
+/// `(x_i, y_i)`를 지나는 k - 1차 이하 다항식 f의 `f(x)`를 구합니다. `x_i`는 서로 달라야 하고, x가 표본점이면 그 표본을
+/// 그대로 반환합니다. 법 M은 소수여야 합니다.
+/// ## 시간복잡도
+/// O(k² + k log M)
+pub fn lagrange_interpolate_points<const M: u32>(
+    points: &[(ModInt<M>, ModInt<M>)],
+    x: ModInt<M>,
+) -> ModInt<M> {
+    if let Some(&(_, y)) = points.iter().find(|&&(xi, _)| xi == x) {
+        return y;
+    }
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &(xi, yi))| {
+            let (mut num, mut den) = (ModInt::new(1), ModInt::new(1));
+            for (j, &(xj, _)) in points.iter().enumerate() {
+                if j != i {
+                    assert!(xi != xj, "sample points must be distinct");
+                    num *= x - xj;
+                    den *= xi - xj;
+                }
+            }
+            yi * num / den
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    type Mint = ModInt<998_244_353>;
+
+    /// `Σ_{i=0}^{x} i^5`를 정확히
+    fn power_sum(x: u64) -> u128 {
+        (0..=x as u128).map(|i| i.pow(5)).sum()
+    }
+
+    #[test]
+    fn sum_of_fifth_powers() {
+        // 6차 다항식이라 표본 7개면 충분
+        let ys: Vec<Mint> = (0..7)
+            .map(|x| Mint::new((power_sum(x) % 998_244_353) as u64))
+            .collect();
+        for x in (0..1000).chain([12_345, 99_999]) {
+            let expected = Mint::new((power_sum(x) % 998_244_353) as u64);
+            assert_eq!(lagrange_interpolate(&ys, x), expected);
+        }
+        // 닫힌 식 n²(n + 1)²(2n² + 2n - 1) / 12와 비교
+        let n = 1_000_000_000_000_000_000u64;
+        let m = Mint::new(n);
+        let closed = m
+            * m
+            * (m + Mint::new(1))
+            * (m + Mint::new(1))
+            * (Mint::new(2) * m * m + Mint::new(2) * m - Mint::new(1))
+            / Mint::new(12);
+        assert_eq!(lagrange_interpolate(&ys, n), closed);
+        let points: Vec<(Mint, Mint)> = (0..7).map(|i| (Mint::new(i), ys[i as usize])).collect();
+        assert_eq!(lagrange_interpolate_points(&points, m), closed);
+    }
+
+    #[test]
+    fn random_polynomials() {
+        let mut rng = XorShift::new(190);
+        for _ in 0..200 {
+            let degree = rng.index(30);
+            let coeffs: Vec<Mint> = (0..=degree).map(|_| Mint::new(rng.next_u64())).collect();
+            let f = |x: Mint| {
+                coeffs
+                    .iter()
+                    .rev()
+                    .fold(Mint::new(0), |acc, &c| acc * x + c)
+            };
+            let k = degree + 1 + rng.index(3);
+            let ys: Vec<Mint> = (0..k).map(|i| f(Mint::from(i))).collect();
+            let x = rng.next_u64();
+            assert_eq!(lagrange_interpolate(&ys, x), f(Mint::new(x)));
+            // 표본점과 합동인 x는 표본 그대로
+            let i = rng.index(k) as u64;
+            assert_eq!(
+                lagrange_interpolate(&ys, i + 998_244_353 * rng.below(100)),
+                ys[i as usize]
+            );
+            let mut points: Vec<(Mint, Mint)> = Vec::new();
+            while points.len() < k {
+                let xi = Mint::new(rng.below(1000));
+                if points.iter().all(|&(p, _)| p != xi) {
+                    points.push((xi, f(xi)));
+                }
+            }
+            let x = Mint::new(rng.below(2000));
+            assert_eq!(lagrange_interpolate_points(&points, x), f(x));
+        }
+        assert_eq!(lagrange_interpolate::<998_244_353>(&[], 5), Mint::new(0));
+        assert_eq!(lagrange_interpolate(&[Mint::new(7)], 1 << 60), Mint::new(7));
+    }
+}
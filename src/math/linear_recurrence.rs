@@ -0,0 +1,237 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::ModInt;
+
+/// # Berlekamp–Massey
+/// 수열의 앞부분 seq를 만족하는 가장 짧은 선형 점화식 `a_n = Σ_{j=1}^{d} rec[j-1] · a_{n-j}`(`n >= d`)의 계수
+/// rec을 구합니다. [`linear_recurrence_kth`]와 함께 쓰면 작은 항 몇 개만 구해서 큰 k번째 항을 "추측"할 수 있습니다.
+///
+/// * 실제 점화식의 차수가 d일 때 항을 2d개 이상 주면 그 점화식을 정확히 찾습니다.
+/// * 항이 2d개보다 적으면 주어진 항은 만족하지만 실제와 다른, 더 짧은 점화식이 나올 수 있습니다.
+/// 예를 들어 `[1, 2]`의 답은 `a_n = 2 · a_{n-1}`입니다.
+/// * 모든 항이 0이면 빈 점화식(모든 항이 0)입니다. 법 M은 소수여야 합니다.
+/// ## 시간복잡도
+/// O(n²)
+pub fn berlekamp_massey<const M: u32>(seq: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    let one = ModInt::new(1);
+    // c(x) = 1 - Σ rec[j-1] x^j, b는 마지막으로 차수가 바뀌기 직전의 c
+    let (mut c, mut b) = (vec![one], vec![one]);
+    let (mut len, mut shift, mut last) = (0, 1, one);
+    for i in 0..seq.len() {
+        let d = seq[i] + (1..=len).map(|j| c[j] * seq[i - j]).sum::<ModInt<M>>();
+        if d == ModInt::new(0) {
+            shift += 1;
+            continue;
+        }
+        let coef = d * last.inv();
+        let prev = c.clone();
+        if c.len() < b.len() + shift {
+            c.resize(b.len() + shift, ModInt::new(0));
+        }
+        for (j, &x) in b.iter().enumerate() {
+            c[j + shift] -= coef * x;
+        }
+        if 2 * len <= i {
+            len = i + 1 - len;
+            b = prev;
+            last = d;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+    }
+    c.resize(len + 1, ModInt::new(0));
+    c[1..].iter().map(|&x| -x).collect()
+}
+
+/// # 선형 점화식의 k번째 항 (Kitamasa)
+/// `a_n = Σ_{j=1}^{d} rec[j-1] · a_{n-j}`이고 처음 d개 항이 `init`인 수열의 k번째(0-based) 항을 구합니다.
+///
+/// * 특성다항식 `f(x) = x^d - Σ rec[j-1] x^{d-j}`에 대해 `x^k mod f(x) = Σ r_i x^i`이면 `a_k = Σ r_i · a_i`임을
+/// 씁니다. `x^k`는 f로 나눈 나머지를 유지하면서 거듭제곱으로 구합니다.
+/// * init은 d개 이상이어야 하고 앞의 d개만 씁니다. rec이 비었으면 모든 항이 0입니다.
+/// ## 시간복잡도
+/// O(d² log k)
+pub fn linear_recurrence_kth<const M: u32>(
+    rec: &[ModInt<M>],
+    init: &[ModInt<M>],
+    k: u64,
+) -> ModInt<M> {
+    let d = rec.len();
+    assert!(
+        init.len() >= d,
+        "recurrence of order {d} needs {d} initial terms, got {}",
+        init.len()
+    );
+    if k < d as u64 {
+        return init[k as usize];
+    }
+    if d == 0 {
+        return ModInt::new(0);
+    }
+    // x^k를 위 비트부터 제곱하고 x를 곱하며 만듦
+    let mut result = vec![ModInt::new(0); d];
+    result[0] = ModInt::new(1);
+    for bit in (0..64 - k.leading_zeros()).rev() {
+        result = mul_mod_char(&result, &result, rec);
+        if (k >> bit) & 1 == 1 {
+            // x를 곱함: 한 칸씩 밀고 넘친 x^d를 줄임
+            let top = result[d - 1];
+            result.rotate_right(1);
+            result[0] = ModInt::new(0);
+            for (r, &c) in result.iter_mut().rev().zip(rec) {
+                *r += top * c;
+            }
+        }
+    }
+    result.iter().zip(init).map(|(&r, &a)| r * a).sum()
+}
+
+/// d - 1차 이하 다항식 둘을 곱해서 특성다항식으로 나눈 나머지
+fn mul_mod_char<const M: u32>(
+    a: &[ModInt<M>],
+    b: &[ModInt<M>],
+    rec: &[ModInt<M>],
+) -> Vec<ModInt<M>> {
+    let d = rec.len();
+    let mut product = vec![ModInt::new(0); 2 * d - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            product[i + j] += x * y;
+        }
+    }
+    // x^i = Σ rec[j-1] x^{i-j}
+    for i in (d..2 * d - 1).rev() {
+        let top = product[i];
+        for (j, &c) in rec.iter().enumerate() {
+            product[i - 1 - j] += top * c;
+        }
+    }
+    product.truncate(d);
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{Matrix, ModInt998244353};
+    use crate::random::XorShift;
+
+    type Mint = ModInt998244353;
+
+    fn generate(rec: &[Mint], init: &[Mint], n: usize) -> Vec<Mint> {
+        let mut seq = init[..rec.len()].to_vec();
+        while seq.len() < n {
+            let next = (1..=rec.len())
+                .map(|j| rec[j - 1] * seq[seq.len() - j])
+                .sum();
+            seq.push(next);
+        }
+        seq.truncate(n);
+        seq
+    }
+
+    /// 동반 행렬의 거듭제곱으로 구한 k번째 항
+    fn by_matrix(rec: &[Mint], init: &[Mint], k: u64) -> Mint {
+        let d = rec.len();
+        let mut rows = vec![vec![Mint::new(0); d]; d];
+        rows[0].copy_from_slice(rec);
+        for i in 1..d {
+            rows[i][i - 1] = Mint::new(1);
+        }
+        let p = Matrix::from_rows(rows).pow(k);
+        // 상태 벡터 (a_{d-1}, ..., a_0)에 곱해서 마지막 칸이 a_k
+        (0..d).map(|j| p[(d - 1, j)] * init[d - 1 - j]).sum()
+    }
+
+    #[test]
+    fn fibonacci_and_tribonacci_at_huge_k() {
+        let ints = |v: &[u64]| v.iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+        let (fib, fib_init) = (ints(&[1, 1]), ints(&[0, 1]));
+        let (trib, trib_init) = (ints(&[1, 1, 1]), ints(&[0, 0, 1]));
+        for k in [
+            0,
+            1,
+            2,
+            10,
+            90,
+            1_000_000_007,
+            1_000_000_000_000_000_000,
+            u64::MAX,
+        ] {
+            assert_eq!(
+                linear_recurrence_kth(&fib, &fib_init, k),
+                by_matrix(&fib, &fib_init, k)
+            );
+            assert_eq!(
+                linear_recurrence_kth(&trib, &trib_init, k),
+                by_matrix(&trib, &trib_init, k)
+            );
+        }
+        assert_eq!(linear_recurrence_kth(&fib, &fib_init, 10), Mint::new(55));
+        assert_eq!(linear_recurrence_kth(&trib, &trib_init, 10), Mint::new(81));
+    }
+
+    #[test]
+    fn recovers_generated_recurrences() {
+        let mut rng = XorShift::new(195);
+        for round in 0..200 {
+            let d = 1 + rng.index(12);
+            let mut rec: Vec<Mint> = (0..d).map(|_| Mint::new(rng.below(998_244_353))).collect();
+            if round % 4 == 0 {
+                // 작은 계수, 0이 섞인 계수
+                rec = (0..d).map(|_| Mint::new(rng.below(3))).collect();
+            }
+            if rec[d - 1] == Mint::new(0) {
+                rec[d - 1] = Mint::new(1);
+            }
+            let init: Vec<Mint> = (0..d).map(|_| Mint::new(rng.below(998_244_353))).collect();
+            let seq = generate(&rec, &init, 2 * d + 10);
+            let found = berlekamp_massey(&seq);
+            assert!(found.len() <= d);
+            // 찾은 점화식으로도 같은 수열이 나오고 먼 항도 같음
+            assert_eq!(generate(&found, &seq, seq.len()), seq);
+            for _ in 0..5 {
+                let k = rng.next_u64();
+                assert_eq!(
+                    linear_recurrence_kth(&found, &seq, k),
+                    linear_recurrence_kth(&rec, &init, k)
+                );
+            }
+            let k = rng.index(200) as u64;
+            assert_eq!(
+                linear_recurrence_kth(&rec, &init, k),
+                generate(&rec, &init, k as usize + 1)[k as usize]
+            );
+        }
+    }
+
+    #[test]
+    fn short_and_degenerate_sequences() {
+        let ints = |v: &[u64]| v.iter().map(|&x| Mint::new(x)).collect::<Vec<_>>();
+        assert!(berlekamp_massey::<998_244_353>(&[]).is_empty());
+        assert!(berlekamp_massey(&ints(&[0, 0, 0])).is_empty());
+        assert_eq!(berlekamp_massey(&ints(&[1, 2])), ints(&[2]));
+        assert_eq!(berlekamp_massey(&ints(&[0, 0, 5])).len(), 3);
+        assert_eq!(
+            berlekamp_massey(&ints(&[0, 1, 1, 2, 3, 5, 8])),
+            ints(&[1, 1])
+        );
+        // 짧은 수열에서도 찾은 점화식은 주어진 항을 만족함
+        let mut rng = XorShift::new(1950);
+        for _ in 0..300 {
+            let n = rng.index(8);
+            let seq: Vec<Mint> = (0..n).map(|_| Mint::new(rng.below(4))).collect();
+            let rec = berlekamp_massey(&seq);
+            assert!(rec.len() <= n);
+            assert_eq!(generate(&rec, &seq, n), seq);
+        }
+        assert_eq!(
+            linear_recurrence_kth::<998_244_353>(&[], &[], 5),
+            Mint::new(0)
+        );
+        assert_eq!(
+            linear_recurrence_kth(&ints(&[3]), &ints(&[1]), 4),
+            Mint::new(81)
+        );
+    }
+}
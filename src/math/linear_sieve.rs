@@ -0,0 +1,204 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # 선형 체 (오일러 피 함수, 뫼비우스 함수)
+/// [`linear_sieve`]가 만드는 표입니다. `0..=limit`의 소수와 `φ(x)`, `μ(x)`를 담고, 표보다 큰 수는 표의 소수로
+/// 소인수분해해서 구합니다.
+///
+/// * `φ(x)`는 `1..=x`에서 x와 서로소인 수의 개수, `μ(x)`는 x가 제곱 인수를 가지면 0, 아니면 `(-1)^(소인수의 개수)`
+/// 입니다. `φ(1) = μ(1) = 1`이고, 0은 받지 않습니다(표에는 `φ(0) = μ(0) = 0`으로 들어 있음).
+/// * 표보다 큰 x는 `x <= limit²`이어야 합니다.
+/// ## 시간복잡도
+/// 표 안의 값 O(1), 표 밖의 값 O(π(√x))
+#[derive(Clone, Debug)]
+pub struct SieveTables {
+    primes: Vec<u32>,
+    phi: Vec<u32>,
+    mu: Vec<i8>,
+}
+
+/// # 선형 체
+/// 합성수 `x = i · p`(p는 x의 가장 작은 소인수)를 정확히 한 번씩 지우면서 곱셈적 함수의 점화식을 씁니다.
+///
+/// * p가 i를 나누면 `φ(i·p) = φ(i)·p`, `μ(i·p) = 0`이고, 아니면 `φ(i·p) = φ(i)·(p - 1)`, `μ(i·p) = -μ(i)`입니다.
+/// ## 시간복잡도
+/// O(limit)
+pub fn linear_sieve(limit: u32) -> SieveTables {
+    let n = limit as usize;
+    let mut primes = Vec::new();
+    let mut phi = vec![0u32; n + 1];
+    let mut mu = vec![0i8; n + 1];
+    let mut composite = vec![false; n + 1];
+    if n >= 1 {
+        phi[1] = 1;
+        mu[1] = 1;
+    }
+    for i in 2..=n {
+        if !composite[i] {
+            primes.push(i as u32);
+            phi[i] = i as u32 - 1;
+            mu[i] = -1;
+        }
+        for &p in &primes {
+            let x = i * p as usize;
+            if x > n {
+                break;
+            }
+            composite[x] = true;
+            if i.is_multiple_of(p as usize) {
+                phi[x] = phi[i] * p;
+                mu[x] = 0;
+                break;
+            }
+            phi[x] = phi[i] * (p - 1);
+            mu[x] = -mu[i];
+        }
+    }
+    SieveTables { primes, phi, mu }
+}
+
+impl SieveTables {
+    /// 표에 있는 가장 큰 수
+    pub fn limit(&self) -> u32 {
+        (self.phi.len() - 1) as u32
+    }
+
+    /// `limit` 이하의 소수를 오름차순으로
+    pub fn primes(&self) -> &[u32] {
+        &self.primes
+    }
+
+    /// `φ(0..=limit)`
+    pub fn phi_table(&self) -> &[u32] {
+        &self.phi
+    }
+
+    /// `μ(0..=limit)`
+    pub fn mu_table(&self) -> &[i8] {
+        &self.mu
+    }
+
+    /// x(`>= 1`)의 오일러 피 함수
+    pub fn phi(&self, x: u64) -> u64 {
+        assert!(x >= 1, "phi is defined for positive integers");
+        if x <= self.limit() as u64 {
+            return self.phi[x as usize] as u64;
+        }
+        self.factorize(x)
+            .into_iter()
+            .fold(x, |acc, (p, _)| acc / p * (p - 1))
+    }
+
+    /// x(`>= 1`)의 뫼비우스 함수
+    pub fn mobius(&self, x: u64) -> i8 {
+        assert!(x >= 1, "mobius is defined for positive integers");
+        if x <= self.limit() as u64 {
+            return self.mu[x as usize];
+        }
+        let factors = self.factorize(x);
+        if factors.iter().any(|&(_, e)| e >= 2) {
+            0
+        } else if factors.len().is_multiple_of(2) {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// 표의 소수로 나눠 보는 소인수분해 (x <= limit²)
+    fn factorize(&self, mut x: u64) -> Vec<(u64, u32)> {
+        let limit = self.limit() as u64;
+        assert!(
+            x.isqrt() <= limit,
+            "{x} is beyond the square of the table limit {limit}"
+        );
+        let mut factors = Vec::new();
+        for &p in &self.primes {
+            let p = p as u64;
+            if p * p > x {
+                break;
+            }
+            let mut e = 0;
+            while x.is_multiple_of(p) {
+                x /= p;
+                e += 1;
+            }
+            if e > 0 {
+                factors.push((p, e));
+            }
+        }
+        if x > 1 {
+            factors.push((x, 1));
+        }
+        factors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::gcd;
+
+    /// 서로소인 수를 직접 셈
+    fn brute_phi(x: u64) -> u64 {
+        (1..=x).filter(|&y| gcd(x, y) == 1).count() as u64
+    }
+
+    /// 제곱 인수와 소인수 개수를 직접 셈
+    fn brute_mu(mut x: u64) -> i8 {
+        let mut sign = 1;
+        let mut d = 2;
+        while d * d <= x {
+            if x.is_multiple_of(d) {
+                x /= d;
+                if x.is_multiple_of(d) {
+                    return 0;
+                }
+                sign = -sign;
+            }
+            d += 1;
+        }
+        if x > 1 { -sign } else { sign }
+    }
+
+    #[test]
+    fn tables_against_definitions() {
+        let tables = linear_sieve(10_000);
+        for x in 1..=10_000u64 {
+            if x <= 2000 {
+                assert_eq!(tables.phi(x), brute_phi(x), "phi({x})");
+            }
+            assert_eq!(tables.mobius(x), brute_mu(x), "mu({x})");
+        }
+        // 약수에 대한 φ의 합은 x, μ의 합은 [x == 1]
+        for x in 1..=3000usize {
+            let divisors = (1..=x).filter(|&d| x.is_multiple_of(d));
+            let (phi_sum, mu_sum) = divisors.fold((0, 0), |(a, b), d| {
+                (a + tables.phi_table()[d], b + tables.mu_table()[d] as i32)
+            });
+            assert_eq!(phi_sum as usize, x);
+            assert_eq!(mu_sum, (x == 1) as i32);
+        }
+        assert_eq!(tables.primes().len(), 1229);
+    }
+
+    #[test]
+    fn values_beyond_the_table() {
+        let small = linear_sieve(1000);
+        let big = linear_sieve(1_000_000);
+        for x in (999_000..=1_000_000).chain([1001, 65_536, 30_030]) {
+            assert_eq!(small.phi(x), big.phi(x));
+            assert_eq!(small.mobius(x), big.mobius(x));
+        }
+        assert_eq!(big.phi(1_000_000), 400_000);
+        assert_eq!(big.mobius(1_000_000), 0);
+        assert_eq!(big.phi(999_983), 999_982);
+        // 10^12 = 2^12 · 5^12, 큰 소수 두 개의 곱
+        assert_eq!(big.phi(1_000_000_000_000), 400_000_000_000);
+        assert_eq!(big.mobius(999_983 * 999_979), 1);
+        assert_eq!(big.phi(999_983 * 999_979), 999_982 * 999_978);
+        let tiny = linear_sieve(1);
+        assert_eq!((tiny.phi(1), tiny.mobius(1)), (1, 1));
+        assert!(tiny.primes().is_empty());
+        assert_eq!(linear_sieve(0).phi_table(), [0]);
+    }
+}
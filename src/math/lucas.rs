@@ -0,0 +1,199 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::{mod_inverse, mul_mod, pow_mod};
+
+/// # 루카스 정리
+/// 작은 소수 p에 대해 `C(n, k) mod p`를 n, k가 u64 전체 범위여도 구합니다. n과 k를 p진법으로 쓴 자릿수를
+/// `n_i`, `k_i`라 하면 `C(n, k) ≡ Π C(n_i, k_i) (mod p)`입니다.
+///
+/// * 자릿수마다의 이항계수는 부를 때마다 만드는 크기 p의 팩토리얼 표로 구하므로 p가 표를 만들 수 있을 만큼
+/// (예: 10^7 이하) 작아야 합니다.
+/// * `k > n`이면 0입니다. p가 소수가 아니면 panic합니다.
+/// ## 시간복잡도
+/// O(p + log_p n)
+pub fn comb_mod_small_prime(n: u64, k: u64, p: u64) -> u64 {
+    assert!(is_small_prime(p), "{p} is not a prime");
+    LucasTable::new(p).comb(n, k)
+}
+
+/// # 제곱 인수가 없는 법의 이항계수
+/// `m = p_1 · p_2 · …`(서로 다른 소수의 곱)일 때 소수마다 루카스 정리로 구한 `C(n, k) mod p_i`를 중국인의 나머지
+/// 정리로 합쳐서 `C(n, k) mod m`을 구합니다.
+///
+/// * `12 = 2^2 · 3`처럼 제곱 인수가 있으면 panic합니다. `m = 1`이면 0입니다.
+/// * 소인수마다 [`comb_mod_small_prime`]의 표를 만들므로 모든 소인수가 작아야 합니다.
+/// ## 시간복잡도
+/// O(√m + Σ p_i)
+pub fn comb_mod_square_free(n: u64, k: u64, m: u64) -> u64 {
+    assert!(m >= 1, "modulus must be positive");
+    let mut primes = Vec::new();
+    let mut rest = m;
+    let mut d = 2;
+    while d * d <= rest {
+        if rest.is_multiple_of(d) {
+            rest /= d;
+            assert!(!rest.is_multiple_of(d), "{m} is not square-free");
+            primes.push(d);
+        }
+        d += 1;
+    }
+    if rest > 1 {
+        primes.push(rest);
+    }
+    // x ≡ (지금까지의 답) (mod modulus)
+    let (mut x, mut modulus) = (0, 1);
+    for p in primes {
+        let r = LucasTable::new(p).comb(n, k);
+        // x + modulus · t ≡ r (mod p)
+        let inv = mod_inverse((modulus % p) as i64, p as i64).unwrap() as u64;
+        let t = mul_mod((r + p - x % p) % p, inv, p);
+        x += modulus * t;
+        modulus *= p;
+    }
+    x % m
+}
+
+fn is_small_prime(p: u64) -> bool {
+    p >= 2
+        && (2..)
+            .take_while(|d| d * d <= p)
+            .all(|d| !p.is_multiple_of(d))
+}
+
+/// 법 p의 `0..p` 팩토리얼과 그 역원
+struct LucasTable {
+    p: u64,
+    fact: Vec<u64>,
+    inv_fact: Vec<u64>,
+}
+
+impl LucasTable {
+    fn new(p: u64) -> Self {
+        let size = p as usize;
+        let mut fact = vec![1; size];
+        for i in 1..size {
+            fact[i] = mul_mod(fact[i - 1], i as u64, p);
+        }
+        let mut inv_fact = vec![1; size];
+        inv_fact[size - 1] = pow_mod(fact[size - 1], p - 2, p);
+        for i in (1..size).rev() {
+            inv_fact[i - 1] = mul_mod(inv_fact[i], i as u64, p);
+        }
+        LucasTable { p, fact, inv_fact }
+    }
+
+    fn comb(&self, mut n: u64, mut k: u64) -> u64 {
+        let p = self.p;
+        let mut result = 1 % p;
+        while k > 0 {
+            let (ni, ki) = ((n % p) as usize, (k % p) as usize);
+            if ki > ni {
+                return 0;
+            }
+            result = mul_mod(result, self.fact[ni], p);
+            result = mul_mod(result, self.inv_fact[ki], p);
+            result = mul_mod(result, self.inv_fact[ni - ki], p);
+            n /= p;
+            k /= p;
+        }
+        // n이 k보다 자릿수가 더 많아도 나머지 자리는 C(n_i, 0) = 1
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// `Π (n - k + i) / i`에서 p의 지수와 p와 서로소인 부분을 따로 세어 구한 `C(n, k) mod p`
+    fn direct(n: u64, k: u64, p: u64) -> u64 {
+        if k > n {
+            return 0;
+        }
+        let (mut exponent, mut unit) = (0i64, 1 % p);
+        for i in 1..=k {
+            for (mut x, sign) in [(n - k + i, 1), (i, -1)] {
+                while x % p == 0 {
+                    x /= p;
+                    exponent += sign;
+                }
+                let factor = if sign == 1 {
+                    x % p
+                } else {
+                    pow_mod(x, p - 2, p)
+                };
+                unit = mul_mod(unit, factor, p);
+            }
+        }
+        if exponent > 0 { 0 } else { unit }
+    }
+
+    #[test]
+    fn random_against_direct_formula() {
+        let mut rng = XorShift::new(178);
+        for _ in 0..300 {
+            let p = [2, 3, 5, 7, 13, 101, 9973][rng.index(7)];
+            let n = rng.below(100_001);
+            let k = rng.below(n + 2);
+            assert_eq!(
+                comb_mod_small_prime(n, k, p),
+                direct(n, k, p),
+                "{n} {k} {p}"
+            );
+        }
+    }
+
+    #[test]
+    fn digit_boundaries() {
+        for p in [2u64, 3, 5] {
+            let mut power = p;
+            while power < 10_000 {
+                // n = p^e - 1은 모든 자리가 p - 1이라서 0이 되는 k가 없음
+                for k in 0..power {
+                    assert_ne!(comb_mod_small_prime(power - 1, k, p), 0);
+                    // n = p^e에서 0 < k < n이면 항상 p로 나누어떨어짐
+                    let expected = if k == 0 { 1 } else { 0 };
+                    assert_eq!(comb_mod_small_prime(power, k, p), expected);
+                }
+                power *= p;
+            }
+        }
+        assert_eq!(comb_mod_small_prime(3, 5, 7), 0);
+        assert_eq!(comb_mod_small_prime(u64::MAX, u64::MAX, 2), 1);
+        assert_eq!(comb_mod_small_prime(u64::MAX, 12345, 2), 1);
+        assert_eq!(comb_mod_small_prime(1 << 60, 1, 2), 0);
+    }
+
+    #[test]
+    fn square_free_moduli_match_per_prime_results() {
+        let mut rng = XorShift::new(1780);
+        for _ in 0..200 {
+            let m = [1, 6, 30, 2 * 3 * 5 * 7 * 11 * 13, 9973 * 101][rng.index(5)];
+            let n = rng.next_u64() >> rng.below(64);
+            let k = rng.below(n.saturating_add(1));
+            let x = comb_mod_square_free(n, k, m);
+            assert!(x < m);
+            let mut rest = m;
+            for p in [2, 3, 5, 7, 11, 13, 101, 9973] {
+                if rest % p == 0 {
+                    rest /= p;
+                    assert_eq!(x % p, comb_mod_small_prime(n, k, p));
+                }
+            }
+        }
+        assert_eq!(comb_mod_square_free(10, 3, 30), 120 % 30);
+        assert_eq!(comb_mod_square_free(10, 3, 1), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a prime")]
+    fn composite_modulus_panics() {
+        comb_mod_small_prime(10, 3, 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "not square-free")]
+    fn square_modulus_panics() {
+        comb_mod_square_free(10, 3, 12);
+    }
+}
@@ -0,0 +1,305 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::Semiring;
+use std::ops::{Add, Index, IndexMut, Mul};
+
+/// # 행렬
+/// 원소가 [`Semiring`]인 `rows × cols` 행렬입니다. 덧셈, 곱셈, 정사각 행렬의 거듭제곱을 지원합니다.
+///
+/// * 원소를 행 우선으로 한 `Vec`에 둡니다. `m[(i, j)]`로 읽고 씁니다.
+/// * 원소가 [`ModInt`](super::ModInt)이면 선형 점화식, [`MinPlus`](crate::algebra::MinPlus)이면 간선을 정확히 k개
+/// 쓰는 최단 경로, `bool`이면 k걸음 도달 가능성이 됩니다.
+/// * 크기가 맞지 않으면 두 행렬의 크기를 담아 panic합니다.
+/// ## 시간복잡도
+/// 곱셈 O(n · m · k), n × n 행렬의 `pow(e)` O(n³ log e)
+///
+/// ```
+/// use pskit::math::{Matrix, ModInt998244353};
+///
+/// // [F(k+1), F(k)] = [[1, 1], [1, 0]]^k · [1, 0]
+/// type Mint = ModInt998244353;
+/// let step = Matrix::from_rows(vec![
+///     vec![Mint::new(1), Mint::new(1)],
+///     vec![Mint::new(1), Mint::new(0)],
+/// ]);
+/// assert_eq!(step.pow(10)[(0, 1)], Mint::new(55));
+/// assert_eq!(step.pow(1_000_000_000_000)[(0, 1)].value(), 822_341_285);
+/// ```
+///
+/// ```
+/// use pskit::algebra::MinPlus;
+/// use pskit::math::Matrix;
+///
+/// // 간선을 정확히 k개 쓰는 최단 경로
+/// let inf = MinPlus::<i64>::INF;
+/// let graph = Matrix::from_rows(vec![
+///     vec![inf, MinPlus(1), MinPlus(5)],
+///     vec![inf, inf, MinPlus(1)],
+///     vec![MinPlus(1), inf, inf],
+/// ]);
+/// assert_eq!(graph.pow(2)[(0, 2)], MinPlus(2));
+/// assert_eq!(graph.pow(3)[(0, 0)], MinPlus(3));
+/// assert_eq!(graph.pow(1)[(1, 0)], inf);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Semiring> Matrix<T> {
+    /// 모든 원소가 `zero`인 `rows × cols` 행렬
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Matrix {
+            rows,
+            cols,
+            data: vec![T::zero(); rows * cols],
+        }
+    }
+
+    /// n × n 단위행렬
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::new(n, n);
+        for i in 0..n {
+            m[(i, i)] = T::one();
+        }
+        m
+    }
+
+    /// 행의 배열로 생성. 행의 길이가 모두 같아야 합니다.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "rows have different lengths"
+        );
+        Matrix {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// 행의 개수
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 열의 개수
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// i번째 행
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// `self^e`. 정사각 행렬이어야 하고, `e = 0`이면 단위행렬입니다.
+    pub fn pow(&self, mut e: u64) -> Self {
+        assert!(
+            self.rows == self.cols,
+            "cannot take a power of a {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+        let mut base = self.clone();
+        let mut result = Self::identity(self.rows);
+        while e > 0 {
+            if e & 1 == 1 {
+                result = &result * &base;
+            }
+            e >>= 1;
+            if e > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(
+            i < self.rows && j < self.cols,
+            "index ({i}, {j}) out of range for {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(
+            i < self.rows && j < self.cols,
+            "index ({i}, {j}) out of range for {}x{} matrix",
+            self.rows,
+            self.cols
+        );
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+impl<T: Semiring> Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Self) -> Matrix<T> {
+        assert!(
+            (self.rows, self.cols) == (rhs.rows, rhs.cols),
+            "cannot add {}x{} matrix and {}x{} matrix",
+            self.rows,
+            self.cols,
+            rhs.rows,
+            rhs.cols
+        );
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(a, b)| T::add(a, b))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Semiring> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Matrix<T> {
+        assert!(
+            self.cols == rhs.rows,
+            "cannot multiply {}x{} matrix by {}x{} matrix",
+            self.rows,
+            self.cols,
+            rhs.rows,
+            rhs.cols
+        );
+        let mut result = Matrix::new(self.rows, rhs.cols);
+        // i-k-j 순서라서 안쪽 반복이 연속된 메모리를 읽음
+        for i in 0..self.rows {
+            let out = &mut result.data[i * rhs.cols..(i + 1) * rhs.cols];
+            for (k, a) in self.row(i).iter().enumerate() {
+                for (o, b) in out.iter_mut().zip(rhs.row(k)) {
+                    *o = T::add(o, &T::mul(a, b));
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::MinPlus;
+    use crate::math::ModInt998244353;
+    use crate::random::XorShift;
+
+    type Mint = ModInt998244353;
+
+    fn random_matrix(rng: &mut XorShift, rows: usize, cols: usize) -> Matrix<Mint> {
+        let values = (0..rows)
+            .map(|_| (0..cols).map(|_| Mint::new(rng.next_u64())).collect())
+            .collect();
+        Matrix::from_rows(values)
+    }
+
+    #[test]
+    fn pow_against_repeated_multiplication() {
+        let mut rng = XorShift::new(185);
+        for _ in 0..50 {
+            let n = rng.index(6);
+            let m = random_matrix(&mut rng, n, n);
+            let mut expected = Matrix::identity(n);
+            for e in 0..20 {
+                assert_eq!(m.pow(e), expected);
+                expected = &expected * &m;
+            }
+        }
+    }
+
+    #[test]
+    fn associativity_and_distributivity() {
+        let mut rng = XorShift::new(1850);
+        for _ in 0..50 {
+            let (a, b, c, d) = (
+                1 + rng.index(4),
+                1 + rng.index(4),
+                1 + rng.index(4),
+                1 + rng.index(4),
+            );
+            let x = random_matrix(&mut rng, a, b);
+            let y = random_matrix(&mut rng, b, c);
+            let z = random_matrix(&mut rng, c, d);
+            assert_eq!(&(&x * &y) * &z, &x * &(&y * &z));
+            let w = random_matrix(&mut rng, b, c);
+            assert_eq!(&x * &(&y + &w), &(&x * &y) + &(&x * &w));
+            assert_eq!(&Matrix::identity(a) * &x, x);
+        }
+    }
+
+    #[test]
+    fn exact_k_edge_paths_against_dp() {
+        let mut rng = XorShift::new(1851);
+        for _ in 0..50 {
+            let n = 1 + rng.index(6);
+            let mut graph = Matrix::new(n, n);
+            for i in 0..n {
+                for j in 0..n {
+                    if rng.below(3) == 0 {
+                        graph[(i, j)] = MinPlus(rng.range_i64(-5, 20));
+                    }
+                }
+            }
+            // dist[v]: 0에서 간선을 정확히 k개 써서 v까지의 최단 거리
+            let mut dist = vec![None; n];
+            dist[0] = Some(0i64);
+            for k in 0..12 {
+                let expected: Vec<MinPlus<i64>> = dist
+                    .iter()
+                    .map(|d| d.map_or(MinPlus::<i64>::INF, MinPlus))
+                    .collect();
+                assert_eq!(graph.pow(k).row(0), expected);
+                let mut next = vec![None; n];
+                for u in 0..n {
+                    for v in 0..n {
+                        if let (Some(d), MinPlus(w)) = (dist[u], graph[(u, v)])
+                            && graph[(u, v)] != MinPlus::<i64>::INF
+                        {
+                            next[v] = Some(next[v].map_or(d + w, |x: i64| x.min(d + w)));
+                        }
+                    }
+                }
+                dist = next;
+            }
+        }
+    }
+
+    #[test]
+    fn boolean_reachability() {
+        // 0 → 1 → 2 → 0 순환
+        let mut graph = Matrix::new(3, 3);
+        graph[(0, 1)] = true;
+        graph[(1, 2)] = true;
+        graph[(2, 0)] = true;
+        assert_eq!(graph.pow(3), Matrix::identity(3));
+        assert!(graph.pow(2)[(0, 2)]);
+        assert!(!graph.pow(2)[(0, 1)]);
+        let empty: Matrix<bool> = Matrix::new(0, 0);
+        assert_eq!(empty.pow(5).rows(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot multiply 2x3 matrix by 2x3 matrix")]
+    fn dimension_mismatch_panics() {
+        let m: Matrix<i64> = Matrix::new(2, 3);
+        let _ = &m * &m;
+    }
+}
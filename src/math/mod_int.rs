@@ -0,0 +1,340 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::ext_gcd;
+use crate::algebra::Semiring;
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// # ModInt
+/// 법 M(컴파일 시간 상수)으로 나눈 나머지 `0..M`을 값으로 갖는 정수입니다. 사칙연산이 모두 mod M으로 됩니다.
+///
+/// * 나눗셈은 역원을 곱합니다. M이 소수이면 페르마 소정리(`a^(M-2)`), 아니면 확장 유클리드로 역원을 구하고,
+/// 역원이 없으면(`gcd(a, M) != 1`) panic합니다.
+/// * 음수를 넣으면 `rem_euclid`처럼 `0..M`으로 옮깁니다. 예를 들어 `ModInt::<7>::from(-1) == 6`입니다.
+/// * `M >= 1`이어야 합니다.
+/// ## 시간복잡도
+/// 덧셈/뺄셈/곱셈 O(1), 나눗셈/`inv` O(log M), `pow` O(log e)
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ModInt<const M: u32> {
+    value: u32,
+}
+
+/// 법이 998244353인 [`ModInt`]
+pub type ModInt998244353 = ModInt<998_244_353>;
+/// 법이 1000000007인 [`ModInt`]
+pub type ModInt1000000007 = ModInt<1_000_000_007>;
+
+/// 시도 나눗셈으로 소수 판정. 컴파일 시간에 법마다 한 번만 계산합니다.
+pub(super) const fn is_prime(m: u32) -> bool {
+    if m < 2 {
+        return false;
+    }
+    let mut d = 2u64;
+    while d * d <= m as u64 {
+        if (m as u64).is_multiple_of(d) {
+            return false;
+        }
+        d += 1;
+    }
+    true
+}
+
+impl<const M: u32> ModInt<M> {
+    const IS_PRIME: bool = is_prime(M);
+
+    /// x mod M
+    pub fn new(x: u64) -> Self {
+        assert!(M >= 1, "modulus must be positive");
+        ModInt {
+            value: (x % M as u64) as u32,
+        }
+    }
+
+    /// 법 M
+    pub const fn modulus() -> u32 {
+        M
+    }
+
+    /// `0..M`의 값
+    pub fn value(self) -> u32 {
+        self.value
+    }
+
+    /// `self^e`. `0^0 = 1`입니다.
+    pub fn pow(self, mut e: u64) -> Self {
+        let (mut base, mut result) = (self, Self::new(1));
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        result
+    }
+
+    /// 곱셈 역원. 없으면 panic
+    pub fn inv(self) -> Self {
+        self.checked_inv()
+            .unwrap_or_else(|| panic!("{} has no inverse modulo {M}", self.value))
+    }
+
+    /// 곱셈 역원. `gcd(self, M) != 1`이면 None
+    pub fn checked_inv(self) -> Option<Self> {
+        if Self::IS_PRIME {
+            return (self.value != 0).then(|| self.pow(M as u64 - 2));
+        }
+        let (g, x, _) = ext_gcd(self.value as i64, M as i64);
+        (g == 1).then(|| Self::from(x))
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl<const M: u32> From<$t> for ModInt<M> {
+                fn from(x: $t) -> Self {
+                    Self::new(x as u64)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl<const M: u32> From<$t> for ModInt<M> {
+                fn from(x: $t) -> Self {
+                    Self::new((x as i64).rem_euclid(M as i64) as u64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+impl<const M: u32> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let sum = self.value as u64 + rhs.value as u64;
+        ModInt {
+            value: if sum >= M as u64 { sum - M as u64 } else { sum } as u32,
+        }
+    }
+}
+
+impl<const M: u32> Sub for ModInt<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let value = if self.value >= rhs.value {
+            self.value - rhs.value
+        } else {
+            self.value + (M - rhs.value)
+        };
+        ModInt { value }
+    }
+}
+
+impl<const M: u32> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        ModInt {
+            value: (self.value as u64 * rhs.value as u64 % M as u64) as u32,
+        }
+    }
+}
+
+impl<const M: u32> Div for ModInt<M> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl<const M: u32> Neg for ModInt<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::default() - self
+    }
+}
+
+macro_rules! impl_assign {
+    ($($trait:ident, $method:ident, $op:ident);*) => {
+        $(
+            impl<const M: u32> $trait for ModInt<M> {
+                fn $method(&mut self, rhs: Self) {
+                    *self = self.$op(rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_assign!(
+    AddAssign, add_assign, add;
+    SubAssign, sub_assign, sub;
+    MulAssign, mul_assign, mul;
+    DivAssign, div_assign, div
+);
+
+impl<const M: u32> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl<'a, const M: u32> Sum<&'a ModInt<M>> for ModInt<M> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<const M: u32> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), Mul::mul)
+    }
+}
+
+impl<'a, const M: u32> Product<&'a ModInt<M>> for ModInt<M> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<const M: u32> Semiring for ModInt<M> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn add(a: &Self, b: &Self) -> Self {
+        *a + *b
+    }
+
+    fn mul(a: &Self, b: &Self) -> Self {
+        *a * *b
+    }
+}
+
+impl<const M: u32> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<const M: u32> fmt::Debug for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    type Mint = ModInt998244353;
+
+    #[test]
+    fn algebraic_identities() {
+        let mut rng = XorShift::new(174);
+        for _ in 0..10_000 {
+            let (a, b, c) = (
+                Mint::new(rng.next_u64()),
+                Mint::new(rng.next_u64()),
+                Mint::new(rng.next_u64()),
+            );
+            assert_eq!(a + b, b + a);
+            assert_eq!(a * b, b * a);
+            assert_eq!((a + b) + c, a + (b + c));
+            assert_eq!((a * b) * c, a * (b * c));
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!(a - b + b, a);
+            assert_eq!(-a + a, Mint::default());
+            let (x, y) = (rng.below(998_244_353), rng.below(998_244_353));
+            assert_eq!(
+                (Mint::new(x) * Mint::new(y)).value() as u64,
+                x * y % 998_244_353
+            );
+            if b != Mint::default() {
+                assert_eq!(a / b * b, a);
+                assert_eq!(b * b.inv(), Mint::new(1));
+            }
+        }
+    }
+
+    #[test]
+    fn negative_conversion_and_display() {
+        assert_eq!(ModInt::<7>::from(-1i64).value(), 6);
+        assert_eq!(ModInt::<7>::from(-14i32).value(), 0);
+        assert_eq!(
+            ModInt::<7>::from(i64::MIN).value(),
+            i64::MIN.rem_euclid(7) as u32
+        );
+        assert_eq!(ModInt1000000007::from(-1i64).value(), 1_000_000_006);
+        assert_eq!(ModInt::<7>::from(20usize).to_string(), "6");
+        assert_eq!(format!("{:?}", ModInt::<7>::from(3u8)), "3");
+        let mut x = ModInt::<1>::from(5u32);
+        x += ModInt::from(3u32);
+        assert_eq!(x.value(), 0);
+    }
+
+    #[test]
+    fn pow_against_repeated_multiplication() {
+        let mut rng = XorShift::new(1740);
+        for _ in 0..200 {
+            let a = Mint::new(rng.next_u64());
+            let mut expected = Mint::new(1);
+            for e in 0..50 {
+                assert_eq!(a.pow(e), expected);
+                expected *= a;
+            }
+        }
+        assert_eq!(Mint::new(0).pow(0), Mint::new(1));
+        // 페르마 소정리
+        assert_eq!(Mint::new(3).pow(998_244_352), Mint::new(1));
+    }
+
+    #[test]
+    fn division_round_trips_and_sums() {
+        let mut rng = XorShift::new(1741);
+        // 합성수 법은 확장 유클리드로 역원을 구함
+        type M12 = ModInt<12>;
+        for a in 0..12u32 {
+            let x = M12::from(a);
+            assert_eq!(x.checked_inv().is_some(), [1, 5, 7, 11].contains(&a));
+            if let Some(inv) = x.checked_inv() {
+                assert_eq!(x * inv, M12::from(1u32));
+            }
+        }
+        let values: Vec<Mint> = (0..100).map(|_| Mint::new(rng.next_u64())).collect();
+        let sum: Mint = values.iter().sum();
+        let product: Mint = values.iter().copied().product();
+        assert_eq!(sum, values.iter().fold(Mint::default(), |s, &v| s + v));
+        assert_eq!(product, values.iter().fold(Mint::new(1), |p, &v| p * v));
+        for &v in &values {
+            let mut x = Mint::new(12345);
+            x *= v;
+            x /= v;
+            assert_eq!(x, Mint::new(12345));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "has no inverse")]
+    fn inverting_zero_panics() {
+        Mint::new(0).inv();
+    }
+}
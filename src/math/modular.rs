@@ -0,0 +1,91 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # u64 법의 모듈러 연산
+/// 법 m이 `u32`를 넘어서 [`ModInt`](super::ModInt)를 쓸 수 없을 때(Miller–Rabin, 64비트 해싱 등) 쓰는 함수들입니다.
+/// 곱셈은 u128로 해서 `a * b % m`처럼 넘치지 않습니다.
+///
+/// * 입력은 m 이상이어도 되고, 결과는 항상 `0..m`입니다. m은 1 이상이어야 합니다.
+/// * 법이 1이면 모든 결과가 0입니다(`pow_mod(a, 0, 1) = 0`).
+/// ## 시간복잡도
+/// `pow_mod` O(log e), 나머지 O(1)
+pub const fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    assert!(m >= 1, "modulus must be positive");
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// `(a + b) mod m`
+pub fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    assert!(m >= 1, "modulus must be positive");
+    let (a, b) = (a % m, b % m);
+    // a + b는 넘칠 수 있으므로 m - b와 비교
+    if a >= m - b { a - (m - b) } else { a + b }
+}
+
+/// `(a - b) mod m`
+pub fn sub_mod(a: u64, b: u64, m: u64) -> u64 {
+    assert!(m >= 1, "modulus must be positive");
+    let (a, b) = (a % m, b % m);
+    if a >= b { a - b } else { a + (m - b) }
+}
+
+/// `a^e mod m`. `0^0 = 1`로 봅니다.
+pub const fn pow_mod(a: u64, mut e: u64, m: u64) -> u64 {
+    assert!(m >= 1, "modulus must be positive");
+    let (mut base, mut result) = (a % m, 1 % m);
+    while e > 0 {
+        if e & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        e >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_i128_arithmetic() {
+        let mut rng = XorShift::new(176);
+        for round in 0..20_000 {
+            let m = match round % 4 {
+                0 => 1 + rng.below(100),
+                1 => u64::MAX - rng.below(100),
+                _ => 1 + rng.below(u64::MAX),
+            };
+            let (a, b) = (rng.next_u64(), rng.next_u64());
+            let (wa, wb, wm) = (a as i128, b as i128, m as i128);
+            // 두 u64의 곱은 i128을 넘을 수 있어서 u128로 비교
+            let (ua, ub, um) = (a as u128, b as u128, m as u128);
+            assert_eq!(mul_mod(a, b, m) as u128, ua * ub % um);
+            assert_eq!(add_mod(a, b, m) as i128, (wa + wb) % wm);
+            assert_eq!(sub_mod(a, b, m) as i128, (wa - wb).rem_euclid(wm));
+            let e = rng.below(30);
+            let mut expected = 1 % um;
+            for _ in 0..e {
+                expected = expected * (ua % um) % um;
+            }
+            assert_eq!(pow_mod(a, e, m) as u128, expected);
+        }
+    }
+
+    #[test]
+    fn edges_near_u64_max() {
+        let m = u64::MAX;
+        assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+        assert_eq!(add_mod(m - 1, m - 1, m), m - 2);
+        assert_eq!(add_mod(u64::MAX, u64::MAX, m), 0);
+        assert_eq!(sub_mod(0, m - 1, m), 1);
+        assert_eq!(pow_mod(m - 1, u64::MAX, m), m - 1);
+        // 2^61 - 1은 소수라서 페르마 소정리가 성립
+        let p = (1 << 61) - 1;
+        assert_eq!(pow_mod(3, p - 1, p), 1);
+        assert_eq!(pow_mod(0, 0, 7), 1);
+        assert_eq!(pow_mod(0, 5, 7), 0);
+        assert_eq!(pow_mod(5, 0, 1), 0);
+        assert_eq!(mul_mod(5, 7, 1), 0);
+    }
+}
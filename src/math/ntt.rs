@@ -0,0 +1,285 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::{ModInt, mod_int::is_prime, pow_mod};
+
+/// 이 길이 이하면 O(nm) 곱셈이 더 빠름
+const NAIVE_THRESHOLD: usize = 32;
+
+/// 법 M의 원시근과 단계별 단위근. 법마다 컴파일 시간에 한 번만 구합니다.
+struct NttRoots<const M: u32>;
+
+impl<const M: u32> NttRoots<M> {
+    /// 가장 작은 원시근. M이 소수가 아니면 0
+    const G: u32 = ntt_primitive_root(M);
+    /// `ROOTS[s]`는 1의 원시 2^s제곱근(2^s가 `M - 1`을 나눌 때만), `INV_ROOTS[s]`는 그 역원
+    const ROOTS: [u32; 32] = stage_roots(M, Self::G, false);
+    const INV_ROOTS: [u32; 32] = stage_roots(M, Self::G, true);
+}
+
+/// 소수 m의 가장 작은 원시근. 소수가 아니면 0
+const fn ntt_primitive_root(m: u32) -> u32 {
+    if !is_prime(m) {
+        return 0;
+    }
+    if m == 2 {
+        return 1;
+    }
+    // m - 1의 서로 다른 소인수 (u32 범위에서는 많아야 9개)
+    let mut primes = [0u64; 9];
+    let mut count = 0;
+    let mut rest = m as u64 - 1;
+    let mut d = 2;
+    while d * d <= rest {
+        if rest.is_multiple_of(d) {
+            primes[count] = d;
+            count += 1;
+            while rest.is_multiple_of(d) {
+                rest /= d;
+            }
+        }
+        d += 1;
+    }
+    if rest > 1 {
+        primes[count] = rest;
+        count += 1;
+    }
+    let mut g = 2;
+    loop {
+        let mut i = 0;
+        while i < count && pow_mod(g, (m as u64 - 1) / primes[i], m as u64) != 1 {
+            i += 1;
+        }
+        if i == count {
+            return g as u32;
+        }
+        g += 1;
+    }
+}
+
+const fn stage_roots(m: u32, g: u32, invert: bool) -> [u32; 32] {
+    let mut roots = [0u32; 32];
+    if g == 0 {
+        return roots;
+    }
+    let (m, g) = (m as u64, g as u64);
+    // g^-1 = g^(m - 2)
+    let base = if invert { pow_mod(g, m - 2, m) } else { g };
+    let mut s = 0;
+    while s < 32 && (m - 1).is_multiple_of(1 << s) {
+        roots[s] = pow_mod(base, (m - 1) >> s, m) as u32;
+        s += 1;
+    }
+    roots
+}
+
+/// # NTT 합성곱 (mod 998244353)
+/// `c[k] = Σ a[i] · b[k - i] (mod 998244353)`인 길이 `n + m - 1`의 c를 구합니다. 입력은 998244353 이상이어도 되고,
+/// 결과는 `0..998244353`입니다. 어느 한쪽이 비었으면 빈 배열입니다.
+/// ## 시간복잡도
+/// O((n + m) log(n + m))
+pub fn convolution(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let a: Vec<ModInt<998_244_353>> = a.iter().map(|&x| ModInt::new(x)).collect();
+    let b: Vec<ModInt<998_244_353>> = b.iter().map(|&x| ModInt::new(x)).collect();
+    convolution_ntt(&a, &b)
+        .into_iter()
+        .map(|x| x.value() as u64)
+        .collect()
+}
+
+/// # NTT 합성곱 (ModInt)
+/// [`convolution`]과 같지만 원소가 [`ModInt`]이고, 법 M은 NTT를 쓸 수 있는 소수(`M - 1`이 큰 2의 거듭제곱으로
+/// 나누어떨어짐, 예: 998244353 = 119 · 2^23 + 1)여야 합니다.
+///
+/// * 비트 반전 순서로 놓은 뒤 길이 2, 4, 8, …의 나비 연산을 반복하는 반복문 radix-2 NTT입니다. 원시근과 단계별
+/// 단위근(과 역원)은 법마다 컴파일 시간에 한 번만 구하고, 단계마다 그 단위근의 거듭제곱 표를 곱셈으로 채웁니다.
+/// * 결과 길이를 2의 거듭제곱으로 올린 값이 `M - 1`을 나누지 않으면 panic합니다.
+/// ## 시간복잡도
+/// O((n + m) log(n + m))
+pub fn convolution_ntt<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= NAIVE_THRESHOLD {
+        let mut c = vec![ModInt::new(0); len];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[i + j] += x * y;
+            }
+        }
+        return c;
+    }
+    let size = len.next_power_of_two();
+    let mut fa = a.to_vec();
+    let mut fb = b.to_vec();
+    fa.resize(size, ModInt::new(0));
+    fb.resize(size, ModInt::new(0));
+    ntt(&mut fa, false);
+    ntt(&mut fb, false);
+    for (x, y) in fa.iter_mut().zip(&fb) {
+        *x *= *y;
+    }
+    ntt(&mut fa, true);
+    fa.truncate(len);
+    fa
+}
+
+/// 길이가 2의 거듭제곱인 a의 (역)변환. 역변환은 길이로 나눈 값까지 구함
+pub(super) fn ntt<const M: u32>(a: &mut [ModInt<M>], invert: bool) {
+    let n = a.len();
+    assert!(n.is_power_of_two(), "NTT length {n} is not a power of two");
+    assert!(
+        ((M - 1) as usize).is_multiple_of(n),
+        "NTT length {n} does not divide {M} - 1"
+    );
+    if n == 1 {
+        return;
+    }
+    assert!(NttRoots::<M>::G != 0, "modulus must be prime");
+    let shift = usize::BITS - n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+    let mut powers = Vec::with_capacity(n / 2);
+    let roots = if invert {
+        &NttRoots::<M>::INV_ROOTS
+    } else {
+        &NttRoots::<M>::ROOTS
+    };
+    let mut half = 1;
+    while half < n {
+        let w = ModInt::<M>::new(roots[(2 * half).trailing_zeros() as usize] as u64);
+        powers.clear();
+        powers.push(ModInt::new(1));
+        for k in 1..half {
+            powers.push(powers[k - 1] * w);
+        }
+        for block in a.chunks_exact_mut(2 * half) {
+            let (lo, hi) = block.split_at_mut(half);
+            for ((x, y), &p) in lo.iter_mut().zip(hi.iter_mut()).zip(&powers) {
+                let (u, v) = (*x, *y * p);
+                *x = u + v;
+                *y = u - v;
+            }
+        }
+        half *= 2;
+    }
+    if invert {
+        let inv_n = ModInt::<M>::from(n).inv();
+        for x in a.iter_mut() {
+            *x *= inv_n;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn naive(a: &[u64], b: &[u64]) -> Vec<u64> {
+        let p = 998_244_353u64;
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+        let mut c = vec![0u64; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[i + j] = (c[i + j] + x % p * (y % p)) % p;
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn random_against_naive() {
+        let mut rng = XorShift::new(187);
+        for round in 0..200 {
+            let limit = if round < 190 { 100 } else { 3000 };
+            let (n, m) = (rng.index(limit), rng.index(limit));
+            let a: Vec<u64> = (0..n).map(|_| rng.next_u64()).collect();
+            let b: Vec<u64> = (0..m).map(|_| rng.below(998_244_353)).collect();
+            assert_eq!(convolution(&a, &b), naive(&a, &b));
+        }
+    }
+
+    #[test]
+    fn zeros_and_identity() {
+        let mut rng = XorShift::new(1870);
+        let a: Vec<u64> = (0..500).map(|_| rng.below(998_244_353)).collect();
+        assert_eq!(convolution(&a, &[1]), a);
+        assert_eq!(convolution(&[1], &a), a);
+        let mut shifted = vec![0; 3];
+        shifted.extend(&a);
+        assert_eq!(convolution(&a, &[0, 0, 0, 1]), shifted);
+        assert_eq!(convolution(&a, &vec![0; 100]), vec![0; 599]);
+        assert!(convolution(&a, &[]).is_empty());
+        assert!(convolution(&[], &[]).is_empty());
+        assert_eq!(convolution(&[3], &[5]), vec![15]);
+        // 다른 NTT 소수
+        let x: Vec<ModInt<167_772_161>> = (1..=40u32).map(ModInt::from).collect();
+        let y = convolution_ntt(&x, &x);
+        assert_eq!(y[0].value(), 1);
+        assert_eq!(y[78].value(), 1600);
+        assert_eq!(y[39].value(), (1..=40).map(|i| i * (41 - i)).sum::<u32>());
+    }
+
+    #[test]
+    fn large_inputs() {
+        let mut rng = XorShift::new(1871);
+        let n = 1 << 20;
+        let a: Vec<u64> = (0..n).map(|_| rng.below(998_244_353)).collect();
+        let b: Vec<u64> = (0..n).map(|_| rng.below(998_244_353)).collect();
+        let c = convolution(&a, &b);
+        assert_eq!(c.len(), 2 * n - 1);
+        // 몇 개의 계수만 직접 계산해서 확인
+        for _ in 0..5 {
+            let k = rng.index(2 * n - 1);
+            let expected = (k.saturating_sub(n - 1)..=k.min(n - 1))
+                .fold(0, |acc, i| (acc + a[i] * b[k - i]) % 998_244_353);
+            assert_eq!(c[k], expected);
+        }
+    }
+
+    #[test]
+    fn roots_match_primitive_root() {
+        fn check<const M: u32>() {
+            let g = crate::math::primitive_root(M as u64).unwrap() as u32;
+            assert_eq!(NttRoots::<M>::G, g);
+            let rank = (M - 1).trailing_zeros() as usize;
+            for s in 0..=rank {
+                let w = ModInt::<M>::new(NttRoots::<M>::ROOTS[s] as u64);
+                let inv = ModInt::<M>::new(NttRoots::<M>::INV_ROOTS[s] as u64);
+                assert_eq!(w * inv, ModInt::new(1));
+                assert_eq!(w.pow(1 << s), ModInt::new(1));
+                if s > 0 {
+                    assert_ne!(w.pow(1 << (s - 1)), ModInt::new(1));
+                }
+            }
+        }
+        check::<998_244_353>();
+        check::<754_974_721>();
+        check::<167_772_161>();
+        check::<469_762_049>();
+        check::<7681>();
+        assert_eq!(NttRoots::<1_000_001>::G, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be prime")]
+    fn composite_modulus_panics() {
+        // 6145 = 5 · 1229 = 3 · 2^11 + 1
+        let a = vec![ModInt::<6145>::new(1); 600];
+        convolution_ntt(&a, &a);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not divide")]
+    fn too_long_for_modulus_panics() {
+        // 7681 - 1 = 2^9 · 15
+        let a = vec![ModInt::<7681>::new(1); 600];
+        convolution_ntt(&a, &a);
+    }
+}
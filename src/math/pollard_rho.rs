@@ -0,0 +1,219 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::{add_mod, gcd, mul_mod, pow_mod};
+
+/// 작은 소수. Miller–Rabin의 증인이자 Pollard's rho 전에 나눠 보는 수입니다.
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// # 결정적 Miller–Rabin 소수 판정
+/// 처음 12개의 소수를 증인으로 쓰면 `2^64` 미만의 모든 수를 틀림없이 판정합니다. 곱셈은 넘치지 않도록
+/// [`mul_mod`]로 합니다.
+/// ## 시간복잡도
+/// O(12 · log n)
+pub fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in SMALL_PRIMES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    SMALL_PRIMES.iter().all(|&a| {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// # Pollard's rho 소인수분해
+/// n(`>= 1`)을 `(소수, 지수)`로 소수 순서로 반환합니다. `factorize_u64(1)`은 비어 있고, 0은 panic합니다.
+///
+/// * 작은 소수로 먼저 나눈 뒤, 남은 수가 [`is_prime_u64`]가 아니면 Brent의 사이클 찾기로 `x ↦ x² + c`의 반복에서
+/// 인수를 찾아 재귀적으로 나눕니다. gcd는 차이를 128개씩 곱해서 한 번에 구합니다.
+/// * 실패하면(gcd가 n) c를 바꿔 다시 합니다. 쓰는 수가 정해져 있어서 결과와 실행 시간이 항상 같습니다.
+/// ## 시간복잡도
+/// 기댓값 O(n^(1/4) · log n)
+pub fn factorize_u64(n: u64) -> Vec<(u64, u32)> {
+    assert!(n >= 1, "cannot factorize 0");
+    let mut primes = Vec::new();
+    let mut rest = n;
+    for p in SMALL_PRIMES {
+        while rest.is_multiple_of(p) {
+            rest /= p;
+            primes.push(p);
+        }
+    }
+    let mut stack = vec![rest];
+    while let Some(x) = stack.pop() {
+        if x == 1 {
+            continue;
+        }
+        if is_prime_u64(x) {
+            primes.push(x);
+            continue;
+        }
+        let d = find_factor(x);
+        stack.push(d);
+        stack.push(x / d);
+    }
+    primes.sort_unstable();
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// 홀수 합성수 n의 1보다 크고 n보다 작은 약수
+fn find_factor(n: u64) -> u64 {
+    // 제곱수는 rho가 잘 못 찾으므로 먼저 확인
+    let root = n.isqrt();
+    if root * root == n {
+        return root;
+    }
+    const BATCH: u64 = 128;
+    for c in 1.. {
+        let f = |x: u64| add_mod(mul_mod(x, x, n), c, n);
+        let (mut x, mut y, mut ys) = (0, 2, 2);
+        let (mut g, mut r, mut q) = (1, 1u64, 1);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BATCH.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += BATCH;
+            }
+            r *= 2;
+        }
+        if g == n {
+            // 묶음 안에서 곱이 0이 됐으면 한 걸음씩 다시 봄
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::Sieve;
+    use crate::random::XorShift;
+
+    fn assert_factorization(n: u64, factors: &[(u64, u32)]) {
+        let product = factors
+            .iter()
+            .fold(1u128, |acc, &(p, e)| acc * (p as u128).pow(e));
+        assert_eq!(product, n as u128, "{n}");
+        assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(factors.iter().all(|&(p, _)| is_prime_u64(p)));
+    }
+
+    #[test]
+    fn agrees_with_sieve() {
+        let sieve = Sieve::new(10_000_000);
+        for x in 0..100_000 {
+            assert_eq!(is_prime_u64(x as u64), sieve.is_prime(x), "{x}");
+        }
+        let mut rng = XorShift::new(181);
+        for round in 0..100_000 {
+            let x = 1 + rng.below(10_000_000) as u32;
+            assert_eq!(is_prime_u64(x as u64), sieve.is_prime(x), "{x}");
+            if round < 10_000 {
+                let expected: Vec<(u64, u32)> = sieve
+                    .factorize(x)
+                    .into_iter()
+                    .map(|(p, e)| (p as u64, e))
+                    .collect();
+                assert_eq!(factorize_u64(x as u64), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn known_primes_and_composites() {
+        for p in [
+            998_244_353,
+            1_000_000_007,
+            4_294_967_291,
+            (1 << 61) - 1,
+            999_999_999_999_999_989,
+            1_000_000_000_000_000_003,
+            18_446_744_073_709_551_557,
+        ] {
+            assert!(is_prime_u64(p), "{p}");
+            assert_eq!(factorize_u64(p), vec![(p, 1)]);
+        }
+        // 카마이클 수, 강한 유사소수 (3215031751은 2, 3, 5, 7에 대한 강한 유사소수)
+        for c in [
+            561,
+            1105,
+            1729,
+            2465,
+            2821,
+            6601,
+            8911,
+            3_215_031_751,
+            u64::MAX,
+        ] {
+            assert!(!is_prime_u64(c), "{c}");
+            assert_factorization(c, &factorize_u64(c));
+        }
+        assert!(!is_prime_u64(0) && !is_prime_u64(1) && is_prime_u64(2));
+        assert!(factorize_u64(1).is_empty());
+        assert_eq!(factorize_u64(2), vec![(2, 1)]);
+        // 큰 소수의 제곱, 가까운 두 소수의 곱
+        let (p, q) = (4_294_967_291u64, 4_294_967_279u64);
+        assert_eq!(factorize_u64(p * p), vec![(p, 2)]);
+        assert_eq!(factorize_u64(p * q), vec![(q, 1), (p, 1)]);
+        assert_eq!(factorize_u64(1 << 63), vec![(2, 63)]);
+        assert_eq!(
+            factorize_u64(1_000_000_007 * 998_244_353),
+            vec![(998_244_353, 1), (1_000_000_007, 1)]
+        );
+    }
+
+    #[test]
+    fn random_u64_round_trips() {
+        let mut rng = XorShift::new(1810);
+        for _ in 0..300 {
+            let n = rng.next_u64().max(1);
+            assert_factorization(n, &factorize_u64(n));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot factorize 0")]
+    fn zero_panics() {
+        factorize_u64(0);
+    }
+}
@@ -0,0 +1,345 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::{ModInt, convolution_ntt};
+use std::ops::{Add, Mul, Sub};
+
+/// # 다항식 (형식적 멱급수)
+/// 계수가 [`ModInt`]인 다항식입니다. 곱셈은 NTT로 하고, 멱급수의 역원, 나눗셈, 미분과 적분, `log`, `exp`를
+/// 지원합니다. 법 M은 NTT를 쓸 수 있는 소수여야 합니다([`convolution_ntt`]).
+///
+/// * 계수는 낮은 차수부터 저장하고, 최고차항이 0이 아니도록 끝의 0을 항상 지웁니다. 그래서 영다항식은 계수가
+/// 없고 차수가 None입니다.
+/// * `inv(n)`, `log(n)`, `exp(n)`은 결과의 `x^0..x^n` 앞 n개 항만 구합니다(mod x^n). 길이가 2의 거듭제곱이 아니어도
+/// 됩니다.
+/// * `inv`는 상수항이 0이 아니어야 하고, `log`는 상수항이 1, `exp`는 상수항이 0이어야 합니다. 적분과 `log`, `exp`는
+/// `1..n`의 역원을 쓰므로 `n < M`이어야 합니다.
+/// ## 시간복잡도
+/// 곱셈, `inv`, `divmod`, `log`, `exp` O(n log n)
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct Poly<const M: u32> {
+    coeffs: Vec<ModInt<M>>,
+}
+
+impl<const M: u32> Poly<M> {
+    /// 계수 배열(낮은 차수부터)로 생성
+    pub fn new(mut coeffs: Vec<ModInt<M>>) -> Self {
+        while coeffs.last().is_some_and(|c| c.value() == 0) {
+            coeffs.pop();
+        }
+        Poly { coeffs }
+    }
+
+    /// 계수(낮은 차수부터). 최고차항은 0이 아닙니다.
+    pub fn coeffs(&self) -> &[ModInt<M>] {
+        &self.coeffs
+    }
+
+    /// 차수. 영다항식이면 None
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    /// 영다항식인지
+    pub fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+
+    /// i차 계수 (차수보다 크면 0)
+    pub fn coeff(&self, i: usize) -> ModInt<M> {
+        self.coeffs.get(i).copied().unwrap_or_default()
+    }
+
+    /// x에서의 값
+    pub fn eval(&self, x: ModInt<M>) -> ModInt<M> {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(ModInt::new(0), |acc, &c| acc * x + c)
+    }
+
+    /// mod x^n (앞 n개 항)
+    pub fn truncate(&self, n: usize) -> Self {
+        Self::new(self.coeffs[..n.min(self.coeffs.len())].to_vec())
+    }
+
+    /// 미분
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| c * ModInt::from(i))
+                .collect(),
+        )
+    }
+
+    /// 상수항이 0인 부정적분
+    pub fn integral(&self) -> Self {
+        let n = self.coeffs.len();
+        let inv = inverses::<M>(n + 1);
+        let mut coeffs = vec![ModInt::new(0); n + 1];
+        for (i, &c) in self.coeffs.iter().enumerate() {
+            coeffs[i + 1] = c * inv[i + 1];
+        }
+        Self::new(coeffs)
+    }
+
+    /// `1 / self mod x^n`. 상수항이 0이면 panic
+    pub fn inv(&self, n: usize) -> Self {
+        let c0 = self.coeff(0);
+        assert!(
+            c0.value() != 0,
+            "power series with zero constant term has no inverse"
+        );
+        // g ← g · (2 - self · g) mod x^(2k)
+        let mut g = Self::new(vec![c0.inv()]);
+        let mut k = 1;
+        while k < n {
+            k *= 2;
+            let fg = &self.truncate(k) * &g;
+            let two_minus = &Self::new(vec![ModInt::new(2)]) - &fg.truncate(k);
+            g = (&g * &two_minus).truncate(k);
+        }
+        g.truncate(n)
+    }
+
+    /// `(몫, 나머지)`. 나머지의 차수는 d의 차수보다 작습니다. d가 영다항식이면 panic
+    pub fn divmod(&self, d: &Self) -> (Self, Self) {
+        let dd = d.degree().expect("division by the zero polynomial");
+        let Some(da) = self.degree().filter(|&da| da >= dd) else {
+            return (Self::default(), self.clone());
+        };
+        // 계수를 뒤집으면 몫의 앞 (da - dd + 1)개 항이 멱급수 나눗셈으로 나옴
+        let k = da - dd + 1;
+        let rev = |p: &Self| Self::new(p.coeffs.iter().rev().copied().collect());
+        let q_rev = (&rev(self).truncate(k) * &rev(d).inv(k)).truncate(k);
+        let mut q_coeffs = q_rev.coeffs.clone();
+        q_coeffs.resize(k, ModInt::new(0));
+        q_coeffs.reverse();
+        let q = Self::new(q_coeffs);
+        let r = (self - &(&q * d)).truncate(dd);
+        (q, r)
+    }
+
+    /// `log(self) mod x^n`. 상수항이 1이 아니면 panic
+    pub fn log(&self, n: usize) -> Self {
+        assert!(self.coeff(0).value() == 1, "log needs constant term 1");
+        if n == 0 {
+            return Self::default();
+        }
+        // log f = ∫ f' / f
+        (&self.derivative() * &self.inv(n))
+            .truncate(n - 1)
+            .integral()
+    }
+
+    /// `exp(self) mod x^n`. 상수항이 0이 아니면 panic
+    pub fn exp(&self, n: usize) -> Self {
+        assert!(self.coeff(0).value() == 0, "exp needs constant term 0");
+        if n == 0 {
+            return Self::default();
+        }
+        // g ← g · (1 - log g + self) mod x^(2k)
+        let mut g = Self::new(vec![ModInt::new(1)]);
+        let mut k = 1;
+        while k < n {
+            k *= 2;
+            let one = Self::new(vec![ModInt::new(1)]);
+            let step = &(&one - &g.log(k)) + &self.truncate(k);
+            g = (&g * &step).truncate(k);
+        }
+        g.truncate(n)
+    }
+}
+
+/// `inv[i] = 1 / i` (`1 <= i < n`), `inv[i] = inv[M mod i] · -(M / i)`로 구함
+fn inverses<const M: u32>(n: usize) -> Vec<ModInt<M>> {
+    assert!(
+        n as u64 <= M as u64,
+        "{n} terms need inverses beyond the modulus {M}"
+    );
+    let mut inv = vec![ModInt::new(1); n.max(2)];
+    for i in 2..n {
+        let q = (M as usize / i) as u64;
+        inv[i] = -(inv[M as usize % i] * ModInt::new(q));
+    }
+    inv
+}
+
+impl<const M: u32> Add for &Poly<M> {
+    type Output = Poly<M>;
+
+    fn add(self, rhs: Self) -> Poly<M> {
+        let len = self.coeffs.len().max(rhs.coeffs.len());
+        Poly::new((0..len).map(|i| self.coeff(i) + rhs.coeff(i)).collect())
+    }
+}
+
+impl<const M: u32> Sub for &Poly<M> {
+    type Output = Poly<M>;
+
+    fn sub(self, rhs: Self) -> Poly<M> {
+        let len = self.coeffs.len().max(rhs.coeffs.len());
+        Poly::new((0..len).map(|i| self.coeff(i) - rhs.coeff(i)).collect())
+    }
+}
+
+impl<const M: u32> Mul for &Poly<M> {
+    type Output = Poly<M>;
+
+    fn mul(self, rhs: Self) -> Poly<M> {
+        Poly::new(convolution_ntt(&self.coeffs, &rhs.coeffs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    type Mint = ModInt<998_244_353>;
+    type P = Poly<998_244_353>;
+
+    fn random_poly(rng: &mut XorShift, len: usize) -> P {
+        P::new((0..len).map(|_| Mint::new(rng.next_u64())).collect())
+    }
+
+    fn with_constant(p: &P, c: u32) -> P {
+        let mut coeffs = p.coeffs().to_vec();
+        coeffs.resize(coeffs.len().max(1), Mint::new(0));
+        coeffs[0] = Mint::from(c);
+        P::new(coeffs)
+    }
+
+    /// `1 / f`를 `f · g = 1`의 계수 점화식으로 구함
+    fn naive_inv(f: &P, n: usize) -> P {
+        let mut g = vec![Mint::new(0); n];
+        let inv0 = f.coeff(0).inv();
+        for k in 0..n {
+            let s: Mint = (1..=k).map(|i| f.coeff(i) * g[k - i]).sum();
+            g[k] = (if k == 0 { Mint::new(1) } else { Mint::new(0) } - s) * inv0;
+        }
+        P::new(g)
+    }
+
+    /// `e' = f' · e`의 계수 점화식 `k · e_k = Σ i · f_i · e_(k-i)`
+    fn naive_exp(f: &P, n: usize) -> P {
+        let mut e = vec![Mint::new(0); n];
+        if n > 0 {
+            e[0] = Mint::new(1);
+        }
+        for k in 1..n {
+            let s: Mint = (1..=k).map(|i| Mint::from(i) * f.coeff(i) * e[k - i]).sum();
+            e[k] = s / Mint::from(k);
+        }
+        P::new(e)
+    }
+
+    #[test]
+    fn inverse_and_exp_against_naive() {
+        let mut rng = XorShift::new(189);
+        for _ in 0..100 {
+            let len = 1 + rng.index(80);
+            let n = rng.index(100);
+            let f = with_constant(&random_poly(&mut rng, len), 1 + rng.below(100) as u32);
+            let g = f.inv(n);
+            assert_eq!(g, naive_inv(&f, n));
+            let one = if n == 0 {
+                P::default()
+            } else {
+                P::new(vec![Mint::new(1)])
+            };
+            assert_eq!((&f * &g).truncate(n), one);
+            let h = with_constant(&f, 0);
+            assert_eq!(h.exp(n), naive_exp(&h, n));
+        }
+    }
+
+    #[test]
+    fn log_exp_round_trips() {
+        let mut rng = XorShift::new(1890);
+        for _ in 0..30 {
+            let n = 1 + rng.index(300);
+            let f = with_constant(&random_poly(&mut rng, n), 1);
+            assert_eq!(f.log(n).exp(n), f.truncate(n));
+            let g = with_constant(&random_poly(&mut rng, n), 0);
+            assert_eq!(g.exp(n).log(n), g.truncate(n));
+            // log의 미분은 f' / f
+            let log = f.log(n);
+            assert_eq!(
+                (&log.derivative() * &f).truncate(n - 1),
+                f.derivative().truncate(n - 1)
+            );
+        }
+    }
+
+    #[test]
+    fn divmod_reconstructs_dividend() {
+        let mut rng = XorShift::new(1891);
+        for _ in 0..200 {
+            let (la, ld) = (rng.index(200), 1 + rng.index(100));
+            let a = random_poly(&mut rng, la);
+            let d = random_poly(&mut rng, ld);
+            if d.is_zero() {
+                continue;
+            }
+            let (q, r) = a.divmod(&d);
+            assert_eq!(&(&q * &d) + &r, a);
+            assert!(r.degree() < d.degree());
+        }
+        // (x² - 1) / (x - 1) = x + 1
+        let m = |v: &[i64]| P::new(v.iter().map(|&x| Mint::from(x)).collect());
+        assert_eq!(
+            m(&[-1, 0, 1]).divmod(&m(&[-1, 1])),
+            (m(&[1, 1]), P::default())
+        );
+        assert_eq!(m(&[3]).divmod(&m(&[0, 1])), (P::default(), m(&[3])));
+    }
+
+    #[test]
+    fn partition_numbers() {
+        // Π 1 / (1 - x^k)는 오일러 오각수 정리의 급수 Σ (-1)^j x^(j(3j-1)/2)의 역원
+        let n = 1001;
+        let mut euler = vec![Mint::new(0); n];
+        for j in 0i64.. {
+            let (a, b) = (
+                (j * (3 * j - 1) / 2) as usize,
+                (j * (3 * j + 1) / 2) as usize,
+            );
+            if a >= n {
+                break;
+            }
+            let sign = if j % 2 == 0 {
+                Mint::new(1)
+            } else {
+                -Mint::new(1)
+            };
+            euler[a] = sign;
+            if b < n {
+                euler[b] = sign;
+            }
+        }
+        let partitions = P::new(euler).inv(n);
+        assert_eq!(partitions.coeff(5).value(), 7);
+        assert_eq!(partitions.coeff(100).value(), 190_569_292);
+        // p(1000) = 24061467864032622473692149727991 mod 998244353
+        let expected = (24_061_467_864_032_622_473_692_149_727_991u128 % 998_244_353) as u32;
+        assert_eq!(partitions.coeff(1000).value(), expected);
+    }
+
+    #[test]
+    fn normalization_and_calculus() {
+        let p = P::new(vec![Mint::new(1), Mint::new(2), Mint::new(0), Mint::new(0)]);
+        assert_eq!(p.degree(), Some(1));
+        assert_eq!(P::new(vec![Mint::new(0)]).degree(), None);
+        assert_eq!(p.eval(Mint::new(10)), Mint::new(21));
+        // ∫ (1 + 2x) = x + x²
+        assert_eq!(
+            p.integral(),
+            P::new(vec![Mint::new(0), Mint::new(1), Mint::new(1)])
+        );
+        assert_eq!(p.integral().derivative(), p);
+        assert!(P::default().derivative().is_zero());
+        assert!((&p - &p).is_zero());
+    }
+}
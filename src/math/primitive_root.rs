@@ -0,0 +1,99 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::{factorize_u64, gcd, pow_mod};
+
+/// # 원시근
+/// 법 m의 가장 작은 원시근(위수가 `φ(m)`인 수)을 반환합니다. 원시근은 m이 1, 2, 4, `p^k`, `2·p^k`(p는 홀수 소수)일
+/// 때만 있고, 나머지는 None입니다.
+///
+/// * `φ(m)`의 서로 다른 소인수 q마다 `g^(φ(m) / q) ≢ 1`인지 보는 것으로 위수를 확인합니다. 소인수분해는
+/// [`factorize_u64`]로 하므로 m이 u64 전체 범위여도 됩니다.
+/// * 작은 수부터 찾으므로 결과가 항상 가장 작은 원시근입니다. `m = 1`이면 모든 수가 0과 합동이라서 0입니다.
+/// ## 시간복잡도
+/// 소인수분해 + O(g · ω(φ(m)) · log m). g는 답이고, 실제로는 아주 작습니다.
+pub fn primitive_root(m: u64) -> Option<u64> {
+    assert!(m >= 1, "modulus must be positive");
+    match m {
+        1 => return Some(0),
+        2 => return Some(1),
+        4 => return Some(3),
+        _ => {}
+    }
+    let odd = if m.is_multiple_of(2) { m / 2 } else { m };
+    let factors = factorize_u64(odd);
+    if factors.len() != 1 || factors[0].0 == 2 {
+        return None;
+    }
+    let (p, _) = factors[0];
+    let phi = odd / p * (p - 1);
+    let phi_primes: Vec<u64> = factorize_u64(phi).into_iter().map(|(q, _)| q).collect();
+    (2..m).find(|&g| gcd(g, m) == 1 && phi_primes.iter().all(|&q| pow_mod(g, phi / q, m) != 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 곱셈 위수 (gcd(g, m) = 1)
+    fn order(g: u64, m: u64) -> u64 {
+        let mut cur = g % m;
+        let mut k = 1;
+        while cur != 1 % m {
+            cur = cur * g % m;
+            k += 1;
+        }
+        k
+    }
+
+    fn phi(m: u64) -> u64 {
+        (1..=m).filter(|&x| gcd(x, m) == 1).count() as u64
+    }
+
+    #[test]
+    fn roots_have_full_order() {
+        for m in 1..=3000 {
+            let phi_m = phi(m);
+            if m > 300 {
+                // 큰 m은 돌려준 값의 위수만 확인
+                if let Some(g) = primitive_root(m) {
+                    assert_eq!(order(g, m), phi_m, "{m}");
+                }
+                continue;
+            }
+            let smallest = (1..m.max(2)).find(|&g| gcd(g, m) == 1 && order(g, m) == phi_m);
+            match primitive_root(m) {
+                Some(g) if m > 1 => {
+                    assert_eq!(order(g, m), phi_m, "{m}");
+                    assert_eq!(Some(g), smallest, "{m}");
+                }
+                Some(g) => assert_eq!(g, 0),
+                None => assert_eq!(smallest, None, "{m}"),
+            }
+        }
+        for m in [8, 12, 15, 16, 21, 24, 1 << 40, 3 * 5 * 7] {
+            assert_eq!(primitive_root(m), None);
+        }
+    }
+
+    #[test]
+    fn large_moduli() {
+        assert_eq!(primitive_root(998_244_353), Some(3));
+        assert_eq!(primitive_root(1_000_000_007), Some(5));
+        assert_eq!(primitive_root(167_772_161), Some(3));
+        assert_eq!(primitive_root(469_762_049), Some(3));
+        assert_eq!(primitive_root(754_974_721), Some(11));
+        // 소수의 거듭제곱과 그 2배
+        for m in [
+            3u64.pow(30),
+            2 * 3u64.pow(30),
+            1_000_000_007 * 1_000_000_007,
+        ] {
+            let g = primitive_root(m).unwrap();
+            let p = factorize_u64(m).last().unwrap().0;
+            let phi = m / if m.is_multiple_of(2) { 2 * p } else { p } * (p - 1);
+            assert_eq!(pow_mod(g, phi, m), 1);
+            for (q, _) in factorize_u64(phi) {
+                assert_ne!(pow_mod(g, phi / q, m), 1);
+            }
+        }
+    }
+}
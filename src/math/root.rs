@@ -0,0 +1,149 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # 정수 제곱근
+/// `⌊√n⌋`을 구합니다.
+///
+/// * f64로 어림한 뒤 u128로 제곱해 보면서 ±1씩 고칩니다. 그래서 `(10^9)^2 - 1`처럼 f64로 반올림되면 경계를 넘는
+/// 값에서도 정확합니다.
+/// ## 시간복잡도
+/// O(1)
+pub fn isqrt(n: u64) -> u64 {
+    let mut r = (n as f64).sqrt() as u64;
+    while (r as u128) * (r as u128) > n as u128 {
+        r -= 1;
+    }
+    while (r as u128 + 1) * (r as u128 + 1) <= n as u128 {
+        r += 1;
+    }
+    r
+}
+
+/// # 정수 k제곱근
+/// `r^k <= n`인 가장 큰 r, 즉 `⌊n^(1/k)⌋`을 구합니다.
+///
+/// * [`isqrt`]처럼 f64로 어림한 뒤 정확한 거듭제곱 비교로 고칩니다. 비교는 u64 곱셈이 넘치면 바로 "n보다 크다"고
+/// 판단하므로 넘치지 않습니다.
+/// * k는 1 이상이어야 합니다. `k >= 64`이면 n이 0일 때 0, 아니면 1입니다.
+/// ## 시간복잡도
+/// O(k)
+pub fn ikth_root(n: u64, k: u32) -> u64 {
+    assert!(k >= 1, "0th root is undefined");
+    match k {
+        1 => return n,
+        2 => return isqrt(n),
+        _ if n <= 1 || k >= 64 => return n.min(1),
+        _ => {}
+    }
+    let mut r = (n as f64).powf(1.0 / k as f64) as u64;
+    while r > 0 && !pow_at_most(r, k, n) {
+        r -= 1;
+    }
+    while pow_at_most(r + 1, k, n) {
+        r += 1;
+    }
+    r
+}
+
+/// n이 제곱수인지
+pub fn is_perfect_square(n: u64) -> bool {
+    let r = isqrt(n);
+    r * r == n
+}
+
+/// `n = a^k`인 정수 a와 `k >= 2`가 있는지. 0과 1도 거듭제곱수입니다.
+pub fn is_perfect_power(n: u64) -> bool {
+    // 2^64 > n이므로 k는 63 이하
+    n <= 1 || (2..64).any(|k| ikth_root(n, k).checked_pow(k) == Some(n))
+}
+
+/// `r^k <= n`인지
+fn pow_at_most(r: u64, k: u32, n: u64) -> bool {
+    r.checked_pow(k).is_some_and(|p| p <= n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// u128로 정확히 계산한 `⌊n^(1/k)⌋`
+    fn brute_root(n: u64, k: u32) -> u64 {
+        let (mut lo, mut hi) = (0u64, u32::MAX as u64 + 1);
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+            let fits = (0..k).try_fold(1u128, |acc, _| {
+                Some(acc * mid as u128).filter(|&p| p <= n as u128)
+            });
+            if fits.is_some() { lo = mid } else { hi = mid }
+        }
+        lo
+    }
+
+    #[test]
+    fn small_exhaustive() {
+        for n in 0..100_000u64 {
+            assert_eq!(isqrt(n), n.isqrt());
+            assert_eq!(is_perfect_square(n), n.isqrt().pow(2) == n);
+            let mut power = n <= 1;
+            for k in 1..=40 {
+                let r = ikth_root(n, k);
+                assert!(r.pow(k) <= n && (r + 1).checked_pow(k).is_none_or(|p| p > n));
+                power |= k >= 2 && r.pow(k) == n;
+            }
+            assert_eq!(is_perfect_power(n), power, "{n}");
+        }
+    }
+
+    #[test]
+    fn around_squares() {
+        let mut rng = XorShift::new(194);
+        let roots = (0..20_000)
+            .map(|_| 1 + rng.below(3_000_000_000))
+            .chain((1u64 << 32) - 3..=(1 << 32) - 1)
+            .chain([1_000_000_000, 999_999_999, 3_037_000_499, 3_037_000_500]);
+        for r in roots {
+            let sq = r * r;
+            assert_eq!(isqrt(sq), r);
+            assert_eq!(isqrt(sq - 1), r - 1);
+            assert!(is_perfect_square(sq));
+            assert!(r == 1 || !is_perfect_square(sq - 1));
+            if let Some(next) = sq.checked_add(1) {
+                assert_eq!(isqrt(next), r);
+                assert_eq!(ikth_root(next, 2), r);
+            }
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert!(!is_perfect_square(u64::MAX));
+    }
+
+    #[test]
+    fn kth_roots_near_powers() {
+        let mut rng = XorShift::new(1940);
+        for k in 2..=63u32 {
+            let max_root = brute_root(u64::MAX, k);
+            assert_eq!(ikth_root(u64::MAX, k), max_root);
+            for _ in 0..200 {
+                let r = 2 + rng.below(max_root - 1);
+                let p = r.pow(k);
+                assert_eq!(ikth_root(p, k), r);
+                assert_eq!(ikth_root(p - 1, k), r - 1);
+                assert_eq!(
+                    ikth_root(p.saturating_add(1), k),
+                    brute_root(p.saturating_add(1), k)
+                );
+                assert!(is_perfect_power(p));
+            }
+            for _ in 0..200 {
+                let n = rng.next_u64() >> rng.below(64);
+                assert_eq!(ikth_root(n, k), brute_root(n, k), "{n} {k}");
+            }
+        }
+        assert_eq!(ikth_root(u64::MAX, 1), u64::MAX);
+        assert_eq!(ikth_root(u64::MAX, 64), 1);
+        assert_eq!(ikth_root(0, 100), 0);
+        assert!(is_perfect_power(1 << 63));
+        assert!(is_perfect_power(3u64.pow(40)));
+        assert!(!is_perfect_power(u64::MAX));
+        assert!(!is_perfect_power((1 << 61) - 1));
+    }
+}
@@ -0,0 +1,226 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::ops::Range;
+
+/// # 에라토스테네스의 체 (최소 소인수 표)
+/// `0..=limit`의 수마다 가장 작은 소인수를 구해 두고, 소수 판정과 소인수분해를 표를 따라가며 합니다.
+///
+/// * 선형 체로 만듭니다. 합성수 x는 가장 작은 소인수 p와 `x / p`로 정확히 한 번만 지워집니다.
+/// * `factorize(x)`는 `(소수, 지수)`를 소수 순서로, `divisors(x)`는 약수를 오름차순으로 반환합니다. `factorize(1)`은
+/// 비어 있고, 0은 받지 않습니다.
+/// * [`primes_in_range`](Self::primes_in_range)는 표의 소수로 구간을 따로 체에 걸러서(segmented sieve) 표보다 큰
+/// 수의 소수를 구합니다. 구간 끝이 `limit²`을 넘으면 panic합니다.
+/// ## 시간복잡도
+/// 생성 O(limit), `is_prime`/`smallest_prime_factor` O(1), `factorize` O(log x), `divisors` O(약수의 개수),
+/// `primes_in_range` O((hi - lo) log log hi + √hi)
+#[derive(Clone, Debug)]
+pub struct Sieve {
+    /// `spf[x]`: x의 가장 작은 소인수 (0, 1은 0)
+    spf: Vec<u32>,
+    primes: Vec<u32>,
+}
+
+impl Sieve {
+    /// `0..=limit`의 표를 만듦
+    pub fn new(limit: u32) -> Self {
+        let n = limit as usize;
+        let mut spf = vec![0u32; n + 1];
+        let mut primes = Vec::new();
+        for i in 2..=n {
+            if spf[i] == 0 {
+                spf[i] = i as u32;
+                primes.push(i as u32);
+            }
+            for &p in &primes {
+                let x = i * p as usize;
+                if p > spf[i] || x > n {
+                    break;
+                }
+                spf[x] = p;
+            }
+        }
+        Sieve { spf, primes }
+    }
+
+    /// 표에 있는 가장 큰 수
+    pub fn limit(&self) -> u32 {
+        (self.spf.len() - 1) as u32
+    }
+
+    /// x가 소수인지
+    pub fn is_prime(&self, x: u32) -> bool {
+        x >= 2 && self.spf[self.check(x)] == x
+    }
+
+    /// `limit` 이하의 소수를 오름차순으로
+    pub fn primes(&self) -> &[u32] {
+        &self.primes
+    }
+
+    /// x(`>= 2`)의 가장 작은 소인수
+    pub fn smallest_prime_factor(&self, x: u32) -> u32 {
+        assert!(x >= 2, "{x} has no prime factor");
+        self.spf[self.check(x)]
+    }
+
+    /// x(`>= 1`)의 소인수분해 `(소수, 지수)`
+    pub fn factorize(&self, mut x: u32) -> Vec<(u32, u32)> {
+        assert!(x >= 1, "cannot factorize 0");
+        self.check(x);
+        let mut factors: Vec<(u32, u32)> = Vec::new();
+        while x > 1 {
+            let p = self.spf[x as usize];
+            match factors.last_mut() {
+                Some((q, e)) if *q == p => *e += 1,
+                _ => factors.push((p, 1)),
+            }
+            x /= p;
+        }
+        factors
+    }
+
+    /// x(`>= 1`)의 약수를 오름차순으로
+    pub fn divisors(&self, x: u32) -> Vec<u64> {
+        let mut divisors = vec![1u64];
+        for (p, e) in self.factorize(x) {
+            let count = divisors.len();
+            let mut power = 1u64;
+            for _ in 0..e {
+                power *= p as u64;
+                for i in 0..count {
+                    divisors.push(divisors[i] * power);
+                }
+            }
+        }
+        divisors.sort_unstable();
+        divisors
+    }
+
+    /// 구간 `lo..hi`의 소수를 오름차순으로. `hi - 1 <= limit²`이어야 합니다.
+    pub fn primes_in_range(&self, range: Range<u64>) -> Vec<u64> {
+        let Range { start: lo, end: hi } = range;
+        if lo >= hi {
+            return Vec::new();
+        }
+        let limit = self.limit() as u64;
+        assert!(
+            (hi - 1).isqrt() <= limit,
+            "range end {hi} needs primes beyond the table limit {limit}"
+        );
+        let lo = lo.max(2);
+        if lo >= hi {
+            return Vec::new();
+        }
+        let mut composite = vec![false; (hi - lo) as usize];
+        for &p in &self.primes {
+            let p = p as u64;
+            if p * p >= hi {
+                break;
+            }
+            // p²보다 작은 p의 배수는 더 작은 소인수로 이미 지워짐
+            let first = (p * p).max(lo.div_ceil(p) * p);
+            for multiple in (first..hi).step_by(p as usize) {
+                composite[(multiple - lo) as usize] = true;
+            }
+        }
+        composite
+            .iter()
+            .enumerate()
+            .filter(|&(_, &c)| !c)
+            .map(|(i, _)| lo + i as u64)
+            .collect()
+    }
+
+    fn check(&self, x: u32) -> usize {
+        assert!(
+            x <= self.limit(),
+            "{x} is beyond the sieve limit {}",
+            self.limit()
+        );
+        x as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn prime_counts_at_checkpoints() {
+        let sieve = Sieve::new(10_000_000);
+        let pi = |x: u32| sieve.primes().partition_point(|&p| p <= x);
+        for (x, count) in [
+            (1, 0),
+            (2, 1),
+            (10, 4),
+            (100, 25),
+            (1000, 168),
+            (10_000, 1229),
+            (100_000, 9592),
+            (1_000_000, 78_498),
+            (10_000_000, 664_579),
+        ] {
+            assert_eq!(pi(x), count);
+        }
+        assert!(sieve.is_prime(9_999_991));
+        assert!(!sieve.is_prime(10_000_000));
+        assert!(!sieve.is_prime(0) && !sieve.is_prime(1));
+    }
+
+    #[test]
+    fn factorizations_round_trip() {
+        let sieve = Sieve::new(100_000);
+        let mut rng = XorShift::new(179);
+        for round in 0..2000 {
+            let x = if round < 100 {
+                round + 1
+            } else {
+                1 + rng.below(100_000) as u32
+            };
+            let factors = sieve.factorize(x);
+            assert_eq!(factors.iter().map(|&(p, e)| p.pow(e)).product::<u32>(), x);
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+            assert!(factors.iter().all(|&(p, e)| sieve.is_prime(p) && e >= 1));
+            if x >= 2 {
+                assert_eq!(sieve.smallest_prime_factor(x), factors[0].0);
+            }
+            if round < 300 {
+                let x = x as u64;
+                let expected: Vec<u64> = (1..=x).filter(|&d| x.is_multiple_of(d)).collect();
+                assert_eq!(sieve.divisors(x as u32), expected);
+            }
+        }
+        assert!(sieve.factorize(1).is_empty());
+        assert_eq!(sieve.divisors(1), vec![1]);
+        assert_eq!(sieve.factorize(65_536), vec![(2, 16)]);
+    }
+
+    #[test]
+    fn segmented_matches_plain_sieve() {
+        let sieve = Sieve::new(1000);
+        let big = Sieve::new(1_000_000);
+        let mut rng = XorShift::new(1790);
+        for _ in 0..200 {
+            let lo = rng.below(1_000_000);
+            let hi = (lo + rng.below(5000)).min(1_000_001);
+            let expected: Vec<u64> = big
+                .primes()
+                .iter()
+                .map(|&p| p as u64)
+                .filter(|p| (lo..hi).contains(p))
+                .collect();
+            assert_eq!(sieve.primes_in_range(lo..hi), expected);
+        }
+        assert_eq!(sieve.primes_in_range(0..12), vec![2, 3, 5, 7, 11]);
+        assert!(sieve.primes_in_range(5..5).is_empty());
+        // 표 끝 근처의 큰 구간
+        let near = sieve.primes_in_range(999_900..1_000_001);
+        assert_eq!(near.last(), Some(&999_983));
+    }
+
+    #[test]
+    #[should_panic(expected = "beyond the table limit")]
+    fn range_beyond_square_of_limit_panics() {
+        Sieve::new(10).primes_in_range(100..122);
+    }
+}
@@ -0,0 +1,183 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # 부분집합 합 변환 (SOS zeta)
+/// 비트마스크로 번호를 붙인 배열을 `f[S] ← Σ_{T ⊆ S} f[T]`로 바꿉니다. "모든 마스크에 대해 부분 마스크의 합" DP입니다.
+///
+/// * 비트마다 그 비트가 켜진 칸에 꺼진 칸을 더합니다.
+/// * 길이는 2의 거듭제곱이어야 합니다.
+/// * 덧셈은 wrapping이라서 중간에 넘쳐도 [`subset_mobius`]로 되돌리면 원래 배열이 나옵니다. 결과 자체가 i64에
+/// 들어가면 그 값은 정확합니다.
+/// ## 시간복잡도
+/// O(n · 2^n), n은 비트 수
+pub fn subset_zeta(f: &mut [i64]) {
+    subset_transform(f, i64::wrapping_add);
+}
+
+/// # 부분집합 뫼비우스 변환
+/// [`subset_zeta`]의 역변환 `f[S] ← Σ_{T ⊆ S} (-1)^{|S \ T|} f[T]`입니다. 길이는 2의 거듭제곱이어야 합니다.
+/// ## 시간복잡도
+/// O(n · 2^n)
+pub fn subset_mobius(f: &mut [i64]) {
+    subset_transform(f, i64::wrapping_sub);
+}
+
+/// # 상위집합 합 변환
+/// `f[S] ← Σ_{T ⊇ S} f[T]`입니다. 길이는 2의 거듭제곱이어야 합니다.
+/// ## 시간복잡도
+/// O(n · 2^n)
+pub fn superset_zeta(f: &mut [i64]) {
+    superset_transform(f, i64::wrapping_add);
+}
+
+/// # 상위집합 뫼비우스 변환
+/// [`superset_zeta`]의 역변환입니다. 길이는 2의 거듭제곱이어야 합니다.
+/// ## 시간복잡도
+/// O(n · 2^n)
+pub fn superset_mobius(f: &mut [i64]) {
+    superset_transform(f, i64::wrapping_sub);
+}
+
+/// # 부분집합 합성곱 (subset convolution)
+/// `h[S] = Σ_{T ⊆ S} f[T] · g[S \ T]`를 구합니다. 서로소인 두 집합으로 나누는 경우만 세므로, 집합 분할의 개수나
+/// 집합 덮기를 셀 때 씁니다.
+///
+/// * 원소 개수(popcount)별로 나눈 배열에 [`subset_zeta`]를 하고, 마스크마다 개수에 대한 다항식 곱을 한 뒤
+/// [`subset_mobius`]로 되돌립니다. 크기가 `|S|`인 항만 남기면 겹치지 않는 쌍만 남습니다.
+/// * f와 g의 길이는 같은 2의 거듭제곱이어야 합니다. 계산은 wrapping이라서 답이 i64에 들어가면 정확합니다.
+/// ## 시간복잡도
+/// O(n² · 2^n)
+pub fn subset_sum_convolution(f: &[i64], g: &[i64]) -> Vec<i64> {
+    assert_eq!(f.len(), g.len(), "lengths must match");
+    let len = f.len();
+    check_len(len);
+    let bits = len.trailing_zeros() as usize;
+    let ranked = |a: &[i64]| {
+        let mut by_rank = vec![vec![0i64; len]; bits + 1];
+        for (mask, &x) in a.iter().enumerate() {
+            by_rank[mask.count_ones() as usize][mask] = x;
+        }
+        by_rank.iter_mut().for_each(|row| subset_zeta(row));
+        by_rank
+    };
+    let (fr, gr) = (ranked(f), ranked(g));
+    let mut h = vec![vec![0i64; len]; bits + 1];
+    for mask in 0..len {
+        for i in 0..=bits {
+            for j in 0..=bits - i {
+                h[i + j][mask] = h[i + j][mask].wrapping_add(fr[i][mask].wrapping_mul(gr[j][mask]));
+            }
+        }
+    }
+    h.iter_mut().for_each(|row| subset_mobius(row));
+    (0..len)
+        .map(|mask| h[mask.count_ones() as usize][mask])
+        .collect()
+}
+
+fn check_len(len: usize) {
+    assert!(len.is_power_of_two(), "length {len} is not a power of two");
+}
+
+/// 켜진 칸을 `op(켜진 칸, 꺼진 칸)`으로 바꾸는 부분집합 방향 변환. op가 덧셈이면 zeta, 뺄셈이면 뫼비우스입니다.
+pub(super) fn subset_transform<T: Copy>(f: &mut [T], op: impl Fn(T, T) -> T) {
+    butterfly(f, |lo, hi| *hi = op(*hi, *lo));
+}
+
+/// 꺼진 칸을 `op(꺼진 칸, 켜진 칸)`으로 바꾸는 상위집합 방향 변환
+pub(super) fn superset_transform<T: Copy>(f: &mut [T], op: impl Fn(T, T) -> T) {
+    butterfly(f, |lo, hi| *lo = op(*lo, *hi));
+}
+
+/// 비트마다 (꺼진 칸, 켜진 칸) 쌍에 op를 적용. 길이가 2의 거듭제곱인지 확인합니다.
+pub(super) fn butterfly<T>(f: &mut [T], op: impl Fn(&mut T, &mut T)) {
+    check_len(f.len());
+    let mut half = 1;
+    while half < f.len() {
+        for block in f.chunks_exact_mut(2 * half) {
+            let (lo, hi) = block.split_at_mut(half);
+            for (l, h) in lo.iter_mut().zip(hi) {
+                op(l, h);
+            }
+        }
+        half *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn random_array(rng: &mut XorShift, len: usize) -> Vec<i64> {
+        (0..len).map(|_| rng.range_i64(-1000, 1000)).collect()
+    }
+
+    #[test]
+    fn transforms_against_double_loop() {
+        let mut rng = XorShift::new(196);
+        for bits in 0..=12 {
+            let len = 1usize << bits;
+            let f = random_array(&mut rng, len);
+            let subset: Vec<i64> = (0..len)
+                .map(|s| (0..len).filter(|&t| t & s == t).map(|t| f[t]).sum())
+                .collect();
+            let superset: Vec<i64> = (0..len)
+                .map(|s| (0..len).filter(|&t| t & s == s).map(|t| f[t]).sum())
+                .collect();
+            let mut a = f.clone();
+            subset_zeta(&mut a);
+            assert_eq!(a, subset);
+            subset_mobius(&mut a);
+            assert_eq!(a, f);
+            let mut b = f.clone();
+            superset_zeta(&mut b);
+            assert_eq!(b, superset);
+            superset_mobius(&mut b);
+            assert_eq!(b, f);
+            // 뫼비우스를 먼저 해도 되돌아옴
+            let mut c = f.clone();
+            subset_mobius(&mut c);
+            subset_zeta(&mut c);
+            assert_eq!(c, f);
+        }
+    }
+
+    #[test]
+    fn round_trip_survives_overflow() {
+        let mut rng = XorShift::new(1960);
+        let f: Vec<i64> = (0..1 << 10).map(|_| rng.next_u64() as i64).collect();
+        let mut a = f.clone();
+        subset_zeta(&mut a);
+        subset_mobius(&mut a);
+        assert_eq!(a, f);
+        superset_zeta(&mut a);
+        superset_mobius(&mut a);
+        assert_eq!(a, f);
+    }
+
+    #[test]
+    fn subset_convolution_against_brute_force() {
+        let mut rng = XorShift::new(1961);
+        for bits in 0..=10 {
+            let len = 1usize << bits;
+            let (f, g) = (random_array(&mut rng, len), random_array(&mut rng, len));
+            let expected: Vec<i64> = (0..len)
+                .map(|s| {
+                    (0..len)
+                        .filter(|&t| t & s == t)
+                        .map(|t| f[t] * g[s ^ t])
+                        .sum()
+                })
+                .collect();
+            assert_eq!(subset_sum_convolution(&f, &g), expected);
+        }
+        // 1, 2, 3을 두 묶음으로 나누는 방법(순서 있음, 빈 묶음 포함)은 2^3
+        assert_eq!(subset_sum_convolution(&[1; 8], &[1; 8])[7], 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a power of two")]
+    fn rejects_bad_length() {
+        subset_zeta(&mut [1, 2, 3]);
+    }
+}
@@ -0,0 +1,211 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::ModInt;
+use super::sos::{
+    butterfly, subset_mobius, subset_transform, subset_zeta, superset_mobius, superset_transform,
+    superset_zeta,
+};
+use std::ops::{Add, Sub};
+
+/// # XOR 합성곱
+/// `c[k] = Σ_{i ^ j = k} a[i] · b[j]`를 구합니다. "XOR이 k인 쌍의 개수" 같은 값을 셀 때 씁니다.
+///
+/// * Walsh–Hadamard 변환을 하고 칸마다 곱한 뒤 역변환합니다. 역변환은 같은 변환을 한 번 더 하고 길이로 나눕니다.
+/// * a와 b의 길이는 같은 2의 거듭제곱이어야 합니다.
+/// * 중간값은 i128로 계산합니다. `Σ|a| · Σ|b| · 길이 < 2^127`이면 넘치지 않고, 결과가 i64를 넘으면 panic합니다.
+/// ## 시간복잡도
+/// O(n · 2^n), n은 비트 수
+pub fn xor_convolution(a: &[i64], b: &[i64]) -> Vec<i64> {
+    let widen = |v: &[i64]| v.iter().map(|&x| x as i128).collect::<Vec<_>>();
+    let len = check_lens(a, b) as i128;
+    convolve(&widen(a), &widen(b), xor_forward, xor_forward, |x, y| x * y)
+        .into_iter()
+        .map(|x| i64::try_from(x / len).expect("xor convolution overflows i64"))
+        .collect()
+}
+
+/// # AND 합성곱
+/// `c[k] = Σ_{i & j = k} a[i] · b[j]`를 구합니다. [`superset_zeta`]로 바꿔 곱하고 [`superset_mobius`]로 되돌립니다.
+///
+/// * a와 b의 길이는 같은 2의 거듭제곱이어야 합니다.
+/// * 계산은 wrapping이라서 결과가 i64에 들어가면 중간에 넘쳐도 정확합니다.
+/// ## 시간복잡도
+/// O(n · 2^n)
+pub fn and_convolution(a: &[i64], b: &[i64]) -> Vec<i64> {
+    check_lens(a, b);
+    convolve(a, b, superset_zeta, superset_mobius, i64::wrapping_mul)
+}
+
+/// # OR 합성곱
+/// `c[k] = Σ_{i | j = k} a[i] · b[j]`를 구합니다. [`subset_zeta`]로 바꿔 곱하고 [`subset_mobius`]로 되돌립니다.
+///
+/// * a와 b의 길이는 같은 2의 거듭제곱이어야 합니다.
+/// * 계산은 wrapping이라서 결과가 i64에 들어가면 중간에 넘쳐도 정확합니다.
+/// ## 시간복잡도
+/// O(n · 2^n)
+pub fn or_convolution(a: &[i64], b: &[i64]) -> Vec<i64> {
+    check_lens(a, b);
+    convolve(a, b, subset_zeta, subset_mobius, i64::wrapping_mul)
+}
+
+/// # XOR 합성곱 (mod M)
+/// [`xor_convolution`]을 mod M으로 구합니다. 역변환에서 길이의 역원을 곱하므로 M은 홀수여야 합니다.
+/// ## 시간복잡도
+/// O(n · 2^n)
+pub fn xor_convolution_mod<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    let len = check_lens(a, b);
+    let inv_len = ModInt::<M>::from(len)
+        .checked_inv()
+        .unwrap_or_else(|| panic!("length {len} is not invertible modulo {M}"));
+    convolve(a, b, xor_forward, xor_forward, |x, y| x * y)
+        .into_iter()
+        .map(|x| x * inv_len)
+        .collect()
+}
+
+/// # AND 합성곱 (mod M)
+/// [`and_convolution`]을 mod M으로 구합니다. 나눗셈이 없어서 M은 아무 값이어도 됩니다.
+/// ## 시간복잡도
+/// O(n · 2^n)
+pub fn and_convolution_mod<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    check_lens(a, b);
+    convolve(
+        a,
+        b,
+        |f| superset_transform(f, Add::add),
+        |f| superset_transform(f, Sub::sub),
+        |x, y| x * y,
+    )
+}
+
+/// # OR 합성곱 (mod M)
+/// [`or_convolution`]을 mod M으로 구합니다. 나눗셈이 없어서 M은 아무 값이어도 됩니다.
+/// ## 시간복잡도
+/// O(n · 2^n)
+pub fn or_convolution_mod<const M: u32>(a: &[ModInt<M>], b: &[ModInt<M>]) -> Vec<ModInt<M>> {
+    check_lens(a, b);
+    convolve(
+        a,
+        b,
+        |f| subset_transform(f, Add::add),
+        |f| subset_transform(f, Sub::sub),
+        |x, y| x * y,
+    )
+}
+
+fn check_lens<T>(a: &[T], b: &[T]) -> usize {
+    assert_eq!(a.len(), b.len(), "lengths must match");
+    assert!(
+        a.len().is_power_of_two(),
+        "length {} is not a power of two",
+        a.len()
+    );
+    a.len()
+}
+
+/// 변환, 칸마다 곱, 역변환. 길이는 이미 확인했다고 가정
+fn convolve<T: Copy>(
+    a: &[T],
+    b: &[T],
+    forward: impl Fn(&mut [T]),
+    inverse: impl Fn(&mut [T]),
+    mul: impl Fn(T, T) -> T,
+) -> Vec<T> {
+    let (mut a, mut b) = (a.to_vec(), b.to_vec());
+    forward(&mut a);
+    forward(&mut b);
+    a.iter_mut().zip(&b).for_each(|(x, &y)| *x = mul(*x, y));
+    inverse(&mut a);
+    a
+}
+
+/// Walsh–Hadamard 변환. 역변환은 같은 변환 뒤에 길이로 나누기
+fn xor_forward<T: Copy + Add<Output = T> + Sub<Output = T>>(a: &mut [T]) {
+    butterfly(a, |x, y| (*x, *y) = (*x + *y, *x - *y));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::ModInt998244353;
+    use crate::random::XorShift;
+
+    type Mint = ModInt998244353;
+
+    fn brute(a: &[i64], b: &[i64], op: fn(usize, usize) -> usize) -> Vec<i64> {
+        let mut c = vec![0; a.len()];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                c[op(i, j)] += x * y;
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn all_operations_against_brute_force() {
+        let mut rng = XorShift::new(197);
+        for bits in 0..=10 {
+            for _ in 0..3 {
+                let len = 1usize << bits;
+                let a: Vec<i64> = (0..len).map(|_| rng.range_i64(-1000, 1000)).collect();
+                let b: Vec<i64> = (0..len).map(|_| rng.range_i64(-1000, 1000)).collect();
+                assert_eq!(xor_convolution(&a, &b), brute(&a, &b, |i, j| i ^ j));
+                assert_eq!(and_convolution(&a, &b), brute(&a, &b, |i, j| i & j));
+                assert_eq!(or_convolution(&a, &b), brute(&a, &b, |i, j| i | j));
+                let to_mint = |v: &[i64]| v.iter().map(|&x| Mint::from(x)).collect::<Vec<_>>();
+                let (am, bm) = (to_mint(&a), to_mint(&b));
+                assert_eq!(
+                    xor_convolution_mod(&am, &bm),
+                    to_mint(&xor_convolution(&a, &b))
+                );
+                assert_eq!(
+                    and_convolution_mod(&am, &bm),
+                    to_mint(&and_convolution(&a, &b))
+                );
+                assert_eq!(
+                    or_convolution_mod(&am, &bm),
+                    to_mint(&or_convolution(&a, &b))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn transforms_round_trip() {
+        let mut rng = XorShift::new(1970);
+        let a: Vec<Mint> = (0..1 << 8).map(|_| Mint::new(rng.next_u64())).collect();
+        let inv_len = Mint::from(a.len()).inv();
+        let mut x = a.clone();
+        xor_forward(&mut x);
+        xor_forward(&mut x);
+        assert_eq!(x.iter().map(|&v| v * inv_len).collect::<Vec<_>>(), a);
+        let mut y = a.clone();
+        superset_transform(&mut y, Add::add);
+        superset_transform(&mut y, Sub::sub);
+        assert_eq!(y, a);
+        let mut z = a.clone();
+        subset_transform(&mut z, Add::add);
+        subset_transform(&mut z, Sub::sub);
+        assert_eq!(z, a);
+    }
+
+    #[test]
+    fn pairs_with_xor_k() {
+        // 값의 개수 배열끼리 XOR 합성곱을 하면 (순서 있는) 쌍의 개수
+        let values = [1usize, 2, 3, 3, 6];
+        let mut count = vec![0i64; 8];
+        values.iter().for_each(|&v| count[v] += 1);
+        let pairs = xor_convolution(&count, &count);
+        assert_eq!(pairs[0], 1 + 1 + 4 + 1);
+        // 3 ^ 6 = 5: 3이 두 개, 6이 한 개이고 순서가 두 가지
+        assert_eq!(pairs[5], 4);
+        assert_eq!(xor_convolution(&[3], &[-4]), vec![-12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "not invertible")]
+    fn xor_mod_needs_odd_modulus() {
+        let a = [ModInt::<1024>::new(1); 4];
+        xor_convolution_mod(&a, &a);
+    }
+}
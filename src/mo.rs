@@ -0,0 +1,156 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Mo's algorithm
+/// 길이 n인 배열에 대한 구간 질의 `l..r`(0-based 반열림)를 오프라인으로 모아서, 구간을 한 칸씩 늘리고 줄이며
+/// 답합니다. 원소 하나를 넣고 빼는 것은 쉽지만 구간을 합치기는 어려운 질의(구간의 서로 다른 값 개수 등)에 씁니다.
+///
+/// * `add(&mut state, i)`는 i번째 원소가 구간에 들어올 때, `remove(&mut state, i)`는 나갈 때 불립니다.
+/// `answer(&state)`는 지금 구간이 질의 구간과 같을 때 불리고, 결과는 질의 순서대로 반환합니다.
+/// * 구간을 먼저 넓힌 다음 좁히므로, 상태가 "빈 구간보다 작은" 구간을 지나는 일은 없습니다.
+/// * 질의를 왼쪽 끝의 블록(크기 약 `n / √q`) 순으로 정렬하고, 블록 안에서는 오른쪽 끝 순으로 정렬합니다.
+/// 홀수 번째 블록은 오른쪽 끝을 거꾸로 정렬해서 오른쪽 포인터가 되돌아가는 거리를 줄입니다.
+/// ## 시간복잡도
+/// add/remove 호출 O(n √q + q), 정렬 O(q log q)
+pub fn mo_algorithm<S, Add, Remove, Answer, R>(
+    n: usize,
+    queries: &[(usize, usize)],
+    mut state: S,
+    mut add: Add,
+    mut remove: Remove,
+    mut answer: Answer,
+) -> Vec<R>
+where
+    Add: FnMut(&mut S, usize),
+    Remove: FnMut(&mut S, usize),
+    Answer: FnMut(&S) -> R,
+{
+    for &(l, r) in queries {
+        assert!(l <= r && r <= n, "invalid range {l}..{r} for length {n}");
+    }
+    let q = queries.len();
+    let block = (n / (q as f64).sqrt().max(1.0) as usize).max(1);
+    let mut order: Vec<usize> = (0..q).collect();
+    order.sort_unstable_by_key(|&i| {
+        let (l, r) = queries[i];
+        let b = l / block;
+        (b, if b & 1 == 0 { r } else { n - r })
+    });
+    let mut results: Vec<Option<R>> = (0..q).map(|_| None).collect();
+    let (mut cur_l, mut cur_r) = (0, 0);
+    for i in order {
+        let (l, r) = queries[i];
+        while cur_l > l {
+            cur_l -= 1;
+            add(&mut state, cur_l);
+        }
+        while cur_r < r {
+            add(&mut state, cur_r);
+            cur_r += 1;
+        }
+        while cur_l < l {
+            remove(&mut state, cur_l);
+            cur_l += 1;
+        }
+        while cur_r > r {
+            cur_r -= 1;
+            remove(&mut state, cur_r);
+        }
+        results[i] = Some(answer(&state));
+    }
+    results.into_iter().map(Option::unwrap).collect()
+}
+
+/// # 구간의 서로 다른 값 개수
+/// 각 질의 `l..r`마다 `values[l..r]`에 있는 서로 다른 값의 개수를 [`mo_algorithm`]으로 구합니다.
+/// ## 시간복잡도
+/// O(n log n + n √q + q log q)
+pub fn distinct_count_in_ranges(values: &[i64], queries: &[(usize, usize)]) -> Vec<usize> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let ranks: Vec<usize> = values
+        .iter()
+        .map(|x| sorted.binary_search(x).unwrap())
+        .collect();
+    // (값마다 구간 안에 있는 개수, 서로 다른 값 개수)
+    let state = (vec![0usize; sorted.len()], 0usize);
+    mo_algorithm(
+        values.len(),
+        queries,
+        state,
+        |(count, distinct), i| {
+            count[ranks[i]] += 1;
+            if count[ranks[i]] == 1 {
+                *distinct += 1;
+            }
+        },
+        |(count, distinct), i| {
+            count[ranks[i]] -= 1;
+            if count[ranks[i]] == 0 {
+                *distinct -= 1;
+            }
+        },
+        |&(_, distinct)| distinct,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use std::collections::HashSet;
+
+    #[test]
+    fn random_distinct_against_brute_force() {
+        let mut rng = XorShift::new(145);
+        for _ in 0..100 {
+            let n = rng.index(60);
+            let spread = 1 + rng.range_i64(0, 20);
+            let values: Vec<i64> = (0..n).map(|_| rng.range_i64(-spread, spread)).collect();
+            let mut queries = vec![(0, n), (n, n), (0, 0)];
+            for _ in 0..rng.index(80) {
+                let l = rng.index(n + 1);
+                let r = l + rng.index(n + 1 - l);
+                queries.push((l, r));
+            }
+            let answers = distinct_count_in_ranges(&values, &queries);
+            for (&(l, r), &answer) in queries.iter().zip(&answers) {
+                let expected = values[l..r].iter().collect::<HashSet<_>>().len();
+                assert_eq!(answer, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn window_never_goes_negative_and_sums_match() {
+        // 상태로 구간 합과 구간 길이를 관리하며, 길이가 음수가 되지 않는지 확인
+        let mut rng = XorShift::new(1450);
+        let n = 500;
+        let values: Vec<i64> = (0..n).map(|_| rng.range_i64(-1000, 1000)).collect();
+        let queries: Vec<(usize, usize)> = (0..2000)
+            .map(|_| {
+                let l = rng.index(n + 1);
+                (l, l + rng.index(n + 1 - l))
+            })
+            .collect();
+        let answers = mo_algorithm(
+            n,
+            &queries,
+            (0i64, 0usize),
+            |(sum, len), i| {
+                *sum += values[i];
+                *len += 1;
+            },
+            |(sum, len), i| {
+                *sum -= values[i];
+                *len -= 1;
+            },
+            |&(sum, len)| (sum, len),
+        );
+        for (&(l, r), &(sum, len)) in queries.iter().zip(&answers) {
+            assert_eq!(len, r - l);
+            assert_eq!(sum, values[l..r].iter().sum::<i64>());
+        }
+        assert!(mo_algorithm(0, &[], (), |_, _| {}, |_, _| {}, |_| 0).is_empty());
+    }
+}
@@ -0,0 +1,9 @@
+#![allow(clippy::doc_lazy_continuation)]
+mod diff;
+pub use diff::{Diff1D, Diff2D};
+mod kadane;
+pub use kadane::max_subarray_sum;
+mod one_d;
+pub use one_d::PrefixSum;
+mod two_d;
+pub use two_d::PrefixSum2D;
@@ -0,0 +1,213 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::AbelianGroup;
+use std::ops::Range;
+
+/// # 차분 배열
+/// 구간에 값을 더하는 연산을 모아 두었다가 마지막에 한 번에 배열로 만듭니다. 중간에 값을 읽을 필요가 없을 때
+/// [`RangeFenwick`](crate::fenwick::RangeFenwick) 대신 씁니다.
+///
+/// * 구간은 [`PrefixSum`](super::PrefixSum)과 같은 0-based 반열림입니다. `add(l..r, v)`는 `d[l] += v`,
+/// `d[r] -= v`이고, `build`는 d의 누적 합입니다.
+/// * `build`는 상태를 바꾸지 않으므로 그 뒤에도 `add`할 수 있습니다. 다만 이미 만든 배열에는 반영되지 않으니 다시
+/// `build`해야 합니다.
+/// ## 시간복잡도
+/// `add` O(1), `build` O(n)
+#[derive(Clone, Debug)]
+pub struct Diff1D<T = i64> {
+    diff: Vec<T>,
+}
+
+impl<T: AbelianGroup> Diff1D<T> {
+    /// 모든 값이 항등원인 길이 n
+    pub fn new(n: usize) -> Self {
+        Diff1D {
+            diff: vec![T::zero(); n + 1],
+        }
+    }
+
+    /// 배열의 길이
+    pub fn len(&self) -> usize {
+        self.diff.len() - 1
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 구간 `l..r`의 모든 값에 v를 더함
+    pub fn add(&mut self, range: Range<usize>, v: T) {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.len(),
+            "invalid range {l}..{r} for length {}",
+            self.len()
+        );
+        self.diff[l] = T::add(&self.diff[l], &v);
+        self.diff[r] = T::sub(&self.diff[r], &v);
+    }
+
+    /// 지금까지 더한 값을 반영한 배열
+    pub fn build(&self) -> Vec<T> {
+        let mut acc = T::zero();
+        self.diff[..self.len()]
+            .iter()
+            .map(|d| {
+                acc = T::add(&acc, d);
+                acc.clone()
+            })
+            .collect()
+    }
+}
+
+/// # 2차원 차분 배열
+/// 직사각형에 값을 더하는 연산을 모아 두었다가 마지막에 한 번에 격자로 만듭니다.
+///
+/// * 직사각형은 [`PrefixSum2D`](super::PrefixSum2D)와 같은 0-based 반열림입니다. `add_rect(r1..r2, c1..c2, v)`는
+/// `(r1, c1)`과 `(r2, c2)`에 v를 더하고 `(r1, c2)`와 `(r2, c1)`에서 빼며, `build`는 2차원 누적 합입니다.
+/// * 마지막 행/열까지 닿는 직사각형도 빼는 칸이 생기도록 내부 배열은 `(rows + 1) × (cols + 1)`입니다.
+/// * [`Diff1D`]처럼 `build` 뒤에도 `add_rect`할 수 있고, 반영하려면 다시 `build`합니다.
+/// ## 시간복잡도
+/// `add_rect` O(1), `build` O(rows · cols)
+#[derive(Clone, Debug)]
+pub struct Diff2D<T = i64> {
+    rows: usize,
+    cols: usize,
+    diff: Vec<T>,
+}
+
+impl<T: AbelianGroup> Diff2D<T> {
+    /// 모든 칸이 항등원인 rows × cols 격자
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Diff2D {
+            rows,
+            cols,
+            diff: vec![T::zero(); (rows + 1) * (cols + 1)],
+        }
+    }
+
+    /// 행의 개수
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 열의 개수
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// 직사각형 `rows × cols`의 모든 칸에 v를 더함
+    pub fn add_rect(&mut self, rows: Range<usize>, cols: Range<usize>, v: T) {
+        let Range { start: r1, end: r2 } = rows;
+        let Range { start: c1, end: c2 } = cols;
+        assert!(
+            r1 <= r2 && r2 <= self.rows && c1 <= c2 && c2 <= self.cols,
+            "invalid rectangle {r1}..{r2} x {c1}..{c2} for {}x{} grid",
+            self.rows,
+            self.cols
+        );
+        if r1 == r2 || c1 == c2 {
+            return;
+        }
+        let w = self.cols + 1;
+        for (r, c, plus) in [
+            (r1, c1, true),
+            (r1, c2, false),
+            (r2, c1, false),
+            (r2, c2, true),
+        ] {
+            let cell = &mut self.diff[r * w + c];
+            *cell = if plus {
+                T::add(cell, &v)
+            } else {
+                T::sub(cell, &v)
+            };
+        }
+    }
+
+    /// 지금까지 더한 값을 반영한 격자
+    pub fn build(&self) -> Vec<Vec<T>> {
+        let w = self.cols + 1;
+        let mut grid: Vec<Vec<T>> = Vec::with_capacity(self.rows);
+        for r in 0..self.rows {
+            let mut row = Vec::with_capacity(self.cols);
+            let mut acc = T::zero();
+            for c in 0..self.cols {
+                acc = T::add(&acc, &self.diff[r * w + c]);
+                let above = grid.last().map_or(T::zero(), |prev| prev[c].clone());
+                row.push(T::add(&above, &acc));
+            }
+            grid.push(row);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_1d_against_direct_updates() {
+        let mut rng = XorShift::new(202);
+        for _ in 0..200 {
+            let n = rng.index(30);
+            let mut diff = Diff1D::new(n);
+            let mut model = vec![0i64; n];
+            for _ in 0..20 {
+                let l = rng.index(n + 1);
+                let r = l + rng.index(n + 1 - l);
+                let v = rng.range_i64(-100, 100);
+                diff.add(l..r, v);
+                model[l..r].iter_mut().for_each(|x| *x += v);
+            }
+            assert_eq!(diff.build(), model);
+            // build 뒤에 더해도 다시 build하면 반영됨
+            diff.add(0..n, 1);
+            model.iter_mut().for_each(|x| *x += 1);
+            assert_eq!(diff.build(), model);
+        }
+    }
+
+    #[test]
+    fn random_2d_against_direct_updates() {
+        let mut rng = XorShift::new(2020);
+        for _ in 0..200 {
+            let (rows, cols) = (rng.index(8), rng.index(8));
+            let mut diff = Diff2D::new(rows, cols);
+            let mut model = vec![vec![0i64; cols]; rows];
+            for _ in 0..20 {
+                let r1 = rng.index(rows + 1);
+                let r2 = r1 + rng.index(rows + 1 - r1);
+                let c1 = rng.index(cols + 1);
+                let c2 = c1 + rng.index(cols + 1 - c1);
+                let v = rng.range_i64(-100, 100);
+                diff.add_rect(r1..r2, c1..c2, v);
+                for row in &mut model[r1..r2] {
+                    row[c1..c2].iter_mut().for_each(|x| *x += v);
+                }
+            }
+            assert_eq!(diff.build(), model);
+        }
+    }
+
+    #[test]
+    fn full_grid_and_single_cells() {
+        let mut diff = Diff2D::new(3, 4);
+        diff.add_rect(0..3, 0..4, 5);
+        diff.add_rect(2..3, 3..4, 1);
+        diff.add_rect(0..1, 0..1, -5);
+        diff.add_rect(1..1, 0..4, 100);
+        assert_eq!(
+            diff.build(),
+            vec![vec![0, 5, 5, 5], vec![5, 5, 5, 5], vec![5, 5, 5, 6]]
+        );
+        assert_eq!((diff.rows(), diff.cols()), (3, 4));
+        let mut line = Diff1D::new(3);
+        line.add(2..3, 7);
+        line.add(0..3, 1);
+        assert_eq!(line.build(), vec![1, 1, 8]);
+        assert!(Diff1D::<i64>::new(0).build().is_empty());
+    }
+}
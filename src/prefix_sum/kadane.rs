@@ -0,0 +1,41 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # 최대 부분 배열 합 (Kadane)
+/// 비어 있지 않은 연속 부분 배열의 합 중 가장 큰 값을 구합니다. 배열이 비었으면 None입니다.
+///
+/// * 각 위치에서 끝나는 최대 합은 `max(a[i], 앞 위치에서 끝나는 최대 합 + a[i])`입니다.
+/// * 모든 값이 음수이면 가장 큰 원소 하나가 답입니다. 합은 i64로 계산하므로 넘치지 않아야 합니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn max_subarray_sum(values: &[i64]) -> Option<i64> {
+    let (&first, rest) = values.split_first()?;
+    let (mut ending_here, mut best) = (first, first);
+    for &x in rest {
+        ending_here = x.max(ending_here + x);
+        best = best.max(ending_here);
+    }
+    Some(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_all_subarrays() {
+        let mut rng = XorShift::new(2011);
+        for _ in 0..300 {
+            let n = rng.index(30);
+            let a: Vec<i64> = (0..n).map(|_| rng.range_i64(-20, 10)).collect();
+            let expected = (0..n)
+                .flat_map(|l| (l + 1..=n).map(move |r| (l, r)))
+                .map(|(l, r)| a[l..r].iter().sum())
+                .max();
+            assert_eq!(max_subarray_sum(&a), expected);
+        }
+        assert_eq!(max_subarray_sum(&[]), None);
+        assert_eq!(max_subarray_sum(&[-5, -2, -9]), Some(-2));
+        assert_eq!(max_subarray_sum(&[2, -1, 2, -5, 4]), Some(4));
+    }
+}
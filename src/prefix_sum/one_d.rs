@@ -0,0 +1,110 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::AbelianGroup;
+use std::ops::{Range, RangeInclusive};
+
+/// # 누적 합
+/// 바뀌지 않는 배열의 구간 합을 O(1)에 구합니다. 값이 바뀌면 [`Fenwick`](crate::fenwick::Fenwick)을 씁니다.
+///
+/// * 구간은 Fenwick과 같은 0-based 반열림이고, `sum(l..r)`은 `a[l] + … + a[r - 1]`입니다. 경계를 포함하는
+/// `sum_inclusive(l..=r)`도 있습니다.
+/// * 값의 타입은 [`AbelianGroup`]이면 됩니다. 10^5개의 10^9처럼 합이 i64에 들어가면 i64를 그대로 쓰고, 넘칠 수
+/// 있으면 `from_fn(n, |i| a[i] as i128)`처럼 더 넓은 타입으로 만듭니다.
+/// ## 시간복잡도
+/// 생성 O(n), 질의 O(1)
+#[derive(Clone, Debug)]
+pub struct PrefixSum<T = i64> {
+    /// `prefix[i] = a[0] + … + a[i - 1]`
+    prefix: Vec<T>,
+}
+
+impl<T: AbelianGroup> PrefixSum<T> {
+    /// 배열로 생성
+    pub fn new(values: &[T]) -> Self {
+        Self::from_fn(values.len(), |i| values[i].clone())
+    }
+
+    /// i번째 값이 `f(i)`인 길이 n의 배열로 생성
+    pub fn from_fn(n: usize, mut f: impl FnMut(usize) -> T) -> Self {
+        let mut prefix = Vec::with_capacity(n + 1);
+        prefix.push(T::zero());
+        for i in 0..n {
+            prefix.push(T::add(&prefix[i], &f(i)));
+        }
+        PrefixSum { prefix }
+    }
+
+    /// 배열의 길이
+    pub fn len(&self) -> usize {
+        self.prefix.len() - 1
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 구간 `l..r`의 합. 빈 구간은 항등원이고, `l > r`이거나 범위를 벗어나면 panic합니다.
+    pub fn sum(&self, range: Range<usize>) -> T {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.len(),
+            "invalid range {l}..{r} for length {}",
+            self.len()
+        );
+        T::sub(&self.prefix[r], &self.prefix[l])
+    }
+
+    /// 구간 `l..=r`의 합
+    pub fn sum_inclusive(&self, range: RangeInclusive<usize>) -> T {
+        let (l, r) = range.into_inner();
+        assert!(
+            r < self.len(),
+            "invalid range {l}..={r} for length {}",
+            self.len()
+        );
+        self.sum(l..r + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_naive_sums() {
+        let mut rng = XorShift::new(201);
+        for _ in 0..100 {
+            let n = rng.index(40);
+            let a: Vec<i64> = (0..n).map(|_| rng.range_i64(-1000, 1000)).collect();
+            let ps = PrefixSum::new(&a);
+            assert_eq!(ps.len(), n);
+            for l in 0..=n {
+                for r in l..=n {
+                    assert_eq!(ps.sum(l..r), a[l..r].iter().sum::<i64>());
+                    if l < r {
+                        assert_eq!(ps.sum_inclusive(l..=r - 1), ps.sum(l..r));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn widened_sums_avoid_overflow() {
+        let a = vec![i64::MAX; 100_000];
+        let ps = PrefixSum::<i128>::from_fn(a.len(), |i| a[i] as i128);
+        assert_eq!(ps.sum(0..a.len()), i64::MAX as i128 * 100_000);
+        assert_eq!(ps.sum(5..5), 0);
+        let b = vec![1_000_000_000i64; 100_000];
+        assert_eq!(PrefixSum::new(&b).sum(0..100_000), 100_000_000_000_000);
+        assert!(PrefixSum::<i64>::new(&[]).is_empty());
+        assert_eq!(PrefixSum::<i64>::new(&[]).sum(0..0), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid range 1..4")]
+    fn out_of_range_panics() {
+        PrefixSum::new(&[1, 2, 3]).sum(1..4);
+    }
+}
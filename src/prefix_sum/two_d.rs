@@ -0,0 +1,137 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::AbelianGroup;
+use std::ops::{Range, RangeInclusive};
+
+/// # 2차원 누적 합
+/// 바뀌지 않는 rows × cols 격자의 직사각형 합을 O(1)에 구합니다.
+///
+/// * 구간은 [`PrefixSum`](super::PrefixSum)과 같은 0-based 반열림입니다. `sum_rect(r1..r2, c1..c2)`는
+/// `r1 <= r < r2`, `c1 <= c < c2`인 칸의 합입니다.
+/// * `(rows + 1) × (cols + 1)` 크기의 배열 하나에 `(0, 0)`부터의 직사각형 합을 두고 포함-배제로 구합니다.
+/// * 합이 넘칠 수 있으면 `from_fn`으로 더 넓은 타입(i128 등)의 격자를 만듭니다.
+/// ## 시간복잡도
+/// 생성 O(rows · cols), 질의 O(1)
+#[derive(Clone, Debug)]
+pub struct PrefixSum2D<T = i64> {
+    rows: usize,
+    cols: usize,
+    prefix: Vec<T>,
+}
+
+impl<T: AbelianGroup> PrefixSum2D<T> {
+    /// 행렬로 생성합니다. 모든 행의 길이가 같아야 합니다.
+    pub fn new(matrix: &[Vec<T>]) -> Self {
+        let cols = matrix.first().map_or(0, Vec::len);
+        for row in matrix {
+            assert_eq!(row.len(), cols, "matrix rows must have the same length");
+        }
+        Self::from_fn(matrix.len(), cols, |r, c| matrix[r][c].clone())
+    }
+
+    /// `(r, c)`의 값이 `f(r, c)`인 rows × cols 격자로 생성
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let w = cols + 1;
+        let mut prefix = vec![T::zero(); (rows + 1) * w];
+        for r in 0..rows {
+            for c in 0..cols {
+                let above = T::add(&prefix[r * w + c + 1], &prefix[(r + 1) * w + c]);
+                let value = T::add(&T::sub(&above, &prefix[r * w + c]), &f(r, c));
+                prefix[(r + 1) * w + c + 1] = value;
+            }
+        }
+        PrefixSum2D { rows, cols, prefix }
+    }
+
+    /// 행의 개수
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 열의 개수
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// 직사각형 `rows × cols`의 합. 빈 직사각형은 항등원입니다.
+    pub fn sum_rect(&self, rows: Range<usize>, cols: Range<usize>) -> T {
+        let Range { start: r1, end: r2 } = rows;
+        let Range { start: c1, end: c2 } = cols;
+        assert!(
+            r1 <= r2 && r2 <= self.rows && c1 <= c2 && c2 <= self.cols,
+            "invalid rectangle {r1}..{r2} x {c1}..{c2} for {}x{} grid",
+            self.rows,
+            self.cols
+        );
+        let at = |r: usize, c: usize| &self.prefix[r * (self.cols + 1) + c];
+        let outer = T::add(at(r2, c2), at(r1, c1));
+        T::sub(&T::sub(&outer, at(r1, c2)), at(r2, c1))
+    }
+
+    /// 경계를 포함하는 직사각형 `r1..=r2 × c1..=c2`의 합
+    pub fn sum_rect_inclusive(
+        &self,
+        rows: RangeInclusive<usize>,
+        cols: RangeInclusive<usize>,
+    ) -> T {
+        let ((r1, r2), (c1, c2)) = (rows.into_inner(), cols.into_inner());
+        assert!(
+            r2 < self.rows && c2 < self.cols,
+            "invalid rectangle {r1}..={r2} x {c1}..={c2} for {}x{} grid",
+            self.rows,
+            self.cols
+        );
+        self.sum_rect(r1..r2 + 1, c1..c2 + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_naive_sums() {
+        let mut rng = XorShift::new(2010);
+        for _ in 0..50 {
+            let (rows, cols) = (1 + rng.index(8), 1 + rng.index(8));
+            let grid: Vec<Vec<i64>> = (0..rows)
+                .map(|_| (0..cols).map(|_| rng.range_i64(-100, 100)).collect())
+                .collect();
+            let ps = PrefixSum2D::new(&grid);
+            for r1 in 0..=rows {
+                for r2 in r1..=rows {
+                    for c1 in 0..=cols {
+                        for c2 in c1..=cols {
+                            let expected: i64 = grid[r1..r2]
+                                .iter()
+                                .map(|row| row[c1..c2].iter().sum::<i64>())
+                                .sum();
+                            assert_eq!(ps.sum_rect(r1..r2, c1..c2), expected);
+                            if r1 < r2 && c1 < c2 {
+                                assert_eq!(
+                                    ps.sum_rect_inclusive(r1..=r2 - 1, c1..=c2 - 1),
+                                    expected
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_row_column_and_overflow() {
+        let row = PrefixSum2D::new(&[vec![1, 2, 3, 4]]);
+        assert_eq!((row.rows(), row.cols()), (1, 4));
+        assert_eq!(row.sum_rect(0..1, 1..3), 5);
+        let column = PrefixSum2D::new(&[vec![1], vec![2], vec![3]]);
+        assert_eq!(column.sum_rect(1..3, 0..1), 5);
+        assert_eq!(column.sum_rect(0..3, 0..0), 0);
+        let big = PrefixSum2D::<i128>::from_fn(1000, 1000, |_, _| i64::MAX as i128);
+        assert_eq!(big.sum_rect(0..1000, 0..1000), i64::MAX as i128 * 1_000_000);
+        assert_eq!(big.sum_rect_inclusive(3..=3, 7..=7), i64::MAX as i128);
+        let empty = PrefixSum2D::<i64>::new(&[]);
+        assert_eq!(empty.sum_rect(0..0, 0..0), 0);
+    }
+}
@@ -0,0 +1,68 @@
+/// # XorShift
+/// 외부 crate 없이 쓰는 xorshift64 기반 의사난수 생성기.
+/// seed가 같으면 항상 같은 수열을 만들기 때문에 재현 가능한 무작위 테스트에 사용합니다.
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// seed로 생성. seed가 0이면 xorshift가 0에 갇히므로 다른 값으로 바꿔서 사용합니다.
+    pub fn new(seed: u64) -> Self {
+        XorShift {
+            state: if seed == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                seed
+            },
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// [0, n) 범위의 정수. n은 0보다 커야 합니다.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "below(0) is empty range");
+        self.next_u64() % n
+    }
+
+    /// [lo, hi] 범위의 정수 (양 끝 포함)
+    pub fn range_i64(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "empty range {lo}..={hi}");
+        let width = (hi as i128 - lo as i128 + 1) as u128;
+        (lo as i128 + (self.next_u64() as u128 % width) as i128) as i64
+    }
+
+    /// [0, n) 범위의 usize
+    pub fn index(&mut self, n: usize) -> usize {
+        self.below(n as u64) as usize
+    }
+}
+
+/// 정점 v(v >= 1)의 부모를 0..v에서 무작위로 골라 만든 트리의 무방향 인접 리스트
+pub fn random_tree(rng: &mut XorShift, n: usize) -> Vec<Vec<usize>> {
+    let mut adj = vec![Vec::new(); n];
+    for v in 1..n {
+        let p = rng.index(v);
+        adj[p].push(v);
+        adj[v].push(p);
+    }
+    adj
+}
+
+/// `first..first + alphabet` 범위의 바이트 n개로 된 무작위 문자열
+pub fn random_bytes(rng: &mut XorShift, n: usize, first: u8, alphabet: u64) -> Vec<u8> {
+    assert!(
+        first as u64 + alphabet <= 256,
+        "alphabet does not fit in u8"
+    );
+    (0..n)
+        .map(|_| (first as u64 + rng.below(alphabet)) as u8)
+        .collect()
+}
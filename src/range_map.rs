@@ -0,0 +1,239 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// # Range map (Chtholly tree)
+/// 위치 `0..n`을 값이 같은 최대 구간들로 나눠 관리합니다. 구간 칠하기 `assign(l..r, v)`와, 구간 안의 조각마다
+/// 값을 바꾸는 [`for_each_range_mut`](Self::for_each_range_mut)를 지원합니다.
+///
+/// * 각 조각의 시작 위치를 키로 하는 `BTreeMap`입니다. 조각은 다음 키(또는 n) 직전까지 이어집니다.
+/// * 연산 전에 l과 r에서 조각을 잘라 두고, 연산 뒤에는 값이 같은 이웃 조각을 합쳐서 항상 최대 구간을 유지합니다.
+/// * 구간은 0-based 반열림입니다.
+/// ## 시간복잡도
+/// `get` O(log k), `assign`은 지운 조각 수 m에 대해 O((m + 1) log k). k는 조각 수.
+/// 칠하기만 섞여 있으면 전체 조각 수가 amortized로 제한되어서, 무작위 입력에서 `for_each_range_mut`도 빠릅니다.
+#[derive(Clone, Debug)]
+pub struct RangeMap<V> {
+    n: usize,
+    starts: BTreeMap<usize, V>,
+}
+
+impl<V: Clone + Eq> RangeMap<V> {
+    /// 모든 위치가 init인 길이 n
+    pub fn new(n: usize, init: V) -> Self {
+        let mut starts = BTreeMap::new();
+        if n > 0 {
+            starts.insert(0, init);
+        }
+        RangeMap { n, starts }
+    }
+
+    /// 위치의 개수
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// 최대 구간(조각)의 개수
+    pub fn range_count(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// i번째 위치의 값
+    pub fn get(&self, i: usize) -> &V {
+        assert!(i < self.n, "index {i} out of range for length {}", self.n);
+        self.starts.range(..=i).next_back().unwrap().1
+    }
+
+    /// 구간 `l..r`의 모든 위치를 v로
+    pub fn assign(&mut self, range: Range<usize>, v: V) {
+        let (l, r) = self.check(range);
+        if l == r {
+            return;
+        }
+        self.split_at(l);
+        self.split_at(r);
+        let inside: Vec<usize> = self.starts.range(l..r).map(|(&k, _)| k).collect();
+        for k in inside {
+            self.starts.remove(&k);
+        }
+        self.starts.insert(l, v);
+        self.merge_around(l, r);
+    }
+
+    /// 구간 `l..r`을 덮는 조각들을 `l..r`로 잘라서 `(위치 구간, 값)` 순서대로
+    pub fn iter_ranges(
+        &self,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = (Range<usize>, &V)> + '_ {
+        let (l, r) = self.check(range);
+        let first = if l < r {
+            self.starts.range(..=l).next_back().map(|(&k, _)| k)
+        } else {
+            None
+        };
+        let mut pieces = first
+            .map(|first| self.starts.range(first..r))
+            .into_iter()
+            .flatten()
+            .peekable();
+        std::iter::from_fn(move || {
+            let (&start, value) = pieces.next()?;
+            let end = pieces.peek().map_or(self.n, |&(&k, _)| k);
+            Some((start.max(l)..end.min(r), value))
+        })
+    }
+
+    /// 구간 `l..r` 안의 조각마다 `f(위치 구간, &mut 값)`을 부름. 끝나면 값이 같아진 이웃 조각을 합칩니다.
+    pub fn for_each_range_mut(
+        &mut self,
+        range: Range<usize>,
+        mut f: impl FnMut(Range<usize>, &mut V),
+    ) {
+        let (l, r) = self.check(range);
+        if l == r {
+            return;
+        }
+        self.split_at(l);
+        self.split_at(r);
+        let mut pieces = self.starts.range_mut(l..r).peekable();
+        while let Some((&start, value)) = pieces.next() {
+            let end = pieces.peek().map_or(r, |&(&k, _)| k);
+            f(start..end, value);
+        }
+        self.merge_around(l, r);
+    }
+
+    fn check(&self, range: Range<usize>) -> (usize, usize) {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.n,
+            "invalid range {l}..{r} for length {}",
+            self.n
+        );
+        (l, r)
+    }
+
+    /// pos에서 조각이 시작하도록 자름
+    fn split_at(&mut self, pos: usize) {
+        if pos >= self.n || self.starts.contains_key(&pos) {
+            return;
+        }
+        let value = self.starts.range(..pos).next_back().unwrap().1.clone();
+        self.starts.insert(pos, value);
+    }
+
+    /// l 바로 앞 조각부터 r에서 시작하는 조각까지, 앞 조각과 값이 같은 조각을 합침
+    fn merge_around(&mut self, l: usize, r: usize) {
+        let Some((&first, _)) = self.starts.range(..l).next_back() else {
+            return self.merge_from(l, r);
+        };
+        self.merge_from(first, r);
+    }
+
+    fn merge_from(&mut self, from: usize, r: usize) {
+        let keys: Vec<usize> = self.starts.range(from..=r).map(|(&k, _)| k).collect();
+        let mut prev = None;
+        for k in keys {
+            if let Some(p) = prev
+                && self.starts[&p] == self.starts[&k]
+            {
+                self.starts.remove(&k);
+                continue;
+            }
+            prev = Some(k);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 조각이 서로 겹치지 않고 0..n을 덮으며, 이웃 조각의 값이 다른지
+    fn assert_maximal(map: &RangeMap<i64>, model: &[i64]) {
+        let pieces: Vec<(Range<usize>, i64)> = map
+            .iter_ranges(0..model.len())
+            .map(|(r, &v)| (r, v))
+            .collect();
+        let mut next = 0;
+        for (i, (range, v)) in pieces.iter().enumerate() {
+            assert_eq!(range.start, next);
+            assert!(range.start < range.end);
+            assert!(model[range.clone()].iter().all(|x| x == v));
+            if i > 0 {
+                assert_ne!(pieces[i - 1].1, *v);
+            }
+            next = range.end;
+        }
+        assert_eq!(next, model.len());
+        assert_eq!(pieces.len(), map.range_count());
+    }
+
+    #[test]
+    fn random_against_plain_vec() {
+        let mut rng = XorShift::new(152);
+        for _ in 0..100 {
+            let n = rng.index(60);
+            let mut map = RangeMap::new(n, 0i64);
+            let mut model = vec![0i64; n];
+            for _ in 0..200 {
+                let l = rng.index(n + 1);
+                let r = l + rng.index(n + 1 - l);
+                match rng.below(4) {
+                    0 | 1 => {
+                        let v = rng.range_i64(0, 3);
+                        map.assign(l..r, v);
+                        model[l..r].iter_mut().for_each(|x| *x = v);
+                    }
+                    2 => {
+                        // 구간마다 더하거나 3으로 나눈 나머지로 바꾸기
+                        let add = rng.range_i64(-1, 1);
+                        map.for_each_range_mut(l..r, |_, v| *v = (*v + add).rem_euclid(3));
+                        model[l..r]
+                            .iter_mut()
+                            .for_each(|x| *x = (*x + add).rem_euclid(3));
+                    }
+                    _ => {
+                        let got: Vec<i64> = map
+                            .iter_ranges(l..r)
+                            .flat_map(|(range, &v)| std::iter::repeat_n(v, range.len()))
+                            .collect();
+                        assert_eq!(got, model[l..r]);
+                    }
+                }
+                for (i, x) in model.iter().enumerate() {
+                    assert_eq!(map.get(i), x);
+                }
+                assert_maximal(&map, &model);
+            }
+        }
+    }
+
+    #[test]
+    fn iter_ranges_are_clipped() {
+        let mut map = RangeMap::new(10, 'a');
+        map.assign(2..5, 'b');
+        map.assign(7..9, 'c');
+        map.assign(3..4, 'b');
+        assert_eq!(map.range_count(), 5);
+        let pieces: Vec<_> = map.iter_ranges(3..8).map(|(r, &v)| (r, v)).collect();
+        assert_eq!(pieces, vec![(3..5, 'b'), (5..7, 'a'), (7..8, 'c')]);
+        assert_eq!(map.iter_ranges(4..4).count(), 0);
+        map.assign(0..10, 'a');
+        assert_eq!(map.range_count(), 1);
+        let mut seen = Vec::new();
+        map.for_each_range_mut(2..6, |range, v| {
+            seen.push(range);
+            *v = 'z';
+        });
+        assert_eq!(seen, vec![2..6]);
+        assert_eq!(map.range_count(), 3);
+        assert!(RangeMap::new(0, 0).is_empty());
+    }
+}
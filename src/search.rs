@@ -0,0 +1,9 @@
+#![allow(clippy::doc_lazy_continuation)]
+mod binary_search;
+pub use binary_search::{Precision, bisect_f64, partition_point_i64};
+mod slice_bisect;
+pub use slice_bisect::SliceBisect;
+mod ternary;
+pub use ternary::{
+    ternary_search_max_f64, ternary_search_max_i64, ternary_search_min_f64, ternary_search_min_i64,
+};
@@ -0,0 +1,119 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # 정수 이분 탐색 (partition point)
+/// 구간 `lo..hi`(반열림)에서 pred가 앞부분에서만 true일 때, pred가 false인 첫 값을 구합니다. 모두 true이면 hi,
+/// 모두 false이면 lo입니다. "답을 이분 탐색"할 때 답의 가능 여부가 단조로우면 씁니다.
+///
+/// * `slice::partition_point`와 같은 규칙이라서, "조건을 만족하는 가장 작은 x"를 찾으려면 `|x| !ok(x)`를, "조건을
+/// 만족하는 가장 큰 x"는 `|x| ok(x)`의 결과에서 1을 뺍니다.
+/// * 가운데 값은 i128로 `⌊(lo + hi) / 2⌋`를 구하므로 `i64::MIN..i64::MAX`에서도 넘치지 않습니다.
+/// * pred는 `lo..hi` 안의 값에서만 불립니다. `lo > hi`이면 panic합니다.
+/// ## 시간복잡도
+/// pred를 O(log(hi - lo))번 부름
+pub fn partition_point_i64(mut lo: i64, mut hi: i64, mut pred: impl FnMut(i64) -> bool) -> i64 {
+    assert!(lo <= hi, "invalid range {lo}..{hi}");
+    while lo < hi {
+        let mid = (lo as i128 + hi as i128).div_euclid(2) as i64;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// [`bisect_f64`]를 멈추는 기준
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Precision {
+    /// 정확히 이 횟수만큼 반으로 나눔. 실행마다 같은 결과가 나오므로 이쪽을 권합니다.
+    Iters(u32),
+    /// 구간의 길이가 이 값 이하가 될 때까지 나눔. 부동소수점의 한계로 줄어들지 않는 경우를 막으려고 최대 200번만
+    /// 나눕니다.
+    Eps(f64),
+}
+
+/// # 실수 이분 탐색
+/// `[lo, hi]`에서 pred가 어떤 경계 t보다 작은 값에서 true, 큰 값에서 false일 때 t를 어림합니다.
+///
+/// * 항상 pred가 true인 쪽 끝과 false인 쪽 끝을 유지하고, 마지막 구간의 가운데를 반환합니다. pred는 양 끝에서
+/// 불리지 않으므로 모두 true이면 hi 가까이, 모두 false이면 lo 가까이로 갑니다.
+/// * 보통 `Iters(100)`이면 충분합니다(f64 구간을 2^100분의 1로 줄임).
+/// ## 시간복잡도
+/// pred를 `Iters(k)`이면 k번, `Eps(e)`이면 O(log((hi - lo) / e))번 부름
+pub fn bisect_f64(
+    mut lo: f64,
+    mut hi: f64,
+    precision: Precision,
+    mut pred: impl FnMut(f64) -> bool,
+) -> f64 {
+    assert!(lo <= hi, "invalid range [{lo}, {hi}]");
+    let (iters, eps) = match precision {
+        Precision::Iters(k) => (k, 0.0),
+        Precision::Eps(eps) => (200, eps),
+    };
+    for _ in 0..iters {
+        if hi - lo <= eps {
+            break;
+        }
+        let mid = lo + (hi - lo) / 2.0;
+        if pred(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo + (hi - lo) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn recovers_thresholds() {
+        let mut rng = XorShift::new(204);
+        for _ in 0..2000 {
+            let lo = rng.range_i64(-1_000_000, 1_000_000);
+            let hi = lo + rng.range_i64(0, 1000);
+            let t = rng.range_i64(lo - 5, hi + 5);
+            let mut calls = Vec::new();
+            let got = partition_point_i64(lo, hi, |x| {
+                calls.push(x);
+                x < t
+            });
+            assert_eq!(got, t.clamp(lo, hi));
+            assert!(calls.iter().all(|&x| lo <= x && x < hi));
+        }
+        // 가장 작은 제곱이 10^18 이상인 x
+        let root = partition_point_i64(0, 2_000_000_000, |x| {
+            (x as i128) * (x as i128) < 1_000_000_000_000_000_000
+        });
+        assert_eq!(root, 1_000_000_000);
+    }
+
+    #[test]
+    fn all_true_all_false_and_extremes() {
+        assert_eq!(partition_point_i64(-10, 10, |_| true), 10);
+        assert_eq!(partition_point_i64(-10, 10, |_| false), -10);
+        assert_eq!(partition_point_i64(5, 5, |_| unreachable!()), 5);
+        assert_eq!(partition_point_i64(i64::MIN, i64::MAX, |_| true), i64::MAX);
+        assert_eq!(partition_point_i64(i64::MIN, i64::MAX, |_| false), i64::MIN);
+        assert_eq!(partition_point_i64(i64::MIN, i64::MAX, |x| x < -3), -3);
+        assert_eq!(partition_point_i64(-100, -50, |x| x < -70), -70);
+    }
+
+    #[test]
+    fn float_against_analytic_answers() {
+        let sqrt2 = bisect_f64(0.0, 2.0, Precision::Iters(100), |x| x * x < 2.0);
+        assert!((sqrt2 - 2f64.sqrt()).abs() < 1e-12);
+        let root = bisect_f64(-10.0, 0.0, Precision::Eps(1e-9), |x| x.powi(3) < -8.0);
+        assert!((root + 2.0).abs() < 1e-8);
+        // 너무 작은 eps도 멈춤
+        let tiny = bisect_f64(1e6, 2e6, Precision::Eps(0.0), |x| x < 1.5e6);
+        assert!((tiny - 1.5e6).abs() < 1e-6);
+        assert!((bisect_f64(0.0, 1.0, Precision::Iters(60), |_| true) - 1.0).abs() < 1e-12);
+        assert!(bisect_f64(0.0, 1.0, Precision::Iters(60), |_| false).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,154 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// # 정렬된 슬라이스의 lower/upper bound
+/// `slice::binary_search`는 같은 값이 여러 개면 그중 아무 위치나 반환하므로, C++의 `lower_bound`, `upper_bound`,
+/// `equal_range`처럼 삽입 위치를 돌려주는 메서드를 슬라이스에 더합니다.
+///
+/// * `lower_bound(x)`는 x 이상인 첫 위치, `upper_bound(x)`는 x보다 큰 첫 위치입니다. 그런 원소가 없으면 `len()`이고,
+/// `equal_range(x)`는 `lower_bound(x)..upper_bound(x)`, 즉 x와 같은 원소가 모인 구간입니다.
+/// * `_by`의 비교 함수는 `binary_search_by`와 같이 "원소가 찾는 값보다 작으면 Less"를 반환해야 하고, `_by_key`는
+/// 원소에서 뽑은 키를 key와 비교합니다.
+/// * 슬라이스는 그 순서로 정렬되어 있어야 합니다. 그렇지 않으면 결과는 어떤 위치일지 모릅니다(panic하지는 않음).
+/// ## 시간복잡도
+/// O(log n)
+pub trait SliceBisect<T> {
+    /// x 이상인 첫 위치
+    fn lower_bound(&self, x: &T) -> usize
+    where
+        T: Ord;
+
+    /// x보다 큰 첫 위치
+    fn upper_bound(&self, x: &T) -> usize
+    where
+        T: Ord;
+
+    /// x와 같은 원소의 위치 구간
+    fn equal_range(&self, x: &T) -> Range<usize>
+    where
+        T: Ord;
+
+    /// `f(원소)`가 Less가 아닌 첫 위치
+    fn lower_bound_by(&self, f: impl FnMut(&T) -> Ordering) -> usize;
+
+    /// `f(원소)`가 Greater인 첫 위치
+    fn upper_bound_by(&self, f: impl FnMut(&T) -> Ordering) -> usize;
+
+    /// `f(원소)`가 Equal인 위치 구간
+    fn equal_range_by(&self, f: impl FnMut(&T) -> Ordering) -> Range<usize>;
+
+    /// `f(원소) >= key`인 첫 위치
+    fn lower_bound_by_key<K: Ord>(&self, key: &K, f: impl FnMut(&T) -> K) -> usize;
+
+    /// `f(원소) > key`인 첫 위치
+    fn upper_bound_by_key<K: Ord>(&self, key: &K, f: impl FnMut(&T) -> K) -> usize;
+
+    /// `f(원소) == key`인 위치 구간
+    fn equal_range_by_key<K: Ord>(&self, key: &K, f: impl FnMut(&T) -> K) -> Range<usize>;
+}
+
+impl<T> SliceBisect<T> for [T] {
+    fn lower_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.lower_bound_by(|e| e.cmp(x))
+    }
+
+    fn upper_bound(&self, x: &T) -> usize
+    where
+        T: Ord,
+    {
+        self.upper_bound_by(|e| e.cmp(x))
+    }
+
+    fn equal_range(&self, x: &T) -> Range<usize>
+    where
+        T: Ord,
+    {
+        self.equal_range_by(|e| e.cmp(x))
+    }
+
+    fn lower_bound_by(&self, mut f: impl FnMut(&T) -> Ordering) -> usize {
+        self.partition_point(|e| f(e) == Ordering::Less)
+    }
+
+    fn upper_bound_by(&self, mut f: impl FnMut(&T) -> Ordering) -> usize {
+        self.partition_point(|e| f(e) != Ordering::Greater)
+    }
+
+    fn equal_range_by(&self, mut f: impl FnMut(&T) -> Ordering) -> Range<usize> {
+        let lo = self.lower_bound_by(&mut f);
+        // lo 앞은 모두 Less이므로 뒷부분에서만 찾음
+        let hi = lo + self[lo..].upper_bound_by(f);
+        lo..hi
+    }
+
+    fn lower_bound_by_key<K: Ord>(&self, key: &K, mut f: impl FnMut(&T) -> K) -> usize {
+        self.lower_bound_by(|e| f(e).cmp(key))
+    }
+
+    fn upper_bound_by_key<K: Ord>(&self, key: &K, mut f: impl FnMut(&T) -> K) -> usize {
+        self.upper_bound_by(|e| f(e).cmp(key))
+    }
+
+    fn equal_range_by_key<K: Ord>(&self, key: &K, mut f: impl FnMut(&T) -> K) -> Range<usize> {
+        self.equal_range_by(|e| f(e).cmp(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_linear_scan() {
+        let mut rng = XorShift::new(203);
+        for round in 0..500 {
+            let n = rng.index(30);
+            let spread = [0, 3, 100][round % 3];
+            let mut a: Vec<i64> = (0..n).map(|_| rng.range_i64(-spread, spread)).collect();
+            a.sort_unstable();
+            for x in -spread - 1..=spread + 1 {
+                let lo = a.lower_bound(&x);
+                let hi = a.upper_bound(&x);
+                assert_eq!(lo, a.iter().filter(|&&v| v < x).count());
+                assert_eq!(hi, a.iter().filter(|&&v| v <= x).count());
+                assert!(lo <= hi);
+                assert!(a[lo..hi].iter().all(|&v| v == x));
+                assert_eq!(hi - lo, a.iter().filter(|&&v| v == x).count());
+                assert_eq!(a.equal_range(&x), lo..hi);
+                assert_eq!(a.equal_range_by(|e| e.cmp(&x)), lo..hi);
+                // 키로 음수를 쓰면 순서가 뒤집힌 슬라이스에서 찾는 것과 같음
+                let rev: Vec<i64> = a.iter().rev().copied().collect();
+                let r = rev.equal_range_by_key(&-x, |&v| -v);
+                assert_eq!(r, n - hi..n - lo);
+                assert_eq!(rev.lower_bound_by_key(&-x, |&v| -v), n - hi);
+                assert_eq!(rev.upper_bound_by_key(&-x, |&v| -v), n - lo);
+            }
+        }
+    }
+
+    #[test]
+    fn duplicates_empty_and_all_equal() {
+        let a = [1, 2, 2, 2, 5, 7, 7];
+        assert_eq!(a.lower_bound(&2), 1);
+        assert_eq!(a.upper_bound(&2), 4);
+        assert_eq!(a.equal_range(&7), 5..7);
+        assert_eq!(a.equal_range(&3), 4..4);
+        assert_eq!(a.lower_bound(&0), 0);
+        assert_eq!(a.upper_bound(&9), 7);
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.lower_bound(&1), 0);
+        assert_eq!(empty.equal_range(&1), 0..0);
+        let same = [4; 10];
+        assert_eq!(same.equal_range(&4), 0..10);
+        assert_eq!(same.lower_bound(&5), 10);
+        assert_eq!(same.upper_bound(&3), 0);
+        let pairs = [(1, 'a'), (3, 'b'), (3, 'c'), (8, 'd')];
+        assert_eq!(pairs.equal_range_by_key(&3, |p| p.0), 1..3);
+        assert_eq!(pairs.upper_bound_by(|p| p.0.cmp(&1)), 1);
+    }
+}
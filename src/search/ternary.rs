@@ -0,0 +1,158 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::cmp::Reverse;
+
+/// # 실수 삼분 탐색 (최댓값)
+/// `[lo, hi]`에서 볼록 올라갔다 내려가는(unimodal) 함수 f의 최댓값을 어림해서 `(x, f(x))`로 반환합니다.
+///
+/// * 구간을 삼등분하는 두 점을 비교해서 작은 쪽 바깥 1/3을 버리는 일을 iters번 합니다. 한 번에 구간이 2/3로
+/// 줄어들므로 보통 100번이면 충분합니다.
+/// ## 시간복잡도
+/// f를 2 · iters + 1번 부름
+pub fn ternary_search_max_f64(
+    mut lo: f64,
+    mut hi: f64,
+    iters: usize,
+    mut f: impl FnMut(f64) -> f64,
+) -> (f64, f64) {
+    assert!(lo <= hi, "invalid range [{lo}, {hi}]");
+    for _ in 0..iters {
+        let third = (hi - lo) / 3.0;
+        let (m1, m2) = (lo + third, hi - third);
+        if f(m1) < f(m2) {
+            lo = m1;
+        } else {
+            hi = m2;
+        }
+    }
+    let x = lo + (hi - lo) / 2.0;
+    (x, f(x))
+}
+
+/// # 실수 삼분 탐색 (최솟값)
+/// [`ternary_search_max_f64`]처럼 내려갔다 올라가는 함수의 최솟값을 `(x, f(x))`로 반환합니다.
+/// 점과 선분 사이의 거리처럼 기하에서 자주 씁니다.
+/// ## 시간복잡도
+/// f를 2 · iters + 1번 부름
+pub fn ternary_search_min_f64(
+    lo: f64,
+    hi: f64,
+    iters: usize,
+    mut f: impl FnMut(f64) -> f64,
+) -> (f64, f64) {
+    let (x, y) = ternary_search_max_f64(lo, hi, iters, |x| -f(x));
+    (x, -y)
+}
+
+/// # 정수 삼분 탐색 (최댓값)
+/// `lo..=hi`(경계 포함)에서 f의 최댓값을 `(x, f(x))`로 반환합니다. 최댓값을 주는 x가 여러 개면 가장 작은 x입니다.
+///
+/// * f는 최댓값 앞에서는 순증가하고 뒤에서는 증가하지 않아야 합니다. 그래서 최댓값이 여러 칸에 걸친 평탄한 구간이나
+/// 내려가는 쪽의 평탄한 구간은 괜찮지만, 올라가는 쪽의 평탄한 구간은 찾을 수 없습니다.
+/// * 구간이 3칸 이하가 될 때까지 삼분 탐색을 한 뒤 남은 칸을 모두 봐서, 끝을 1씩 잘못 옮기는 문제를 피합니다.
+/// ## 시간복잡도
+/// f를 O(log(hi - lo))번 부름
+pub fn ternary_search_max_i64<T: Ord>(
+    mut lo: i64,
+    mut hi: i64,
+    mut f: impl FnMut(i64) -> T,
+) -> (i64, T) {
+    assert!(lo <= hi, "invalid range {lo}..={hi}");
+    while hi.abs_diff(lo) > 2 {
+        let third = (hi.abs_diff(lo) / 3) as i64;
+        let (m1, m2) = (lo + third, hi - third);
+        if f(m1) < f(m2) {
+            // m1 이하는 모두 f(m2)보다 작음
+            lo = m1 + 1;
+        } else {
+            // 가장 왼쪽의 최댓값은 m2 이하
+            hi = m2;
+        }
+    }
+    let mut best = (lo, f(lo));
+    for x in lo + 1..=hi {
+        let y = f(x);
+        if y > best.1 {
+            best = (x, y);
+        }
+    }
+    best
+}
+
+/// # 정수 삼분 탐색 (최솟값)
+/// [`ternary_search_max_i64`]를 뒤집어서, 최솟값 앞에서 순감소하고 뒤에서 감소하지 않는 f의 최솟값을 구합니다.
+/// ## 시간복잡도
+/// f를 O(log(hi - lo))번 부름
+pub fn ternary_search_min_i64<T: Ord>(lo: i64, hi: i64, mut f: impl FnMut(i64) -> T) -> (i64, T) {
+    let (x, Reverse(y)) = ternary_search_max_i64(lo, hi, |x| Reverse(f(x)));
+    (x, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn quadratics_with_known_optima() {
+        let mut rng = XorShift::new(205);
+        for _ in 0..2000 {
+            let peak = rng.range_i64(-1000, 1000);
+            let lo = rng.range_i64(-1200, 1200);
+            let hi = lo + rng.range_i64(0, 500);
+            let f = |x: i64| -(x - peak) * (x - peak);
+            let best = peak.clamp(lo, hi);
+            assert_eq!(ternary_search_max_i64(lo, hi, f), (best, f(best)));
+            assert_eq!(ternary_search_min_i64(lo, hi, |x| -f(x)), (best, -f(best)));
+        }
+        let (x, y) = ternary_search_max_f64(-10.0, 10.0, 100, |x| -(x - 1.5) * (x - 1.5) + 4.0);
+        assert!((x - 1.5).abs() < 1e-6 && (y - 4.0).abs() < 1e-9);
+        // 원점에서 선분 (1, -1)-(1, 3) 위의 점까지의 거리는 y = 0에서 1
+        let (t, d) = ternary_search_min_f64(-1.0, 3.0, 100, |y| (1.0 + y * y).sqrt());
+        assert!(t.abs() < 1e-6 && (d - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn plateaus() {
+        // 10까지 오르고, 20까지 평탄하고, 30까지 내려가 평탄
+        let f = |x: i64| x.min(10) - (x - 20).clamp(0, 10);
+        for lo in -5..25 {
+            for hi in lo..40 {
+                let expected = (lo..=hi).max_by_key(|&x| (f(x), -x)).unwrap();
+                assert_eq!(
+                    ternary_search_max_i64(lo, hi, f),
+                    (expected, f(expected)),
+                    "{lo} {hi}"
+                );
+            }
+        }
+        let mut rng = XorShift::new(2050);
+        for _ in 0..500 {
+            // 평탄한 꼭대기가 있는 무작위 산
+            let (up, top, down) = (rng.index(20), 1 + rng.index(20), rng.index(20));
+            let mut values: Vec<i64> = (0..up as i64).collect();
+            values.extend(std::iter::repeat_n(up as i64, top));
+            // 내려가는 쪽은 평탄한 칸이 섞임
+            let mut drop = 0;
+            for _ in 0..down {
+                values.push(up as i64 - 1 - drop);
+                drop += rng.range_i64(0, 1);
+            }
+            let n = values.len() as i64;
+            let got = ternary_search_max_i64(0, n - 1, |x| values[x as usize]);
+            assert_eq!(got, (up as i64, up as i64));
+        }
+    }
+
+    #[test]
+    fn narrow_ranges() {
+        assert_eq!(ternary_search_max_i64(7, 7, |x| x), (7, 7));
+        assert_eq!(ternary_search_max_i64(7, 8, |x| -x), (7, -7));
+        assert_eq!(ternary_search_max_i64(7, 9, |x| -(x - 8).abs()), (8, 0));
+        assert_eq!(ternary_search_min_i64(1, 3, |x| x), (1, 1));
+        assert_eq!(ternary_search_min_i64(1, 3, |x| -x), (3, -3));
+        assert_eq!(
+            ternary_search_max_i64(i64::MIN, i64::MAX, |x| Reverse(x.unsigned_abs())),
+            (0, Reverse(0))
+        );
+    }
+}
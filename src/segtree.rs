@@ -0,0 +1,10 @@
+mod beats;
+pub use beats::SegTreeBeats;
+mod dynamic;
+pub use dynamic::DynamicSegTree;
+mod lazy;
+pub use lazy::{Add, Assign, LazySegTree, RangeAddMin, RangeAddSum, RangeAssignSum, SumLen};
+mod persistent;
+pub use persistent::{PersistentSegTree, kth_smallest_in_range};
+mod point;
+pub use point::SegTree;
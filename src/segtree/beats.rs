@@ -0,0 +1,404 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::ops::Range;
+
+const NEG_INF: i64 = i64::MIN;
+const POS_INF: i64 = i64::MAX;
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    sum: i64,
+    len: i64,
+    max: i64,
+    /// 최댓값보다 작은 값 중 가장 큰 값. 없으면 NEG_INF
+    max2: i64,
+    max_cnt: i64,
+    min: i64,
+    /// 최솟값보다 큰 값 중 가장 작은 값. 없으면 POS_INF
+    min2: i64,
+    min_cnt: i64,
+    /// 아직 자식에게 내려보내지 않은 더하기
+    add: i64,
+}
+
+impl Node {
+    fn leaf(x: i64) -> Self {
+        Node {
+            sum: x,
+            len: 1,
+            max: x,
+            max2: NEG_INF,
+            max_cnt: 1,
+            min: x,
+            min2: POS_INF,
+            min_cnt: 1,
+            add: 0,
+        }
+    }
+
+    fn merge(a: &Node, b: &Node) -> Self {
+        let (max, max_cnt, max2) = if a.max == b.max {
+            (a.max, a.max_cnt + b.max_cnt, a.max2.max(b.max2))
+        } else if a.max > b.max {
+            (a.max, a.max_cnt, a.max2.max(b.max))
+        } else {
+            (b.max, b.max_cnt, a.max.max(b.max2))
+        };
+        let (min, min_cnt, min2) = if a.min == b.min {
+            (a.min, a.min_cnt + b.min_cnt, a.min2.min(b.min2))
+        } else if a.min < b.min {
+            (a.min, a.min_cnt, a.min2.min(b.min))
+        } else {
+            (b.min, b.min_cnt, a.min.min(b.min2))
+        };
+        Node {
+            sum: a.sum + b.sum,
+            len: a.len + b.len,
+            max,
+            max2,
+            max_cnt,
+            min,
+            min2,
+            min_cnt,
+            add: 0,
+        }
+    }
+
+    /// 최댓값들만 x로 내림 (max2 < x < max일 때만 부름)
+    fn lower_max(&mut self, x: i64) {
+        self.sum += (x - self.max) * self.max_cnt;
+        if self.max == self.min {
+            self.min = x;
+        } else if self.max == self.min2 {
+            self.min2 = x;
+        }
+        self.max = x;
+    }
+
+    /// 최솟값들만 x로 올림 (min < x < min2일 때만 부름)
+    fn raise_min(&mut self, x: i64) {
+        self.sum += (x - self.min) * self.min_cnt;
+        if self.min == self.max {
+            self.max = x;
+        } else if self.min == self.max2 {
+            self.max2 = x;
+        }
+        self.min = x;
+    }
+
+    fn add_all(&mut self, x: i64) {
+        self.sum += x * self.len;
+        self.max += x;
+        self.min += x;
+        if self.max2 != NEG_INF {
+            self.max2 += x;
+        }
+        if self.min2 != POS_INF {
+            self.min2 += x;
+        }
+        self.add += x;
+    }
+}
+
+/// # Segment tree beats
+/// 길이 n인 배열에 구간 chmin(`a[i] = min(a[i], x)`), 구간 chmax, 구간 더하기와 구간 합 / 최솟값 / 최댓값 질의를
+/// 처리합니다. chmin/chmax는 lazy segment tree의 작용으로 표현할 수 없어서 따로 만든 구조입니다.
+///
+/// * 노드마다 최댓값, 두 번째 최댓값, 최댓값의 개수(최솟값 쪽도 같음)를 저장합니다.
+/// * chmin(x)에서 `max <= x`면 멈추고, `max2 < x < max`면 최댓값들만 x로 바꾸면 되므로 그 노드에서 끝냅니다.
+/// 둘 다 아니면 자식으로 내려갑니다("beats"). 이렇게 내려가는 횟수가 amortized로 제한됩니다.
+/// * 구간은 0-based 반열림이고, 빈 구간의 합은 0, 최솟값은 `i64::MAX`, 최댓값은 `i64::MIN`입니다.
+/// * 재귀 깊이는 O(log n)입니다.
+///
+/// > 합과 값은 i64 범위 안이어야 합니다.
+/// ## 시간복잡도
+/// 더하기가 없으면 amortized O((n + q) log n), 더하기가 섞이면 amortized O((n + q) log² n)
+#[derive(Clone, Debug)]
+pub struct SegTreeBeats {
+    n: usize,
+    nodes: Vec<Node>,
+}
+
+impl SegTreeBeats {
+    /// 주어진 배열로 생성
+    pub fn new(values: &[i64]) -> Self {
+        let n = values.len();
+        let mut tree = SegTreeBeats {
+            n,
+            nodes: vec![Node::leaf(0); 4 * n.max(1)],
+        };
+        if n > 0 {
+            tree.build(1, 0, n, values);
+        }
+        tree
+    }
+
+    /// 배열의 길이
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// 구간 `l..r`의 모든 원소를 `min(a[i], x)`로
+    pub fn chmin(&mut self, range: Range<usize>, x: i64) {
+        let (l, r) = self.check(range);
+        if l < r {
+            self.chmin_rec(1, 0, self.n, l, r, x);
+        }
+    }
+
+    /// 구간 `l..r`의 모든 원소를 `max(a[i], x)`로
+    pub fn chmax(&mut self, range: Range<usize>, x: i64) {
+        let (l, r) = self.check(range);
+        if l < r {
+            self.chmax_rec(1, 0, self.n, l, r, x);
+        }
+    }
+
+    /// 구간 `l..r`의 모든 원소에 x를 더함
+    pub fn add(&mut self, range: Range<usize>, x: i64) {
+        let (l, r) = self.check(range);
+        if l < r {
+            self.add_rec(1, 0, self.n, l, r, x);
+        }
+    }
+
+    /// 구간 `l..r`의 합
+    pub fn range_sum(&mut self, range: Range<usize>) -> i64 {
+        self.fold(range, 0, |acc, node| acc + node.sum)
+    }
+
+    /// 구간 `l..r`의 최솟값. 빈 구간이면 `i64::MAX`
+    pub fn range_min(&mut self, range: Range<usize>) -> i64 {
+        self.fold(range, POS_INF, |acc, node| acc.min(node.min))
+    }
+
+    /// 구간 `l..r`의 최댓값. 빈 구간이면 `i64::MIN`
+    pub fn range_max(&mut self, range: Range<usize>) -> i64 {
+        self.fold(range, NEG_INF, |acc, node| acc.max(node.max))
+    }
+
+    fn check(&self, range: Range<usize>) -> (usize, usize) {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.n,
+            "invalid range {l}..{r} for length {}",
+            self.n
+        );
+        (l, r)
+    }
+
+    fn build(&mut self, k: usize, lo: usize, hi: usize, values: &[i64]) {
+        if hi - lo == 1 {
+            self.nodes[k] = Node::leaf(values[lo]);
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        self.build(2 * k, lo, mid, values);
+        self.build(2 * k + 1, mid, hi, values);
+        self.pull(k);
+    }
+
+    fn pull(&mut self, k: usize) {
+        self.nodes[k] = Node::merge(&self.nodes[2 * k], &self.nodes[2 * k + 1]);
+    }
+
+    fn push(&mut self, k: usize) {
+        let Node { add, max, min, .. } = self.nodes[k];
+        for c in [2 * k, 2 * k + 1] {
+            let child = &mut self.nodes[c];
+            if add != 0 {
+                child.add_all(add);
+            }
+            if child.max > max {
+                child.lower_max(max);
+            }
+            if child.min < min {
+                child.raise_min(min);
+            }
+        }
+        self.nodes[k].add = 0;
+    }
+
+    fn chmin_rec(&mut self, k: usize, lo: usize, hi: usize, l: usize, r: usize, x: i64) {
+        if r <= lo || hi <= l || self.nodes[k].max <= x {
+            return;
+        }
+        if l <= lo && hi <= r && self.nodes[k].max2 < x {
+            self.nodes[k].lower_max(x);
+            return;
+        }
+        self.push(k);
+        let mid = (lo + hi) / 2;
+        self.chmin_rec(2 * k, lo, mid, l, r, x);
+        self.chmin_rec(2 * k + 1, mid, hi, l, r, x);
+        self.pull(k);
+    }
+
+    fn chmax_rec(&mut self, k: usize, lo: usize, hi: usize, l: usize, r: usize, x: i64) {
+        if r <= lo || hi <= l || self.nodes[k].min >= x {
+            return;
+        }
+        if l <= lo && hi <= r && self.nodes[k].min2 > x {
+            self.nodes[k].raise_min(x);
+            return;
+        }
+        self.push(k);
+        let mid = (lo + hi) / 2;
+        self.chmax_rec(2 * k, lo, mid, l, r, x);
+        self.chmax_rec(2 * k + 1, mid, hi, l, r, x);
+        self.pull(k);
+    }
+
+    fn add_rec(&mut self, k: usize, lo: usize, hi: usize, l: usize, r: usize, x: i64) {
+        if r <= lo || hi <= l {
+            return;
+        }
+        if l <= lo && hi <= r {
+            self.nodes[k].add_all(x);
+            return;
+        }
+        self.push(k);
+        let mid = (lo + hi) / 2;
+        self.add_rec(2 * k, lo, mid, l, r, x);
+        self.add_rec(2 * k + 1, mid, hi, l, r, x);
+        self.pull(k);
+    }
+
+    /// 구간을 덮는 노드들을 왼쪽부터 접음
+    fn fold(&mut self, range: Range<usize>, init: i64, f: impl Fn(i64, &Node) -> i64) -> i64 {
+        let (l, r) = self.check(range);
+        let mut acc = init;
+        if l < r {
+            self.fold_rec(1, 0, self.n, l, r, &mut acc, &f);
+        }
+        acc
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn fold_rec(
+        &mut self,
+        k: usize,
+        lo: usize,
+        hi: usize,
+        l: usize,
+        r: usize,
+        acc: &mut i64,
+        f: &impl Fn(i64, &Node) -> i64,
+    ) {
+        if r <= lo || hi <= l {
+            return;
+        }
+        if l <= lo && hi <= r {
+            *acc = f(*acc, &self.nodes[k]);
+            return;
+        }
+        self.push(k);
+        let mid = (lo + hi) / 2;
+        self.fold_rec(2 * k, lo, mid, l, r, acc, f);
+        self.fold_rec(2 * k + 1, mid, hi, l, r, acc, f);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_plain_vec() {
+        let mut rng = XorShift::new(139);
+        for round in 0..200 {
+            let n = rng.index(50);
+            // 값의 범위를 좁게 해서 같은 값(최댓값 개수 > 1)이 자주 나오게 함
+            let spread = if round % 2 == 0 { 5 } else { 1_000_000 };
+            let mut a: Vec<i64> = (0..n).map(|_| rng.range_i64(-spread, spread)).collect();
+            let mut tree = SegTreeBeats::new(&a);
+            for _ in 0..500 {
+                let l = rng.index(n + 1);
+                let r = l + rng.index(n + 1 - l);
+                let x = rng.range_i64(-spread, spread);
+                match rng.below(6) {
+                    0 => {
+                        tree.chmin(l..r, x);
+                        a[l..r].iter_mut().for_each(|v| *v = (*v).min(x));
+                    }
+                    1 => {
+                        tree.chmax(l..r, x);
+                        a[l..r].iter_mut().for_each(|v| *v = (*v).max(x));
+                    }
+                    2 => {
+                        tree.add(l..r, x);
+                        a[l..r].iter_mut().for_each(|v| *v += x);
+                    }
+                    3 => assert_eq!(tree.range_sum(l..r), a[l..r].iter().sum::<i64>()),
+                    4 => assert_eq!(
+                        tree.range_min(l..r),
+                        a[l..r].iter().copied().min().unwrap_or(i64::MAX)
+                    ),
+                    _ => assert_eq!(
+                        tree.range_max(l..r),
+                        a[l..r].iter().copied().max().unwrap_or(i64::MIN)
+                    ),
+                }
+            }
+            for (i, &v) in a.iter().enumerate() {
+                assert_eq!(tree.range_sum(i..i + 1), v);
+            }
+        }
+    }
+
+    #[test]
+    fn clipping_examples() {
+        let mut tree = SegTreeBeats::new(&[5, 1, 9, 3, 7]);
+        tree.chmin(0..5, 6);
+        assert_eq!(tree.range_sum(0..5), 5 + 1 + 6 + 3 + 6);
+        tree.chmax(1..4, 4);
+        assert_eq!(tree.range_min(0..5), 4);
+        assert_eq!(tree.range_sum(0..5), 5 + 4 + 6 + 4 + 6);
+        tree.add(0..2, -10);
+        assert_eq!(tree.range_min(0..5), -6);
+        assert_eq!(tree.range_max(0..2), -5);
+        // 모든 값이 하나로 모임
+        tree.chmin(0..5, 0);
+        tree.chmax(0..5, 0);
+        assert_eq!((tree.range_min(0..5), tree.range_max(0..5)), (0, 0));
+        assert_eq!(tree.range_sum(0..5), 0);
+        assert_eq!(tree.range_sum(2..2), 0);
+        assert!(SegTreeBeats::new(&[]).is_empty());
+    }
+
+    #[test]
+    fn long_sequence_on_larger_array() {
+        let mut rng = XorShift::new(1390);
+        let n = 2000;
+        let mut a: Vec<i64> = (0..n).map(|_| rng.range_i64(-1000, 1000)).collect();
+        let mut tree = SegTreeBeats::new(&a);
+        for _ in 0..3000 {
+            let l = rng.index(n);
+            let r = l + 1 + rng.index(n - l);
+            let x = rng.range_i64(-1000, 1000);
+            match rng.below(4) {
+                0 => {
+                    tree.chmin(l..r, x);
+                    a[l..r].iter_mut().for_each(|v| *v = (*v).min(x));
+                }
+                1 => {
+                    tree.chmax(l..r, x);
+                    a[l..r].iter_mut().for_each(|v| *v = (*v).max(x));
+                }
+                2 => {
+                    tree.add(l..r, x / 10);
+                    a[l..r].iter_mut().for_each(|v| *v += x / 10);
+                }
+                _ => {
+                    assert_eq!(tree.range_sum(l..r), a[l..r].iter().sum::<i64>());
+                    assert_eq!(tree.range_max(l..r), *a[l..r].iter().max().unwrap());
+                }
+            }
+        }
+    }
+}
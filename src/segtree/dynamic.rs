@@ -0,0 +1,177 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::AbelianGroup;
+use std::ops::RangeInclusive;
+
+/// 자식이 없음을 나타내는 번호. 0번은 루트라서 누구의 자식도 아닙니다.
+const NONE: u32 = 0;
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    sum: T,
+    child: [u32; 2],
+}
+
+/// # 동적 Segment tree
+/// 위치 `0..=u64::MAX` 전체에 한 점 더하기와 구간 합을 좌표 압축 없이 처리합니다. 질의가 온라인으로 들어와서
+/// 미리 좌표를 모을 수 없을 때 씁니다.
+///
+/// * 필요한 노드만 만듭니다. 노드는 Vec 하나(arena)에 두고, 자식은 u32 번호로 가리킵니다.
+/// 한 번 더할 때 루트에서 잎까지 최대 65개의 노드가 생기므로, q번 갱신하면 노드는 최대 `1 + 64q`개입니다.
+/// * 구간의 끝 `u64::MAX`를 표현할 수 있도록 질의는 양 끝을 포함하는 `l..=r`로 받습니다.
+/// * 갱신과 질의 모두 재귀 없이 처리합니다.
+/// ## 시간복잡도
+/// 연산마다 O(log U) = O(64), 메모리 O(q log U)
+#[derive(Clone, Debug)]
+pub struct DynamicSegTree<T = i64> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T: AbelianGroup> Default for DynamicSegTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: AbelianGroup> DynamicSegTree<T> {
+    /// 모든 값이 0인 트리 (루트 노드 하나)
+    pub fn new() -> Self {
+        DynamicSegTree {
+            nodes: vec![Node {
+                sum: T::zero(),
+                child: [NONE; 2],
+            }],
+        }
+    }
+
+    /// 지금까지 만든 노드의 개수
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// `a[i] += delta`
+    pub fn add(&mut self, i: u64, delta: T) {
+        let (mut lo, mut hi) = (0u64, u64::MAX);
+        let mut v = 0usize;
+        loop {
+            self.nodes[v].sum = T::add(&self.nodes[v].sum, &delta);
+            if lo == hi {
+                return;
+            }
+            let mid = lo + (hi - lo) / 2;
+            let side = usize::from(i > mid);
+            if side == 0 {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+            if self.nodes[v].child[side] == NONE {
+                let id = u32::try_from(self.nodes.len()).expect("too many nodes for u32 indices");
+                self.nodes.push(Node {
+                    sum: T::zero(),
+                    child: [NONE; 2],
+                });
+                self.nodes[v].child[side] = id;
+            }
+            v = self.nodes[v].child[side] as usize;
+        }
+    }
+
+    /// `a[i]`
+    pub fn get(&self, i: u64) -> T {
+        self.query(i..=i)
+    }
+
+    /// 구간 `l..=r`(양 끝 포함)의 합. `l > r`이면 0입니다.
+    pub fn query(&self, range: RangeInclusive<u64>) -> T {
+        let (l, r) = (*range.start(), *range.end());
+        let mut sum = T::zero();
+        if l > r {
+            return sum;
+        }
+        // (노드, 노드가 맡은 구간)
+        let mut stack = vec![(0usize, 0u64, u64::MAX)];
+        while let Some((v, lo, hi)) = stack.pop() {
+            if r < lo || hi < l {
+                continue;
+            }
+            if l <= lo && hi <= r {
+                sum = T::add(&sum, &self.nodes[v].sum);
+                continue;
+            }
+            let mid = lo + (hi - lo) / 2;
+            let [left, right] = self.nodes[v].child;
+            if left != NONE {
+                stack.push((left as usize, lo, mid));
+            }
+            if right != NONE {
+                stack.push((right as usize, mid + 1, hi));
+            }
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use std::collections::HashMap;
+
+    #[test]
+    fn random_sparse_against_hash_map() {
+        let mut rng = XorShift::new(136);
+        for round in 0..50 {
+            let mut tree = DynamicSegTree::new();
+            let mut model: HashMap<u64, i64> = HashMap::new();
+            // 작은 범위에 몰린 위치와 전체 범위에 흩어진 위치를 섞음
+            let position = |rng: &mut XorShift| match rng.below(3) {
+                0 => rng.below(50),
+                1 => u64::MAX - rng.below(50),
+                _ => rng.next_u64(),
+            };
+            let updates = 1 + round * 4;
+            for _ in 0..updates {
+                let i = position(&mut rng);
+                let delta = rng.range_i64(-100, 100);
+                tree.add(i, delta);
+                *model.entry(i).or_insert(0) += delta;
+                let (a, b) = (position(&mut rng), position(&mut rng));
+                let (l, r) = (a.min(b), a.max(b));
+                let expected: i64 = model
+                    .iter()
+                    .filter(|&(&k, _)| l <= k && k <= r)
+                    .map(|(_, &v)| v)
+                    .sum();
+                assert_eq!(tree.query(l..=r), expected);
+                assert_eq!(tree.get(i), model[&i]);
+            }
+            // 노드 개수 상한
+            assert!(tree.node_count() <= 1 + 64 * updates);
+        }
+    }
+
+    #[test]
+    fn extreme_ends() {
+        let mut tree = DynamicSegTree::new();
+        tree.add(0, 3);
+        tree.add(u64::MAX, 5);
+        tree.add(u64::MAX / 2, 7);
+        tree.add(u64::MAX / 2 + 1, 11);
+        assert_eq!(tree.query(0..=u64::MAX), 26);
+        assert_eq!(tree.query(u64::MAX..=u64::MAX), 5);
+        assert_eq!(tree.query(0..=0), 3);
+        assert_eq!(tree.query(1..=u64::MAX - 1), 18);
+        assert_eq!(tree.query(0..=u64::MAX / 2), 10);
+        assert_eq!(tree.query(u64::MAX / 2 + 1..=u64::MAX), 16);
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = tree.query(5..=4);
+        assert_eq!(empty, 0);
+        // 같은 위치를 반복해서 갱신해도 노드가 늘지 않음
+        let before = tree.node_count();
+        for _ in 0..100 {
+            tree.add(u64::MAX, 1);
+        }
+        assert_eq!(tree.node_count(), before);
+        assert_eq!(tree.get(u64::MAX), 105);
+    }
+}
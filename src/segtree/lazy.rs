@@ -0,0 +1,581 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::{Action, Min, Monoid};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// # Lazy segment tree
+/// 길이 n인 배열에 "구간 `l..r`의 모든 원소에 함수 f 적용"과 "구간을 [`Monoid`]로 접은 값"을 O(log n)에 처리합니다.
+/// 값은 모노이드 `M`, 함수는 `M`에 대한 작용 [`Action`] `A`로 정합니다 (ACL의 lazy_segtree와 같은 구조).
+///
+/// * 노드마다 "아직 자식에게 내려보내지 않은 함수"를 저장합니다. 새 함수 f가 들어오면 저장된 g와 `f ∘ g`로
+/// 합성합니다. 즉 나중에 들어온 함수가 바깥쪽입니다.
+/// * 질의와 갱신 모두 재귀 없이 처리합니다. 질의도 내려보내기를 하므로 `&mut self`를 받습니다.
+/// * 자주 쓰는 조합은 [`RangeAddSum`], [`RangeAddMin`], [`RangeAssignSum`]으로 준비되어 있습니다.
+/// ## 시간복잡도
+/// 생성 O(n), 연산마다 O(log n)
+pub struct LazySegTree<M: Monoid, A: Action<M>> {
+    n: usize,
+    size: usize,
+    log: u32,
+    data: Vec<M::S>,
+    lazy: Vec<A::F>,
+}
+
+impl<M: Monoid, A: Action<M>> LazySegTree<M, A> {
+    /// 모든 값이 항등원인 길이 n의 배열
+    pub fn new(n: usize) -> Self {
+        Self::from_slice(&vec![M::identity(); n])
+    }
+
+    /// 주어진 배열로 O(n)에 생성
+    pub fn from_slice(values: &[M::S]) -> Self {
+        let n = values.len();
+        let size = n.next_power_of_two();
+        let log = size.trailing_zeros();
+        let mut data = vec![M::identity(); 2 * size];
+        data[size..size + n].clone_from_slice(values);
+        let mut tree = LazySegTree {
+            n,
+            size,
+            log,
+            data,
+            lazy: vec![A::identity_map(); size],
+        };
+        for k in (1..size).rev() {
+            tree.update(k);
+        }
+        tree
+    }
+
+    /// 배열의 길이
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// `a[i] = value`
+    pub fn set(&mut self, i: usize, value: M::S) {
+        assert!(i < self.n, "index {i} out of range for length {}", self.n);
+        let k = i + self.size;
+        for s in (1..=self.log).rev() {
+            self.push(k >> s);
+        }
+        self.data[k] = value;
+        for s in 1..=self.log {
+            self.update(k >> s);
+        }
+    }
+
+    /// `a[i]`
+    pub fn get(&mut self, i: usize) -> M::S {
+        assert!(i < self.n, "index {i} out of range for length {}", self.n);
+        let k = i + self.size;
+        for s in (1..=self.log).rev() {
+            self.push(k >> s);
+        }
+        self.data[k].clone()
+    }
+
+    /// `op(a[l], …, a[r - 1])`. 빈 구간은 항등원이고, `l > r`이거나 범위를 벗어나면 panic합니다.
+    pub fn query(&mut self, range: Range<usize>) -> M::S {
+        let (l, r) = self.check(range);
+        if l == r {
+            return M::identity();
+        }
+        let (mut l, mut r) = (l + self.size, r + self.size);
+        self.push_boundaries(l, r);
+        let mut left = M::identity();
+        let mut right = M::identity();
+        while l < r {
+            if l & 1 == 1 {
+                left = M::op(&left, &self.data[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                right = M::op(&self.data[r], &right);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        M::op(&left, &right)
+    }
+
+    /// 전체 배열을 접은 값
+    pub fn all(&self) -> &M::S {
+        &self.data[1]
+    }
+
+    /// `pred(query(l..r))`가 참인 가장 큰 r (l ≤ r ≤ n). [`SegTree::max_right`](crate::segtree::SegTree::max_right)와
+    /// 같고, `pred`는 단조여야 하며 `pred(identity)`는 참이어야 합니다.
+    /// ## 시간복잡도
+    /// O(log n)
+    pub fn max_right(&mut self, l: usize, pred: impl Fn(&M::S) -> bool) -> usize {
+        assert!(l <= self.n, "index {l} out of range for length {}", self.n);
+        debug_assert!(pred(&M::identity()), "pred(identity) must be true");
+        if l == self.n {
+            return self.n;
+        }
+        let mut l = l + self.size;
+        for s in (1..=self.log).rev() {
+            self.push(l >> s);
+        }
+        let mut acc = M::identity();
+        loop {
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+            let next = M::op(&acc, &self.data[l]);
+            if !pred(&next) {
+                while l < self.size {
+                    self.push(l);
+                    l *= 2;
+                    let next = M::op(&acc, &self.data[l]);
+                    if pred(&next) {
+                        acc = next;
+                        l += 1;
+                    }
+                }
+                return l - self.size;
+            }
+            acc = next;
+            l += 1;
+            if l.is_power_of_two() {
+                return self.n;
+            }
+        }
+    }
+
+    /// `pred(query(l..r))`가 참인 가장 작은 l (0 ≤ l ≤ r). `pred`는 단조여야 하며 `pred(identity)`는 참이어야 합니다.
+    /// ## 시간복잡도
+    /// O(log n)
+    pub fn min_left(&mut self, r: usize, pred: impl Fn(&M::S) -> bool) -> usize {
+        assert!(r <= self.n, "index {r} out of range for length {}", self.n);
+        debug_assert!(pred(&M::identity()), "pred(identity) must be true");
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.size;
+        for s in (1..=self.log).rev() {
+            self.push((r - 1) >> s);
+        }
+        let mut acc = M::identity();
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let next = M::op(&self.data[r], &acc);
+            if !pred(&next) {
+                while r < self.size {
+                    self.push(r);
+                    r = 2 * r + 1;
+                    let next = M::op(&self.data[r], &acc);
+                    if pred(&next) {
+                        acc = next;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.size;
+            }
+            acc = next;
+            if r.is_power_of_two() {
+                return 0;
+            }
+        }
+    }
+
+    /// 구간 `l..r`의 모든 원소 x를 `f(x)`로 바꿉니다. 빈 구간이면 아무 일도 없습니다.
+    pub fn apply_range(&mut self, range: Range<usize>, f: A::F) {
+        let (l, r) = self.check(range);
+        if l == r {
+            return;
+        }
+        let (l, r) = (l + self.size, r + self.size);
+        self.push_boundaries(l, r);
+        let (mut l2, mut r2) = (l, r);
+        while l2 < r2 {
+            if l2 & 1 == 1 {
+                self.all_apply(l2, &f);
+                l2 += 1;
+            }
+            if r2 & 1 == 1 {
+                r2 -= 1;
+                self.all_apply(r2, &f);
+            }
+            l2 >>= 1;
+            r2 >>= 1;
+        }
+        for s in 1..=self.log {
+            if (l >> s) << s != l {
+                self.update(l >> s);
+            }
+            if (r >> s) << s != r {
+                self.update((r - 1) >> s);
+            }
+        }
+    }
+
+    fn check(&self, range: Range<usize>) -> (usize, usize) {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.n,
+            "invalid range {l}..{r} for length {}",
+            self.n
+        );
+        (l, r)
+    }
+
+    /// 구간 `l..r`(트리 위치)의 양 끝을 덮는 노드들의 함수를 위에서부터 내려보냄
+    fn push_boundaries(&mut self, l: usize, r: usize) {
+        for s in (1..=self.log).rev() {
+            if (l >> s) << s != l {
+                self.push(l >> s);
+            }
+            if (r >> s) << s != r {
+                self.push((r - 1) >> s);
+            }
+        }
+    }
+
+    fn update(&mut self, k: usize) {
+        self.data[k] = M::op(&self.data[2 * k], &self.data[2 * k + 1]);
+    }
+
+    fn all_apply(&mut self, k: usize, f: &A::F) {
+        self.data[k] = A::apply(f, &self.data[k]);
+        if k < self.size {
+            self.lazy[k] = A::compose(f, &self.lazy[k]);
+        }
+    }
+
+    fn push(&mut self, k: usize) {
+        let f = std::mem::replace(&mut self.lazy[k], A::identity_map());
+        self.all_apply(2 * k, &f);
+        self.all_apply(2 * k + 1, &f);
+    }
+}
+
+/// 합과 원소 개수 `(sum, len)`의 모노이드. 구간에 더하거나 대입할 때 길이가 필요해서 함께 들고 다닙니다.
+///
+/// > 항등원은 길이 0인 `(0, 0)`이므로 `new(n)`으로 만들면 더하거나 대입해도 합이 변하지 않습니다.
+/// [`LazySegTree::zeros`]나 [`LazySegTree::from_values`]로 만듭니다. 디버그 빌드에서는 길이 0인 원소에 0이 아닌
+/// 값을 더하거나 대입하면 panic합니다.
+pub struct SumLen;
+
+impl Monoid for SumLen {
+    type S = (i64, i64);
+
+    fn identity() -> (i64, i64) {
+        (0, 0)
+    }
+
+    fn op(a: &(i64, i64), b: &(i64, i64)) -> (i64, i64) {
+        (a.0 + b.0, a.1 + b.1)
+    }
+}
+
+/// 구간 갱신이 닿은 `(0, 0)` 원소는 `new(n)`으로 만든 트리에서만 생김
+const EMPTY_LEAF: &str =
+    "range update on a length-0 element; build with zeros(n) or from_values, not new(n)";
+
+/// 구간의 모든 원소에 더하기. 함수는 더할 값입니다.
+pub struct Add<T>(PhantomData<T>);
+
+impl Action<SumLen> for Add<SumLen> {
+    type F = i64;
+
+    fn identity_map() -> i64 {
+        0
+    }
+
+    fn apply(f: &i64, x: &(i64, i64)) -> (i64, i64) {
+        debug_assert!(x.1 > 0 || *f == 0, "{EMPTY_LEAF}");
+        (x.0 + f * x.1, x.1)
+    }
+
+    fn compose(f: &i64, g: &i64) -> i64 {
+        f + g
+    }
+}
+
+impl Action<Min<i64>> for Add<Min<i64>> {
+    type F = i64;
+
+    fn identity_map() -> i64 {
+        0
+    }
+
+    /// 항등원(`i64::MAX`, 빈 구간)은 그대로 둡니다.
+    fn apply(f: &i64, x: &i64) -> i64 {
+        if *x == i64::MAX { *x } else { x + f }
+    }
+
+    fn compose(f: &i64, g: &i64) -> i64 {
+        f + g
+    }
+}
+
+/// 구간의 모든 원소를 한 값으로 대입하기. 함수는 `Some(값)`이고, None이 항등 함수입니다.
+pub struct Assign;
+
+impl Action<SumLen> for Assign {
+    type F = Option<i64>;
+
+    fn identity_map() -> Option<i64> {
+        None
+    }
+
+    fn apply(f: &Option<i64>, x: &(i64, i64)) -> (i64, i64) {
+        debug_assert!(x.1 > 0 || f.is_none_or(|v| v == 0), "{EMPTY_LEAF}");
+        match f {
+            Some(v) => (v * x.1, x.1),
+            None => *x,
+        }
+    }
+
+    /// 나중에 대입한 값이 이깁니다.
+    fn compose(f: &Option<i64>, g: &Option<i64>) -> Option<i64> {
+        f.or(*g)
+    }
+}
+
+/// 구간 더하기 / 구간 합. 값은 `(합, 길이)`이고, 질의 결과의 `.0`이 합입니다.
+///
+/// ```
+/// use pskit::segtree::RangeAddSum;
+///
+/// // new(n)이 아니라 zeros(n): 원소마다 길이 1이 있어야 더한 값이 합에 들어감
+/// let mut tree = RangeAddSum::zeros(5);
+/// tree.apply_range(1..4, 3);
+/// assert_eq!(tree.query(0..5).0, 9);
+/// assert_eq!(tree.get(1), (3, 1));
+/// ```
+pub type RangeAddSum = LazySegTree<SumLen, Add<SumLen>>;
+/// 구간 더하기 / 구간 최솟값
+pub type RangeAddMin = LazySegTree<Min<i64>, Add<Min<i64>>>;
+/// 구간 대입 / 구간 합. 값은 `(합, 길이)`이고, 질의 결과의 `.0`이 합입니다.
+pub type RangeAssignSum = LazySegTree<SumLen, Assign>;
+
+impl<A: Action<SumLen>> LazySegTree<SumLen, A> {
+    /// 모든 원소가 0인 길이 n의 배열. 각 원소는 `(0, 1)`입니다.
+    pub fn zeros(n: usize) -> Self {
+        Self::from_slice(&vec![(0, 1); n])
+    }
+
+    /// 원소 값들로 생성합니다. 각 원소는 길이 1짜리 `(값, 1)`이 됩니다.
+    pub fn from_values(values: &[i64]) -> Self {
+        let pairs: Vec<(i64, i64)> = values.iter().map(|&x| (x, 1)).collect();
+        Self::from_slice(&pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 대입 후 더하기 `x → assign.unwrap_or(x) + add`. 합성 순서가 틀리면 바로 드러납니다.
+    struct AssignAdd;
+
+    impl Action<SumLen> for AssignAdd {
+        type F = (Option<i64>, i64);
+
+        fn identity_map() -> Self::F {
+            (None, 0)
+        }
+
+        fn apply(f: &Self::F, x: &(i64, i64)) -> (i64, i64) {
+            let base = f.0.map_or(x.0, |v| v * x.1);
+            (base + f.1 * x.1, x.1)
+        }
+
+        fn compose(f: &Self::F, g: &Self::F) -> Self::F {
+            match f.0 {
+                Some(_) => *f,
+                None => (g.0, g.1 + f.1),
+            }
+        }
+    }
+
+    fn random_range(rng: &mut XorShift, n: usize) -> Range<usize> {
+        let l = rng.index(n + 1);
+        l..l + rng.index(n + 1 - l)
+    }
+
+    #[test]
+    fn range_add_sum_and_min_against_vec() {
+        let mut rng = XorShift::new(134);
+        for _ in 0..100 {
+            let n = rng.index(40);
+            let mut a: Vec<i64> = (0..n).map(|_| rng.range_i64(-100, 100)).collect();
+            let mut sum = RangeAddSum::from_values(&a);
+            let mut min = RangeAddMin::from_slice(&a);
+            for _ in 0..100 {
+                let range = random_range(&mut rng, n);
+                match rng.below(4) {
+                    0 => {
+                        let delta = rng.range_i64(-100, 100);
+                        sum.apply_range(range.clone(), delta);
+                        min.apply_range(range.clone(), delta);
+                        a[range].iter_mut().for_each(|x| *x += delta);
+                    }
+                    1 if n > 0 => {
+                        let i = rng.index(n);
+                        a[i] = rng.range_i64(-100, 100);
+                        sum.set(i, (a[i], 1));
+                        min.set(i, a[i]);
+                        assert_eq!(sum.get(i).0, a[i]);
+                    }
+                    _ => {
+                        let expected_sum: i64 = a[range.clone()].iter().sum();
+                        let expected_min = a[range.clone()].iter().copied().min();
+                        assert_eq!(sum.query(range.clone()).0, expected_sum);
+                        assert_eq!(min.query(range.clone()), expected_min.unwrap_or(i64::MAX));
+                    }
+                }
+            }
+            assert_eq!(sum.all().0, a.iter().sum::<i64>());
+        }
+    }
+
+    #[test]
+    fn range_assign_sum_against_vec() {
+        let mut rng = XorShift::new(1340);
+        for _ in 0..100 {
+            let n = rng.index(40);
+            let mut a: Vec<i64> = (0..n).map(|_| rng.range_i64(-100, 100)).collect();
+            let mut tree = RangeAssignSum::from_values(&a);
+            let mut mixed: LazySegTree<SumLen, AssignAdd> = LazySegTree::from_values(&a);
+            let mut b = a.clone();
+            for _ in 0..100 {
+                let range = random_range(&mut rng, n);
+                let v = rng.range_i64(-100, 100);
+                match rng.below(3) {
+                    0 => {
+                        tree.apply_range(range.clone(), Some(v));
+                        a[range.clone()].iter_mut().for_each(|x| *x = v);
+                        mixed.apply_range(range.clone(), (Some(v), 0));
+                        b[range].iter_mut().for_each(|x| *x = v);
+                    }
+                    1 => {
+                        mixed.apply_range(range.clone(), (None, v));
+                        b[range].iter_mut().for_each(|x| *x += v);
+                    }
+                    _ => {
+                        assert_eq!(tree.query(range.clone()).0, a[range.clone()].iter().sum());
+                        assert_eq!(mixed.query(range.clone()).0, b[range].iter().sum());
+                    }
+                }
+            }
+            for (i, &x) in b.iter().enumerate() {
+                assert_eq!(mixed.get(i).0, x);
+            }
+        }
+    }
+
+    #[test]
+    fn later_assignment_overrides_earlier_adds() {
+        let mut tree: LazySegTree<SumLen, AssignAdd> = LazySegTree::from_values(&[1; 8]);
+        tree.apply_range(0..8, (None, 5));
+        tree.apply_range(2..6, (None, 3));
+        // 더하기가 내려가기 전에 대입이 덮어써야 함
+        tree.apply_range(0..4, (Some(10), 0));
+        tree.apply_range(3..8, (None, 1));
+        let values: Vec<i64> = (0..8).map(|i| tree.get(i).0).collect();
+        assert_eq!(values, vec![10, 10, 10, 11, 10, 10, 7, 7]);
+        assert_eq!(tree.query(0..8).0, 75);
+
+        let mut tree = RangeAssignSum::from_values(&[0; 6]);
+        tree.apply_range(0..6, Some(4));
+        tree.apply_range(1..3, Some(-1));
+        tree.apply_range(2..5, Some(2));
+        assert_eq!(tree.query(0..6).0, 4 - 1 + 2 + 2 + 2 + 4);
+        assert_eq!(tree.query(2..2).0, 0);
+    }
+
+    #[test]
+    fn zeros_counts_every_element() {
+        let mut add = RangeAddSum::zeros(7);
+        let mut assign = RangeAssignSum::zeros(7);
+        assert_eq!(add.query(0..7), (0, 7));
+        add.apply_range(2..7, -4);
+        assign.apply_range(0..3, Some(5));
+        assert_eq!(add.query(0..7), (-20, 7));
+        assert_eq!(add.query(1..3), (-4, 2));
+        assert_eq!(assign.query(2..7), (5, 5));
+        assert_eq!(assign.all(), &(15, 7));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "length-0 element")]
+    fn range_add_on_new_panics_in_debug() {
+        RangeAddSum::new(4).apply_range(0..2, 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "length-0 element")]
+    fn range_assign_on_new_panics_in_debug() {
+        RangeAssignSum::new(4).apply_range(1..4, Some(2));
+    }
+
+    #[test]
+    fn binary_search_after_range_updates() {
+        let mut rng = XorShift::new(135);
+        for _ in 0..100 {
+            let n = rng.index(30);
+            let mut a: Vec<i64> = (0..n).map(|_| rng.range_i64(0, 10)).collect();
+            let mut sum = RangeAddSum::from_values(&a);
+            let mut min = RangeAddMin::from_slice(&a);
+            for _ in 0..50 {
+                let range = random_range(&mut rng, n);
+                let delta = rng.range_i64(0, 5);
+                sum.apply_range(range.clone(), delta);
+                min.apply_range(range.clone(), delta);
+                a[range].iter_mut().for_each(|x| *x += delta);
+
+                let (i, x) = (rng.index(n + 1), rng.range_i64(0, 80));
+                // 합이 x 이하인 가장 긴 구간을 선형으로
+                let expected_right = (i..=n)
+                    .take_while(|&r| a[i..r].iter().sum::<i64>() <= x)
+                    .last()
+                    .unwrap();
+                assert_eq!(sum.max_right(i, |s| s.0 <= x), expected_right);
+                let expected_left = (0..=i).find(|&l| a[l..i].iter().sum::<i64>() <= x).unwrap();
+                assert_eq!(sum.min_left(i, |s| s.0 <= x), expected_left);
+                // 값이 x / 8 이상인 칸이 이어지는 동안
+                let t = x / 8;
+                let expected_right = i + a[i..].iter().take_while(|&&v| v >= t).count();
+                assert_eq!(min.max_right(i, |&m| m >= t), expected_right);
+                let expected_left = i - a[..i].iter().rev().take_while(|&&v| v >= t).count();
+                assert_eq!(min.min_left(i, |&m| m >= t), expected_left);
+            }
+        }
+    }
+
+    #[test]
+    fn large_smoke_test() {
+        let n = 200_000;
+        let mut rng = XorShift::new(1341);
+        let mut tree = RangeAddSum::zeros(n);
+        let mut min = RangeAddMin::from_slice(&vec![0; n]);
+        let mut total = 0i64;
+        for _ in 0..200_000 {
+            let l = rng.index(n);
+            let r = l + 1 + rng.index(n - l);
+            let delta = rng.range_i64(-5, 5);
+            tree.apply_range(l..r, delta);
+            min.apply_range(l..r, delta);
+            total += delta * (r - l) as i64;
+        }
+        assert_eq!(tree.query(0..n).0, total);
+        let true_min = (0..n).map(|i| min.get(i)).min().unwrap();
+        assert_eq!(min.query(0..n), true_min);
+    }
+}
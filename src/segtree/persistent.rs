@@ -0,0 +1,254 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::ops::Range;
+
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    sum: i64,
+    child: [u32; 2],
+}
+
+/// # Persistent segment tree
+/// 길이 n인 배열에 한 점 더하기를 할 때마다 새 버전을 만들고, 예전 버전도 그대로 질의할 수 있는 구간 합 트리입니다.
+///
+/// * 버전은 루트 노드 번호(`usize`)입니다. [`empty`](Self::empty)는 모든 값이 0인 버전이고,
+/// [`add`](Self::add)는 바뀐 경로의 노드만 새로 만들어서 새 버전을 반환합니다. 예전 버전은 바뀌지 않습니다.
+/// * 노드는 Vec 하나(arena)에 둡니다. 0번 노드는 "모두 0인 트리"이고 자식이 자기 자신이라서, 아직 만들지 않은
+/// 부분을 가리킬 때 씁니다.
+/// * 구간은 0-based 반열림입니다.
+/// ## 시간복잡도
+/// 연산마다 O(log n), 갱신마다 노드 O(log n)개
+#[derive(Clone, Debug)]
+pub struct PersistentSegTree {
+    n: usize,
+    nodes: Vec<Node>,
+}
+
+impl PersistentSegTree {
+    /// 길이 n. 처음에는 [`empty`](Self::empty) 버전 하나만 있습니다.
+    pub fn new(n: usize) -> Self {
+        PersistentSegTree {
+            n,
+            nodes: vec![Node {
+                sum: 0,
+                child: [0, 0],
+            }],
+        }
+    }
+
+    /// 배열의 길이
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// 모든 값이 0인 버전
+    pub fn empty(&self) -> usize {
+        0
+    }
+
+    /// 버전 root에서 `a[i] += delta`를 한 새 버전
+    pub fn add(&mut self, root: usize, i: usize, delta: i64) -> usize {
+        assert!(i < self.n, "index {i} out of range for length {}", self.n);
+        let new_root = self.copy_of(root);
+        let (mut lo, mut hi) = (0, self.n);
+        let mut v = new_root;
+        loop {
+            self.nodes[v].sum += delta;
+            if hi - lo == 1 {
+                return new_root;
+            }
+            let mid = (lo + hi) / 2;
+            let side = usize::from(i >= mid);
+            if side == 0 {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+            let child = self.copy_of(self.nodes[v].child[side] as usize);
+            self.nodes[v].child[side] = child as u32;
+            v = child;
+        }
+    }
+
+    /// 버전 root에서 구간 `l..r`의 합
+    pub fn query(&self, root: usize, range: Range<usize>) -> i64 {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.n,
+            "invalid range {l}..{r} for length {}",
+            self.n
+        );
+        let mut sum = 0;
+        let mut stack = vec![(root, 0, self.n)];
+        while let Some((v, lo, hi)) = stack.pop() {
+            if v == 0 || r <= lo || hi <= l {
+                continue;
+            }
+            if l <= lo && hi <= r {
+                sum += self.nodes[v].sum;
+                continue;
+            }
+            let mid = (lo + hi) / 2;
+            let [left, right] = self.nodes[v].child;
+            stack.push((left as usize, lo, mid));
+            stack.push((right as usize, mid, hi));
+        }
+        sum
+    }
+
+    /// 버전 `newer`에서 버전 `older`를 뺀 배열에서, 앞에서부터 합이 k를 넘는 첫 위치.
+    /// 값이 개수일 때 "두 버전 사이에 들어온 원소 중 k번째(0-based)로 작은 것"의 위치이고, 없으면 None입니다.
+    /// 차이의 모든 값이 0 이상이어야 합니다(예: `older`에 원소를 추가하기만 해서 `newer`를 만든 경우).
+    pub fn kth_between(&self, newer: usize, older: usize, k: i64) -> Option<usize> {
+        let (mut a, mut b) = (newer, older);
+        if k < 0 || self.n == 0 || self.nodes[a].sum - self.nodes[b].sum <= k {
+            return None;
+        }
+        let mut k = k;
+        let (mut lo, mut hi) = (0, self.n);
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            let [al, ar] = self.nodes[a].child;
+            let [bl, br] = self.nodes[b].child;
+            let left = self.nodes[al as usize].sum - self.nodes[bl as usize].sum;
+            if k < left {
+                (a, b, hi) = (al as usize, bl as usize, mid);
+            } else {
+                k -= left;
+                (a, b, lo) = (ar as usize, br as usize, mid);
+            }
+        }
+        Some(lo)
+    }
+
+    fn copy_of(&mut self, v: usize) -> usize {
+        let node = self.nodes[v];
+        self.nodes.push(node);
+        assert!(
+            self.nodes.len() <= u32::MAX as usize,
+            "too many nodes for u32 indices"
+        );
+        self.nodes.len() - 1
+    }
+}
+
+/// # 구간 k번째 수
+/// 각 질의 `(l, r, k)`마다 `values[l..r]`를 정렬했을 때 k번째(1-based, `1 <= k <= r - l`)로 작은 값을 구합니다.
+///
+/// 값을 좌표 압축한 뒤, i번째 버전이 `values[..i]`의 값별 개수인 [`PersistentSegTree`]를 만듭니다.
+/// 버전 r과 버전 l의 차이가 `values[l..r]`의 개수이므로, 두 트리를 함께 내려가며 k번째를 찾습니다.
+/// ## 시간복잡도
+/// O((n + q) log n)
+pub fn kth_smallest_in_range(values: &[i64], queries: &[(usize, usize, usize)]) -> Vec<i64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    sorted.dedup();
+    let mut tree = PersistentSegTree::new(sorted.len());
+    let mut versions = Vec::with_capacity(values.len() + 1);
+    versions.push(tree.empty());
+    for &x in values {
+        let rank = sorted.binary_search(&x).unwrap();
+        let last = *versions.last().unwrap();
+        versions.push(tree.add(last, rank, 1));
+    }
+    queries
+        .iter()
+        .map(|&(l, r, k)| {
+            assert!(
+                l < r && r <= values.len() && 1 <= k && k <= r - l,
+                "invalid query ({l}, {r}, {k}) for length {}",
+                values.len()
+            );
+            let rank = tree
+                .kth_between(versions[r], versions[l], k as i64 - 1)
+                .unwrap();
+            sorted[rank]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn versions_are_independent() {
+        let mut rng = XorShift::new(137);
+        for _ in 0..50 {
+            let n = 1 + rng.index(30);
+            let mut tree = PersistentSegTree::new(n);
+            let mut arrays = vec![vec![0i64; n]];
+            let mut roots = vec![tree.empty()];
+            for _ in 0..60 {
+                // 아무 예전 버전에서 새 버전을 만듦
+                let base = rng.index(roots.len());
+                let i = rng.index(n);
+                let delta = rng.range_i64(-10, 10);
+                let mut a = arrays[base].clone();
+                a[i] += delta;
+                roots.push(tree.add(roots[base], i, delta));
+                arrays.push(a);
+                let v = rng.index(roots.len());
+                let l = rng.index(n + 1);
+                let r = l + rng.index(n + 1 - l);
+                assert_eq!(tree.query(roots[v], l..r), arrays[v][l..r].iter().sum());
+            }
+        }
+    }
+
+    #[test]
+    fn random_kth_against_sorting() {
+        let mut rng = XorShift::new(1370);
+        for _ in 0..100 {
+            let n = 1 + rng.index(50);
+            let values: Vec<i64> = (0..n).map(|_| rng.range_i64(-20, 20)).collect();
+            let queries: Vec<(usize, usize, usize)> = (0..50)
+                .map(|q| {
+                    let l = rng.index(n);
+                    let r = l + 1 + rng.index(n - l);
+                    // 한 원소 구간, k = 구간 길이도 섞음
+                    match q % 5 {
+                        0 => (l, l + 1, 1),
+                        1 => (l, r, r - l),
+                        _ => (l, r, 1 + rng.index(r - l)),
+                    }
+                })
+                .collect();
+            let answers = kth_smallest_in_range(&values, &queries);
+            for (&(l, r, k), &answer) in queries.iter().zip(&answers) {
+                let mut sub = values[l..r].to_vec();
+                sub.sort_unstable();
+                assert_eq!(answer, sub[k - 1]);
+            }
+        }
+    }
+
+    #[test]
+    fn small_example() {
+        let values = [5, 1, 4, 1, 3, i64::MAX, i64::MIN];
+        let queries = [
+            (0, 5, 1),
+            (0, 5, 3),
+            (2, 3, 1),
+            (0, 7, 7),
+            (0, 7, 1),
+            (1, 4, 2),
+        ];
+        assert_eq!(
+            kth_smallest_in_range(&values, &queries),
+            vec![1, 3, 4, i64::MAX, i64::MIN, 1]
+        );
+        assert_eq!(kth_smallest_in_range(&[], &[]), vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid query (1, 3, 3)")]
+    fn k_larger_than_range_panics() {
+        kth_smallest_in_range(&[1, 2, 3], &[(1, 3, 3)]);
+    }
+}
@@ -0,0 +1,378 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::Monoid;
+use std::ops::Range;
+
+/// # Segment tree
+/// 길이 n인 배열에 한 점 바꾸기와 구간 `l..r`을 [`Monoid`]로 접은 값 구하기를 O(log n)에 처리합니다.
+///
+/// * 크기를 2의 거듭제곱 `size`로 늘린 완전이진트리를 배열 하나에 담습니다. `data[size + i]`가 i번째 원소이고,
+/// `data[k] = op(data[2k], data[2k + 1])`입니다.
+/// * 질의는 재귀 없이 양 끝에서 위로 올라가며 왼쪽 결과와 오른쪽 결과를 따로 모읍니다. 그래서 교환법칙이 없는
+/// 연산(문자열 이어 붙이기, 행렬 곱 등)도 `a[l], a[l + 1], …, a[r - 1]` 순서대로 접힙니다.
+/// * 구간은 0-based 반열림이고, 빈 구간은 항등원입니다.
+/// ## 시간복잡도
+/// 생성 O(n), 연산마다 O(log n)
+pub struct SegTree<M: Monoid> {
+    n: usize,
+    size: usize,
+    data: Vec<M::S>,
+}
+
+impl<M: Monoid> SegTree<M> {
+    /// 모든 값이 항등원인 길이 n의 배열
+    pub fn new(n: usize) -> Self {
+        Self::from_slice(&vec![M::identity(); n])
+    }
+
+    /// 주어진 배열로 O(n)에 생성
+    pub fn from_slice(values: &[M::S]) -> Self {
+        let n = values.len();
+        let size = n.next_power_of_two();
+        let mut data = vec![M::identity(); 2 * size];
+        data[size..size + n].clone_from_slice(values);
+        for k in (1..size).rev() {
+            data[k] = M::op(&data[2 * k], &data[2 * k + 1]);
+        }
+        SegTree { n, size, data }
+    }
+
+    /// 배열의 길이
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// `a[i] = value`
+    pub fn set(&mut self, i: usize, value: M::S) {
+        assert!(i < self.n, "index {i} out of range for length {}", self.n);
+        let mut k = i + self.size;
+        self.data[k] = value;
+        while k > 1 {
+            k >>= 1;
+            self.data[k] = M::op(&self.data[2 * k], &self.data[2 * k + 1]);
+        }
+    }
+
+    /// `a[i]`
+    pub fn get(&self, i: usize) -> &M::S {
+        assert!(i < self.n, "index {i} out of range for length {}", self.n);
+        &self.data[i + self.size]
+    }
+
+    /// `op(a[l], a[l + 1], …, a[r - 1])`. 빈 구간은 항등원이고, `l > r`이거나 범위를 벗어나면 panic합니다.
+    pub fn query(&self, range: Range<usize>) -> M::S {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.n,
+            "invalid range {l}..{r} for length {}",
+            self.n
+        );
+        let (mut l, mut r) = (l + self.size, r + self.size);
+        let mut left = M::identity();
+        let mut right = M::identity();
+        while l < r {
+            if l & 1 == 1 {
+                left = M::op(&left, &self.data[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                right = M::op(&self.data[r], &right);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        M::op(&left, &right)
+    }
+
+    /// 전체 배열을 접은 값
+    pub fn all(&self) -> &M::S {
+        &self.data[1]
+    }
+
+    /// `pred(query(l..r))`가 참인 가장 큰 r (l ≤ r ≤ n).
+    ///
+    /// * `pred`는 단조여야 합니다. 즉 `pred(query(l..r))`가 참이면 더 짧은 `l..r'`(r' < r)에서도 참이어야 합니다.
+    /// * `pred(identity)`는 참이어야 합니다(디버그 빌드에서 검사). 그래서 답은 항상 l 이상입니다.
+    /// * 예: 값이 0 이상일 때 `max_right(l, |s| *s <= x)`는 합이 x를 넘지 않는 가장 긴 구간의 끝입니다.
+    /// ## 시간복잡도
+    /// O(log n). r을 이분 탐색하며 `query`를 부르는 O(log² n) 방법 대신 트리를 한 번 오르내립니다.
+    pub fn max_right(&self, l: usize, pred: impl Fn(&M::S) -> bool) -> usize {
+        assert!(l <= self.n, "index {l} out of range for length {}", self.n);
+        debug_assert!(pred(&M::identity()), "pred(identity) must be true");
+        if l == self.n {
+            return self.n;
+        }
+        let mut l = l + self.size;
+        let mut acc = M::identity();
+        loop {
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+            let next = M::op(&acc, &self.data[l]);
+            if !pred(&next) {
+                while l < self.size {
+                    l *= 2;
+                    let next = M::op(&acc, &self.data[l]);
+                    if pred(&next) {
+                        acc = next;
+                        l += 1;
+                    }
+                }
+                return l - self.size;
+            }
+            acc = next;
+            l += 1;
+            if l.is_power_of_two() {
+                return self.n;
+            }
+        }
+    }
+
+    /// `pred(query(l..r))`가 참인 가장 작은 l (0 ≤ l ≤ r). [`max_right`](Self::max_right)의 반대 방향입니다.
+    /// `pred`는 단조여야 하고 `pred(identity)`는 참이어야 합니다.
+    /// ## 시간복잡도
+    /// O(log n)
+    pub fn min_left(&self, r: usize, pred: impl Fn(&M::S) -> bool) -> usize {
+        assert!(r <= self.n, "index {r} out of range for length {}", self.n);
+        debug_assert!(pred(&M::identity()), "pred(identity) must be true");
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.size;
+        let mut acc = M::identity();
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let next = M::op(&self.data[r], &acc);
+            if !pred(&next) {
+                while r < self.size {
+                    r = 2 * r + 1;
+                    let next = M::op(&self.data[r], &acc);
+                    if pred(&next) {
+                        acc = next;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.size;
+            }
+            acc = next;
+            if r.is_power_of_two() {
+                return 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::{Gcd, Max, Min, Sum};
+    use crate::random::XorShift;
+
+    /// 문자열 이어 붙이기: 교환법칙이 없음
+    struct Concat;
+
+    impl Monoid for Concat {
+        type S = String;
+
+        fn identity() -> String {
+            String::new()
+        }
+
+        fn op(a: &String, b: &String) -> String {
+            format!("{a}{b}")
+        }
+    }
+
+    /// 2×2 행렬 곱 (mod 1_000_000_007)
+    struct MatMul;
+
+    impl Monoid for MatMul {
+        type S = [[u64; 2]; 2];
+
+        fn identity() -> Self::S {
+            [[1, 0], [0, 1]]
+        }
+
+        fn op(a: &Self::S, b: &Self::S) -> Self::S {
+            const P: u64 = 1_000_000_007;
+            let mut c = [[0; 2]; 2];
+            for (i, row) in c.iter_mut().enumerate() {
+                for (j, x) in row.iter_mut().enumerate() {
+                    *x = (a[i][0] * b[0][j] + a[i][1] * b[1][j]) % P;
+                }
+            }
+            c
+        }
+    }
+
+    fn check_against_fold<M: Monoid>(
+        rng: &mut XorShift,
+        mut gen_value: impl FnMut(&mut XorShift) -> M::S,
+    ) where
+        M::S: PartialEq + std::fmt::Debug,
+    {
+        for _ in 0..50 {
+            let n = rng.index(40);
+            let mut a: Vec<M::S> = (0..n).map(|_| gen_value(rng)).collect();
+            let mut tree: SegTree<M> = if rng.below(2) == 0 {
+                SegTree::from_slice(&a)
+            } else {
+                let mut t = SegTree::new(n);
+                for (i, x) in a.iter().enumerate() {
+                    t.set(i, x.clone());
+                }
+                t
+            };
+            for _ in 0..100 {
+                if n > 0 && rng.below(2) == 0 {
+                    let i = rng.index(n);
+                    a[i] = gen_value(rng);
+                    tree.set(i, a[i].clone());
+                    assert_eq!(tree.get(i), &a[i]);
+                }
+                let l = rng.index(n + 1);
+                let r = l + rng.index(n + 1 - l);
+                let expected = a[l..r].iter().fold(M::identity(), |acc, x| M::op(&acc, x));
+                assert_eq!(tree.query(l..r), expected);
+            }
+            let total = a.iter().fold(M::identity(), |acc, x| M::op(&acc, x));
+            assert_eq!(tree.all(), &total);
+        }
+    }
+
+    #[test]
+    fn random_against_brute_force_fold() {
+        let mut rng = XorShift::new(133);
+        check_against_fold::<Sum<i64>>(&mut rng, |r| r.range_i64(-100, 100));
+        check_against_fold::<Min<i64>>(&mut rng, |r| r.range_i64(-100, 100));
+        check_against_fold::<Max<i32>>(&mut rng, |r| r.range_i64(-100, 100) as i32);
+        check_against_fold::<Gcd<u64>>(&mut rng, |r| r.below(6) * r.below(6));
+        check_against_fold::<Gcd<i64>>(&mut rng, |r| r.range_i64(0, 12));
+        check_against_fold::<Concat>(&mut rng, |r| {
+            ((b'a' + r.below(26) as u8) as char).to_string()
+        });
+        check_against_fold::<MatMul>(&mut rng, |r| {
+            [[r.below(5), r.below(5)], [r.below(5), r.below(5)]]
+        });
+    }
+
+    #[test]
+    fn fold_order_is_left_to_right() {
+        let words: Vec<String> = ["ab", "c", "", "def", "g"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mut tree: SegTree<Concat> = SegTree::from_slice(&words);
+        assert_eq!(tree.query(0..5), "abcdefg");
+        assert_eq!(tree.query(1..4), "cdef");
+        tree.set(2, "X".to_string());
+        assert_eq!(tree.query(1..5), "cXdefg");
+        // 피보나치 행렬과 교환되지 않는 행렬
+        let fib = [[1, 1], [1, 0]];
+        let shear = [[1, 2], [0, 1]];
+        let tree: SegTree<MatMul> = SegTree::from_slice(&[fib, shear, fib]);
+        let expected = MatMul::op(&MatMul::op(&fib, &shear), &fib);
+        assert_eq!(tree.query(0..3), expected);
+        assert_ne!(tree.query(0..2), MatMul::op(&shear, &fib));
+    }
+
+    /// `pred(query(l..r))`가 참인 가장 큰 r을 이분 탐색으로 (O(log² n))
+    fn max_right_by_bisection<M: Monoid>(
+        tree: &SegTree<M>,
+        l: usize,
+        pred: impl Fn(&M::S) -> bool,
+    ) -> usize {
+        let (mut lo, mut hi) = (l, tree.len() + 1);
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if pred(&tree.query(l..mid)) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    fn min_left_by_bisection<M: Monoid>(
+        tree: &SegTree<M>,
+        r: usize,
+        pred: impl Fn(&M::S) -> bool,
+    ) -> usize {
+        // pred(query(l..r))가 참인 l은 [answer, r] 구간
+        let (mut lo, mut hi) = (0, r + 1);
+        if pred(&tree.query(0..r)) {
+            return 0;
+        }
+        while hi - lo > 1 {
+            let mid = (lo + hi) / 2;
+            if pred(&tree.query(mid..r)) {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        hi
+    }
+
+    #[test]
+    fn binary_search_on_tree() {
+        let mut rng = XorShift::new(135);
+        for _ in 0..200 {
+            let n = rng.index(40);
+            let a: Vec<i64> = (0..n).map(|_| rng.range_i64(0, 10)).collect();
+            let sum: SegTree<Sum<i64>> = SegTree::from_slice(&a);
+            let min: SegTree<Min<i64>> = SegTree::from_slice(&a);
+            for _ in 0..30 {
+                let x = rng.range_i64(0, 60);
+                let i = rng.index(n + 1);
+                let le = |s: &i64| *s <= x;
+                assert_eq!(sum.max_right(i, le), max_right_by_bisection(&sum, i, le));
+                assert_eq!(sum.min_left(i, le), min_left_by_bisection(&sum, i, le));
+                // 최솟값이 x / 6 이상인 동안
+                let ge = |m: &i64| *m >= x / 6;
+                assert_eq!(min.max_right(i, ge), max_right_by_bisection(&min, i, ge));
+                assert_eq!(min.min_left(i, ge), min_left_by_bisection(&min, i, ge));
+            }
+        }
+    }
+
+    #[test]
+    fn binary_search_edge_cases() {
+        let tree: SegTree<Sum<i64>> = SegTree::from_slice(&[5, 1, 2, 7, 3]);
+        // 첫 원소부터 조건을 깨면 답은 l 자신
+        assert_eq!(tree.max_right(0, |s| *s < 5), 0);
+        assert_eq!(tree.max_right(3, |s| *s < 7), 3);
+        assert_eq!(tree.min_left(4, |s| *s < 7), 4);
+        // 끝까지 만족하면 n 또는 0
+        assert_eq!(tree.max_right(1, |_| true), 5);
+        assert_eq!(tree.min_left(5, |_| true), 0);
+        assert_eq!(tree.max_right(5, |s| *s == 0), 5);
+        assert_eq!(tree.min_left(0, |s| *s == 0), 0);
+        // 합이 8 이하인 가장 긴 앞부분: 5 + 1 + 2
+        assert_eq!(tree.max_right(0, |s| *s <= 8), 3);
+        assert_eq!(tree.min_left(5, |s| *s <= 10), 3);
+    }
+
+    #[test]
+    fn tiny_sizes() {
+        let empty: SegTree<Sum<i64>> = SegTree::new(0);
+        assert!(empty.is_empty());
+        assert_eq!(empty.query(0..0), 0);
+        assert_eq!(*empty.all(), 0);
+        let mut one: SegTree<Min<i64>> = SegTree::from_slice(&[5]);
+        assert_eq!(one.query(0..1), 5);
+        assert_eq!(one.query(1..1), i64::MAX);
+        one.set(0, -3);
+        assert_eq!(*one.all(), -3);
+        assert_eq!(one.len(), 1);
+    }
+}
@@ -0,0 +1,165 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::algebra::{Gcd, Monoid};
+use std::ops::{BitAnd, BitOr, Range};
+
+/// # SparseTable
+/// 값이 바뀌지 않는 배열에서 구간 연산 결과를 O(1)에 구합니다.
+///
+/// * `table[k][i]`는 구간 `i..i + 2^k`의 연산 결과입니다. 전처리 O(n log n)
+/// * 질의는 길이 2^k짜리 두 구간을 겹쳐서 합치므로 `op`는 결합법칙과 멱등성(`op(a, a) == a`)을 만족해야 합니다.
+/// 예: min, max, gcd, bitwise and/or. 자주 쓰는 연산은 [`min`](Self::min), [`gcd`](Self::gcd) 같은 생성자가 있습니다.
+/// * 멱등이 아닌 연산(합, 곱)은 [`DisjointSparseTable`](crate::disjoint_sparse_table::DisjointSparseTable)을 쓰세요.
+/// * 항등원이 없으므로 빈 구간 질의는 panic합니다.
+pub struct SparseTable<T, F> {
+    table: Vec<Vec<T>>,
+    op: F,
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> SparseTable<T, F> {
+    /// 배열과 멱등 연산으로 생성
+    pub fn new(values: &[T], op: F) -> Self {
+        let n = values.len();
+        let mut table = vec![values.to_vec()];
+        let mut k = 1;
+        while (1usize << k) <= n {
+            let prev = &table[k - 1];
+            let half = 1usize << (k - 1);
+            let next: Vec<T> = (0..=n - (1 << k))
+                .map(|i| op(&prev[i], &prev[i + half]))
+                .collect();
+            table.push(next);
+            k += 1;
+        }
+        SparseTable { table, op }
+    }
+
+    /// 원소 개수
+    pub fn len(&self) -> usize {
+        self.table[0].len()
+    }
+
+    /// 비었는지
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 구간 `l..r`의 연산 결과. 빈 구간이거나 범위를 벗어나면 panic합니다.
+    pub fn query(&self, range: Range<usize>) -> T {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l < r && r <= self.len(),
+            "invalid range {l}..{r} for length {}",
+            self.len()
+        );
+        let k = (r - l).ilog2() as usize;
+        (self.op)(&self.table[k][l], &self.table[k][r - (1 << k)])
+    }
+}
+
+impl<T: Ord + Clone> SparseTable<T, fn(&T, &T) -> T> {
+    /// 구간 최솟값 테이블
+    pub fn min(values: &[T]) -> Self {
+        Self::new(values, |a, b| a.min(b).clone())
+    }
+
+    /// 구간 최댓값 테이블
+    pub fn max(values: &[T]) -> Self {
+        Self::new(values, |a, b| a.max(b).clone())
+    }
+}
+
+impl<T: Copy> SparseTable<T, fn(&T, &T) -> T> {
+    /// 구간 최대공약수 테이블. 부호 있는 타입이면 값이 0 이상이어야 합니다.
+    pub fn gcd(values: &[T]) -> Self
+    where
+        Gcd<T>: Monoid<S = T>,
+    {
+        Self::new(values, <Gcd<T> as Monoid>::op)
+    }
+
+    /// 구간 bitwise and 테이블
+    pub fn bit_and(values: &[T]) -> Self
+    where
+        T: BitAnd<Output = T>,
+    {
+        Self::new(values, |a, b| *a & *b)
+    }
+
+    /// 구간 bitwise or 테이블
+    pub fn bit_or(values: &[T]) -> Self
+    where
+        T: BitOr<Output = T>,
+    {
+        Self::new(values, |a, b| *a | *b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_range_min_against_brute_force() {
+        let mut rng = XorShift::new(111);
+        for _ in 0..50 {
+            let n = 1 + rng.index(100);
+            let values: Vec<i64> = (0..n).map(|_| rng.range_i64(-50, 50)).collect();
+            let st = SparseTable::new(&values, |a: &i64, b: &i64| *a.min(b));
+            for l in 0..n {
+                for r in l + 1..=n {
+                    assert_eq!(st.query(l..r), *values[l..r].iter().min().unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn convenience_constructors_against_brute_force() {
+        let mut rng = XorShift::new(142);
+        for _ in 0..50 {
+            let n = 1 + rng.index(60);
+            let values: Vec<u64> = (0..n).map(|_| rng.below(64) * (1 + rng.below(4))).collect();
+            let max = SparseTable::max(&values);
+            let gcd = SparseTable::gcd(&values);
+            let and = SparseTable::bit_and(&values);
+            let or = SparseTable::bit_or(&values);
+            for l in 0..n {
+                for r in l + 1..=n {
+                    let part = &values[l..r];
+                    assert_eq!(max.query(l..r), *part.iter().max().unwrap());
+                    assert_eq!(
+                        gcd.query(l..r),
+                        part.iter()
+                            .fold(0, |g, &x| <Gcd<u64> as Monoid>::op(&g, &x))
+                    );
+                    assert_eq!(and.query(l..r), part.iter().fold(u64::MAX, |a, &x| a & x));
+                    assert_eq!(or.query(l..r), part.iter().fold(0, |a, &x| a | x));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_elements_and_full_range() {
+        let values = [12i64, 18, 7, -3, 30];
+        let min = SparseTable::min(&values);
+        for (i, &v) in values.iter().enumerate() {
+            assert_eq!(min.query(i..i + 1), v);
+        }
+        assert_eq!(min.query(0..values.len()), -3);
+        let gcd = SparseTable::gcd(&[12i64, 18, 30, 42]);
+        assert_eq!(gcd.query(0..4), 6);
+        assert_eq!(gcd.query(2..3), 30);
+        let words = SparseTable::max(&["b", "abc", "ba"]);
+        assert_eq!(words.query(0..3), "ba");
+        assert!(SparseTable::<u8, fn(&u8, &u8) -> u8>::min(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_range_panics() {
+        let st = SparseTable::new(&[1, 2, 3], |a: &i32, b: &i32| *a.max(b));
+        st.query(1..1);
+    }
+}
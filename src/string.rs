@@ -0,0 +1,34 @@
+mod aho_corasick;
+pub use aho_corasick::AhoCorasick;
+mod edit_distance;
+pub use edit_distance::{EditOp, edit_distance, edit_distance_bounded, edit_distance_ops};
+mod eertree;
+pub use eertree::Eertree;
+mod kmp;
+pub use kmp::{kmp_find_all, kmp_find_all_str, period, period_str, prefix_function};
+mod kmp_automaton;
+pub use kmp_automaton::{MatchPolicy, kmp_automaton};
+mod lcs;
+pub use lcs::{lcs, lcs_length};
+mod lyndon;
+pub use lyndon::{duval, minimal_rotation_duval, smallest_suffix};
+mod manacher;
+pub use manacher::{count_palindromic_substrings, longest_palindromic_substring, manacher};
+mod pattern_2d;
+pub use pattern_2d::find_pattern_2d;
+mod repeated;
+pub use repeated::{longest_repeated_substring, longest_substring_repeated_k_times};
+mod rolling_hash;
+pub use rolling_hash::{HashParams, RollingHash, StrHash};
+mod rotation;
+pub use rotation::{canonical_rotation, minimal_rotation, rotations_equal};
+mod run_length;
+pub use run_length::{run_length_decode, run_length_encode, run_length_encode_str, runs};
+mod suffix_array;
+pub use suffix_array::{SuffixArray, lcp_array, suffix_array};
+mod suffix_automaton;
+pub use suffix_automaton::SuffixAutomaton;
+mod trie;
+pub use trie::Trie;
+mod z;
+pub use z::{z_function, z_search};
@@ -0,0 +1,248 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::collections::VecDeque;
+
+/// 출력 링크가 없음을 나타내는 번호
+const NIL: u32 = u32::MAX;
+
+#[derive(Clone, Debug)]
+struct Node {
+    /// (글자, 자식). 글자 순으로 정렬되어 있습니다.
+    next: Vec<(u8, u32)>,
+    fail: u32,
+    /// fail 링크를 따라가서 처음 만나는, 패턴이 끝나는 노드
+    dict: u32,
+    depth: usize,
+    /// 이 노드에서 끝나는 패턴 번호 (같은 패턴을 여러 번 넣으면 모두)
+    patterns: Vec<usize>,
+}
+
+impl Node {
+    fn child(&self, c: u8) -> Option<u32> {
+        self.next
+            .binary_search_by_key(&c, |&(d, _)| d)
+            .ok()
+            .map(|i| self.next[i].1)
+    }
+}
+
+/// # Aho–Corasick
+/// 여러 패턴을 한꺼번에 text에서 찾습니다. 겹치는 등장과 같은 패턴을 여러 번 넣은 경우를 모두 따로 셉니다.
+///
+/// * 패턴으로 트라이를 만들고 BFS로 fail 링크(가장 긴 진짜 접미사 노드)를 답니다. 전이가 없으면 fail 링크를
+/// 따라가므로 text를 훑는 데 amortized O(|text|)가 듭니다.
+/// * [`find_all`](Self::find_all)은 노드마다 "패턴이 끝나는 가장 가까운 fail 조상" 링크를 따라 모든 등장을
+/// 나열합니다. 등장 횟수만 필요하면 [`count_matches`](Self::count_matches)를 쓰세요. 노드별 방문 횟수를 fail
+/// 트리에서 BFS 역순으로 더하므로 패턴이 많이 겹쳐도 O(|text| + 노드 수)입니다.
+/// * 빈 패턴은 `0..=|text|`의 모든 위치에서 한 번씩 나타난다고 봅니다([`kmp_find_all`](super::kmp_find_all)과 같음).
+/// ## 시간복잡도
+/// 생성 O(Σ|패턴| log σ), `find_all` O(|text| log σ + 답의 개수), `count_matches` O(|text| log σ + Σ|패턴|)
+#[derive(Clone, Debug)]
+pub struct AhoCorasick {
+    nodes: Vec<Node>,
+    /// 패턴별 끝 노드
+    pattern_node: Vec<u32>,
+    /// BFS 순서의 노드 번호
+    order: Vec<u32>,
+}
+
+impl AhoCorasick {
+    /// 패턴 목록으로 생성
+    pub fn build(patterns: &[&[u8]]) -> Self {
+        let mut nodes = vec![Node {
+            next: Vec::new(),
+            fail: 0,
+            dict: NIL,
+            depth: 0,
+            patterns: Vec::new(),
+        }];
+        let mut pattern_node = Vec::with_capacity(patterns.len());
+        for (id, pattern) in patterns.iter().enumerate() {
+            let mut v = 0;
+            for &c in pattern.iter() {
+                v = match nodes[v as usize].child(c) {
+                    Some(to) => to,
+                    None => {
+                        let to =
+                            u32::try_from(nodes.len()).expect("too many nodes for u32 indices");
+                        assert!(to != NIL, "too many nodes for u32 indices");
+                        let depth = nodes[v as usize].depth + 1;
+                        nodes.push(Node {
+                            next: Vec::new(),
+                            fail: 0,
+                            dict: NIL,
+                            depth,
+                            patterns: Vec::new(),
+                        });
+                        let next = &mut nodes[v as usize].next;
+                        let at = next.partition_point(|&(d, _)| d < c);
+                        next.insert(at, (c, to));
+                        to
+                    }
+                };
+            }
+            nodes[v as usize].patterns.push(id);
+            pattern_node.push(v);
+        }
+        let mut automaton = AhoCorasick {
+            nodes,
+            pattern_node,
+            order: Vec::new(),
+        };
+        let mut queue = VecDeque::from([0u32]);
+        while let Some(v) = queue.pop_front() {
+            automaton.order.push(v);
+            for i in 0..automaton.nodes[v as usize].next.len() {
+                let (c, to) = automaton.nodes[v as usize].next[i];
+                let fail = if v == 0 {
+                    0
+                } else {
+                    automaton.step(automaton.nodes[v as usize].fail, c)
+                };
+                let dict = if automaton.nodes[fail as usize].patterns.is_empty() {
+                    automaton.nodes[fail as usize].dict
+                } else {
+                    fail
+                };
+                let node = &mut automaton.nodes[to as usize];
+                node.fail = fail;
+                node.dict = dict;
+                queue.push_back(to);
+            }
+        }
+        automaton
+    }
+
+    /// 패턴의 개수
+    pub fn pattern_count(&self) -> usize {
+        self.pattern_node.len()
+    }
+
+    /// 모든 등장의 `(시작 위치, 패턴 번호)`를 정렬해서
+    pub fn find_all(&self, text: &[u8]) -> Vec<(usize, usize)> {
+        let mut result = Vec::new();
+        let empty = &self.nodes[0].patterns;
+        let mut v = 0;
+        for (i, &c) in text.iter().enumerate() {
+            result.extend(empty.iter().map(|&id| (i, id)));
+            v = self.step(v, c);
+            let mut u = if self.nodes[v as usize].patterns.is_empty() {
+                self.nodes[v as usize].dict
+            } else {
+                v
+            };
+            while u != NIL && u != 0 {
+                let node = &self.nodes[u as usize];
+                result.extend(node.patterns.iter().map(|&id| (i + 1 - node.depth, id)));
+                u = node.dict;
+            }
+        }
+        result.extend(empty.iter().map(|&id| (text.len(), id)));
+        result.sort_unstable();
+        result
+    }
+
+    /// 패턴별 등장 횟수
+    pub fn count_matches(&self, text: &[u8]) -> Vec<u64> {
+        let mut visits = vec![0u64; self.nodes.len()];
+        visits[0] = text.len() as u64 + 1;
+        let mut v = 0;
+        for &c in text {
+            v = self.step(v, c);
+            if v != 0 {
+                visits[v as usize] += 1;
+            }
+        }
+        // 깊은 노드부터 fail 부모에 더하면, 각 노드의 값은 그 노드로 끝나는 위치의 개수
+        for &v in self.order.iter().skip(1).rev() {
+            let fail = self.nodes[v as usize].fail;
+            if fail != 0 {
+                visits[fail as usize] += visits[v as usize];
+            }
+        }
+        self.pattern_node
+            .iter()
+            .map(|&v| visits[v as usize])
+            .collect()
+    }
+
+    /// 상태 v에서 c를 읽은 다음 상태
+    fn step(&self, mut v: u32, c: u8) -> u32 {
+        loop {
+            if let Some(to) = self.nodes[v as usize].child(c) {
+                return to;
+            }
+            if v == 0 {
+                return 0;
+            }
+            v = self.nodes[v as usize].fail;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_bytes};
+    use crate::string::kmp_find_all;
+
+    #[test]
+    fn random_against_kmp_per_pattern() {
+        let mut rng = XorShift::new(162);
+        for round in 0..300 {
+            let alphabet = [1, 2, 3, 26][round % 4];
+            let count = rng.index(8);
+            let patterns: Vec<Vec<u8>> = (0..count)
+                .map(|_| {
+                    let m = rng.index(5);
+                    random_bytes(&mut rng, m, b'a', alphabet)
+                })
+                .collect();
+            let refs: Vec<&[u8]> = patterns.iter().map(|p| p.as_slice()).collect();
+            let automaton = AhoCorasick::build(&refs);
+            assert_eq!(automaton.pattern_count(), count);
+            for _ in 0..5 {
+                let n = rng.index(60);
+                let text = random_bytes(&mut rng, n, b'a', alphabet);
+                let mut expected: Vec<(usize, usize)> = refs
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(id, p)| kmp_find_all(&text, p).into_iter().map(move |i| (i, id)))
+                    .collect();
+                expected.sort_unstable();
+                assert_eq!(automaton.find_all(&text), expected);
+                let counts: Vec<u64> = refs
+                    .iter()
+                    .map(|p| kmp_find_all(&text, p).len() as u64)
+                    .collect();
+                assert_eq!(automaton.count_matches(&text), counts);
+            }
+        }
+    }
+
+    #[test]
+    fn nested_duplicate_and_empty_patterns() {
+        let patterns: [&[u8]; 5] = [b"a", b"aa", b"aaa", b"aa", b""];
+        let automaton = AhoCorasick::build(&patterns);
+        assert_eq!(automaton.count_matches(b"aaaa"), vec![4, 3, 2, 3, 5]);
+        assert_eq!(
+            automaton.find_all(b"aa"),
+            vec![(0, 0), (0, 1), (0, 3), (0, 4), (1, 0), (1, 4), (2, 4)]
+        );
+        let none = AhoCorasick::build(&[]);
+        assert!(none.find_all(b"abc").is_empty());
+        assert!(none.count_matches(b"abc").is_empty());
+    }
+
+    #[test]
+    fn heavily_nested_patterns_count_fast() {
+        // "a", "aa", ..., 길이 1000짜리까지: 출력 링크를 따라 세면 위치마다 1000번 걸음
+        let patterns: Vec<Vec<u8>> = (1..=1000).map(|m| vec![b'a'; m]).collect();
+        let refs: Vec<&[u8]> = patterns.iter().map(|p| p.as_slice()).collect();
+        let automaton = AhoCorasick::build(&refs);
+        let text = vec![b'a'; 200_000];
+        let counts = automaton.count_matches(&text);
+        for (m, &c) in (1..=1000u64).zip(&counts) {
+            assert_eq!(c, 200_001 - m);
+        }
+    }
+}
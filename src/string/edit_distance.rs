@@ -0,0 +1,212 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// 편집 연산 하나. 위치는 연산을 적용할 때의 문자열 기준입니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditOp {
+    /// pos 앞에 byte를 넣음
+    Insert { pos: usize, byte: u8 },
+    /// pos의 글자를 지움
+    Delete { pos: usize },
+    /// pos의 글자를 byte로 바꿈
+    Substitute { pos: usize, byte: u8 },
+}
+
+/// # Edit distance (Levenshtein)
+/// a를 b로 바꾸는 데 필요한 글자 넣기, 지우기, 바꾸기의 최소 횟수입니다.
+///
+/// * DP의 행 하나만 두고, 짧은 쪽을 행으로 씁니다.
+/// ## 시간복잡도
+/// O(|a| |b|), 메모리 O(min(|a|, |b|))
+pub fn edit_distance(a: &[u8], b: &[u8]) -> usize {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut row: Vec<usize> = (0..=short.len()).collect();
+    for (i, &x) in long.iter().enumerate() {
+        // diagonal: 지난 행의 row[j]
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &y) in short.iter().enumerate() {
+            let cost = diagonal + usize::from(x != y);
+            diagonal = row[j + 1];
+            row[j + 1] = cost.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[short.len()]
+}
+
+/// # Edit script
+/// [`edit_distance`]만큼의 연산으로 a를 b로 바꾸는 순서를 반환합니다.
+///
+/// * 연산은 a의 뒤쪽부터 나오므로, 앞에서부터 차례로 적용하면 각 연산의 위치는 아직 건드리지 않은 a의 위치와
+/// 같습니다.
+/// ## 시간복잡도
+/// O(|a| |b|), 메모리 O(|a| |b|)
+pub fn edit_distance_ops(a: &[u8], b: &[u8]) -> Vec<EditOp> {
+    let (n, m) = (a.len(), b.len());
+    let mut dp = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in dp.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    dp[0] = (0..=m).collect();
+    for i in 1..=n {
+        for j in 1..=m {
+            let substitute = dp[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            dp[i][j] = substitute.min(dp[i - 1][j] + 1).min(dp[i][j - 1] + 1);
+        }
+    }
+    let mut ops = Vec::with_capacity(dp[n][m]);
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && dp[i][j] == dp[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]) {
+            if a[i - 1] != b[j - 1] {
+                ops.push(EditOp::Substitute {
+                    pos: i - 1,
+                    byte: b[j - 1],
+                });
+            }
+            (i, j) = (i - 1, j - 1);
+        } else if i > 0 && dp[i][j] == dp[i - 1][j] + 1 {
+            ops.push(EditOp::Delete { pos: i - 1 });
+            i -= 1;
+        } else {
+            ops.push(EditOp::Insert {
+                pos: i,
+                byte: b[j - 1],
+            });
+            j -= 1;
+        }
+    }
+    ops
+}
+
+/// # Banded edit distance
+/// 편집 거리가 k 이하이면 그 값을, 아니면 None을 반환합니다.
+///
+/// * 거리가 k 이하인 경로는 `|i - j| <= k`인 칸만 지나므로 그 띠 안만 계산하고, k를 넘는 값은 `k + 1`로 자릅니다.
+/// ## 시간복잡도
+/// O((|a| + |b|) k)
+pub fn edit_distance_bounded(a: &[u8], b: &[u8], k: usize) -> Option<usize> {
+    let (n, m) = (a.len(), b.len());
+    if n.abs_diff(m) > k {
+        return None;
+    }
+    let cap = k + 1;
+    let width = 2 * k + 1;
+    // row[d]: 지금 행 i에서 j = i + d - k인 칸 (띠 밖은 cap)
+    let mut row: Vec<usize> = (0..width)
+        .map(|d| if d >= k && d - k <= m { d - k } else { cap })
+        .collect();
+    let mut next = vec![cap; width];
+    for i in 1..=n {
+        for d in 0..width {
+            next[d] = cap;
+            let Some(j) = (i + d).checked_sub(k) else {
+                continue;
+            };
+            if j > m {
+                continue;
+            }
+            let mut best = cap;
+            if j == 0 {
+                best = i;
+            } else {
+                // 위 칸 (i - 1, j)는 지난 행의 d + 1, 왼쪽 칸 (i, j - 1)은 이번 행의 d - 1
+                best = best.min(row[d] + usize::from(a[i - 1] != b[j - 1]));
+                if d + 1 < width {
+                    best = best.min(row[d + 1] + 1);
+                }
+                if d > 0 {
+                    best = best.min(next[d - 1] + 1);
+                }
+            }
+            next[d] = best.min(cap);
+        }
+        std::mem::swap(&mut row, &mut next);
+    }
+    let distance = row[m + k - n];
+    (distance <= k).then_some(distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_bytes};
+
+    fn apply(a: &[u8], ops: &[EditOp]) -> Vec<u8> {
+        let mut s = a.to_vec();
+        for &op in ops {
+            match op {
+                EditOp::Insert { pos, byte } => s.insert(pos, byte),
+                EditOp::Delete { pos } => {
+                    s.remove(pos);
+                }
+                EditOp::Substitute { pos, byte } => s[pos] = byte,
+            }
+        }
+        s
+    }
+
+    #[test]
+    fn random_scripts_and_metric_properties() {
+        let mut rng = XorShift::new(167);
+        for round in 0..300 {
+            let alphabet = [2, 3, 26][round % 3];
+            let (n, m, l) = (rng.index(25), rng.index(25), rng.index(25));
+            let a = random_bytes(&mut rng, n, b'a', alphabet);
+            let b = random_bytes(&mut rng, m, b'a', alphabet);
+            let c = random_bytes(&mut rng, l, b'a', alphabet);
+            let ab = edit_distance(&a, &b);
+            assert_eq!(ab, edit_distance(&b, &a));
+            assert!(ab <= n.max(m) && ab >= n.abs_diff(m));
+            assert!(edit_distance(&a, &c) <= ab + edit_distance(&b, &c));
+            let ops = edit_distance_ops(&a, &b);
+            assert_eq!(ops.len(), ab);
+            assert_eq!(apply(&a, &ops), b);
+            for k in 0..ab + 3 {
+                let expected = (ab <= k).then_some(ab);
+                assert_eq!(edit_distance_bounded(&a, &b, k), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn bounded_on_long_similar_strings() {
+        let mut rng = XorShift::new(1670);
+        let a = random_bytes(&mut rng, 20_000, b'a', 4);
+        let mut b = a.clone();
+        // 몇 군데만 고침
+        for _ in 0..5 {
+            let pos = rng.index(b.len());
+            match rng.below(3) {
+                0 => b[pos] = b'z',
+                1 => {
+                    b.remove(pos);
+                }
+                _ => b.insert(pos, b'z'),
+            }
+        }
+        let distance = edit_distance_bounded(&a, &b, 10).unwrap();
+        assert!((1..=5).contains(&distance));
+        assert_eq!(edit_distance_bounded(&a, &b, distance - 1), None);
+    }
+
+    #[test]
+    fn small_cases() {
+        assert_eq!(edit_distance(b"kitten", b"sitting"), 3);
+        assert_eq!(edit_distance(b"", b"abc"), 3);
+        assert_eq!(edit_distance(b"", b""), 0);
+        assert_eq!(
+            edit_distance_ops(b"ab", b""),
+            vec![EditOp::Delete { pos: 1 }, EditOp::Delete { pos: 0 }]
+        );
+        assert_eq!(
+            edit_distance_ops(b"", b"xy"),
+            vec![
+                EditOp::Insert { pos: 0, byte: b'y' },
+                EditOp::Insert { pos: 0, byte: b'x' }
+            ]
+        );
+        assert_eq!(edit_distance_bounded(b"abc", b"abc", 0), Some(0));
+        assert_eq!(edit_distance_bounded(b"abc", b"abd", 0), None);
+        assert_eq!(edit_distance_bounded(b"", b"abcd", 3), None);
+    }
+}
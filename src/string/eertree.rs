@@ -0,0 +1,251 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// 길이가 -1인 가상 루트
+const ODD_ROOT: u32 = 0;
+/// 길이가 0인 루트 (빈 회문)
+const EVEN_ROOT: u32 = 1;
+
+#[derive(Clone, Debug)]
+struct Node {
+    len: isize,
+    /// 자기 자신을 뺀 가장 긴 회문 접미사의 노드
+    link: u32,
+    /// (글자, 양쪽에 그 글자를 붙인 회문의 노드)
+    next: Vec<(u8, u32)>,
+    /// 이 회문이 어떤 위치의 가장 긴 회문 접미사였던 횟수
+    own: u64,
+}
+
+/// # Palindromic tree (eertree)
+/// 글자를 하나씩 붙이면서 서로 다른 회문 부분 문자열을 모두 노드로 관리합니다.
+///
+/// * 루트가 둘입니다. 길이 -1인 가상 루트(양쪽에 c를 붙이면 글자 하나짜리 회문 `c`)와 길이 0인 빈 회문이고,
+/// 빈 회문의 suffix link는 가상 루트입니다.
+/// * 위치마다 새로 생기는 서로 다른 회문은 많아야 하나라서, 노드(회문)는 n개 이하입니다. 회문에는 만들어진
+/// 순서대로 `0..distinct_palindrome_count()`의 번호가 붙습니다.
+/// * 등장 횟수는 "가장 긴 회문 접미사였던 횟수"를 suffix link를 따라 더해서 구합니다. suffix link는 항상 먼저
+/// 만들어진 노드를 가리키므로 번호 역순으로 한 번 훑으면 됩니다.
+/// * 전이는 노드마다 `(글자, 노드)` 목록을 선형 탐색하므로 글자 종류가 적을 때 알맞습니다.
+/// ## 시간복잡도
+/// `push` amortized O(σ), [`occurrence_counts`](Self::occurrence_counts) O(n)
+#[derive(Clone, Debug)]
+pub struct Eertree {
+    s: Vec<u8>,
+    nodes: Vec<Node>,
+    /// 지금 문자열의 가장 긴 회문 접미사
+    last: u32,
+    /// 위치별 가장 긴 회문 접미사의 노드
+    suffix: Vec<u32>,
+}
+
+impl Default for Eertree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Eertree {
+    /// 빈 문자열의 트리
+    pub fn new() -> Self {
+        let root = |len, link| Node {
+            len,
+            link,
+            next: Vec::new(),
+            own: 0,
+        };
+        Eertree {
+            s: Vec::new(),
+            nodes: vec![root(-1, ODD_ROOT), root(0, ODD_ROOT)],
+            last: EVEN_ROOT,
+            suffix: Vec::new(),
+        }
+    }
+
+    /// 지금까지 넣은 문자열의 길이
+    pub fn len(&self) -> usize {
+        self.s.len()
+    }
+
+    /// 빈 문자열인지
+    pub fn is_empty(&self) -> bool {
+        self.s.is_empty()
+    }
+
+    /// 문자열 끝에 c를 붙임. 이 위치에서 끝나는 새로운(처음 나타난) 회문의 개수(0 또는 1)를 반환합니다.
+    pub fn push(&mut self, c: u8) -> usize {
+        let i = self.s.len();
+        self.s.push(c);
+        let v = self.extendable(self.last, i);
+        if let Some(to) = self.child(v, c) {
+            self.nodes[to as usize].own += 1;
+            self.last = to;
+            self.suffix.push(to);
+            return 0;
+        }
+        let len = self.nodes[v as usize].len + 2;
+        let link = if len == 1 {
+            EVEN_ROOT
+        } else {
+            let u = self.extendable(self.nodes[v as usize].link, i);
+            self.child(u, c).unwrap()
+        };
+        let id = u32::try_from(self.nodes.len()).expect("too many nodes for u32 indices");
+        self.nodes.push(Node {
+            len,
+            link,
+            next: Vec::new(),
+            own: 1,
+        });
+        self.nodes[v as usize].next.push((c, id));
+        self.last = id;
+        self.suffix.push(id);
+        1
+    }
+
+    /// 서로 다른 (빈 문자열이 아닌) 회문 부분 문자열의 개수
+    pub fn distinct_palindrome_count(&self) -> usize {
+        self.nodes.len() - 2
+    }
+
+    /// 번호가 id인 회문의 길이
+    pub fn palindrome_len(&self, id: usize) -> usize {
+        self.nodes[id + 2].len as usize
+    }
+
+    /// 번호별 회문의 (겹치는 것까지 센) 등장 횟수
+    pub fn occurrence_counts(&self) -> Vec<u64> {
+        let mut count: Vec<u64> = self.nodes.iter().map(|node| node.own).collect();
+        for v in (2..self.nodes.len()).rev() {
+            let link = self.nodes[v].link as usize;
+            count[link] += count[v];
+        }
+        count.split_off(2)
+    }
+
+    /// `s[..=i]`의 가장 긴 회문 접미사의 번호
+    pub fn suffix_palindrome(&self, i: usize) -> usize {
+        self.suffix[i] as usize - 2
+    }
+
+    /// `s[..=i]`의 가장 긴 회문 접미사의 길이
+    pub fn longest_suffix_palindrome(&self, i: usize) -> usize {
+        self.nodes[self.suffix[i] as usize].len as usize
+    }
+
+    /// v에서 suffix link를 따라가며, 양쪽에 `s[i]`를 붙여도 `s[..=i]`의 접미사인 첫 회문
+    fn extendable(&self, mut v: u32, i: usize) -> u32 {
+        loop {
+            let start = i as isize - 1 - self.nodes[v as usize].len;
+            if start >= 0 && self.s[start as usize] == self.s[i] {
+                return v;
+            }
+            v = self.nodes[v as usize].link;
+        }
+    }
+
+    fn child(&self, v: u32, c: u8) -> Option<u32> {
+        self.nodes[v as usize]
+            .next
+            .iter()
+            .find(|&&(d, _)| d == c)
+            .map(|&(_, to)| to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use std::collections::HashMap;
+
+    /// 중심에서 넓혀 가며 모든 회문 구간 `(l, r)`을 나열
+    fn all_palindromes(s: &[u8]) -> Vec<(usize, usize)> {
+        let n = s.len();
+        let mut result = Vec::new();
+        // 홀수 길이는 s[i..i + 1], 짝수 길이는 s[i..i]에서 시작
+        let starts = (0..n).map(|i| (i, i + 1)).chain((0..=n).map(|i| (i, i)));
+        for (mut l, mut r) in starts {
+            if r - l == 1 {
+                result.push((l, r));
+            }
+            while l > 0 && r < n && s[l - 1] == s[r] {
+                (l, r) = (l - 1, r + 1);
+                result.push((l, r));
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn random_against_enumeration() {
+        let mut rng = XorShift::new(165);
+        for round in 0..200 {
+            let alphabet = [1, 2, 3][round % 3];
+            let n = if round < 6 { 500 } else { rng.index(40) };
+            let s: Vec<u8> = (0..n).map(|_| b'a' + rng.below(alphabet) as u8).collect();
+            let mut tree = Eertree::new();
+            let mut created = 0;
+            for &c in &s {
+                created += tree.push(c);
+            }
+            let palindromes = all_palindromes(&s);
+            let mut counts: HashMap<&[u8], u64> = HashMap::new();
+            for &(l, r) in &palindromes {
+                *counts.entry(&s[l..r]).or_default() += 1;
+            }
+            assert_eq!(tree.distinct_palindrome_count(), counts.len());
+            assert_eq!(created, counts.len());
+            let occurrences = tree.occurrence_counts();
+            for i in 0..n {
+                let len = palindromes
+                    .iter()
+                    .filter(|&&(_, r)| r == i + 1)
+                    .map(|&(l, r)| r - l)
+                    .max()
+                    .unwrap();
+                assert_eq!(tree.longest_suffix_palindrome(i), len);
+                let id = tree.suffix_palindrome(i);
+                assert_eq!(tree.palindrome_len(id), len);
+                assert_eq!(occurrences[id], counts[&s[i + 1 - len..=i]]);
+            }
+            assert_eq!(occurrences.iter().sum::<u64>(), palindromes.len() as u64);
+        }
+    }
+
+    #[test]
+    fn small_cases() {
+        let mut tree = Eertree::new();
+        assert_eq!(tree.distinct_palindrome_count(), 0);
+        // "abaaba": a, b, aba, aa, baab, abaaba
+        let created: Vec<usize> = b"abaaba".iter().map(|&c| tree.push(c)).collect();
+        assert_eq!(created, vec![1, 1, 1, 1, 1, 1]);
+        assert_eq!(tree.distinct_palindrome_count(), 6);
+        let mut repeated = Eertree::new();
+        let created: Vec<usize> = b"abcab".iter().map(|&c| repeated.push(c)).collect();
+        assert_eq!(created, vec![1, 1, 1, 0, 0]);
+        assert_eq!(tree.longest_suffix_palindrome(5), 6);
+        assert_eq!(tree.longest_suffix_palindrome(3), 2);
+        let (mut same, n) = (Eertree::new(), 1_000_000);
+        for _ in 0..n {
+            same.push(b'z');
+        }
+        assert_eq!(same.distinct_palindrome_count(), n);
+        let counts = same.occurrence_counts();
+        assert_eq!(counts[0], n as u64);
+        assert_eq!(counts[n - 1], 1);
+    }
+
+    #[test]
+    fn long_random_input() {
+        let mut rng = XorShift::new(1650);
+        let mut tree = Eertree::new();
+        for _ in 0..1_000_000 {
+            tree.push(b'a' + rng.below(2) as u8);
+        }
+        let counts = tree.occurrence_counts();
+        // 가장 긴 회문 접미사의 개수를 모두 더하면 회문 부분 문자열의 총 개수
+        let total: u64 = counts.iter().sum();
+        assert!(total >= 1_000_000);
+        assert!(tree.distinct_palindrome_count() <= 1_000_000);
+    }
+}
@@ -0,0 +1,136 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Prefix function (KMP 실패 함수)
+/// `pi[i]`는 `s[..=i]`의 접두사이면서 접미사인 것 중 자기 자신을 뺀 가장 긴 것의 길이입니다.
+///
+/// * 바이트 단위로 계산하므로 ASCII 문자열과 임의의 바이트열에 모두 쓸 수 있습니다.
+/// * 빈 입력이면 빈 배열을 반환합니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn prefix_function(s: &[u8]) -> Vec<usize> {
+    let mut pi = vec![0; s.len()];
+    for i in 1..s.len() {
+        let mut k = pi[i - 1];
+        while k > 0 && s[i] != s[k] {
+            k = pi[k - 1];
+        }
+        if s[i] == s[k] {
+            k += 1;
+        }
+        pi[i] = k;
+    }
+    pi
+}
+
+/// # KMP 문자열 검색
+/// text 안에서 pattern이 나타나는 모든 시작 위치를 오름차순으로 반환합니다. 겹치는 등장도 모두 셉니다.
+///
+/// * 빈 pattern은 `0..=text.len()`의 모든 위치에서 나타난다고 봅니다(`str::match_indices`와 같은 규칙).
+/// * pattern이 text보다 길면 빈 배열입니다.
+/// ## 시간복잡도
+/// O(|text| + |pattern|)
+pub fn kmp_find_all(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+    let pi = prefix_function(pattern);
+    let mut result = Vec::new();
+    let mut k = 0;
+    for (i, &c) in text.iter().enumerate() {
+        while k > 0 && c != pattern[k] {
+            k = pi[k - 1];
+        }
+        if c == pattern[k] {
+            k += 1;
+        }
+        if k == m {
+            result.push(i + 1 - m);
+            k = pi[k - 1];
+        }
+    }
+    result
+}
+
+/// [`kmp_find_all`]의 `&str` 버전. 위치는 바이트 단위입니다.
+pub fn kmp_find_all_str(text: &str, pattern: &str) -> Vec<usize> {
+    kmp_find_all(text.as_bytes(), pattern.as_bytes())
+}
+
+/// # 최소 주기
+/// 모든 i에 대해 `s[i] == s[i + p]`인 가장 작은 p(`1 <= p <= n`)입니다. `n - pi[n - 1]`로 구합니다.
+///
+/// * s가 `s[..p]`를 몇 번 반복한 문자열인 것은 `n % p == 0`일 때뿐입니다. 아니면 마지막 반복이 잘린 꼴입니다.
+/// * 빈 입력이면 0입니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn period(s: &[u8]) -> usize {
+    match prefix_function(s).last() {
+        Some(&border) => s.len() - border,
+        None => 0,
+    }
+}
+
+/// [`period`]의 `&str` 버전. 주기는 바이트 단위입니다.
+pub fn period_str(s: &str) -> usize {
+    period(s.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_bytes};
+
+    fn naive_find_all(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+        if pattern.len() > text.len() {
+            return Vec::new();
+        }
+        (0..=text.len() - pattern.len())
+            .filter(|&i| &text[i..i + pattern.len()] == pattern)
+            .collect()
+    }
+
+    #[test]
+    fn random_against_naive_search() {
+        let mut rng = XorShift::new(156);
+        for round in 0..500 {
+            let alphabet = [1, 2, 3, 26][round % 4];
+            let n = rng.index(60);
+            let text = random_bytes(&mut rng, n, b'a', alphabet);
+            let m = rng.index(6);
+            let pattern = random_bytes(&mut rng, m, b'a', alphabet);
+            assert_eq!(
+                kmp_find_all(&text, &pattern),
+                naive_find_all(&text, &pattern)
+            );
+            let pi = prefix_function(&text);
+            for (i, &k) in pi.iter().enumerate() {
+                let expected = (0..=i)
+                    .rev()
+                    .find(|&k| text[..k] == text[i + 1 - k..=i])
+                    .unwrap();
+                assert_eq!(k, expected);
+            }
+            let n = text.len();
+            let expected = (1..=n)
+                .find(|&p| (0..n - p).all(|i| text[i] == text[i + p]))
+                .unwrap_or(0);
+            assert_eq!(period(&text), expected);
+        }
+    }
+
+    #[test]
+    fn overlapping_and_edge_cases() {
+        assert_eq!(kmp_find_all_str("aaaa", "aa"), vec![0, 1, 2]);
+        assert_eq!(kmp_find_all_str("aaaa", "aaaa"), vec![0]);
+        assert_eq!(kmp_find_all_str("aaa", "aaaa"), Vec::<usize>::new());
+        assert_eq!(kmp_find_all_str("abc", ""), vec![0, 1, 2, 3]);
+        assert_eq!(kmp_find_all_str("", ""), vec![0]);
+        assert_eq!(kmp_find_all_str("abababa", "aba"), vec![0, 2, 4]);
+        assert_eq!(prefix_function(b"aabaaab"), vec![0, 1, 0, 1, 2, 2, 3]);
+        assert_eq!(period_str("abcabcab"), 3);
+        assert_eq!(period_str("aaaa"), 1);
+        assert_eq!(period_str("abcd"), 4);
+        assert_eq!(period_str(""), 0);
+    }
+}
@@ -0,0 +1,153 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::prefix_function;
+
+/// 패턴을 모두 맞춘 상태(m)에서 글자를 더 읽을 때의 규칙
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatchPolicy {
+    /// 상태 m에 계속 머묾. "패턴을 포함하는/피하는 문자열 세기"처럼 한 번이라도 나왔는지만 볼 때 씁니다.
+    Absorb,
+    /// 실패 함수를 따라가서 다음 (겹치는) 등장을 계속 찾음. 등장 횟수를 셀 때 씁니다.
+    Continue,
+}
+
+/// # KMP automaton
+/// `table[k][c]`는 패턴의 앞 k글자를 맞춘 상태에서 글자 `first + c`를 읽은 뒤 맞춘 앞부분의 길이입니다.
+/// 상태는 `0..=m`이고, 상태 m은 패턴 전체를 맞춘 상태입니다.
+///
+/// * 글자는 `first..first + 26` 범위의 26가지로 정합니다(보통 `first = b'a'`). 패턴의 글자도 이 범위여야 합니다.
+/// 범위가 u8을 넘지 않도록 `first`는 230 이하여야 합니다.
+/// * 상태 m에서의 전이는 `policy`로 정합니다. 빈 패턴이면 상태 0이 곧 상태 m입니다.
+/// * 길이 n인 문자열을 세는 DP나, 전이를 행렬로 바꿔 거듭제곱하는 데 씁니다.
+/// ## 시간복잡도
+/// O(26 m)
+pub fn kmp_automaton(pattern: &[u8], first: u8, policy: MatchPolicy) -> Vec<[u32; 26]> {
+    let m = pattern.len();
+    assert!(
+        first <= 230,
+        "alphabet {first}..{} does not fit in u8",
+        first as u32 + 26
+    );
+    assert!(
+        pattern.iter().all(|&c| c.wrapping_sub(first) < 26),
+        "pattern byte out of the alphabet {first}..{}",
+        first as u32 + 26
+    );
+    let pi = prefix_function(pattern);
+    let mut table = vec![[0u32; 26]; m + 1];
+    for k in 0..=m {
+        for c in 0..26u8 {
+            table[k][c as usize] = if k < m && pattern[k].wrapping_sub(first) == c {
+                k as u32 + 1
+            } else if k == m && policy == MatchPolicy::Absorb {
+                m as u32
+            } else if k == 0 {
+                0
+            } else {
+                // 실패한 뒤 상태는 더 짧은 경계로 가서 같은 글자를 읽은 결과
+                let border = if k == m { pi[m - 1] } else { pi[k - 1] };
+                table[border][c as usize]
+            };
+        }
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_bytes};
+    use crate::string::kmp_find_all;
+
+    #[test]
+    fn simulation_matches_kmp_find_all() {
+        let mut rng = XorShift::new(170);
+        for round in 0..300 {
+            let alphabet = [1, 2, 3, 26][round % 4];
+            let m = rng.index(6);
+            let pattern = random_bytes(&mut rng, m, b'a', alphabet);
+            let n = rng.index(60);
+            let text = random_bytes(&mut rng, n, b'a', alphabet);
+            let table = kmp_automaton(&pattern, b'a', MatchPolicy::Continue);
+            let mut state = 0;
+            let mut found = if m == 0 { vec![0] } else { Vec::new() };
+            for (i, &c) in text.iter().enumerate() {
+                state = table[state][(c - b'a') as usize] as usize;
+                if state == m {
+                    found.push(i + 1 - m);
+                }
+            }
+            assert_eq!(found, kmp_find_all(&text, &pattern));
+            // Absorb는 처음 맞은 뒤로 m에 머묾
+            let absorb = kmp_automaton(&pattern, b'a', MatchPolicy::Absorb);
+            let mut state = 0;
+            let mut first = (m == 0).then_some(0);
+            for (i, &c) in text.iter().enumerate() {
+                state = absorb[state][(c - b'a') as usize] as usize;
+                if state == m && first.is_none() {
+                    first = Some(i + 1);
+                }
+                assert_eq!(state == m, first.is_some());
+            }
+            assert_eq!(first.map(|end| end - m), found.first().copied());
+        }
+    }
+
+    #[test]
+    fn counting_dp_against_brute_force() {
+        // 글자 {a, b, c}로 된 길이 len인 문자열 중 "aba"를 포함하지 않는 것의 개수
+        let pattern = b"aba";
+        let table = kmp_automaton(pattern, b'a', MatchPolicy::Absorb);
+        for len in 0..=8u32 {
+            let mut dp = vec![0u64; pattern.len() + 1];
+            dp[0] = 1;
+            for _ in 0..len {
+                let mut next = vec![0u64; pattern.len() + 1];
+                for (state, &ways) in dp.iter().enumerate() {
+                    for c in 0..3 {
+                        next[table[state][c] as usize] += ways;
+                    }
+                }
+                dp = next;
+            }
+            let avoiding: u64 = dp[..pattern.len()].iter().sum();
+            let brute = (0..3u64.pow(len))
+                .filter(|&code| {
+                    let s: Vec<u8> = (0..len)
+                        .map(|i| b'a' + (code / 3u64.pow(i) % 3) as u8)
+                        .collect();
+                    kmp_find_all(&s, pattern).is_empty()
+                })
+                .count() as u64;
+            assert_eq!(avoiding, brute);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the alphabet")]
+    fn pattern_outside_alphabet_panics() {
+        kmp_automaton(b"aB", b'a', MatchPolicy::Absorb);
+    }
+
+    #[test]
+    fn highest_alphabet() {
+        // 230..256의 글자
+        let table = kmp_automaton(&[255, 230, 255], 230, MatchPolicy::Continue);
+        assert_eq!(table[0][25], 1);
+        assert_eq!(table[1][0], 2);
+        assert_eq!(table[2][25], 3);
+        assert_eq!(table[3][0], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the alphabet")]
+    fn wrapped_byte_panics() {
+        // 0 - 230은 wrapping하면 26이므로 범위 밖
+        kmp_automaton(&[240, 0], 230, MatchPolicy::Absorb);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in u8")]
+    fn alphabet_beyond_u8_panics() {
+        kmp_automaton(&[240, 0], 240, MatchPolicy::Absorb);
+    }
+}
@@ -0,0 +1,135 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Longest common subsequence (길이)
+/// a와 b의 최장 공통 부분 수열의 길이입니다. 바이트 문자열뿐 아니라 `Eq`인 아무 값의 배열에 쓸 수 있습니다.
+///
+/// * DP의 행 하나만 두고, 짧은 쪽을 행으로 씁니다.
+/// ## 시간복잡도
+/// O(|a| |b|), 메모리 O(min(|a|, |b|))
+pub fn lcs_length<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    *last_row(long.iter(), short.iter()).last().unwrap()
+}
+
+/// # Longest common subsequence (Hirschberg)
+/// a와 b의 최장 공통 부분 수열 하나를 반환합니다.
+///
+/// * a를 반으로 나누고, 앞 절반은 앞에서부터, 뒤 절반은 뒤에서부터 DP의 마지막 행만 구해서 b를 나눌 위치를 찾은
+/// 뒤 양쪽을 재귀로 풉니다. 그래서 표 전체를 두지 않고도 답을 복원합니다.
+/// * 재귀 깊이는 O(log |a|)입니다.
+/// ## 시간복잡도
+/// O(|a| |b|), 메모리 O(|a| + |b|)
+pub fn lcs<T: Eq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut result = Vec::new();
+    hirschberg(a, b, &mut result);
+    result
+}
+
+fn hirschberg<T: Eq + Clone>(a: &[T], b: &[T], out: &mut Vec<T>) {
+    if a.is_empty() || b.is_empty() {
+        return;
+    }
+    if a.len() == 1 {
+        if b.contains(&a[0]) {
+            out.push(a[0].clone());
+        }
+        return;
+    }
+    let mid = a.len() / 2;
+    let k = split_point(a, b, mid);
+    hirschberg(&a[..mid], &b[..k], out);
+    hirschberg(&a[mid..], &b[k..], out);
+}
+
+/// a를 `a[..mid]`, `a[mid..]`로 나눴을 때 b를 나눌 위치. 두 행은 여기서 버려서 재귀하는 동안 남지 않게 함
+fn split_point<T: Eq>(a: &[T], b: &[T], mid: usize) -> usize {
+    let front = last_row(a[..mid].iter(), b.iter());
+    let back = last_row(a[mid..].iter().rev(), b.iter().rev());
+    // b[..k]와 b[k..]로 나눴을 때 양쪽 LCS 길이의 합이 가장 큰 k
+    let m = b.len();
+    (0..=m).max_by_key(|&k| front[k] + back[m - k]).unwrap()
+}
+
+/// `row[j]`: a 전체와 b의 앞 j개의 LCS 길이
+fn last_row<'a, T: Eq + 'a>(
+    a: impl Iterator<Item = &'a T>,
+    b: impl Iterator<Item = &'a T> + Clone,
+) -> Vec<usize> {
+    let mut row = vec![0; b.clone().count() + 1];
+    for x in a {
+        // diagonal: 지난 행의 row[j]
+        let mut diagonal = 0;
+        for (j, y) in b.clone().enumerate() {
+            let value = if x == y {
+                diagonal + 1
+            } else {
+                row[j].max(row[j + 1])
+            };
+            diagonal = row[j + 1];
+            row[j + 1] = value;
+        }
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn quadratic_lcs(a: &[u8], b: &[u8]) -> usize {
+        let mut dp = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                dp[i + 1][j + 1] = if a[i] == b[j] {
+                    dp[i][j] + 1
+                } else {
+                    dp[i][j + 1].max(dp[i + 1][j])
+                };
+            }
+        }
+        dp[a.len()][b.len()]
+    }
+
+    fn is_subsequence<T: Eq>(sub: &[T], of: &[T]) -> bool {
+        let mut it = of.iter();
+        sub.iter().all(|x| it.any(|y| y == x))
+    }
+
+    #[test]
+    fn random_against_quadratic_dp() {
+        let mut rng = XorShift::new(168);
+        for round in 0..300 {
+            let alphabet = [1, 2, 4, 26][round % 4];
+            let (n, m) = (rng.index(40), rng.index(40));
+            let a: Vec<u8> = (0..n).map(|_| b'a' + rng.below(alphabet) as u8).collect();
+            let b: Vec<u8> = (0..m).map(|_| b'a' + rng.below(alphabet) as u8).collect();
+            let expected = quadratic_lcs(&a, &b);
+            assert_eq!(lcs_length(&a, &b), expected);
+            let common = lcs(&a, &b);
+            assert_eq!(common.len(), expected);
+            assert!(is_subsequence(&common, &a));
+            assert!(is_subsequence(&common, &b));
+        }
+    }
+
+    #[test]
+    fn generic_and_larger_inputs() {
+        let mut rng = XorShift::new(1680);
+        let a: Vec<i64> = (0..2000).map(|_| rng.range_i64(-3, 3)).collect();
+        let b: Vec<i64> = (0..1500).map(|_| rng.range_i64(-3, 3)).collect();
+        let common = lcs(&a, &b);
+        assert_eq!(common.len(), lcs_length(&a, &b));
+        assert!(is_subsequence(&common, &a));
+        assert!(is_subsequence(&common, &b));
+    }
+
+    #[test]
+    fn empty_inputs() {
+        assert_eq!(lcs_length::<u8>(&[], &[]), 0);
+        assert_eq!(lcs_length(b"", b"abc"), 0);
+        assert!(lcs(b"abc", b"").is_empty());
+        assert_eq!(lcs(b"abcbdab", b"bdcaba").len(), 4);
+        assert_eq!(lcs(b"same", b"same"), b"same");
+    }
+}
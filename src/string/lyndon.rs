@@ -0,0 +1,108 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::ops::Range;
+
+/// # Lyndon factorization (Duval)
+/// s를 `w1 ≥ w2 ≥ ... ≥ wk`(사전 순, 나란히 놓은 순서대로 증가하지 않음)인 Lyndon word들로 유일하게 나눕니다.
+/// Lyndon word는 자기의 모든 진짜 접미사보다 사전 순으로 작은 (빈 문자열이 아닌) 문자열입니다.
+///
+/// * 조각을 s에서의 위치 구간으로 반환합니다. 빈 입력이면 빈 배열입니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn duval(s: &[u8]) -> Vec<Range<usize>> {
+    let n = s.len();
+    let mut factors = Vec::new();
+    let mut i = 0;
+    while i < n {
+        // s[i..j]는 Lyndon word w를 반복한 뒤 w의 접두사가 붙은 꼴이고, k는 j와 비교할 위치
+        let (mut j, mut k) = (i + 1, i);
+        while j < n && s[k] <= s[j] {
+            k = if s[k] < s[j] { i } else { k + 1 };
+            j += 1;
+        }
+        let period = j - k;
+        while i <= k {
+            factors.push(i..i + period);
+            i += period;
+        }
+    }
+    factors
+}
+
+/// s의 접미사 중 사전 순으로 가장 작은 것의 시작 위치. 빈 입력이면 0
+///
+/// 가장 작은 접미사는 Lyndon factorization의 마지막 조각입니다.
+pub fn smallest_suffix(s: &[u8]) -> usize {
+    duval(s).last().map_or(0, |last| last.start)
+}
+
+/// [`minimal_rotation`](super::minimal_rotation)과 같은 값을 Duval로 구합니다.
+///
+/// `s + s`를 나눈 조각 중 같은 조각이 이어진 묶음마다, 시작 위치가 n보다 작은 마지막 묶음의 시작 위치가 가장 작은
+/// 회전의 (가장 작은) 위치입니다.
+pub fn minimal_rotation_duval(s: &[u8]) -> usize {
+    let n = s.len();
+    let doubled = [s, s].concat();
+    let mut answer = 0;
+    let mut prev: Option<&[u8]> = None;
+    for f in duval(&doubled) {
+        if f.start >= n {
+            break;
+        }
+        let word = &doubled[f.clone()];
+        if prev != Some(word) {
+            answer = f.start;
+        }
+        prev = Some(word);
+    }
+    answer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use crate::string::minimal_rotation;
+
+    fn is_lyndon(w: &[u8]) -> bool {
+        !w.is_empty() && (1..w.len()).all(|i| w < &w[i..])
+    }
+
+    #[test]
+    fn random_factorizations_are_lyndon_and_non_increasing() {
+        let mut rng = XorShift::new(166);
+        for round in 0..500 {
+            let alphabet = [1, 2, 3, 26][round % 4];
+            let n = rng.index(40);
+            let s: Vec<u8> = (0..n).map(|_| b'a' + rng.below(alphabet) as u8).collect();
+            let factors = duval(&s);
+            let mut next = 0;
+            for f in &factors {
+                assert_eq!(f.start, next);
+                assert!(is_lyndon(&s[f.clone()]));
+                next = f.end;
+            }
+            assert_eq!(next, n);
+            for pair in factors.windows(2) {
+                assert!(s[pair[0].clone()] >= s[pair[1].clone()]);
+            }
+            let smallest = (0..n).min_by_key(|&i| &s[i..]).unwrap_or(0);
+            assert_eq!(smallest_suffix(&s), smallest);
+            assert_eq!(minimal_rotation_duval(&s), minimal_rotation(&s));
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert!(duval(b"").is_empty());
+        assert_eq!(duval(b"x"), vec![0..1]);
+        assert_eq!(duval(b"aaa"), vec![0..1, 1..2, 2..3]);
+        assert_eq!(duval(b"dcba"), vec![0..1, 1..2, 2..3, 3..4]);
+        assert_eq!(duval(b"abcd"), vec![0..4]);
+        assert_eq!(duval(b"abab"), vec![0..2, 2..4]);
+        assert_eq!(duval(b"banana"), vec![0..1, 1..3, 3..5, 5..6]);
+        assert_eq!(smallest_suffix(b"banana"), 5);
+        assert_eq!(smallest_suffix(b""), 0);
+        assert_eq!(minimal_rotation_duval(b"baba"), 1);
+        assert_eq!(minimal_rotation_duval(b""), 0);
+    }
+}
@@ -0,0 +1,150 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::ops::Range;
+
+/// # Manacher
+/// 모든 중심에 대한 회문 반지름 `(odd, even)`을 구합니다.
+///
+/// * `odd[i]`: i를 중심으로 하는 홀수 길이 회문의 개수. 가장 긴 것은 `s[i + 1 - odd[i]..i + odd[i]]`(길이
+/// `2 * odd[i] - 1`)이고, 글자 하나도 회문이라 s가 비어 있지 않으면 `odd[i] >= 1`입니다.
+/// * `even[i]`: `s[i - 1]`과 `s[i]` 사이를 중심으로 하는 짝수 길이 회문의 개수. 가장 긴 것은
+/// `s[i - even[i]..i + even[i]]`(길이 `2 * even[i]`)이고, `even[0] = 0`입니다.
+/// * 두 배열의 길이는 모두 n입니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn manacher(s: &[u8]) -> (Vec<usize>, Vec<usize>) {
+    let n = s.len();
+    let mut odd = vec![0; n];
+    // [l, r): 지금까지 찾은 것 중 r이 가장 큰 회문
+    let (mut l, mut r) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < r {
+            odd[l + r - 1 - i].min(r - i)
+        } else {
+            1
+        };
+        while i >= k && i + k < n && s[i - k] == s[i + k] {
+            k += 1;
+        }
+        odd[i] = k;
+        if i + k > r {
+            (l, r) = (i + 1 - k, i + k);
+        }
+    }
+    let mut even = vec![0; n];
+    let (mut l, mut r) = (0, 0);
+    for i in 0..n {
+        let mut k = if i < r { even[l + r - i].min(r - i) } else { 0 };
+        while i > k && i + k < n && s[i - k - 1] == s[i + k] {
+            k += 1;
+        }
+        even[i] = k;
+        if i + k > r {
+            (l, r) = (i - k, i + k);
+        }
+    }
+    (odd, even)
+}
+
+/// 가장 긴 회문 부분 문자열의 위치. 여러 개면 가장 왼쪽 것이고, 빈 입력이면 `0..0`
+pub fn longest_palindromic_substring(s: &[u8]) -> Range<usize> {
+    let (odd, even) = manacher(s);
+    let mut best = 0..0;
+    for i in 0..s.len() {
+        let candidates = [i + 1 - odd[i]..i + odd[i], i - even[i]..i + even[i]];
+        for range in candidates {
+            if range.len() > best.len() || (range.len() == best.len() && range.start < best.start) {
+                best = range;
+            }
+        }
+    }
+    best
+}
+
+/// 회문인 (위치가 다른) 부분 문자열의 개수. 빈 문자열은 세지 않습니다.
+pub fn count_palindromic_substrings(s: &[u8]) -> u64 {
+    let (odd, even) = manacher(s);
+    odd.iter().chain(&even).map(|&k| k as u64).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn is_palindrome(s: &[u8]) -> bool {
+        s.iter().eq(s.iter().rev())
+    }
+
+    /// 중심에서 한 칸씩 넓혀 가는 O(n²) 계산
+    fn brute_radii(s: &[u8]) -> (Vec<usize>, Vec<usize>) {
+        let n = s.len();
+        let expand = |mut l: usize, mut r: usize| {
+            // s[l..r]이 회문일 때 양쪽으로 넓힐 수 있는 만큼 넓힌 횟수
+            let mut k = 0;
+            while l > 0 && r < n && s[l - 1] == s[r] {
+                (l, r, k) = (l - 1, r + 1, k + 1);
+            }
+            k
+        };
+        let odd = (0..n).map(|i| expand(i, i + 1) + 1).collect();
+        let even = (0..n).map(|i| expand(i, i)).collect();
+        (odd, even)
+    }
+
+    #[test]
+    fn random_against_expansion() {
+        let mut rng = XorShift::new(158);
+        for round in 0..300 {
+            let alphabet = [1, 2, 3][round % 3];
+            let n = if round < 10 { 2000 } else { rng.index(40) };
+            let s: Vec<u8> = (0..n).map(|_| b'a' + rng.below(alphabet) as u8).collect();
+            let (odd, even) = manacher(&s);
+            assert_eq!((odd.clone(), even.clone()), brute_radii(&s));
+            if n < 40 {
+                let count = (0..n)
+                    .flat_map(|l| (l + 1..=n).map(move |r| (l, r)))
+                    .filter(|&(l, r)| is_palindrome(&s[l..r]))
+                    .count();
+                assert_eq!(count_palindromic_substrings(&s), count as u64);
+            }
+            let best = longest_palindromic_substring(&s);
+            assert!(is_palindrome(&s[best.clone()]));
+            let longest = odd
+                .iter()
+                .map(|&k| 2 * k - 1)
+                .chain(even.iter().map(|&k| 2 * k))
+                .max()
+                .unwrap_or(0);
+            assert_eq!(best.len(), longest);
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(manacher(b"a"), (vec![1], vec![0]));
+        assert_eq!(manacher(b""), (vec![], vec![]));
+        assert_eq!(manacher(b"aaaa"), (vec![1, 2, 2, 1], vec![0, 1, 2, 1]));
+        // 경계에 붙은 짝수 회문
+        assert_eq!(longest_palindromic_substring(b"abbacd"), 0..4);
+        assert_eq!(longest_palindromic_substring(b"cdabba"), 2..6);
+        assert_eq!(longest_palindromic_substring(b"abc"), 0..1);
+        assert_eq!(longest_palindromic_substring(b""), 0..0);
+        assert_eq!(count_palindromic_substrings(b"aaa"), 6);
+        assert_eq!(count_palindromic_substrings(b"abc"), 3);
+    }
+
+    #[test]
+    fn long_input_runs_in_linear_time() {
+        let mut rng = XorShift::new(1580);
+        let s: Vec<u8> = (0..1_000_000).map(|_| b'a' + rng.below(2) as u8).collect();
+        let (odd, even) = manacher(&s);
+        assert!(odd.iter().all(|&k| k >= 1));
+        assert_eq!(even[0], 0);
+        let same = vec![b'z'; 1_000_000];
+        assert_eq!(
+            count_palindromic_substrings(&same),
+            1_000_000 * 1_000_001 / 2
+        );
+        assert_eq!(longest_palindromic_substring(&same), 0..1_000_000);
+    }
+}
@@ -0,0 +1,196 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::HashParams;
+use super::rolling_hash::{add_mod, mul_mod};
+
+/// # 2차원 패턴 매칭 (Rabin–Karp)
+/// 격자 grid 안에서 pattern과 똑같은 부분 격자의 왼쪽 위 위치 `(행, 열)`을 모두 행 우선 순서로 찾습니다.
+///
+/// * 각 행에서 폭이 pattern의 열 수인 창의 해시를 구하고, 그 값들을 다시 세로로 높이가 pattern의 행 수인 창으로
+/// 해시합니다. 가로와 세로에 서로 다른 밑을 쓰고, (법, 밑) 두 쌍을 무작위로 골라 둘 다 같을 때만 후보로 봅니다.
+/// * 후보는 마지막에 글자를 직접 비교해서 확인하므로 해시 충돌이 있어도 답은 틀리지 않습니다.
+/// * 격자와 pattern은 각각 모든 행의 길이가 같아야 합니다. pattern이 격자보다 크면 빈 배열이고, 행이나 열이 0개인
+/// pattern은 들어갈 수 있는 모든 위치에서 나타난다고 봅니다.
+/// ## 시간복잡도
+/// 기댓값 O(RC + rc + 답의 개수 · rc). R × C는 격자, r × c는 pattern의 크기
+pub fn find_pattern_2d(grid: &[Vec<u8>], pattern: &[Vec<u8>]) -> Vec<(usize, usize)> {
+    let (rows, cols) = dimensions(grid);
+    let (pr, pc) = dimensions(pattern);
+    if pr > rows || pc > cols {
+        return Vec::new();
+    }
+    let candidates: Vec<(usize, usize)> = if pr == 0 || pc == 0 {
+        (0..=rows - pr)
+            .flat_map(|i| (0..=cols - pc).map(move |j| (i, j)))
+            .collect()
+    } else {
+        let horizontal = HashParams::random();
+        let vertical = HashParams::with_modulus(horizontal.modulus, HashParams::random().base[0]);
+        let mut matches: Vec<bool> = vec![true; (rows - pr + 1) * (cols - pc + 1)];
+        for k in 0..2 {
+            let m = horizontal.modulus[k];
+            let (bc, br) = (horizontal.base[k], vertical.base[k]);
+            let grid_hash = window_hashes_2d(grid, pr, pc, bc, br, m);
+            let pattern_hash = window_hashes_2d(pattern, pr, pc, bc, br, m)[0];
+            for (ok, &h) in matches.iter_mut().zip(&grid_hash) {
+                *ok &= h == pattern_hash;
+            }
+        }
+        let width = cols - pc + 1;
+        matches
+            .iter()
+            .enumerate()
+            .filter(|&(_, &ok)| ok)
+            .map(|(idx, _)| (idx / width, idx % width))
+            .collect()
+    };
+    candidates
+        .into_iter()
+        .filter(|&(i, j)| (0..pr).all(|t| grid[i + t][j..j + pc] == pattern[t][..]))
+        .collect()
+}
+
+fn dimensions(grid: &[Vec<u8>]) -> (usize, usize) {
+    let cols = grid.first().map_or(0, |row| row.len());
+    assert!(
+        grid.iter().all(|row| row.len() == cols),
+        "all rows must have the same length"
+    );
+    (grid.len(), cols)
+}
+
+/// 크기가 `pr × pc`인 모든 창의 해시를 행 우선 순서로
+fn window_hashes_2d(grid: &[Vec<u8>], pr: usize, pc: usize, bc: u64, br: u64, m: u64) -> Vec<u64> {
+    let rows_hash: Vec<Vec<u64>> = grid
+        .iter()
+        .map(|row| window_hashes(row.iter().map(|&c| c as u64 + 1), pc, bc, m))
+        .collect();
+    let width = grid[0].len() - pc + 1;
+    let columns: Vec<Vec<u64>> = (0..width)
+        .map(|j| window_hashes(rows_hash.iter().map(|h| h[j]), pr, br, m))
+        .collect();
+    let height = grid.len() - pr + 1;
+    (0..height)
+        .flat_map(|i| columns.iter().map(move |column| column[i]))
+        .collect()
+}
+
+/// 길이 w인 모든 창의 다항식 해시 `Σ v[t] · base^(w - 1 - t)`
+fn window_hashes(
+    values: impl Iterator<Item = u64> + Clone,
+    w: usize,
+    base: u64,
+    m: u64,
+) -> Vec<u64> {
+    let top = (1..w).fold(1, |p, _| mul_mod(p, base, m));
+    let mut hashes = Vec::new();
+    let mut h = 0;
+    let mut outgoing = values.clone();
+    for (t, v) in values.enumerate() {
+        if t >= w {
+            let old = outgoing.next().unwrap();
+            h = add_mod(h, m - mul_mod(old, top, m), m);
+        }
+        h = add_mod(mul_mod(h, base, m), v % m, m);
+        if t + 1 >= w {
+            hashes.push(h);
+        }
+    }
+    hashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn brute_force(grid: &[Vec<u8>], pattern: &[Vec<u8>]) -> Vec<(usize, usize)> {
+        let (rows, cols) = (grid.len(), grid.first().map_or(0, |r| r.len()));
+        let (pr, pc) = (pattern.len(), pattern.first().map_or(0, |r| r.len()));
+        let mut result = Vec::new();
+        if pr > rows || pc > cols {
+            return result;
+        }
+        for i in 0..=rows - pr {
+            for j in 0..=cols - pc {
+                if (0..pr).all(|t| (0..pc).all(|u| grid[i + t][j + u] == pattern[t][u])) {
+                    result.push((i, j));
+                }
+            }
+        }
+        result
+    }
+
+    fn random_grid(rng: &mut XorShift, rows: usize, cols: usize, alphabet: u64) -> Vec<Vec<u8>> {
+        (0..rows)
+            .map(|_| {
+                (0..cols)
+                    .map(|_| b'a' + rng.below(alphabet) as u8)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(172);
+        for round in 0..300 {
+            let alphabet = [1, 2, 3][round % 3];
+            let (rows, cols) = (rng.index(12), rng.index(12));
+            let grid = random_grid(&mut rng, rows, cols, alphabet);
+            let (pr, pc) = (rng.index(4), rng.index(4));
+            let pattern = if rng.below(2) == 0 && pr <= rows && pc <= cols && pr > 0 {
+                // 격자의 일부를 잘라 써서 답이 자주 있도록 함
+                let (i, j) = (rng.index(rows - pr + 1), rng.index(cols - pc + 1));
+                grid[i..i + pr]
+                    .iter()
+                    .map(|row| row[j..j + pc].to_vec())
+                    .collect()
+            } else {
+                random_grid(&mut rng, pr, pc, alphabet)
+            };
+            if pr == 0 {
+                // 행이 0개면 열 수도 0으로 봄
+                assert_eq!(find_pattern_2d(&grid, &[]), brute_force(&grid, &[]));
+                continue;
+            }
+            assert_eq!(
+                find_pattern_2d(&grid, &pattern),
+                brute_force(&grid, &pattern)
+            );
+        }
+    }
+
+    #[test]
+    fn edge_shapes() {
+        let grid: Vec<Vec<u8>> = ["abab", "baba", "abab"]
+            .iter()
+            .map(|r| r.as_bytes().to_vec())
+            .collect();
+        let row = vec![b"ab".to_vec()];
+        assert_eq!(
+            find_pattern_2d(&grid, &row),
+            vec![(0, 0), (0, 2), (1, 1), (2, 0), (2, 2)]
+        );
+        let column = vec![b"a".to_vec(), b"b".to_vec()];
+        assert_eq!(
+            find_pattern_2d(&grid, &column),
+            vec![(0, 0), (0, 2), (1, 1), (1, 3)]
+        );
+        assert_eq!(find_pattern_2d(&grid, &grid), vec![(0, 0)]);
+        let too_big = vec![b"ababa".to_vec()];
+        assert!(find_pattern_2d(&grid, &too_big).is_empty());
+        assert!(find_pattern_2d(&[], &row).is_empty());
+    }
+
+    #[test]
+    fn all_identical_worst_case() {
+        let grid = vec![vec![b'a'; 200]; 200];
+        let pattern = vec![vec![b'a'; 50]; 50];
+        let found = find_pattern_2d(&grid, &pattern);
+        assert_eq!(found.len(), 151 * 151);
+        assert_eq!(found[152], (1, 1));
+        let mut other = pattern.clone();
+        other[49][49] = b'b';
+        assert!(find_pattern_2d(&grid, &other).is_empty());
+    }
+}
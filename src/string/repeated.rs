@@ -0,0 +1,113 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::SuffixArray;
+use crate::sparse_table::SparseTable;
+use std::ops::Range;
+
+/// # Longest repeated substring
+/// 두 번 이상(겹쳐도 됨) 나타나는 가장 긴 부분 문자열의 한 등장 위치입니다. 그런 것이 없으면 `0..0`입니다.
+///
+/// * suffix array에서 이웃한 접미사의 LCP 중 가장 큰 값이 답의 길이입니다. 길이가 같은 답이 여러 개면 그중 하나를
+/// 반환합니다.
+/// ## 시간복잡도
+/// O(n log n)
+pub fn longest_repeated_substring(s: &[u8]) -> Range<usize> {
+    longest_substring_repeated_k_times(s, 2)
+}
+
+/// # Longest substring repeated k times
+/// k번 이상(겹쳐도 됨) 나타나는 가장 긴 부분 문자열의 한 등장 위치입니다. 그런 것이 없으면 `0..0`입니다.
+///
+/// * suffix array에서 이어진 k개의 접미사가 공유하는 접두사의 길이는 그 사이 LCP k - 1개의 최솟값이라서,
+/// [`SparseTable`]로 길이 k - 1인 창마다 최솟값을 구합니다.
+/// * `k <= 1`이면 문자열 전체가 답입니다.
+/// ## 시간복잡도
+/// O(n log n)
+pub fn longest_substring_repeated_k_times(s: &[u8], k: usize) -> Range<usize> {
+    let n = s.len();
+    if k <= 1 {
+        return 0..n;
+    }
+    if k > n {
+        return 0..0;
+    }
+    let suffixes = SuffixArray::new(s);
+    let (sa, lcp) = (suffixes.sa(), suffixes.lcp());
+    let table = SparseTable::min(lcp);
+    let mut best = 0..0;
+    // 창의 시작 i마다 sa[i..i + k]의 접미사가 공유하는 길이
+    for (i, &start) in sa.iter().enumerate().take(n + 1 - k) {
+        let len = table.query(i..i + k - 1);
+        if len > best.len() {
+            best = start..start + len;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use crate::string::kmp_find_all;
+    use std::collections::HashMap;
+
+    /// k번 이상 나타나는 가장 긴 부분 문자열의 길이 (길이 L이 되면 L - 1도 되므로 이분 탐색)
+    fn brute_length(s: &[u8], k: usize) -> usize {
+        let repeats = |len: usize| {
+            let mut count: HashMap<&[u8], usize> = HashMap::new();
+            s.windows(len).any(|w| {
+                let c = count.entry(w).or_default();
+                *c += 1;
+                *c >= k
+            })
+        };
+        let (mut lo, mut hi) = (0, s.len());
+        while lo < hi {
+            let mid = (lo + hi).div_ceil(2);
+            if repeats(mid) {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        lo
+    }
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(171);
+        for round in 0..200 {
+            let alphabet = [1, 2, 3, 26][round % 4];
+            let n = if round < 4 { 1000 } else { rng.index(30) };
+            let s: Vec<u8> = (0..n).map(|_| b'a' + rng.below(alphabet) as u8).collect();
+            for k in [2, 3, rng.index(6)] {
+                let range = longest_substring_repeated_k_times(&s, k);
+                if k >= 2 {
+                    assert_eq!(range.len(), brute_length(&s, k));
+                }
+                if k >= 2 && !range.is_empty() {
+                    assert!(kmp_find_all(&s, &s[range.clone()]).len() >= k);
+                }
+            }
+            assert_eq!(
+                longest_repeated_substring(&s),
+                longest_substring_repeated_k_times(&s, 2)
+            );
+        }
+    }
+
+    #[test]
+    fn distinct_and_all_equal_strings() {
+        assert_eq!(longest_repeated_substring(b"abcdef"), 0..0);
+        assert_eq!(longest_repeated_substring(b""), 0..0);
+        assert_eq!(longest_repeated_substring(b"a"), 0..0);
+        let same = vec![b'a'; 1000];
+        assert_eq!(longest_repeated_substring(&same).len(), 999);
+        assert_eq!(longest_substring_repeated_k_times(&same, 10).len(), 991);
+        assert_eq!(longest_substring_repeated_k_times(&same, 1000).len(), 1);
+        assert_eq!(longest_substring_repeated_k_times(&same, 1001), 0..0);
+        assert_eq!(longest_substring_repeated_k_times(b"abc", 1), 0..3);
+        let banana = longest_repeated_substring(b"banana");
+        assert_eq!(&b"banana"[banana], b"ana");
+    }
+}
@@ -0,0 +1,293 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::ops::Range;
+
+/// 해시에 쓸 수 있는 60비트 안팎의 소수
+const PRIMES: [u64; 8] = [
+    (1 << 61) - 1,
+    (1 << 62) - 57,
+    (1 << 63) - 25,
+    (1 << 60) - 93,
+    (1 << 59) - 55,
+    (1 << 58) - 27,
+    (1 << 62) - 87,
+    (1 << 63) - 259,
+];
+
+/// # Rolling hash 설정
+/// 서로 독립인 (법, 밑) 두 쌍입니다. 같은 설정으로 만든 [`RollingHash`]끼리만 해시를 비교할 수 있습니다.
+///
+/// * 법은 큰 소수 목록에서 서로 다르게 두 개 고르고, 밑은 `[2^16, 법 - 1)`에서 고릅니다.
+/// * [`random`](Self::random)은 실행마다 다른 값을 고르므로 anti-hash 입력에 걸리지 않습니다. 재현이 필요하면
+/// [`from_seed`](Self::from_seed)를 씁니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HashParams {
+    pub(super) modulus: [u64; 2],
+    pub(super) base: [u64; 2],
+}
+
+impl HashParams {
+    /// 실행마다 다른 설정
+    pub fn random() -> Self {
+        Self::from_seed(RandomState::new().build_hasher().finish())
+    }
+
+    /// seed로 정해지는 설정
+    pub fn from_seed(seed: u64) -> Self {
+        let mut state = seed;
+        let first = (splitmix64(&mut state) % PRIMES.len() as u64) as usize;
+        let step = 1 + (splitmix64(&mut state) % (PRIMES.len() as u64 - 1)) as usize;
+        let modulus = [PRIMES[first], PRIMES[(first + step) % PRIMES.len()]];
+        let base = random_bases(modulus, &mut state);
+        HashParams { modulus, base }
+    }
+
+    /// 법은 그대로 두고 밑만 seed로 새로 고른 설정. 2차원 해시처럼 같은 법에서 밑이 여럿 필요할 때 씁니다.
+    pub(super) fn with_modulus(modulus: [u64; 2], seed: u64) -> Self {
+        let mut state = seed;
+        let base = random_bases(modulus, &mut state);
+        HashParams { modulus, base }
+    }
+}
+
+/// 법마다 `[2^16, 법 - 1)`의 밑
+fn random_bases(modulus: [u64; 2], state: &mut u64) -> [u64; 2] {
+    modulus.map(|m| (1 << 16) + splitmix64(state) % (m - 1 - (1 << 16)))
+}
+
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// 부분 문자열의 해시와 길이. 같은 [`HashParams`]에서 나온 값끼리만 비교하세요.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StrHash {
+    pub hash: [u64; 2],
+    pub len: usize,
+}
+
+/// # Rolling hash
+/// 문자열의 앞부분 해시와 밑의 거듭제곱을 미리 계산해서, 부분 문자열의 해시와 비교, 두 접미사의 최장 공통 접두사를
+/// 빠르게 구합니다.
+///
+/// * 두 (법, 밑) 쌍에 대한 다항식 해시 `Σ (s[i] + 1) · base^(len - 1 - i) mod m`입니다. 곱셈은 u128로 해서 넘치지
+/// 않습니다.
+/// * 해시가 같으면 같은 문자열이라고 보므로 아주 작은 확률로 틀릴 수 있습니다.
+/// * 구간은 0-based 반열림입니다.
+/// ## 시간복잡도
+/// 생성 O(n), `hash`/`eq`/`concat_hash` O(1), `lcp` O(log n)
+#[derive(Clone, Debug)]
+pub struct RollingHash {
+    params: HashParams,
+    /// `prefix[k][i]`: `s[..i]`의 k번째 해시
+    prefix: [Vec<u64>; 2],
+    /// `power[k][i]`: `base[k]^i mod modulus[k]`
+    power: [Vec<u64>; 2],
+}
+
+impl RollingHash {
+    /// 무작위 설정으로 생성
+    pub fn new(s: &[u8]) -> Self {
+        Self::with_params(s, HashParams::random())
+    }
+
+    /// seed로 정한 설정으로 생성
+    pub fn with_seed(s: &[u8], seed: u64) -> Self {
+        Self::with_params(s, HashParams::from_seed(seed))
+    }
+
+    /// 주어진 설정으로 생성. 다른 문자열과 해시를 비교하려면 같은 설정을 넘기세요.
+    pub fn with_params(s: &[u8], params: HashParams) -> Self {
+        let n = s.len();
+        let build = |k: usize| {
+            let (m, b) = (params.modulus[k], params.base[k]);
+            let mut prefix = vec![0; n + 1];
+            let mut power = vec![1; n + 1];
+            for (i, &c) in s.iter().enumerate() {
+                prefix[i + 1] = add_mod(mul_mod(prefix[i], b, m), c as u64 + 1, m);
+                power[i + 1] = mul_mod(power[i], b, m);
+            }
+            (prefix, power)
+        };
+        let ((p0, w0), (p1, w1)) = (build(0), build(1));
+        RollingHash {
+            params,
+            prefix: [p0, p1],
+            power: [w0, w1],
+        }
+    }
+
+    /// 이 해시에 쓴 설정
+    pub fn params(&self) -> HashParams {
+        self.params
+    }
+
+    /// 문자열의 길이
+    pub fn len(&self) -> usize {
+        self.prefix[0].len() - 1
+    }
+
+    /// 빈 문자열인지
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `s[l..r]`의 해시
+    pub fn hash(&self, range: Range<usize>) -> StrHash {
+        let Range { start: l, end: r } = range;
+        let n = self.len();
+        assert!(l <= r && r <= n, "invalid range {l}..{r} for length {n}");
+        let hash = [0, 1].map(|k| {
+            let m = self.params.modulus[k];
+            let shifted = mul_mod(self.prefix[k][l], self.power[k][r - l], m);
+            add_mod(self.prefix[k][r], m - shifted, m)
+        });
+        StrHash { hash, len: r - l }
+    }
+
+    /// 두 부분 문자열이 같은지
+    pub fn eq(&self, a: Range<usize>, b: Range<usize>) -> bool {
+        a.len() == b.len() && self.hash(a) == self.hash(b)
+    }
+
+    /// `s[i..]`와 `s[j..]`의 최장 공통 접두사의 길이
+    pub fn lcp(&self, i: usize, j: usize) -> usize {
+        let n = self.len();
+        assert!(i <= n && j <= n, "index out of range for length {n}");
+        let (mut lo, mut hi) = (0, n - i.max(j));
+        // 길이 lo는 항상 같고, hi 초과는 확인할 필요 없음
+        while lo < hi {
+            let mid = lo + (hi - lo).div_ceil(2);
+            if self.hash(i..i + mid) == self.hash(j..j + mid) {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        lo
+    }
+
+    /// 앞 조각 a 뒤에 b를 붙인 문자열의 해시. b의 길이는 이 문자열의 길이 이하여야 합니다.
+    pub fn concat_hash(&self, a: StrHash, b: StrHash) -> StrHash {
+        assert!(
+            b.len <= self.len(),
+            "piece of length {} is longer than the hashed string",
+            b.len
+        );
+        let hash = [0, 1].map(|k| {
+            let m = self.params.modulus[k];
+            add_mod(mul_mod(a.hash[k], self.power[k][b.len], m), b.hash[k], m)
+        });
+        StrHash {
+            hash,
+            len: a.len + b.len,
+        }
+    }
+}
+
+pub(super) fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+pub(super) fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    let (sum, carry) = a.overflowing_add(b);
+    if carry || sum >= m {
+        sum.wrapping_sub(m)
+    } else {
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_bytes};
+
+    #[test]
+    fn substring_equality_and_lcp_against_slices() {
+        let mut rng = XorShift::new(159);
+        for round in 0..100 {
+            let n = rng.index(80);
+            // 바이트 0도 섞음
+            let s = random_bytes(&mut rng, n, 0, [1, 2, 256][round % 3]);
+            let hash = RollingHash::with_seed(&s, round as u64);
+            for _ in 0..200 {
+                let len = rng.index(n + 1);
+                let (a, b) = (rng.index(n - len + 1), rng.index(n - len + 1));
+                assert_eq!(
+                    hash.eq(a..a + len, b..b + len),
+                    s[a..a + len] == s[b..b + len]
+                );
+                let (i, j) = (rng.index(n + 1), rng.index(n + 1));
+                let expected = s[i..]
+                    .iter()
+                    .zip(&s[j..])
+                    .take_while(|(x, y)| x == y)
+                    .count();
+                assert_eq!(hash.lcp(i, j), expected);
+                // 조각을 붙인 해시는 이어진 구간의 해시와 같음
+                let mid = a + rng.index(len + 1);
+                let joined = hash.concat_hash(hash.hash(a..mid), hash.hash(mid..a + len));
+                assert_eq!(joined, hash.hash(a..a + len));
+            }
+        }
+    }
+
+    #[test]
+    fn cross_string_comparison_with_shared_params() {
+        let mut rng = XorShift::new(1590);
+        let params = HashParams::random();
+        for _ in 0..100 {
+            let s = random_bytes(&mut rng, 30, 0, 2);
+            let t = random_bytes(&mut rng, 30, 0, 2);
+            let (hs, ht) = (
+                RollingHash::with_params(&s, params),
+                RollingHash::with_params(&t, params),
+            );
+            for _ in 0..50 {
+                let len = rng.index(10);
+                let (a, b) = (rng.index(31 - len), rng.index(31 - len));
+                assert_eq!(
+                    hs.hash(a..a + len) == ht.hash(b..b + len),
+                    s[a..a + len] == t[b..b + len]
+                );
+            }
+        }
+        // 설정이 같으면 어느 문자열에서 계산했든 같은 해시
+        let (a, b) = (
+            RollingHash::with_params(b"xxabcxx", params),
+            RollingHash::with_params(b"abc", params),
+        );
+        assert_eq!(a.hash(2..5), b.hash(0..3));
+        assert_eq!(a.params(), b.params());
+    }
+
+    #[test]
+    fn params_are_well_formed() {
+        for seed in 0..1000 {
+            let p = HashParams::from_seed(seed);
+            assert_ne!(p.modulus[0], p.modulus[1]);
+            for k in 0..2 {
+                assert!(PRIMES.contains(&p.modulus[k]));
+                assert!((1 << 16) <= p.base[k] && p.base[k] < p.modulus[k] - 1);
+            }
+        }
+        assert_eq!(HashParams::from_seed(7), HashParams::from_seed(7));
+        // 같은 seed는 버전이 바뀌어도 같은 설정
+        let p = HashParams::from_seed(7);
+        assert_eq!(p.modulus, [(1 << 63) - 259, (1 << 60) - 93]);
+        assert_eq!(p.base, [7_392_729_709_960_964_870, 376_872_386_840_505_625]);
+        let p = HashParams::from_seed(2024);
+        assert_eq!(p.modulus, [(1 << 58) - 27, (1 << 62) - 87]);
+        assert_eq!(p.base, [31_380_883_687_581_587, 2_143_266_886_398_031_961]);
+        let empty = RollingHash::new(b"");
+        assert!(empty.is_empty());
+        assert_eq!(empty.lcp(0, 0), 0);
+        assert_eq!(empty.hash(0..0).len, 0);
+    }
+}
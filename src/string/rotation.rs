@@ -0,0 +1,105 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Booth's algorithm
+/// s의 회전 `s[k..] + s[..k]` 중 사전 순으로 가장 작은 것의 시작 위치 k를 구합니다. 그런 k가 여러 개면(s가 주기적이면)
+/// 가장 작은 것을 반환합니다.
+///
+/// * `s + s` 위에서 지금까지의 최소 회전 후보 k에 대한 실패 함수를 유지하고, 더 작은 글자를 만나면 후보를 옮깁니다.
+/// * 빈 입력이면 0입니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn minimal_rotation(s: &[u8]) -> usize {
+    let n = s.len();
+    if n == 0 {
+        return 0;
+    }
+    let at = |i: usize| s[i % n];
+    // fail[j]: 후보 k에서 시작하는 (s + s)[k..k + j + 1]의 가장 긴 진짜 경계의 길이 - 1 (없으면 -1)
+    let mut fail = vec![-1isize; 2 * n];
+    let mut k = 0;
+    for j in 1..2 * n {
+        let c = at(j);
+        let mut i = fail[j - k - 1];
+        while i != -1 && c != at(k + i as usize + 1) {
+            if c < at(k + i as usize + 1) {
+                k = j - i as usize - 1;
+            }
+            i = fail[i as usize];
+        }
+        if i == -1 && c != at(k) {
+            if c < at(k) {
+                k = j;
+            }
+            fail[j - k] = -1;
+        } else {
+            fail[j - k] = i + 1;
+        }
+    }
+    k % n
+}
+
+/// 사전 순으로 가장 작은 회전
+pub fn canonical_rotation(s: &[u8]) -> Vec<u8> {
+    let k = minimal_rotation(s);
+    [&s[k..], &s[..k]].concat()
+}
+
+/// b가 a의 회전인지 (길이가 다르면 false)
+pub fn rotations_equal(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && canonical_rotation(a) == canonical_rotation(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    fn brute_minimal_rotation(s: &[u8]) -> usize {
+        let n = s.len();
+        (0..n)
+            .min_by(|&a, &b| {
+                let ra = s[a..].iter().chain(&s[..a]);
+                let rb = s[b..].iter().chain(&s[..b]);
+                ra.cmp(rb)
+            })
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn random_against_all_rotations() {
+        let mut rng = XorShift::new(164);
+        for round in 0..400 {
+            let alphabet = [1, 2, 3, 26][round % 4];
+            let n = if round < 8 { 2000 } else { rng.index(30) };
+            let s: Vec<u8> = if round % 5 == 0 {
+                // 같은 블록을 반복한 문자열
+                let block: Vec<u8> = (0..rng.index(4) + 1)
+                    .map(|_| b'a' + rng.below(alphabet) as u8)
+                    .collect();
+                block.iter().copied().cycle().take(n).collect()
+            } else {
+                (0..n).map(|_| b'a' + rng.below(alphabet) as u8).collect()
+            };
+            let k = minimal_rotation(&s);
+            assert_eq!(k, brute_minimal_rotation(&s));
+            let r = rng.index(n + 1);
+            let rotated = [&s[r..], &s[..r]].concat();
+            assert!(rotations_equal(&s, &rotated));
+            assert_eq!(canonical_rotation(&rotated), canonical_rotation(&s));
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(minimal_rotation(b""), 0);
+        assert_eq!(minimal_rotation(b"z"), 0);
+        assert_eq!(minimal_rotation(b"aaaa"), 0);
+        assert_eq!(minimal_rotation(b"baba"), 1);
+        assert_eq!(minimal_rotation(b"cabca"), 1);
+        assert_eq!(canonical_rotation(b"bca"), b"abc");
+        assert!(rotations_equal(b"", b""));
+        assert!(rotations_equal(b"abcd", b"cdab"));
+        assert!(!rotations_equal(b"abcd", b"acbd"));
+        assert!(!rotations_equal(b"ab", b"aba"));
+    }
+}
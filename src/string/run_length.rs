@@ -0,0 +1,89 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Runs
+/// 배열에서 같은 값이 이어진 최대 구간마다 `(값, 길이)`를 앞에서부터 내놓습니다. 값을 복사하지 않습니다.
+///
+/// * 길이는 항상 1 이상이고, 빈 배열이면 아무것도 내놓지 않습니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn runs<T: Eq>(items: &[T]) -> impl Iterator<Item = (&T, usize)> + '_ {
+    let mut rest = items;
+    std::iter::from_fn(move || {
+        let first = rest.first()?;
+        let len = rest.iter().take_while(|&x| x == first).count();
+        rest = &rest[len..];
+        Some((first, len))
+    })
+}
+
+/// # Run-length encoding
+/// [`runs`]를 `(값, 길이)` 배열로 모읍니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn run_length_encode<T: Eq + Clone>(items: &[T]) -> Vec<(T, usize)> {
+    runs(items).map(|(x, len)| (x.clone(), len)).collect()
+}
+
+/// [`run_length_encode`]의 결과를 원래 배열로 되돌림
+pub fn run_length_decode<T: Clone>(encoded: &[(T, usize)]) -> Vec<T> {
+    encoded
+        .iter()
+        .flat_map(|(x, len)| std::iter::repeat_n(x, *len).cloned())
+        .collect()
+}
+
+/// 문자열의 run-length encoding. 바이트가 아니라 `char` 단위라서 여러 바이트짜리 UTF-8 글자도 한 글자로 셉니다.
+pub fn run_length_encode_str(s: &str) -> Vec<(char, usize)> {
+    let mut result: Vec<(char, usize)> = Vec::new();
+    for c in s.chars() {
+        match result.last_mut() {
+            Some((last, len)) if *last == c => *len += 1,
+            _ => result.push((c, 1)),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_round_trip() {
+        let mut rng = XorShift::new(169);
+        for round in 0..300 {
+            let n = rng.index(50);
+            let values = [1, 2, 5][round % 3];
+            let items: Vec<u64> = (0..n).map(|_| rng.below(values)).collect();
+            let encoded = run_length_encode(&items);
+            assert_eq!(run_length_decode(&encoded), items);
+            assert!(encoded.iter().all(|&(_, len)| len > 0));
+            assert!(encoded.windows(2).all(|w| w[0].0 != w[1].0));
+            assert_eq!(encoded.iter().map(|&(_, len)| len).sum::<usize>(), n);
+            let zero_copy: Vec<(u64, usize)> = runs(&items).map(|(&x, len)| (x, len)).collect();
+            assert_eq!(zero_copy, encoded);
+        }
+    }
+
+    #[test]
+    fn edge_cases_and_utf8() {
+        assert!(run_length_encode::<u8>(&[]).is_empty());
+        assert!(run_length_decode::<u8>(&[]).is_empty());
+        assert_eq!(runs::<u8>(&[]).count(), 0);
+        assert_eq!(run_length_encode(&[7, 7, 7]), vec![(7, 3)]);
+        assert_eq!(
+            run_length_encode(b"aabccc"),
+            vec![(b'a', 2), (b'b', 1), (b'c', 3)]
+        );
+        assert_eq!(
+            run_length_encode_str("가가나aa😀😀😀"),
+            vec![('가', 2), ('나', 1), ('a', 2), ('😀', 3)]
+        );
+        assert!(run_length_encode_str("").is_empty());
+        let decoded: String = run_length_decode(&run_length_encode_str("ééx"))
+            .into_iter()
+            .collect();
+        assert_eq!(decoded, "ééx");
+    }
+}
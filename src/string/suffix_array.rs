@@ -0,0 +1,219 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::ops::Range;
+
+/// # Suffix array
+/// s의 모든 접미사 `s[i..]`를 사전 순으로 정렬했을 때의 시작 위치 i의 배열입니다.
+///
+/// * 앞 `2^k` 글자의 순위로 접미사를 정렬하는 doubling이고, 단계마다 (앞 순위, 뒤 순위) 두 키를 counting sort로
+/// 정렬합니다. 모든 순위가 달라지면 멈춥니다.
+/// * 짧은 접미사가 그것으로 시작하는 긴 접미사보다 앞섭니다. 빈 입력이면 빈 배열입니다.
+/// ## 시간복잡도
+/// O(n log n)
+pub fn suffix_array(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    let mut rank: Vec<usize> = s.iter().map(|&c| c as usize).collect();
+    counting_sort(&mut sa, &rank, 256);
+    let mut classes = 256;
+    let mut k = 1;
+    let mut order = Vec::with_capacity(n);
+    let mut next_rank = vec![0; n];
+    while k < n {
+        // 뒤 키(rank[i + k]) 순서: 뒤가 없는 접미사가 먼저, 그다음은 sa 순서
+        order.clear();
+        order.extend(n - k..n);
+        order.extend(sa.iter().filter(|&&i| i >= k).map(|&i| i - k));
+        counting_sort(&mut order, &rank, classes);
+        std::mem::swap(&mut sa, &mut order);
+        let key = |i: usize| (rank[i], if i + k < n { rank[i + k] + 1 } else { 0 });
+        next_rank[sa[0]] = 0;
+        for j in 1..n {
+            next_rank[sa[j]] = next_rank[sa[j - 1]] + usize::from(key(sa[j - 1]) != key(sa[j]));
+        }
+        std::mem::swap(&mut rank, &mut next_rank);
+        classes = rank[sa[n - 1]] + 1;
+        if classes == n {
+            break;
+        }
+        k *= 2;
+    }
+    sa
+}
+
+/// items를 `key[item]` 순서로 안정 정렬 (`key[item] < classes`)
+fn counting_sort(items: &mut Vec<usize>, key: &[usize], classes: usize) {
+    let mut start = vec![0; classes + 1];
+    for &i in items.iter() {
+        start[key[i] + 1] += 1;
+    }
+    for c in 0..classes {
+        start[c + 1] += start[c];
+    }
+    let mut sorted = vec![0; items.len()];
+    for &i in items.iter() {
+        sorted[start[key[i]]] = i;
+        start[key[i]] += 1;
+    }
+    *items = sorted;
+}
+
+/// # LCP array (Kasai)
+/// `lcp[i]`는 suffix array에서 이웃한 두 접미사 `s[sa[i]..]`와 `s[sa[i + 1]..]`의 최장 공통 접두사의 길이이고,
+/// 길이는 `n - 1`(빈 입력이면 0)입니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn lcp_array(s: &[u8], sa: &[usize]) -> Vec<usize> {
+    let n = s.len();
+    assert_eq!(sa.len(), n, "suffix array length does not match the string");
+    let mut rank = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() {
+        rank[p] = i;
+    }
+    let mut lcp = vec![0; n.saturating_sub(1)];
+    let mut h: usize = 0;
+    // 접미사를 s에서의 위치 순서로 보면 h는 한 칸에 1씩만 줄어듦
+    for i in 0..n {
+        if rank[i] + 1 == n {
+            h = 0;
+            continue;
+        }
+        let j = sa[rank[i] + 1];
+        while i + h < n && j + h < n && s[i + h] == s[j + h] {
+            h += 1;
+        }
+        lcp[rank[i]] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+/// # Suffix array 묶음
+/// 문자열과 그 [`suffix_array`], [`lcp_array`]를 함께 두고 패턴 검색과 서로 다른 부분 문자열 개수를 구합니다.
+/// ## 시간복잡도
+/// 생성 O(n log n), `find` O(|pattern| log n), `count_distinct_substrings` O(n)
+#[derive(Clone, Debug)]
+pub struct SuffixArray {
+    s: Vec<u8>,
+    sa: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl SuffixArray {
+    /// 문자열로 생성
+    pub fn new(s: &[u8]) -> Self {
+        let sa = suffix_array(s);
+        let lcp = lcp_array(s, &sa);
+        SuffixArray {
+            s: s.to_vec(),
+            sa,
+            lcp,
+        }
+    }
+
+    /// 문자열의 길이
+    pub fn len(&self) -> usize {
+        self.s.len()
+    }
+
+    /// 빈 문자열인지
+    pub fn is_empty(&self) -> bool {
+        self.s.is_empty()
+    }
+
+    /// suffix array
+    pub fn sa(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// LCP array
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// pattern으로 시작하는 접미사들의 suffix array 구간. 등장 위치는 `sa()[range]`이고, 개수는 구간의 길이입니다.
+    /// 빈 pattern이면 `0..n`입니다.
+    pub fn find(&self, pattern: &[u8]) -> Range<usize> {
+        let m = pattern.len();
+        let prefix = |i: usize| &self.s[i..(i + m).min(self.s.len())];
+        let lo = self.sa.partition_point(|&i| prefix(i) < pattern);
+        let hi = self.sa.partition_point(|&i| prefix(i) <= pattern);
+        lo..hi
+    }
+
+    /// 서로 다른 (빈 문자열이 아닌) 부분 문자열의 개수
+    pub fn count_distinct_substrings(&self) -> u64 {
+        let n = self.s.len() as u64;
+        n * (n + 1) / 2 - self.lcp.iter().map(|&h| h as u64).sum::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_bytes};
+    use crate::string::kmp_find_all;
+    use std::collections::HashSet;
+
+    #[test]
+    fn random_against_sorting_suffixes() {
+        let mut rng = XorShift::new(160);
+        for round in 0..200 {
+            let alphabet = [1, 2, 3, 26][round % 4];
+            let n = if round < 8 { 2000 } else { rng.index(50) };
+            let s = random_bytes(&mut rng, n, b'a', alphabet);
+            let mut expected: Vec<usize> = (0..n).collect();
+            expected.sort_unstable_by_key(|&i| &s[i..]);
+            let sa = suffix_array(&s);
+            assert_eq!(sa, expected);
+            let lcp = lcp_array(&s, &sa);
+            for (i, &h) in lcp.iter().enumerate() {
+                let naive = s[sa[i]..]
+                    .iter()
+                    .zip(&s[sa[i + 1]..])
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                assert_eq!(h, naive);
+            }
+            let wrapped = SuffixArray::new(&s);
+            for _ in 0..20 {
+                let m = rng.index(5);
+                let pattern = random_bytes(&mut rng, m, b'a', alphabet);
+                let mut found = wrapped.sa()[wrapped.find(&pattern)].to_vec();
+                found.sort_unstable();
+                let mut expected = kmp_find_all(&s, &pattern);
+                // 빈 pattern은 KMP가 끝 위치 n도 셈
+                if pattern.is_empty() {
+                    expected.pop();
+                }
+                assert_eq!(found, expected);
+            }
+            if n <= 50 {
+                let distinct: HashSet<&[u8]> = (0..n)
+                    .flat_map(|l| (l + 1..=n).map(move |r| (l, r)))
+                    .map(|(l, r)| &s[l..r])
+                    .collect();
+                assert_eq!(wrapped.count_distinct_substrings(), distinct.len() as u64);
+            }
+        }
+    }
+
+    #[test]
+    fn empty_and_all_equal() {
+        assert!(suffix_array(b"").is_empty());
+        assert!(lcp_array(b"", &[]).is_empty());
+        let empty = SuffixArray::new(b"");
+        assert_eq!(empty.find(b""), 0..0);
+        assert_eq!(empty.find(b"a"), 0..0);
+        assert_eq!(empty.count_distinct_substrings(), 0);
+        assert_eq!(suffix_array(b"aaaa"), vec![3, 2, 1, 0]);
+        assert_eq!(lcp_array(b"aaaa", &[3, 2, 1, 0]), vec![1, 2, 3]);
+        let same = SuffixArray::new(b"aaaa");
+        assert_eq!(same.count_distinct_substrings(), 4);
+        assert_eq!(same.find(b"aa").len(), 3);
+        assert_eq!(suffix_array(b"banana"), vec![5, 3, 1, 0, 4, 2]);
+        let banana = SuffixArray::new(b"banana");
+        assert_eq!(banana.lcp(), &[1, 3, 0, 0, 2]);
+        assert_eq!(banana.find(b"nab"), 5..5);
+        assert_eq!(banana.count_distinct_substrings(), 15);
+    }
+}
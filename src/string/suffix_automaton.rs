@@ -0,0 +1,305 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// suffix link가 없음을 나타내는 번호 (루트만 해당)
+const NIL: u32 = u32::MAX;
+
+#[derive(Clone, Debug)]
+struct State {
+    /// 이 상태가 나타내는 가장 긴 문자열의 길이
+    len: usize,
+    link: u32,
+    /// (글자, 다음 상태). 글자 종류가 적다고 보고 선형 탐색합니다.
+    next: Vec<(u8, u32)>,
+    /// 복제로 생긴 상태가 아니면 1. endpos 크기를 셀 때의 시작값입니다.
+    own: u64,
+}
+
+impl State {
+    fn go(&self, c: u8) -> Option<u32> {
+        self.next.iter().find(|&&(d, _)| d == c).map(|&(_, v)| v)
+    }
+
+    fn set(&mut self, c: u8, to: u32) {
+        match self.next.iter_mut().find(|(d, _)| *d == c) {
+            Some(edge) => edge.1 = to,
+            None => self.next.push((c, to)),
+        }
+    }
+}
+
+/// # Suffix automaton
+/// 문자열의 모든 부분 문자열을 받아들이는 가장 작은 DFA를 글자를 하나씩 붙여 가며 온라인으로 만듭니다.
+///
+/// * 상태는 끝나는 위치 집합(endpos)이 같은 부분 문자열의 묶음이고, 상태 수는 `2n - 1` 이하입니다.
+/// * 부분 문자열의 등장 횟수는 그 상태의 endpos 크기입니다. 처음 물어볼 때 suffix link 트리를 길이 내림차순으로
+/// 훑어서 한 번에 구해 두고, [`push`](Self::push)하면 다시 구합니다. 그래서
+/// [`count_occurrences`](Self::count_occurrences)는 `&mut self`를 받습니다.
+/// * 전이는 상태마다 `(글자, 상태)` 목록을 선형 탐색하므로 글자 종류 σ가 작을 때 알맞습니다.
+/// ## 시간복잡도
+/// `push` amortized O(σ), 패턴 질의 O(|pattern| σ), endpos 크기 계산 O(n), LCS O(|other| σ)
+#[derive(Clone, Debug)]
+pub struct SuffixAutomaton {
+    states: Vec<State>,
+    /// 지금까지의 문자열 전체에 해당하는 상태
+    last: u32,
+    distinct: u64,
+    /// 상태별 endpos 크기. push하면 비움
+    occ: Vec<u64>,
+}
+
+impl Default for SuffixAutomaton {
+    fn default() -> Self {
+        SuffixAutomaton {
+            states: vec![State {
+                len: 0,
+                link: NIL,
+                next: Vec::new(),
+                own: 0,
+            }],
+            last: 0,
+            distinct: 0,
+            occ: Vec::new(),
+        }
+    }
+}
+
+impl SuffixAutomaton {
+    /// s로 만든 자동자
+    pub fn new(s: &[u8]) -> Self {
+        let mut automaton = Self::default();
+        for &c in s {
+            automaton.push(c);
+        }
+        automaton
+    }
+
+    /// 지금까지 넣은 문자열의 길이
+    pub fn len(&self) -> usize {
+        self.states[self.last as usize].len
+    }
+
+    /// 빈 문자열인지
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 상태의 개수 (루트 포함)
+    pub fn state_count(&self) -> usize {
+        self.states.len()
+    }
+
+    /// 문자열 끝에 c를 붙임
+    pub fn push(&mut self, c: u8) {
+        self.occ.clear();
+        let cur = self.new_state(self.len() + 1, NIL, Vec::new(), 1);
+        let mut p = self.last;
+        while p != NIL && self.states[p as usize].go(c).is_none() {
+            self.states[p as usize].set(c, cur);
+            p = self.states[p as usize].link;
+        }
+        let link = if p == NIL {
+            0
+        } else {
+            let q = self.states[p as usize].go(c).unwrap();
+            if self.states[p as usize].len + 1 == self.states[q as usize].len {
+                q
+            } else {
+                // q를 길이 len(p) + 1에서 둘로 나눔
+                let next = self.states[q as usize].next.clone();
+                let clone_link = self.states[q as usize].link;
+                let clone = self.new_state(self.states[p as usize].len + 1, clone_link, next, 0);
+                while p != NIL && self.states[p as usize].go(c) == Some(q) {
+                    self.states[p as usize].set(c, clone);
+                    p = self.states[p as usize].link;
+                }
+                self.states[q as usize].link = clone;
+                clone
+            }
+        };
+        self.states[cur as usize].link = link;
+        self.distinct += (self.states[cur as usize].len - self.states[link as usize].len) as u64;
+        self.last = cur;
+    }
+
+    /// pattern이 부분 문자열인지. 빈 pattern은 항상 true
+    pub fn contains(&self, pattern: &[u8]) -> bool {
+        self.walk(pattern).is_some()
+    }
+
+    /// pattern이 (겹치는 것까지) 몇 번 나타나는지. 빈 pattern은 `n + 1`번으로 셉니다.
+    pub fn count_occurrences(&mut self, pattern: &[u8]) -> u64 {
+        if pattern.is_empty() {
+            return self.len() as u64 + 1;
+        }
+        let Some(v) = self.walk(pattern) else {
+            return 0;
+        };
+        if self.occ.is_empty() {
+            self.compute_occurrences();
+        }
+        self.occ[v as usize]
+    }
+
+    /// 서로 다른 (빈 문자열이 아닌) 부분 문자열의 개수
+    pub fn count_distinct_substrings(&self) -> u64 {
+        self.distinct
+    }
+
+    /// 이 문자열과 other의 최장 공통 부분 문자열의 길이
+    pub fn longest_common_substring(&self, other: &[u8]) -> usize {
+        let (mut v, mut len, mut best) = (0u32, 0, 0);
+        for &c in other {
+            // c로 갈 수 있을 때까지 suffix link를 따라 더 짧은 접미사로 줄임
+            while v != 0 && self.states[v as usize].go(c).is_none() {
+                v = self.states[v as usize].link;
+                len = self.states[v as usize].len;
+            }
+            if let Some(to) = self.states[v as usize].go(c) {
+                v = to;
+                len += 1;
+            }
+            best = best.max(len);
+        }
+        best
+    }
+
+    fn new_state(&mut self, len: usize, link: u32, next: Vec<(u8, u32)>, own: u64) -> u32 {
+        let id = u32::try_from(self.states.len()).expect("too many states for u32 indices");
+        assert!(id != NIL, "too many states for u32 indices");
+        self.states.push(State {
+            len,
+            link,
+            next,
+            own,
+        });
+        id
+    }
+
+    fn walk(&self, pattern: &[u8]) -> Option<u32> {
+        let mut v = 0;
+        for &c in pattern {
+            v = self.states[v as usize].go(c)?;
+        }
+        Some(v)
+    }
+
+    /// 길이 내림차순으로 자기 endpos 크기를 suffix link 부모에 더함
+    fn compute_occurrences(&mut self) {
+        let n = self.len();
+        let mut start = vec![0; n + 2];
+        for state in &self.states {
+            start[state.len + 1] += 1;
+        }
+        for i in 0..=n {
+            start[i + 1] += start[i];
+        }
+        let mut order = vec![0; self.states.len()];
+        for (v, state) in self.states.iter().enumerate() {
+            order[start[state.len]] = v;
+            start[state.len] += 1;
+        }
+        self.occ = self.states.iter().map(|state| state.own).collect();
+        for &v in order.iter().rev() {
+            let link = self.states[v].link;
+            if link != NIL {
+                self.occ[link as usize] += self.occ[v];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_bytes};
+    use crate::string::SuffixArray;
+
+    fn naive_count(s: &[u8], pattern: &[u8]) -> u64 {
+        if pattern.len() > s.len() {
+            return 0;
+        }
+        (0..=s.len() - pattern.len())
+            .filter(|&i| &s[i..i + pattern.len()] == pattern)
+            .count() as u64
+    }
+
+    fn lcs_dp(a: &[u8], b: &[u8]) -> usize {
+        let mut best = 0;
+        let mut prev = vec![0; b.len() + 1];
+        for &x in a {
+            let mut cur = vec![0; b.len() + 1];
+            for (j, &y) in b.iter().enumerate() {
+                if x == y {
+                    cur[j + 1] = prev[j] + 1;
+                    best = best.max(cur[j + 1]);
+                }
+            }
+            prev = cur;
+        }
+        best
+    }
+
+    #[test]
+    fn random_against_naive_and_suffix_array() {
+        let mut rng = XorShift::new(161);
+        for round in 0..300 {
+            let alphabet = [1, 2, 3, 26][round % 4];
+            let n = rng.index(40);
+            let s = random_bytes(&mut rng, n, b'a', alphabet);
+            // 한 글자씩 붙이면서 중간 상태도 확인
+            let mut automaton = SuffixAutomaton::default();
+            for (i, &c) in s.iter().enumerate() {
+                automaton.push(c);
+                assert_eq!(
+                    automaton.count_distinct_substrings(),
+                    SuffixArray::new(&s[..=i]).count_distinct_substrings()
+                );
+                assert!(automaton.state_count() <= (2 * (i + 1)).max(2));
+                let m = rng.index(4);
+                let pattern = random_bytes(&mut rng, m, b'a', alphabet);
+                let expected = if m == 0 {
+                    i as u64 + 2
+                } else {
+                    naive_count(&s[..=i], &pattern)
+                };
+                assert_eq!(automaton.count_occurrences(&pattern), expected);
+            }
+            for _ in 0..20 {
+                let m = rng.index(6);
+                let pattern = random_bytes(&mut rng, m, b'a', alphabet);
+                let count = naive_count(&s, &pattern);
+                assert_eq!(automaton.contains(&pattern), m == 0 || count > 0);
+                if m > 0 {
+                    assert_eq!(automaton.count_occurrences(&pattern), count);
+                }
+                let len = rng.index(40);
+                let other = random_bytes(&mut rng, len, b'a', alphabet);
+                assert_eq!(
+                    automaton.longest_common_substring(&other),
+                    lcs_dp(&s, &other)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn small_cases() {
+        let mut automaton = SuffixAutomaton::new(b"abcbc");
+        assert_eq!(automaton.count_distinct_substrings(), 12);
+        assert_eq!(automaton.count_occurrences(b"bc"), 2);
+        assert_eq!(automaton.count_occurrences(b"cb"), 1);
+        assert_eq!(automaton.count_occurrences(b"abcbcd"), 0);
+        assert!(!automaton.contains(b"ac"));
+        assert_eq!(automaton.longest_common_substring(b"xxcbcx"), 3);
+        let mut same = SuffixAutomaton::new(b"aaaa");
+        assert_eq!(same.count_occurrences(b"aa"), 3);
+        assert_eq!(same.count_distinct_substrings(), 4);
+        same.push(b'a');
+        assert_eq!(same.count_occurrences(b"aa"), 4);
+        let empty = SuffixAutomaton::new(b"");
+        assert!(empty.is_empty());
+        assert!(empty.contains(b""));
+        assert_eq!(empty.count_distinct_substrings(), 0);
+        assert_eq!(empty.longest_common_substring(b"abc"), 0);
+    }
+}
@@ -0,0 +1,276 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    /// (글자, 자식). 글자 순으로 정렬되어 있습니다.
+    next: Vec<(u8, u32)>,
+    /// 이 노드를 지나는(이 노드의 문자열로 시작하는) 단어 수
+    pass: usize,
+    /// 이 노드에서 끝나는 단어 수
+    end: usize,
+}
+
+impl Node {
+    fn child(&self, c: u8) -> Option<u32> {
+        self.next
+            .binary_search_by_key(&c, |&(d, _)| d)
+            .ok()
+            .map(|i| self.next[i].1)
+    }
+}
+
+/// # Trie
+/// 바이트 문자열의 multiset을 접두사 트리로 관리합니다. 같은 단어를 여러 번 넣을 수 있습니다.
+///
+/// * 자식은 노드마다 글자 순으로 정렬한 `(글자, 노드)` 목록이라, 글자가 모두 합쳐 10^6개여도 노드당 몇십 바이트만
+/// 씁니다. 어떤 단어도 지나지 않게 된 노드는 떼어 내고 자리를 다시 씁니다.
+/// * 바이트 단위로 다루므로 `&str`은 `as_bytes()`로 넣으면 됩니다. UTF-8은 바이트 순서와 코드 포인트 순서가 같아서
+/// [`iter`](Self::iter)의 순서는 문자열 정렬 순서와 같습니다. 다만 접두사는 글자가 아니라 바이트 기준입니다.
+/// * 빈 단어도 넣을 수 있습니다.
+/// ## 시간복잡도
+/// 단어 길이 L에 대해 연산마다 O(L log σ)
+#[derive(Clone, Debug)]
+pub struct Trie {
+    nodes: Vec<Node>,
+    free: Vec<u32>,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Trie {
+    /// 빈 트라이
+    pub fn new() -> Self {
+        Trie {
+            nodes: vec![Node::default()],
+            free: Vec::new(),
+        }
+    }
+
+    /// 단어의 개수 (중복 포함)
+    pub fn len(&self) -> usize {
+        self.nodes[0].pass
+    }
+
+    /// 비었는지
+    pub fn is_empty(&self) -> bool {
+        self.nodes[0].pass == 0
+    }
+
+    /// 단어를 하나 넣음
+    pub fn insert(&mut self, word: &[u8]) {
+        let mut v = 0;
+        self.nodes[0].pass += 1;
+        for &c in word {
+            v = match self.nodes[v as usize].child(c) {
+                Some(to) => to,
+                None => {
+                    let to = self.alloc();
+                    let next = &mut self.nodes[v as usize].next;
+                    let at = next.partition_point(|&(d, _)| d < c);
+                    next.insert(at, (c, to));
+                    to
+                }
+            };
+            self.nodes[v as usize].pass += 1;
+        }
+        self.nodes[v as usize].end += 1;
+    }
+
+    /// 단어를 하나 뺌. 없으면 false
+    pub fn remove_one(&mut self, word: &[u8]) -> bool {
+        if self.count_equal(word) == 0 {
+            return false;
+        }
+        let mut v = 0;
+        self.nodes[0].pass -= 1;
+        for &c in word {
+            let to = self.nodes[v as usize].child(c).unwrap();
+            self.nodes[to as usize].pass -= 1;
+            if self.nodes[to as usize].pass == 0 {
+                // 이 아래로는 남은 단어가 없으므로 통째로 떼어 냄
+                self.nodes[v as usize].next.retain(|&(d, _)| d != c);
+                self.release(to);
+                return true;
+            }
+            v = to;
+        }
+        self.nodes[v as usize].end -= 1;
+        true
+    }
+
+    /// word와 같은 단어의 개수
+    pub fn count_equal(&self, word: &[u8]) -> usize {
+        self.find(word).map_or(0, |v| self.nodes[v as usize].end)
+    }
+
+    /// prefix로 시작하는 단어의 개수. 빈 prefix면 전체 개수입니다.
+    pub fn count_prefix(&self, prefix: &[u8]) -> usize {
+        self.find(prefix).map_or(0, |v| self.nodes[v as usize].pass)
+    }
+
+    /// query의 접두사인 단어 중 가장 긴 것의 길이. 그런 단어가 없거나 빈 단어뿐이면 0
+    pub fn longest_prefix_of(&self, query: &[u8]) -> usize {
+        let mut v = 0;
+        let mut best = 0;
+        for (i, &c) in query.iter().enumerate() {
+            let Some(to) = self.nodes[v as usize].child(c) else {
+                break;
+            };
+            v = to;
+            if self.nodes[v as usize].end > 0 {
+                best = i + 1;
+            }
+        }
+        best
+    }
+
+    /// `(단어, 개수)`를 사전 순서로
+    pub fn iter(&self) -> impl Iterator<Item = (Vec<u8>, usize)> + '_ {
+        let mut word: Vec<u8> = Vec::new();
+        // (노드, 다음에 볼 자식의 위치). 자식 위치가 0이면 아직 노드 자신을 내놓지 않은 것
+        let mut stack: Vec<(u32, usize)> = vec![(0, 0)];
+        let mut started = false;
+        std::iter::from_fn(move || {
+            loop {
+                let &mut (v, ref mut i) = stack.last_mut()?;
+                let node = &self.nodes[v as usize];
+                if !started {
+                    started = true;
+                    if node.end > 0 {
+                        return Some((word.clone(), node.end));
+                    }
+                }
+                if *i == node.next.len() {
+                    stack.pop();
+                    word.pop();
+                    continue;
+                }
+                let (c, to) = node.next[*i];
+                *i += 1;
+                word.push(c);
+                stack.push((to, 0));
+                started = false;
+            }
+        })
+    }
+
+    fn find(&self, word: &[u8]) -> Option<u32> {
+        let mut v = 0;
+        for &c in word {
+            v = self.nodes[v as usize].child(c)?;
+        }
+        Some(v)
+    }
+
+    fn alloc(&mut self) -> u32 {
+        if let Some(v) = self.free.pop() {
+            return v;
+        }
+        let v = u32::try_from(self.nodes.len()).expect("too many nodes for u32 indices");
+        self.nodes.push(Node::default());
+        v
+    }
+
+    /// v와 그 아래 노드를 모두 비우고 자리를 돌려받음
+    fn release(&mut self, v: u32) {
+        let mut stack = vec![v];
+        while let Some(v) = stack.pop() {
+            let node = std::mem::take(&mut self.nodes[v as usize]);
+            stack.extend(node.next.iter().map(|&(_, to)| to));
+            self.free.push(v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn random_against_btree_map() {
+        let mut rng = XorShift::new(163);
+        for round in 0..100 {
+            let alphabet = [2, 3, 256][round % 3];
+            let mut trie = Trie::new();
+            let mut model: BTreeMap<Vec<u8>, usize> = BTreeMap::new();
+            for _ in 0..300 {
+                let n = rng.index(5);
+                let word: Vec<u8> = (0..n).map(|_| rng.below(alphabet) as u8).collect();
+                match rng.below(4) {
+                    0 | 1 => {
+                        trie.insert(&word);
+                        *model.entry(word.clone()).or_default() += 1;
+                    }
+                    2 => {
+                        let present = model.contains_key(&word);
+                        assert_eq!(trie.remove_one(&word), present);
+                        if present {
+                            let count = model.get_mut(&word).unwrap();
+                            *count -= 1;
+                            if *count == 0 {
+                                model.remove(&word);
+                            }
+                        }
+                    }
+                    _ => {
+                        assert_eq!(
+                            trie.count_equal(&word),
+                            model.get(&word).copied().unwrap_or(0)
+                        );
+                        let prefix_count: usize = model
+                            .iter()
+                            .filter(|(w, _)| w.starts_with(&word))
+                            .map(|(_, &c)| c)
+                            .sum();
+                        assert_eq!(trie.count_prefix(&word), prefix_count);
+                        let longest = model
+                            .keys()
+                            .filter(|w| word.starts_with(w))
+                            .map(|w| w.len())
+                            .max();
+                        assert_eq!(trie.longest_prefix_of(&word), longest.unwrap_or(0));
+                    }
+                }
+                assert_eq!(trie.len(), model.values().sum::<usize>());
+            }
+            let listed: Vec<(Vec<u8>, usize)> = trie.iter().collect();
+            let expected: Vec<(Vec<u8>, usize)> = model.into_iter().collect();
+            assert_eq!(listed, expected);
+        }
+    }
+
+    #[test]
+    fn words_prefixes_and_reuse() {
+        let mut trie = Trie::new();
+        for word in ["app", "apple", "apply", "apt", "app", "", "한글"] {
+            trie.insert(word.as_bytes());
+        }
+        assert_eq!(trie.count_equal(b"app"), 2);
+        assert_eq!(trie.count_prefix(b"app"), 4);
+        assert_eq!(trie.count_prefix(b""), 7);
+        assert_eq!(trie.count_prefix(b"b"), 0);
+        assert_eq!(trie.longest_prefix_of(b"applesauce"), 5);
+        assert_eq!(trie.longest_prefix_of(b"ap"), 0);
+        // UTF-8 바이트 순서 = 문자열 정렬 순서
+        let words: Vec<String> = trie
+            .iter()
+            .map(|(w, _)| String::from_utf8(w).unwrap())
+            .collect();
+        assert_eq!(words, ["", "app", "apple", "apply", "apt", "한글"]);
+        assert!(trie.remove_one(b"apple"));
+        assert!(!trie.remove_one(b"apple"));
+        assert!(!trie.remove_one(b"ap"));
+        let nodes = trie.nodes.len();
+        trie.insert(b"appx");
+        // 떼어 낸 노드 자리를 다시 씀
+        assert_eq!(trie.nodes.len(), nodes);
+        assert_eq!(trie.count_prefix(b"appx"), 1);
+        assert_eq!(trie.len(), 7);
+    }
+}
@@ -0,0 +1,86 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Z-algorithm
+/// `z[i]`는 s와 `s[i..]`의 가장 긴 공통 접두사의 길이입니다.
+///
+/// * `z[0]`은 s 전체와 자기 자신의 공통 접두사로 보아 `n`으로 둡니다(0으로 두는 구현도 많으니 주의).
+/// * 빈 입력이면 빈 배열을 반환합니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn z_function(s: &[u8]) -> Vec<usize> {
+    let n = s.len();
+    let mut z = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    // [l, r): 지금까지 찾은 것 중 r이 가장 큰 일치 구간 s[l..r] == s[..r - l]
+    let (mut l, mut r) = (0, 0);
+    for i in 1..n {
+        let mut k = if i < r { z[i - l].min(r - i) } else { 0 };
+        while i + k < n && s[k] == s[i + k] {
+            k += 1;
+        }
+        z[i] = k;
+        if i + k > r {
+            (l, r) = (i, i + k);
+        }
+    }
+    z
+}
+
+/// # Z-algorithm 문자열 검색
+/// text 안에서 pattern이 나타나는 모든 시작 위치를 오름차순으로, 겹치는 등장까지 반환합니다.
+/// [`kmp_find_all`](super::kmp_find_all)과 결과가 같습니다.
+///
+/// * `pattern + text`의 z 배열에서 `z[m + i] >= m`인 i를 찾습니다. 구분자를 넣지 않으므로 z 값이 m보다 커질 수
+/// 있지만 `>= m`으로만 비교하므로 상관없고, 어떤 바이트든 text와 pattern에 쓸 수 있습니다.
+/// * 빈 pattern은 `0..=text.len()`의 모든 위치에서 나타나고, pattern이 text보다 길면 빈 배열입니다.
+/// ## 시간복잡도
+/// O(|text| + |pattern|)
+pub fn z_search(text: &[u8], pattern: &[u8]) -> Vec<usize> {
+    let m = pattern.len();
+    if m == 0 {
+        return (0..=text.len()).collect();
+    }
+    let joined = [pattern, text].concat();
+    let z = z_function(&joined);
+    (0..text.len()).filter(|&i| z[m + i] >= m).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use crate::string::kmp_find_all;
+
+    #[test]
+    fn random_against_brute_force_lcp() {
+        let mut rng = XorShift::new(157);
+        for round in 0..500 {
+            let alphabet = [1, 2, 3, 26][round % 4];
+            let n = rng.index(60);
+            let s: Vec<u8> = (0..n).map(|_| b'a' + rng.below(alphabet) as u8).collect();
+            let z = z_function(&s);
+            for (i, &k) in z.iter().enumerate() {
+                let expected = s[i..].iter().zip(&s).take_while(|(a, b)| a == b).count();
+                assert_eq!(k, expected);
+            }
+            let m = rng.index(5);
+            let pattern: Vec<u8> = (0..m).map(|_| b'a' + rng.below(alphabet) as u8).collect();
+            assert_eq!(z_search(&s, &pattern), kmp_find_all(&s, &pattern));
+        }
+    }
+
+    #[test]
+    fn conventions_and_overlaps() {
+        assert_eq!(z_function(b"aaaaa"), vec![5, 4, 3, 2, 1]);
+        assert_eq!(z_function(b"aabxaab"), vec![7, 1, 0, 0, 3, 1, 0]);
+        assert_eq!(z_function(b""), Vec::<usize>::new());
+        assert_eq!(z_search(b"aaaa", b"aa"), vec![0, 1, 2]);
+        assert_eq!(z_search(b"ab", b"abc"), Vec::<usize>::new());
+        assert_eq!(z_search(b"ab", b""), vec![0, 1, 2]);
+        // text와 pattern에 어떤 바이트가 있어도 됨
+        assert_eq!(z_search(&[0, 255, 0, 255], &[0, 255]), vec![0, 2]);
+    }
+}
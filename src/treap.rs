@@ -0,0 +1,27 @@
+mod implicit;
+pub use implicit::ImplicitTreap;
+mod ordered;
+pub use ordered::Treap;
+
+/// 자식이 없음을 나타내는 번호
+const NIL: u32 = u32::MAX;
+
+/// 노드 우선순위를 만드는 xorshift64
+#[derive(Clone, Debug)]
+pub(crate) struct Priorities(u64);
+
+impl Priorities {
+    pub(crate) fn new(seed: u64) -> Self {
+        // 상태가 0이면 계속 0이 나오므로 피함
+        Priorities(seed | 1)
+    }
+
+    pub(crate) fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+}
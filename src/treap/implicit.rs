@@ -0,0 +1,505 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::{NIL, Priorities};
+use crate::algebra::Monoid;
+use std::ops::Range;
+
+struct Node<S> {
+    value: S,
+    /// 서브트리를 순서대로 접은 값
+    sum: S,
+    /// 서브트리를 거꾸로 접은 값. 뒤집기를 할 때 sum과 맞바꿉니다.
+    rev_sum: S,
+    size: usize,
+    priority: u64,
+    /// 자식들을 아직 뒤집지 않았는지. 이 노드의 sum/rev_sum에는 이미 반영되어 있습니다.
+    rev: bool,
+    child: [u32; 2],
+}
+
+/// # Implicit treap
+/// 위치로 접근하는 수열에 임의 위치 삽입/삭제, 구간 뒤집기, 구간을 [`Monoid`]로 접기를 처리합니다.
+/// "부분 문자열을 잘라서 다른 곳에 붙이기", "구간 뒤집기"처럼 배열이나 segment tree로는 어려운 문제에 씁니다.
+///
+/// * 키 대신 "왼쪽 서브트리 크기"로 위치를 정하는 treap입니다. 모든 연산은 위치 k에서 자르는 split과
+/// 이어 붙이는 merge로 만듭니다. [`split_off`](Self::split_off)와 [`append`](Self::append)로 직접 쓸 수도 있습니다.
+/// * 뒤집기는 lazy 표시로 미룹니다. 교환법칙이 없는 연산도 뒤집은 순서대로 접히도록 노드마다 정방향으로 접은 값과
+/// 역방향으로 접은 값을 함께 저장하고, 뒤집을 때 둘을 맞바꿉니다.
+/// * 노드는 [`Treap`](super::Treap)처럼 Vec 하나(arena)에 두고 자식은 u32 번호로 가리킵니다. 지운 노드의 자리는
+/// 다시 씁니다. 수열마다 arena가 따로라서 `split_off`와 `append`는 두 수열 중 짧은 쪽의 노드를 옮깁니다.
+/// * 구간은 0-based 반열림이고, 빈 구간은 항등원입니다.
+/// * split/merge는 재귀지만 깊이는 기댓값 O(log n)입니다.
+/// ## 시간복잡도
+/// 연산마다 기댓값 O(log n). `split_off`와 `append`는 짧은 쪽 길이 k에 대해 O(k + log n)
+pub struct ImplicitTreap<M: Monoid> {
+    nodes: Vec<Node<M::S>>,
+    free: Vec<u32>,
+    root: u32,
+    priorities: Priorities,
+}
+
+impl<M: Monoid> Default for ImplicitTreap<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: Monoid> ImplicitTreap<M> {
+    /// 빈 수열
+    pub fn new() -> Self {
+        Self::with_seed(0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// 우선순위 시드를 정한 빈 수열
+    pub fn with_seed(seed: u64) -> Self {
+        ImplicitTreap {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            priorities: Priorities::new(seed),
+        }
+    }
+
+    /// 주어진 배열로 생성
+    pub fn from_slice(values: &[M::S]) -> Self {
+        let mut treap = Self::new();
+        for x in values {
+            let node = treap.alloc(x.clone());
+            treap.root = treap.merge(treap.root, node);
+        }
+        treap
+    }
+
+    /// 길이
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    /// 비었는지
+    pub fn is_empty(&self) -> bool {
+        self.root == NIL
+    }
+
+    /// pos 앞에 value를 끼워 넣음. `pos == len()`이면 맨 뒤에 붙입니다.
+    pub fn insert(&mut self, pos: usize, value: M::S) {
+        let n = self.len();
+        assert!(pos <= n, "index {pos} out of range for length {n}");
+        let (left, right) = self.split(self.root, pos);
+        let node = self.alloc(value);
+        let left = self.merge(left, node);
+        self.root = self.merge(left, right);
+    }
+
+    /// pos번째 원소를 빼서 반환
+    pub fn remove(&mut self, pos: usize) -> M::S {
+        let n = self.len();
+        assert!(pos < n, "index {pos} out of range for length {n}");
+        let (left, rest) = self.split(self.root, pos);
+        let (middle, right) = self.split(rest, 1);
+        self.root = self.merge(left, right);
+        self.free.push(middle);
+        std::mem::replace(&mut self.nodes[middle as usize].value, M::identity())
+    }
+
+    /// pos번째 원소
+    pub fn get(&self, pos: usize) -> &M::S {
+        let n = self.len();
+        assert!(pos < n, "index {pos} out of range for length {n}");
+        // 내려가면서 뒤집기 표시를 직접 반영하지 않고, 지나온 표시의 홀짝으로 왼쪽/오른쪽을 정함
+        let mut k = pos;
+        let mut flipped = false;
+        let mut v = self.root;
+        loop {
+            let node = &self.nodes[v as usize];
+            flipped ^= node.rev;
+            let [first, second] = node.child;
+            let (first, second) = if flipped {
+                (second, first)
+            } else {
+                (first, second)
+            };
+            let left = self.size(first);
+            if k < left {
+                v = first;
+            } else if k == left {
+                return &node.value;
+            } else {
+                k -= left + 1;
+                v = second;
+            }
+        }
+    }
+
+    /// 구간 `l..r`을 뒤집음
+    pub fn reverse(&mut self, range: Range<usize>) {
+        let [left, middle, right] = self.cut(range);
+        if middle != NIL {
+            self.toggle(middle);
+        }
+        let left = self.merge(left, middle);
+        self.root = self.merge(left, right);
+    }
+
+    /// 구간 `l..r`을 순서대로 접은 값
+    pub fn fold(&mut self, range: Range<usize>) -> M::S {
+        let [left, middle, right] = self.cut(range);
+        let result = self.sum(middle);
+        let left = self.merge(left, middle);
+        self.root = self.merge(left, right);
+        result
+    }
+
+    /// 전체를 순서대로 접은 값
+    pub fn all(&self) -> M::S {
+        self.sum(self.root)
+    }
+
+    /// 앞의 at개를 남기고, 나머지를 새 수열로 떼어 냄
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let n = self.len();
+        assert!(at <= n, "index {at} out of range for length {n}");
+        let (left, right) = self.split(self.root, at);
+        let mut other = Self::with_seed(self.priorities.next());
+        if self.size(left) >= self.size(right) {
+            self.root = left;
+            other.root = other.adopt(self, right);
+        } else {
+            // 짧은 앞부분을 옮기고 arena를 맞바꿈
+            self.root = right;
+            other.root = other.adopt(self, left);
+            std::mem::swap(self, &mut other);
+        }
+        other
+    }
+
+    /// other를 뒤에 이어 붙임
+    pub fn append(&mut self, mut other: Self) {
+        if self.len() >= other.len() {
+            let root = other.root;
+            let right = self.adopt(&mut other, root);
+            self.root = self.merge(self.root, right);
+        } else {
+            // 짧은 self를 other의 arena로 옮김
+            std::mem::swap(self, &mut other);
+            let root = other.root;
+            let left = self.adopt(&mut other, root);
+            self.root = self.merge(left, self.root);
+        }
+    }
+
+    /// 수열 전체를 Vec으로
+    pub fn to_vec(&self) -> Vec<M::S> {
+        let mut result = Vec::with_capacity(self.len());
+        // (노드, 지나온 뒤집기 표시의 홀짝, 노드 자신을 꺼낼 차례인지)
+        let mut stack: Vec<(u32, bool, bool)> = Vec::new();
+        if self.root != NIL {
+            stack.push((self.root, false, false));
+        }
+        while let Some((v, flipped, emit)) = stack.pop() {
+            let node = &self.nodes[v as usize];
+            if emit {
+                result.push(node.value.clone());
+                continue;
+            }
+            let flipped = flipped ^ node.rev;
+            let [first, second] = node.child;
+            let (first, second) = if flipped {
+                (second, first)
+            } else {
+                (first, second)
+            };
+            if second != NIL {
+                stack.push((second, flipped, false));
+            }
+            stack.push((v, flipped, true));
+            if first != NIL {
+                stack.push((first, flipped, false));
+            }
+        }
+        result
+    }
+
+    fn alloc(&mut self, value: M::S) -> u32 {
+        let node = Node {
+            sum: value.clone(),
+            rev_sum: value.clone(),
+            value,
+            size: 1,
+            priority: self.priorities.next(),
+            rev: false,
+            child: [NIL; 2],
+        };
+        self.place(node)
+    }
+
+    /// 빈 자리가 있으면 다시 쓰고, 없으면 arena 뒤에 붙임
+    fn place(&mut self, node: Node<M::S>) -> u32 {
+        if let Some(id) = self.free.pop() {
+            self.nodes[id as usize] = node;
+            id
+        } else {
+            let id = u32::try_from(self.nodes.len()).expect("too many nodes for u32 indices");
+            assert!(id != NIL, "too many nodes for u32 indices");
+            self.nodes.push(node);
+            id
+        }
+    }
+
+    /// other의 서브트리 v를 이 arena로 옮기고 새 번호를 반환. other에 남은 자리는 다시 쓰도록 비움
+    fn adopt(&mut self, other: &mut Self, v: u32) -> u32 {
+        if v == NIL {
+            return NIL;
+        }
+        let [l, r] = other.nodes[v as usize].child;
+        let child = [self.adopt(other, l), self.adopt(other, r)];
+        let old = &mut other.nodes[v as usize];
+        let node = Node {
+            value: std::mem::replace(&mut old.value, M::identity()),
+            sum: std::mem::replace(&mut old.sum, M::identity()),
+            rev_sum: std::mem::replace(&mut old.rev_sum, M::identity()),
+            size: old.size,
+            priority: old.priority,
+            rev: old.rev,
+            child,
+        };
+        other.free.push(v);
+        self.place(node)
+    }
+
+    /// (앞부분, `l..r`, 뒷부분)으로 자름
+    fn cut(&mut self, range: Range<usize>) -> [u32; 3] {
+        let Range { start: l, end: r } = range;
+        let n = self.len();
+        assert!(l <= r && r <= n, "invalid range {l}..{r} for length {n}");
+        let (left, rest) = self.split(self.root, l);
+        let (middle, right) = self.split(rest, r - l);
+        [left, middle, right]
+    }
+
+    fn size(&self, v: u32) -> usize {
+        if v == NIL {
+            0
+        } else {
+            self.nodes[v as usize].size
+        }
+    }
+
+    fn sum(&self, v: u32) -> M::S {
+        if v == NIL {
+            M::identity()
+        } else {
+            self.nodes[v as usize].sum.clone()
+        }
+    }
+
+    fn toggle(&mut self, v: u32) {
+        let node = &mut self.nodes[v as usize];
+        node.rev ^= true;
+        std::mem::swap(&mut node.sum, &mut node.rev_sum);
+    }
+
+    fn push(&mut self, v: u32) {
+        let node = &mut self.nodes[v as usize];
+        if node.rev {
+            node.child.swap(0, 1);
+            node.rev = false;
+            for c in node.child {
+                if c != NIL {
+                    self.toggle(c);
+                }
+            }
+        }
+    }
+
+    fn update(&mut self, v: u32) {
+        let identity = M::identity();
+        let node = &self.nodes[v as usize];
+        let [l, r] = node.child;
+        let sums = |c: u32| {
+            if c == NIL {
+                (&identity, &identity)
+            } else {
+                (&self.nodes[c as usize].sum, &self.nodes[c as usize].rev_sum)
+            }
+        };
+        let ((l_sum, l_rev), (r_sum, r_rev)) = (sums(l), sums(r));
+        let sum = M::op(&M::op(l_sum, &node.value), r_sum);
+        let rev_sum = M::op(&M::op(r_rev, &node.value), l_rev);
+        let size = 1 + self.size(l) + self.size(r);
+        let node = &mut self.nodes[v as usize];
+        node.sum = sum;
+        node.rev_sum = rev_sum;
+        node.size = size;
+    }
+
+    /// v를 앞의 k개와 나머지로 나눔
+    fn split(&mut self, v: u32, k: usize) -> (u32, u32) {
+        if v == NIL {
+            return (NIL, NIL);
+        }
+        self.push(v);
+        let [l, r] = self.nodes[v as usize].child;
+        let left = self.size(l);
+        if k <= left {
+            let (a, b) = self.split(l, k);
+            self.nodes[v as usize].child[0] = b;
+            self.update(v);
+            (a, v)
+        } else {
+            let (a, b) = self.split(r, k - left - 1);
+            self.nodes[v as usize].child[1] = a;
+            self.update(v);
+            (v, b)
+        }
+    }
+
+    /// a 뒤에 b를 이어 붙임
+    fn merge(&mut self, a: u32, b: u32) -> u32 {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if self.nodes[a as usize].priority > self.nodes[b as usize].priority {
+            self.push(a);
+            let right = self.merge(self.nodes[a as usize].child[1], b);
+            self.nodes[a as usize].child[1] = right;
+            self.update(a);
+            a
+        } else {
+            self.push(b);
+            let left = self.merge(a, self.nodes[b as usize].child[0]);
+            self.nodes[b as usize].child[0] = left;
+            self.update(b);
+            b
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::Sum;
+    use crate::random::XorShift;
+
+    struct Concat;
+
+    impl Monoid for Concat {
+        type S = String;
+
+        fn identity() -> String {
+            String::new()
+        }
+
+        fn op(a: &String, b: &String) -> String {
+            format!("{a}{b}")
+        }
+    }
+
+    fn fold_concat(a: &[String]) -> String {
+        a.concat()
+    }
+
+    #[test]
+    fn random_against_plain_vec() {
+        let mut rng = XorShift::new(147);
+        for _ in 0..20 {
+            let mut treap: ImplicitTreap<Concat> = ImplicitTreap::with_seed(rng.next_u64());
+            let mut model: Vec<String> = Vec::new();
+            for _ in 0..500 {
+                let n = model.len();
+                let l = rng.index(n + 1);
+                let r = l + rng.index(n + 1 - l);
+                match rng.below(7) {
+                    0 | 1 => {
+                        let word = ((b'a' + rng.below(26) as u8) as char).to_string();
+                        treap.insert(l, word.clone());
+                        model.insert(l, word);
+                    }
+                    2 if n > 0 => {
+                        let i = rng.index(n);
+                        assert_eq!(treap.remove(i), model.remove(i));
+                    }
+                    3 => {
+                        treap.reverse(l..r);
+                        model[l..r].reverse();
+                    }
+                    4 => assert_eq!(treap.fold(l..r), fold_concat(&model[l..r])),
+                    5 if n > 0 => {
+                        let i = rng.index(n);
+                        assert_eq!(treap.get(i), &model[i]);
+                    }
+                    _ => {
+                        // l..r를 잘라서 남은 수열의 임의 위치에 붙이기
+                        let mut tail = treap.split_off(l);
+                        let rest = tail.split_off(r - l);
+                        treap.append(rest);
+                        let at = rng.index(treap.len() + 1);
+                        let back = treap.split_off(at);
+                        treap.append(tail);
+                        treap.append(back);
+                        let piece: Vec<String> = model.drain(l..r).collect();
+                        model.splice(at..at, piece);
+                    }
+                }
+                assert_eq!(treap.len(), model.len());
+                assert_eq!(treap.all(), fold_concat(&model));
+            }
+            assert_eq!(treap.to_vec(), model);
+        }
+    }
+
+    #[test]
+    fn sums_with_long_sequence() {
+        let mut rng = XorShift::new(1470);
+        let values: Vec<i64> = (0..10_000).map(|_| rng.range_i64(-100, 100)).collect();
+        let mut treap: ImplicitTreap<Sum<i64>> = ImplicitTreap::from_slice(&values);
+        let mut model = values;
+        for _ in 0..2000 {
+            let n = model.len();
+            let l = rng.index(n + 1);
+            let r = l + rng.index(n + 1 - l);
+            if rng.below(2) == 0 {
+                treap.reverse(l..r);
+                model[l..r].reverse();
+            } else {
+                assert_eq!(treap.fold(l..r), model[l..r].iter().sum::<i64>());
+            }
+        }
+        assert_eq!(treap.to_vec(), model);
+    }
+
+    #[test]
+    fn arena_slots_are_reused() {
+        let mut rng = XorShift::new(1471);
+        let mut treap: ImplicitTreap<Sum<i64>> = ImplicitTreap::from_slice(&[0; 100]);
+        for i in 0..10_000 {
+            let n = treap.len();
+            treap.remove(rng.index(n));
+            treap.insert(rng.index(n), i);
+            // 짧은 쪽을 떼었다가 다시 붙여도 노드 수는 그대로
+            let back = treap.split_off(rng.index(n + 1));
+            treap.append(back);
+            assert_eq!(treap.len(), 100);
+            assert_eq!(treap.nodes.len() - treap.free.len(), 100);
+        }
+        assert!(treap.nodes.len() <= 200);
+    }
+
+    #[test]
+    fn large_smoke() {
+        let n = 200_000;
+        let mut treap: ImplicitTreap<Sum<i64>> = ImplicitTreap::new();
+        for i in 0..n {
+            treap.insert(i as usize, i);
+        }
+        treap.reverse(0..n as usize);
+        assert_eq!(*treap.get(0), n - 1);
+        assert_eq!(treap.fold(0..10), (n - 10..n).sum());
+        let back = treap.split_off(n as usize / 2);
+        treap.append(back);
+        assert_eq!(treap.all(), n * (n - 1) / 2);
+        assert_eq!(treap.remove(0), n - 1);
+        assert_eq!(treap.len(), n as usize - 1);
+    }
+}
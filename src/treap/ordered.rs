@@ -0,0 +1,385 @@
+#![allow(clippy::doc_lazy_continuation)]
+use super::{NIL, Priorities};
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug)]
+struct Node<T> {
+    key: T,
+    /// 같은 값의 개수
+    count: usize,
+    /// 서브트리 전체 원소 개수 (중복 포함)
+    size: usize,
+    priority: u64,
+    child: [u32; 2],
+}
+
+/// # Treap (순서 통계 multiset)
+/// 값을 넣고 빼면서 k번째 값, x보다 작은 값의 개수, x 바로 앞뒤 값을 O(log n)에 구합니다.
+/// std의 `BTreeSet`에는 없는 순서 통계를 지원하는 multiset입니다.
+///
+/// * 값은 BST 순서, 무작위 우선순위는 heap 순서를 따르는 randomized BST입니다. 우선순위는 내부 xorshift로 만들고,
+/// [`with_seed`](Self::with_seed)로 시드를 정하면 같은 입력에 항상 같은 트리가 됩니다.
+/// * 같은 값은 노드 하나에 개수로 저장합니다. [`len`](Self::len)과 [`kth`](Self::kth)는 중복을 모두 셉니다.
+/// * 노드는 Vec 하나(arena)에 두고 자식은 u32 번호로 가리킵니다. 지운 노드의 자리는 다시 씁니다.
+/// * split/merge는 재귀지만 깊이는 기댓값 O(log n)입니다.
+/// ## 시간복잡도
+/// 연산마다 기댓값 O(log n)
+#[derive(Clone, Debug)]
+pub struct Treap<T> {
+    nodes: Vec<Node<T>>,
+    free: Vec<u32>,
+    root: u32,
+    priorities: Priorities,
+}
+
+impl<T: Ord> Default for Treap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> Treap<T> {
+    /// 빈 multiset
+    pub fn new() -> Self {
+        Self::with_seed(0x9E37_79B9_7F4A_7C15)
+    }
+
+    /// 우선순위 시드를 정한 빈 multiset
+    pub fn with_seed(seed: u64) -> Self {
+        Treap {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            priorities: Priorities::new(seed),
+        }
+    }
+
+    /// 원소 개수 (중복 포함)
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    /// 비었는지
+    pub fn is_empty(&self) -> bool {
+        self.root == NIL
+    }
+
+    /// x를 하나 넣음
+    pub fn insert(&mut self, x: T) {
+        if self.count(&x) > 0 {
+            self.change_count(&x, true);
+            return;
+        }
+        let (left, right) = self.split(self.root, &x, false);
+        let node = self.alloc(x);
+        let left = self.merge(left, node);
+        self.root = self.merge(left, right);
+    }
+
+    /// x를 하나 뺌. x가 없으면 false
+    pub fn remove_one(&mut self, x: &T) -> bool {
+        match self.count(x) {
+            0 => false,
+            1 => {
+                let (left, rest) = self.split(self.root, x, false);
+                let (middle, right) = self.split(rest, x, true);
+                self.free.push(middle);
+                self.root = self.merge(left, right);
+                true
+            }
+            _ => {
+                self.change_count(x, false);
+                true
+            }
+        }
+    }
+
+    /// x가 있는지
+    pub fn contains(&self, x: &T) -> bool {
+        self.count(x) > 0
+    }
+
+    /// x의 개수
+    pub fn count(&self, x: &T) -> usize {
+        let mut v = self.root;
+        while v != NIL {
+            let node = &self.nodes[v as usize];
+            match x.cmp(&node.key) {
+                Ordering::Less => v = node.child[0],
+                Ordering::Greater => v = node.child[1],
+                Ordering::Equal => return node.count,
+            }
+        }
+        0
+    }
+
+    /// 정렬했을 때 k번째(0-based, 중복 포함) 값. `k >= len()`이면 None
+    pub fn kth(&self, k: usize) -> Option<&T> {
+        let mut k = k;
+        let mut v = self.root;
+        while v != NIL {
+            let node = &self.nodes[v as usize];
+            let left = self.size(node.child[0]);
+            if k < left {
+                v = node.child[0];
+            } else if k < left + node.count {
+                return Some(&node.key);
+            } else {
+                k -= left + node.count;
+                v = node.child[1];
+            }
+        }
+        None
+    }
+
+    /// x보다 작은 원소의 개수 (중복 포함)
+    pub fn rank(&self, x: &T) -> usize {
+        let mut rank = 0;
+        let mut v = self.root;
+        while v != NIL {
+            let node = &self.nodes[v as usize];
+            if node.key < *x {
+                rank += self.size(node.child[0]) + node.count;
+                v = node.child[1];
+            } else {
+                v = node.child[0];
+            }
+        }
+        rank
+    }
+
+    /// x보다 작은 값 중 가장 큰 것
+    pub fn prev(&self, x: &T) -> Option<&T> {
+        let mut best = None;
+        let mut v = self.root;
+        while v != NIL {
+            let node = &self.nodes[v as usize];
+            if node.key < *x {
+                best = Some(&node.key);
+                v = node.child[1];
+            } else {
+                v = node.child[0];
+            }
+        }
+        best
+    }
+
+    /// x보다 큰 값 중 가장 작은 것
+    pub fn next(&self, x: &T) -> Option<&T> {
+        let mut best = None;
+        let mut v = self.root;
+        while v != NIL {
+            let node = &self.nodes[v as usize];
+            if node.key > *x {
+                best = Some(&node.key);
+                v = node.child[0];
+            } else {
+                v = node.child[1];
+            }
+        }
+        best
+    }
+
+    fn size(&self, v: u32) -> usize {
+        if v == NIL {
+            0
+        } else {
+            self.nodes[v as usize].size
+        }
+    }
+
+    fn update(&mut self, v: u32) {
+        let [l, r] = self.nodes[v as usize].child;
+        let size = self.size(l) + self.size(r) + self.nodes[v as usize].count;
+        self.nodes[v as usize].size = size;
+    }
+
+    fn alloc(&mut self, key: T) -> u32 {
+        let node = Node {
+            key,
+            count: 1,
+            size: 1,
+            priority: self.priorities.next(),
+            child: [NIL; 2],
+        };
+        if let Some(id) = self.free.pop() {
+            self.nodes[id as usize] = node;
+            id
+        } else {
+            let id = u32::try_from(self.nodes.len()).expect("too many nodes for u32 indices");
+            assert!(id != NIL, "too many nodes for u32 indices");
+            self.nodes.push(node);
+            id
+        }
+    }
+
+    /// 이미 있는 x의 개수를 하나 늘리거나 줄이고, 경로의 size를 고침
+    fn change_count(&mut self, x: &T, increase: bool) {
+        let mut v = self.root;
+        loop {
+            let node = &mut self.nodes[v as usize];
+            if increase {
+                node.size += 1;
+            } else {
+                node.size -= 1;
+            }
+            match x.cmp(&node.key) {
+                Ordering::Less => v = node.child[0],
+                Ordering::Greater => v = node.child[1],
+                Ordering::Equal => {
+                    if increase {
+                        node.count += 1;
+                    } else {
+                        node.count -= 1;
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    /// v를 (x보다 작은 값, 나머지)로 나눔. inclusive면 (x 이하, 나머지)
+    fn split(&mut self, v: u32, x: &T, inclusive: bool) -> (u32, u32) {
+        if v == NIL {
+            return (NIL, NIL);
+        }
+        let key = &self.nodes[v as usize].key;
+        let goes_left = if inclusive { key <= x } else { key < x };
+        if goes_left {
+            let (l, r) = self.split(self.nodes[v as usize].child[1], x, inclusive);
+            self.nodes[v as usize].child[1] = l;
+            self.update(v);
+            (v, r)
+        } else {
+            let (l, r) = self.split(self.nodes[v as usize].child[0], x, inclusive);
+            self.nodes[v as usize].child[0] = r;
+            self.update(v);
+            (l, v)
+        }
+    }
+
+    /// a의 모든 값이 b의 값보다 작을 때 둘을 합침
+    fn merge(&mut self, a: u32, b: u32) -> u32 {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if self.nodes[a as usize].priority > self.nodes[b as usize].priority {
+            let right = self.merge(self.nodes[a as usize].child[1], b);
+            self.nodes[a as usize].child[1] = right;
+            self.update(a);
+            a
+        } else {
+            let left = self.merge(a, self.nodes[b as usize].child[0]);
+            self.nodes[b as usize].child[0] = left;
+            self.update(b);
+            b
+        }
+    }
+
+    #[cfg(test)]
+    fn height(&self) -> usize {
+        let mut height = 0;
+        let mut stack = vec![(self.root, 1)];
+        while let Some((v, depth)) = stack.pop() {
+            if v == NIL {
+                continue;
+            }
+            height = height.max(depth);
+            let [l, r] = self.nodes[v as usize].child;
+            stack.push((l, depth + 1));
+            stack.push((r, depth + 1));
+        }
+        height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_sorted_vec() {
+        let mut rng = XorShift::new(146);
+        for round in 0..30 {
+            let mut treap = Treap::with_seed(round);
+            let mut sorted: Vec<i64> = Vec::new();
+            let spread = if round % 2 == 0 { 10 } else { 1000 };
+            for _ in 0..2000 {
+                let x = rng.range_i64(-spread, spread);
+                match rng.below(5) {
+                    0 | 1 => {
+                        treap.insert(x);
+                        let i = sorted.partition_point(|&y| y < x);
+                        sorted.insert(i, x);
+                    }
+                    2 => {
+                        let expected = sorted.binary_search(&x).map(|i| sorted.remove(i));
+                        assert_eq!(treap.remove_one(&x), expected.is_ok());
+                    }
+                    3 => {
+                        let k = rng.index(sorted.len() + 2);
+                        assert_eq!(treap.kth(k), sorted.get(k));
+                        assert_eq!(treap.rank(&x), sorted.partition_point(|&y| y < x));
+                    }
+                    _ => {
+                        let lower = sorted.partition_point(|&y| y < x);
+                        let upper = sorted.partition_point(|&y| y <= x);
+                        assert_eq!(treap.count(&x), upper - lower);
+                        assert_eq!(treap.contains(&x), upper > lower);
+                        assert_eq!(treap.prev(&x), lower.checked_sub(1).map(|i| &sorted[i]));
+                        assert_eq!(treap.next(&x), sorted.get(upper));
+                    }
+                }
+                assert_eq!(treap.len(), sorted.len());
+            }
+        }
+    }
+
+    #[test]
+    fn sorted_insertions_keep_depth_logarithmic() {
+        let mut treap = Treap::new();
+        let n = 100_000;
+        for x in 0..n {
+            treap.insert(x);
+        }
+        // 기댓값은 약 2 ln n ≈ 23. 넉넉하게 잡음
+        assert!(treap.height() <= 60, "height {}", treap.height());
+        assert_eq!(treap.kth(12_345), Some(&12_345));
+        for x in (0..n).step_by(2) {
+            assert!(treap.remove_one(&x));
+        }
+        assert_eq!(treap.len(), n as usize / 2);
+        assert_eq!(treap.kth(0), Some(&1));
+        assert!(treap.height() <= 60, "height {}", treap.height());
+        // 지운 노드 자리를 다시 씀
+        let before = treap.nodes.len();
+        for x in (0..n).step_by(2) {
+            treap.insert(x);
+        }
+        assert_eq!(treap.nodes.len(), before);
+    }
+
+    #[test]
+    fn duplicates_are_counted() {
+        let mut treap = Treap::with_seed(1460);
+        for x in [5, 3, 5, 5, 1] {
+            treap.insert(x);
+        }
+        assert_eq!(treap.len(), 5);
+        assert_eq!(treap.count(&5), 3);
+        assert_eq!(treap.kth(2), Some(&5));
+        assert_eq!(treap.kth(4), Some(&5));
+        assert_eq!(treap.rank(&5), 2);
+        assert_eq!(treap.next(&3), Some(&5));
+        assert_eq!(treap.prev(&3), Some(&1));
+        assert!(treap.remove_one(&5));
+        assert_eq!(treap.count(&5), 2);
+        assert!(!treap.remove_one(&4));
+        assert_eq!(treap.next(&5), None);
+        assert_eq!(treap.prev(&1), None);
+    }
+}
@@ -0,0 +1,18 @@
+mod cartesian;
+pub use cartesian::{CartesianTree, cartesian_tree, cartesian_tree_max};
+mod centroid;
+pub use centroid::CentroidDecomposition;
+mod diameter;
+pub use diameter::{eccentricities, tree_centers, tree_diameter};
+mod euler_lca;
+pub use euler_lca::EulerLca;
+mod euler_tour;
+pub use euler_tour::EulerTour;
+mod hash;
+pub use hash::{TreeCanonizer, tree_hash, trees_isomorphic};
+mod hld;
+pub use hld::{Hld, HldSegTree, PathValues};
+mod lca;
+pub use lca::Lca;
+mod reroot;
+pub use reroot::reroot;
@@ -0,0 +1,157 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Cartesian tree
+/// [`cartesian_tree`], [`cartesian_tree_max`]의 결과입니다. 정점은 배열의 위치 `0..n`입니다.
+/// * `root`: 루트. 배열이 비었으면 None
+/// * `parent[i]`, `left[i]`, `right[i]`: 부모, 왼쪽 자식, 오른쪽 자식
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CartesianTree {
+    pub root: Option<usize>,
+    pub parent: Vec<Option<usize>>,
+    pub left: Vec<Option<usize>>,
+    pub right: Vec<Option<usize>>,
+}
+
+/// # Cartesian tree (최솟값 루트)
+/// 값으로는 heap 순서(부모 ≤ 자식), 위치로는 BST 순서(중위 순회가 `0, 1, …, n - 1`)인 트리를 만듭니다.
+///
+/// * 구간 `l..=r`의 최솟값은 l과 r의 LCA에 있으므로 RMQ를 LCA로 바꿀 수 있고, 루트(최솟값)를 기준으로 나누는
+/// 분할 정복의 뼈대가 됩니다.
+/// * 왼쪽부터 오른쪽 경계(루트에서 오른쪽 자식만 따라간 경로)를 스택으로 유지합니다. 새 원소보다 큰 값을 스택에서
+/// 빼고, 마지막으로 뺀 노드를 새 원소의 왼쪽 자식으로, 남은 스택 꼭대기의 오른쪽 자식으로 새 원소를 붙입니다.
+/// * 같은 값은 왼쪽에 있는 것이 조상입니다. 그래서 루트는 가장 왼쪽의 최솟값이고, LCA도 구간에서 가장 왼쪽의
+/// 최솟값 위치입니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn cartesian_tree(values: &[i64]) -> CartesianTree {
+    build(values, |top, x| top > x)
+}
+
+/// # Cartesian tree (최댓값 루트)
+/// [`cartesian_tree`]와 같지만 부모 ≥ 자식입니다. 같은 값은 왼쪽에 있는 것이 조상입니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn cartesian_tree_max(values: &[i64]) -> CartesianTree {
+    build(values, |top, x| top < x)
+}
+
+/// `pops(top, x)`가 참이면 스택 꼭대기 값 top을 새 값 x의 아래로 내림
+fn build(values: &[i64], pops: impl Fn(i64, i64) -> bool) -> CartesianTree {
+    let n = values.len();
+    let mut parent = vec![None; n];
+    let mut left = vec![None; n];
+    let mut right = vec![None; n];
+    let mut stack: Vec<usize> = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut last = None;
+        while let Some(&top) = stack.last() {
+            if !pops(values[top], values[i]) {
+                break;
+            }
+            last = stack.pop();
+        }
+        if let Some(c) = last {
+            left[i] = Some(c);
+            parent[c] = Some(i);
+        }
+        if let Some(&top) = stack.last() {
+            right[top] = Some(i);
+            parent[i] = Some(top);
+        }
+        stack.push(i);
+    }
+    CartesianTree {
+        root: stack.first().copied(),
+        parent,
+        left,
+        right,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+    use crate::sparse_table::SparseTable;
+    use crate::tree::Lca;
+
+    fn inorder(tree: &CartesianTree) -> Vec<usize> {
+        let mut order = Vec::new();
+        let mut stack = Vec::new();
+        let mut v = tree.root;
+        while v.is_some() || !stack.is_empty() {
+            while let Some(u) = v {
+                stack.push(u);
+                v = tree.left[u];
+            }
+            let u = stack.pop().unwrap();
+            order.push(u);
+            v = tree.right[u];
+        }
+        order
+    }
+
+    #[test]
+    fn heap_and_bst_properties() {
+        let mut rng = XorShift::new(148);
+        for round in 0..200 {
+            let n = rng.index(60);
+            let spread = if round % 2 == 0 { 3 } else { 1000 };
+            let values: Vec<i64> = (0..n).map(|_| rng.range_i64(-spread, spread)).collect();
+            for maximize in [false, true] {
+                let tree = if maximize {
+                    cartesian_tree_max(&values)
+                } else {
+                    cartesian_tree(&values)
+                };
+                assert_eq!(inorder(&tree), (0..n).collect::<Vec<_>>());
+                for i in 0..n {
+                    match tree.parent[i] {
+                        None => assert_eq!(tree.root, Some(i)),
+                        Some(p) => {
+                            let (pv, cv) = (values[p], values[i]);
+                            assert!(if maximize { pv >= cv } else { pv <= cv });
+                            // 같은 값이면 왼쪽이 조상
+                            assert!(pv != cv || p < i);
+                            assert!(tree.left[p] == Some(i) || tree.right[p] == Some(i));
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(cartesian_tree(&[]).root, None);
+    }
+
+    #[test]
+    fn range_min_via_lca_matches_sparse_table() {
+        let mut rng = XorShift::new(1480);
+        for _ in 0..50 {
+            let n = 1 + rng.index(80);
+            let values: Vec<i64> = (0..n).map(|_| rng.range_i64(-10, 10)).collect();
+            let tree = cartesian_tree(&values);
+            let root = tree.root.unwrap();
+            let parents: Vec<usize> = tree.parent.iter().map(|p| p.unwrap_or(root)).collect();
+            let lca = Lca::from_parents(&parents, root);
+            let table = SparseTable::min(&values);
+            for l in 0..n {
+                for r in l..n {
+                    let m = lca.lca(l, r);
+                    assert_eq!(values[m], table.query(l..r + 1));
+                    // 가장 왼쪽의 최솟값
+                    assert_eq!(values[l..m].iter().position(|&x| x == values[m]), None);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sorted_input_makes_a_path() {
+        let values: Vec<i64> = (0..100_000).collect();
+        let tree = cartesian_tree(&values);
+        assert_eq!(tree.root, Some(0));
+        assert!((0..99_999).all(|i| tree.right[i] == Some(i + 1)));
+        let tree = cartesian_tree_max(&values);
+        assert_eq!(tree.root, Some(99_999));
+        assert_eq!(tree.left[99_999], Some(99_998));
+    }
+}
@@ -0,0 +1,298 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Centroid decomposition
+/// 트리에서 centroid(제거하면 남는 조각이 모두 크기 n/2 이하가 되는 정점)를 반복해서 제거하며 만든 centroid tree입니다.
+///
+/// * centroid tree의 깊이는 O(log n)이고, 원래 트리의 모든 경로는 경로 위 정점 중 centroid tree에서 가장 얕은 정점을 지납니다.
+/// * 각 정점에 대해 centroid tree의 조상들과 그 조상까지의 (원래 트리에서의) 거리를 [`ancestors`](CentroidDecomposition::ancestors)로 제공합니다.
+/// * "거리가 k 이하인 쌍의 수" 같은 문제는 [`for_each_centroid`](CentroidDecomposition::for_each_centroid)만으로 풀 수 있습니다.
+/// * 재귀 없이 작업 스택과 BFS로 구현되어 있어서 깊은 트리에서도 스택 오버플로가 나지 않습니다.
+///
+/// > 트리는 연결되어 있어야 합니다.
+/// ## 시간복잡도
+/// 전처리 O(n log n), 메모리 O(n log n)
+pub struct CentroidDecomposition {
+    parent: Vec<usize>,
+    level: Vec<usize>,
+    ancestors: Vec<Vec<(usize, usize)>>,
+    order: Vec<usize>,
+    members: Vec<Vec<usize>>,
+    blocks: Vec<Vec<Vec<usize>>>,
+}
+
+impl CentroidDecomposition {
+    /// 무방향 인접 리스트로 생성
+    pub fn new(adj: &[Vec<usize>]) -> Self {
+        let n = adj.len();
+        let mut removed = vec![false; n];
+        let mut parent = vec![usize::MAX; n];
+        let mut level = vec![0usize; n];
+        let mut ancestors = vec![Vec::new(); n];
+        let mut order = Vec::with_capacity(n);
+        let mut members = vec![Vec::new(); n];
+        let mut blocks = vec![Vec::new(); n];
+        let mut bfs_parent = vec![usize::MAX; n];
+        let mut size = vec![0usize; n];
+        // (조각의 아무 정점, 부모 centroid, centroid tree에서의 깊이)
+        let mut work = if n > 0 {
+            vec![(0, usize::MAX, 0)]
+        } else {
+            Vec::new()
+        };
+        while let Some((start, par, lvl)) = work.pop() {
+            // 제거되지 않은 정점들로 이루어진 조각을 BFS로 모으고 크기를 다시 계산
+            let mut comp = vec![start];
+            bfs_parent[start] = usize::MAX;
+            let mut i = 0;
+            while i < comp.len() {
+                let u = comp[i];
+                i += 1;
+                size[u] = 1;
+                for &v in &adj[u] {
+                    if !removed[v] && v != bfs_parent[u] {
+                        bfs_parent[v] = u;
+                        comp.push(v);
+                    }
+                }
+            }
+            for &u in comp.iter().rev() {
+                if bfs_parent[u] != usize::MAX {
+                    size[bfs_parent[u]] += size[u];
+                }
+            }
+            let total = comp.len();
+            let mut c = start;
+            while let Some(&v) = adj[c]
+                .iter()
+                .find(|&&v| !removed[v] && v != bfs_parent[c] && size[v] > total / 2)
+            {
+                c = v;
+            }
+
+            removed[c] = true;
+            parent[c] = par;
+            level[c] = lvl;
+            order.push(c);
+            ancestors[c].push((c, 0));
+            let mut component = vec![c];
+            let mut block_distances = Vec::new();
+            for &b in &adj[c] {
+                if removed[b] {
+                    continue;
+                }
+                let mut block = Vec::new();
+                // (정점, 직전 정점, 거리)
+                let mut queue = vec![(b, c, 1)];
+                let mut j = 0;
+                while j < queue.len() {
+                    let (u, prev, d) = queue[j];
+                    j += 1;
+                    component.push(u);
+                    block.push(d);
+                    ancestors[u].push((c, d));
+                    for &v in &adj[u] {
+                        if v != prev && !removed[v] {
+                            queue.push((v, u, d + 1));
+                        }
+                    }
+                }
+                block_distances.push(block);
+                work.push((b, c, lvl + 1));
+            }
+            members[c] = component;
+            blocks[c] = block_distances;
+        }
+        CentroidDecomposition {
+            parent,
+            level,
+            ancestors,
+            order,
+            members,
+            blocks,
+        }
+    }
+
+    /// 정점 개수
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// 정점이 하나도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// centroid tree의 루트 (처음 고른 centroid)
+    pub fn root(&self) -> Option<usize> {
+        self.order.first().copied()
+    }
+
+    /// centroid tree에서의 부모. 루트면 None
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        (self.parent[v] != usize::MAX).then_some(self.parent[v])
+    }
+
+    /// centroid tree에서의 깊이 (루트는 0). v는 이 깊이에서 centroid로 제거됩니다.
+    pub fn level(&self, v: usize) -> usize {
+        self.level[v]
+    }
+
+    /// v를 포함했던 조각들의 `(centroid, v까지의 거리)`. i번째 원소가 깊이 i의 조각이고 마지막은 `(v, 0)`입니다.
+    pub fn ancestors(&self, v: usize) -> &[(usize, usize)] {
+        &self.ancestors[v]
+    }
+
+    /// # centroid마다 조각 정보 순회
+    /// centroid tree의 위에서부터 각 centroid c에 대해 `f(c, component_vertices, block_distances)`를 호출합니다.
+    ///
+    /// * `block_distances[i]`는 c를 제거했을 때 생기는 i번째 조각(block)의 정점들과 c 사이의 거리입니다.
+    /// * `component_vertices`는 c가 centroid였던 조각의 정점들입니다. 첫 원소는 c이고,
+    /// 그 뒤로 `block_distances`를 이어 붙인 것과 같은 순서로 정점이 나옵니다.
+    ///
+    /// 같은 block 안의 두 정점 사이 경로는 c를 지나지 않으므로, c를 지나는 경로를 셀 때 block별로 빼 주면 됩니다.
+    pub fn for_each_centroid<F: FnMut(usize, &[usize], &[Vec<usize>])>(&self, mut f: F) {
+        for &c in &self.order {
+            f(c, &self.members[c], &self.blocks[c]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_tree};
+
+    /// 합이 k 이하인 쌍의 수 (정렬 + 투 포인터)
+    fn pairs_within(dist: &mut [usize], k: usize) -> u64 {
+        dist.sort_unstable();
+        let mut count = 0;
+        let mut r = dist.len();
+        for l in 0..dist.len() {
+            while r > 0 && dist[l] + dist[r - 1] > k {
+                r -= 1;
+            }
+            if r <= l {
+                break;
+            }
+            count += (r - l - 1) as u64;
+        }
+        count
+    }
+
+    fn count_pairs_within(adj: &[Vec<usize>], k: usize) -> u64 {
+        let cd = CentroidDecomposition::new(adj);
+        let mut total = 0;
+        cd.for_each_centroid(|_, _, blocks| {
+            // c를 지나는 쌍 = 전체 쌍 - 같은 block 안의 쌍
+            let mut all = vec![0];
+            for block in blocks {
+                all.extend_from_slice(block);
+            }
+            total += pairs_within(&mut all, k);
+            for block in blocks {
+                total -= pairs_within(&mut block.clone(), k);
+            }
+        });
+        total
+    }
+
+    fn bfs(adj: &[Vec<usize>], s: usize) -> Vec<usize> {
+        let mut dist = vec![usize::MAX; adj.len()];
+        dist[s] = 0;
+        let mut queue = vec![s];
+        let mut i = 0;
+        while i < queue.len() {
+            let u = queue[i];
+            i += 1;
+            for &v in &adj[u] {
+                if dist[v] == usize::MAX {
+                    dist[v] = dist[u] + 1;
+                    queue.push(v);
+                }
+            }
+        }
+        dist
+    }
+
+    fn path_graph(n: usize) -> Vec<Vec<usize>> {
+        let mut adj = vec![Vec::new(); n];
+        for v in 1..n {
+            adj[v - 1].push(v);
+            adj[v].push(v - 1);
+        }
+        adj
+    }
+
+    fn bit_length(n: usize) -> usize {
+        (usize::BITS - n.leading_zeros()) as usize
+    }
+
+    #[test]
+    fn depth_is_logarithmic_on_path_and_star() {
+        let n = 100_000;
+        let cd = CentroidDecomposition::new(&path_graph(n));
+        let depth = (0..n).map(|v| cd.level(v)).max().unwrap();
+        assert!(depth < bit_length(n), "depth {depth}");
+
+        let mut star = vec![Vec::new(); n];
+        for v in 1..n {
+            star[0].push(v);
+            star[v].push(0);
+        }
+        let cd = CentroidDecomposition::new(&star);
+        assert_eq!(cd.root(), Some(0));
+        assert!((1..n).all(|v| cd.level(v) == 1 && cd.parent(v) == Some(0)));
+    }
+
+    #[test]
+    fn structure_matches_definition() {
+        let mut rng = XorShift::new(115);
+        for _ in 0..30 {
+            let n = 1 + rng.index(80);
+            let adj = random_tree(&mut rng, n);
+            let cd = CentroidDecomposition::new(&adj);
+            let mut seen = vec![false; n];
+            cd.for_each_centroid(|c, component, blocks| {
+                assert!(!seen[c]);
+                seen[c] = true;
+                assert_eq!(component[0], c);
+                let size: usize = blocks.iter().map(Vec::len).sum();
+                assert_eq!(component.len(), size + 1);
+                // centroid의 조건: 각 block은 조각 크기의 절반 이하
+                for block in blocks {
+                    assert!(2 * block.len() <= component.len());
+                }
+                let dist = bfs(&adj, c);
+                let flat: Vec<usize> = blocks.iter().flatten().copied().collect();
+                for (&v, &d) in component[1..].iter().zip(&flat) {
+                    assert_eq!(dist[v], d);
+                    assert_eq!(cd.ancestors(v)[cd.level(c)], (c, d));
+                }
+            });
+            assert!(seen.iter().all(|&s| s));
+            for v in 0..n {
+                assert_eq!(cd.ancestors(v).len(), cd.level(v) + 1);
+                assert_eq!(*cd.ancestors(v).last().unwrap(), (v, 0));
+                if let Some(p) = cd.parent(v) {
+                    assert_eq!(cd.level(p) + 1, cd.level(v));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn count_pairs_within_distance_against_brute_force() {
+        let mut rng = XorShift::new(1150);
+        for _ in 0..100 {
+            let n = 1 + rng.index(60);
+            let adj = random_tree(&mut rng, n);
+            let k = rng.index(n + 1);
+            let all: Vec<Vec<usize>> = (0..n).map(|s| bfs(&adj, s)).collect();
+            let mut expected = 0;
+            for (u, row) in all.iter().enumerate() {
+                expected += row[u + 1..].iter().filter(|&&d| d <= k).count() as u64;
+            }
+            assert_eq!(count_pairs_within(&adj, k), expected);
+        }
+    }
+}
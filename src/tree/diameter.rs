@@ -0,0 +1,180 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # 트리의 지름
+/// 가중치 있는 무방향 트리 `adj[u] = [(v, w), ...]`에서 가장 먼 두 정점 사이의 거리와, 그 경로의 정점 순서를 반환합니다.
+///
+/// * 임의의 정점에서 가장 먼 정점 a를 찾고, a에서 가장 먼 정점 b를 찾으면 a-b 경로가 지름입니다. (가중치가 0 이상일 때)
+/// * 정점이 하나면 `(0, [0])`, 없으면 `(0, [])`입니다.
+/// * 탐색은 명시적인 스택으로 하므로 깊은 트리에서도 스택 오버플로가 나지 않습니다.
+/// ## 시간복잡도
+/// O(n)
+pub fn tree_diameter(adj: &[Vec<(usize, u64)>]) -> (u64, Vec<usize>) {
+    if adj.is_empty() {
+        return (0, Vec::new());
+    }
+    let (dist0, _) = distances_from(adj, 0);
+    let a = farthest(&dist0);
+    let (dist_a, parent) = distances_from(adj, a);
+    let b = farthest(&dist_a);
+    let mut path = vec![b];
+    let mut v = b;
+    while v != a {
+        v = parent[v];
+        path.push(v);
+    }
+    (dist_a[b], path)
+}
+
+/// # 정점별 이심률
+/// 각 정점에서 가장 먼 정점까지의 거리입니다.
+/// 지름의 양 끝 a, b에 대해 `ecc(v) = max(dist(a, v), dist(b, v))`이므로 탐색 두 번(지름 찾기 포함 세 번)으로 구합니다.
+pub fn eccentricities(adj: &[Vec<(usize, u64)>]) -> Vec<u64> {
+    if adj.is_empty() {
+        return Vec::new();
+    }
+    let (_, path) = tree_diameter(adj);
+    let (from_b, _) = distances_from(adj, path[0]);
+    let (from_a, _) = distances_from(adj, *path.last().unwrap());
+    from_a
+        .iter()
+        .zip(&from_b)
+        .map(|(&x, &y)| x.max(y))
+        .collect()
+}
+
+/// # 트리의 중심
+/// 이심률이 가장 작은 정점들을 오름차순으로 반환합니다.
+/// 모든 가중치가 양수이면 중심은 한 개 또는 (인접한) 두 개입니다. 가중치 0인 간선이 있으면 더 많을 수 있습니다.
+pub fn tree_centers(adj: &[Vec<(usize, u64)>]) -> Vec<usize> {
+    let ecc = eccentricities(adj);
+    let Some(&best) = ecc.iter().min() else {
+        return Vec::new();
+    };
+    (0..ecc.len()).filter(|&v| ecc[v] == best).collect()
+}
+
+/// start에서 각 정점까지의 거리와 (start를 루트로 한) 부모
+fn distances_from(adj: &[Vec<(usize, u64)>], start: usize) -> (Vec<u64>, Vec<usize>) {
+    let n = adj.len();
+    let mut dist = vec![u64::MAX; n];
+    let mut parent = vec![usize::MAX; n];
+    dist[start] = 0;
+    parent[start] = start;
+    let mut stack = vec![start];
+    while let Some(u) = stack.pop() {
+        for &(v, w) in &adj[u] {
+            if dist[v] == u64::MAX {
+                dist[v] = dist[u] + w;
+                parent[v] = u;
+                stack.push(v);
+            }
+        }
+    }
+    (dist, parent)
+}
+
+/// 거리가 가장 큰 정점 (같으면 번호가 작은 정점)
+fn farthest(dist: &[u64]) -> usize {
+    let mut best = 0;
+    for (v, &d) in dist.iter().enumerate() {
+        if d > dist[best] {
+            best = v;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_tree};
+
+    fn weighted(rng: &mut XorShift, n: usize, max_w: u64) -> Vec<Vec<(usize, u64)>> {
+        let tree = random_tree(rng, n);
+        let mut adj = vec![Vec::new(); n];
+        for u in 0..n {
+            for &v in &tree[u] {
+                if u < v {
+                    let w = rng.below(max_w + 1);
+                    adj[u].push((v, w));
+                    adj[v].push((u, w));
+                }
+            }
+        }
+        adj
+    }
+
+    fn all_pairs(adj: &[Vec<(usize, u64)>]) -> Vec<Vec<u64>> {
+        (0..adj.len()).map(|s| distances_from(adj, s).0).collect()
+    }
+
+    #[test]
+    fn small_cases() {
+        assert_eq!(tree_diameter(&[]), (0, vec![]));
+        assert_eq!(tree_diameter(&[vec![]]), (0, vec![0]));
+        assert_eq!(tree_centers(&[vec![]]), vec![0]);
+
+        let two = vec![vec![(1, 5)], vec![(0, 5)]];
+        let (d, path) = tree_diameter(&two);
+        assert_eq!(d, 5);
+        assert_eq!(path.len(), 2);
+        assert_eq!(eccentricities(&two), vec![5, 5]);
+        assert_eq!(tree_centers(&two), vec![0, 1]);
+
+        // 0 -1- 1 -1- 2 -10- 3
+        let adj = vec![
+            vec![(1, 1)],
+            vec![(0, 1), (2, 1)],
+            vec![(1, 1), (3, 10)],
+            vec![(2, 10)],
+        ];
+        let (d, path) = tree_diameter(&adj);
+        assert_eq!(d, 12);
+        assert!(path == vec![0, 1, 2, 3] || path == vec![3, 2, 1, 0]);
+        assert_eq!(eccentricities(&adj), vec![12, 11, 10, 12]);
+        assert_eq!(tree_centers(&adj), vec![2]);
+    }
+
+    #[test]
+    fn random_against_all_pairs() {
+        let mut rng = XorShift::new(112);
+        for _ in 0..200 {
+            let n = 1 + rng.index(40);
+            let max_w = if rng.below(2) == 0 { 1 } else { 20 };
+            let adj = weighted(&mut rng, n, max_w);
+            let dist = all_pairs(&adj);
+            let expected = dist.iter().flatten().copied().max().unwrap();
+            let (d, path) = tree_diameter(&adj);
+            assert_eq!(d, expected);
+            // 경로가 실제 간선으로 이어져 있고 길이 합이 d
+            let mut sum = 0;
+            for w in path.windows(2) {
+                let &(_, weight) = adj[w[0]].iter().find(|&&(v, _)| v == w[1]).unwrap();
+                sum += weight;
+            }
+            assert_eq!(sum, d);
+
+            let ecc = eccentricities(&adj);
+            for v in 0..n {
+                assert_eq!(ecc[v], *dist[v].iter().max().unwrap());
+            }
+            let best = *ecc.iter().min().unwrap();
+            let centers: Vec<usize> = (0..n).filter(|&v| ecc[v] == best).collect();
+            assert_eq!(tree_centers(&adj), centers);
+        }
+    }
+
+    #[test]
+    fn long_path_does_not_overflow() {
+        let n = 200_000;
+        let mut adj = vec![Vec::new(); n];
+        for v in 1..n {
+            adj[v - 1].push((v, 2));
+            adj[v].push((v - 1, 2));
+        }
+        let (d, path) = tree_diameter(&adj);
+        assert_eq!(d, 2 * (n as u64 - 1));
+        assert_eq!(path.len(), n);
+        assert_eq!(tree_centers(&adj), vec![n / 2 - 1, n / 2]);
+    }
+}
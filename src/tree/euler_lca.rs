@@ -0,0 +1,164 @@
+#![allow(clippy::doc_lazy_continuation)]
+use crate::sparse_table::SparseTable;
+
+type DepthVertex = (usize, usize);
+
+/// # LCA (Euler tour + sparse table)
+/// 질의가 O(1)인 LCA입니다. 전처리 O(n log n)이고, 질의가 아주 많을 때 [`Lca`](crate::tree::Lca)보다 빠릅니다.
+///
+/// * DFS로 정점을 들어갈 때와 자식에서 돌아올 때마다 기록한 길이 2n-1짜리 Euler tour를 만듭니다.
+/// * u, v의 LCA는 tour에서 u와 v가 처음 나타난 위치 사이에 있는 정점 중 깊이가 가장 작은 정점입니다.
+/// 이 구간 최솟값을 `(깊이, 정점)` 쌍에 대한 [`SparseTable`]로 구합니다.
+/// * DFS는 명시적인 스택으로 구현되어 있어서 깊은 트리에서도 스택 오버플로가 나지 않습니다.
+///
+/// > 트리는 연결되어 있어야 합니다.
+pub struct EulerLca {
+    first: Vec<usize>,
+    depth: Vec<usize>,
+    table: SparseTable<DepthVertex, fn(&DepthVertex, &DepthVertex) -> DepthVertex>,
+}
+
+impl EulerLca {
+    /// 무방향 인접 리스트와 루트로 생성
+    pub fn new(adj: &[Vec<usize>], root: usize) -> Self {
+        let n = adj.len();
+        assert!(root < n, "root {root} out of range for {n} vertices");
+        let mut first = vec![usize::MAX; n];
+        let mut depth = vec![0usize; n];
+        let mut tour: Vec<DepthVertex> = Vec::with_capacity(2 * n);
+        // (정점, 부모, 다음에 볼 이웃 위치)
+        let mut stack = vec![(root, usize::MAX, 0usize)];
+        first[root] = 0;
+        tour.push((0, root));
+        while let Some(&mut (u, parent, ref mut i)) = stack.last_mut() {
+            if *i < adj[u].len() {
+                let v = adj[u][*i];
+                *i += 1;
+                if v != parent && first[v] == usize::MAX {
+                    depth[v] = depth[u] + 1;
+                    first[v] = tour.len();
+                    tour.push((depth[v], v));
+                    stack.push((v, u, 0));
+                }
+            } else {
+                stack.pop();
+                if let Some(&(p, _, _)) = stack.last() {
+                    tour.push((depth[p], p));
+                }
+            }
+        }
+        let min: fn(&DepthVertex, &DepthVertex) -> DepthVertex = |a, b| *a.min(b);
+        EulerLca {
+            first,
+            depth,
+            table: SparseTable::new(&tour, min),
+        }
+    }
+
+    /// 정점 개수
+    pub fn len(&self) -> usize {
+        self.depth.len()
+    }
+
+    /// 정점이 하나도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.depth.is_empty()
+    }
+
+    /// 루트로부터의 깊이 (루트는 0)
+    pub fn depth(&self, v: usize) -> usize {
+        self.depth[v]
+    }
+
+    /// u와 v의 최소 공통 조상
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        let (a, b) = (self.first[u], self.first[v]);
+        let (l, r) = if a <= b { (a, b) } else { (b, a) };
+        self.table.query(l..r + 1).1
+    }
+
+    /// u와 v 사이 경로의 간선 수
+    pub fn dist(&self, u: usize, v: usize) -> usize {
+        self.depth[u] + self.depth[v] - 2 * self.depth[self.lca(u, v)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_tree};
+    use crate::tree::Lca;
+    use std::time::Instant;
+
+    #[test]
+    fn edge_cases() {
+        // 정점 하나
+        let lca = EulerLca::new(&[vec![]], 0);
+        assert_eq!(lca.lca(0, 0), 0);
+
+        // 0 - 1 - 2, 1 - 3
+        let adj = vec![vec![1], vec![0, 2, 3], vec![1], vec![1]];
+        let lca = EulerLca::new(&adj, 0);
+        assert_eq!(lca.lca(2, 2), 2);
+        // 한쪽이 다른 쪽의 조상
+        assert_eq!(lca.lca(0, 3), 0);
+        assert_eq!(lca.lca(2, 1), 1);
+        assert_eq!(lca.lca(2, 3), 1);
+        assert_eq!(lca.dist(2, 3), 2);
+    }
+
+    #[test]
+    fn random_trees_against_binary_lifting() {
+        let mut rng = XorShift::new(111);
+        for round in 0..20 {
+            let n = if round < 15 {
+                1 + rng.index(300)
+            } else {
+                100_000
+            };
+            let adj = random_tree(&mut rng, n);
+            let root = rng.index(n);
+            let euler = EulerLca::new(&adj, root);
+            let lifting = Lca::new(&adj, root);
+            for _ in 0..2000 {
+                let (u, v) = (rng.index(n), rng.index(n));
+                assert_eq!(euler.lca(u, v), lifting.lca(u, v));
+                assert_eq!(euler.dist(u, v), lifting.dist(u, v));
+            }
+        }
+    }
+
+    #[test]
+    fn deep_path_does_not_overflow() {
+        let n = 200_000;
+        let mut adj = vec![Vec::new(); n];
+        for v in 1..n {
+            adj[v - 1].push(v);
+            adj[v].push(v - 1);
+        }
+        let lca = EulerLca::new(&adj, 0);
+        assert_eq!(lca.lca(n - 1, n / 2), n / 2);
+    }
+
+    /// 질의 비용 비교용. `cargo test --release -- --ignored --nocapture`로 실행
+    #[test]
+    #[ignore]
+    fn query_time_against_binary_lifting() {
+        let mut rng = XorShift::new(1110);
+        let n = 100_000;
+        let q = 2_000_000;
+        let adj = random_tree(&mut rng, n);
+        let queries: Vec<(usize, usize)> = (0..q).map(|_| (rng.index(n), rng.index(n))).collect();
+        let euler = EulerLca::new(&adj, 0);
+        let lifting = Lca::new(&adj, 0);
+
+        let start = Instant::now();
+        let a: usize = queries.iter().map(|&(u, v)| euler.lca(u, v)).sum();
+        let euler_time = start.elapsed();
+        let start = Instant::now();
+        let b: usize = queries.iter().map(|&(u, v)| lifting.lca(u, v)).sum();
+        let lifting_time = start.elapsed();
+        assert_eq!(a, b);
+        println!("{q} queries: euler tour {euler_time:?}, binary lifting {lifting_time:?}");
+    }
+}
@@ -0,0 +1,153 @@
+use std::ops::Range;
+
+/// # Euler tour (서브트리 평탄화)
+/// DFS 전위 순회 순서로 정점에 번호를 붙여서, 모든 서브트리를 연속 구간으로 만듭니다.
+///
+/// * `tin[v]`는 v의 번호, `tout[v]`는 v의 서브트리가 끝난 다음 번호입니다. v의 서브트리는 `tin[v]..tout[v]`입니다.
+/// * `order[i]`는 번호가 i인 정점입니다.
+/// * 서브트리 합 같은 질의는 [`EulerTour::flatten_values`]로 값을 번호 순서로 늘어놓은 뒤 구간 질의로 바꾸면 됩니다.
+/// * DFS는 명시적인 스택으로 구현되어 있어서 깊은 트리에서도 스택 오버플로가 나지 않습니다.
+///
+/// > 트리는 연결되어 있어야 합니다.
+pub struct EulerTour {
+    tin: Vec<usize>,
+    tout: Vec<usize>,
+    order: Vec<usize>,
+}
+
+impl EulerTour {
+    /// 무방향 인접 리스트와 루트로 생성
+    pub fn new(adj: &[Vec<usize>], root: usize) -> Self {
+        let n = adj.len();
+        assert!(root < n, "root {root} out of range for {n} vertices");
+        let mut tin = vec![usize::MAX; n];
+        let mut tout = vec![0usize; n];
+        let mut order = Vec::with_capacity(n);
+        // (정점, 다음에 볼 이웃 위치)
+        let mut stack = vec![(root, 0usize)];
+        tin[root] = 0;
+        order.push(root);
+        while let Some(&mut (u, ref mut i)) = stack.last_mut() {
+            if *i < adj[u].len() {
+                let v = adj[u][*i];
+                *i += 1;
+                if tin[v] == usize::MAX {
+                    tin[v] = order.len();
+                    order.push(v);
+                    stack.push((v, 0));
+                }
+            } else {
+                tout[u] = order.len();
+                stack.pop();
+            }
+        }
+        EulerTour { tin, tout, order }
+    }
+
+    /// 정점 개수
+    pub fn len(&self) -> usize {
+        self.tin.len()
+    }
+
+    /// 정점이 하나도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.tin.is_empty()
+    }
+
+    /// v의 번호
+    pub fn tin(&self, v: usize) -> usize {
+        self.tin[v]
+    }
+
+    /// v의 서브트리가 끝난 다음 번호
+    pub fn tout(&self, v: usize) -> usize {
+        self.tout[v]
+    }
+
+    /// 번호 순서로 늘어놓은 정점들
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// u가 v의 조상인지 (u == v도 포함)
+    pub fn is_ancestor(&self, u: usize, v: usize) -> bool {
+        self.tin[u] <= self.tin[v] && self.tin[v] < self.tout[u]
+    }
+
+    /// v의 서브트리가 차지하는 번호 구간
+    pub fn subtree_range(&self, v: usize) -> Range<usize> {
+        self.tin[v]..self.tout[v]
+    }
+
+    /// 정점별 값 `values[v]`를 번호 순서로 늘어놓음. 결과의 i번째는 `values[order[i]]`입니다.
+    pub fn flatten_values<T: Clone>(&self, values: &[T]) -> Vec<T> {
+        assert_eq!(values.len(), self.len(), "need one value per vertex");
+        self.order.iter().map(|&v| values[v].clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_tree};
+    use crate::tree::Lca;
+
+    /// root 방향을 기준으로 v의 서브트리 합을 직접 셈
+    fn brute_subtree_sum(adj: &[Vec<usize>], lca: &Lca, v: usize, values: &[i64]) -> i64 {
+        let mut sum = 0;
+        let mut stack = vec![v];
+        while let Some(u) = stack.pop() {
+            sum += values[u];
+            for &w in &adj[u] {
+                if lca.depth(w) > lca.depth(u) {
+                    stack.push(w);
+                }
+            }
+        }
+        sum
+    }
+
+    #[test]
+    fn subtree_sums_and_ancestors() {
+        let mut rng = XorShift::new(114);
+        for _ in 0..50 {
+            let n = 1 + rng.index(150);
+            let adj = random_tree(&mut rng, n);
+            let root = rng.index(n);
+            let tour = EulerTour::new(&adj, root);
+            let lca = Lca::new(&adj, root);
+            let values: Vec<i64> = (0..n).map(|_| rng.range_i64(-1000, 1000)).collect();
+            let flat = tour.flatten_values(&values);
+            let mut prefix = vec![0i64; n + 1];
+            for i in 0..n {
+                prefix[i + 1] = prefix[i] + flat[i];
+            }
+            for v in 0..n {
+                assert_eq!(tour.order()[tour.tin(v)], v);
+                let range = tour.subtree_range(v);
+                assert_eq!(
+                    prefix[range.end] - prefix[range.start],
+                    brute_subtree_sum(&adj, &lca, v, &values)
+                );
+            }
+            for _ in 0..200 {
+                let (u, v) = (rng.index(n), rng.index(n));
+                assert_eq!(tour.is_ancestor(u, v), lca.lca(u, v) == u);
+            }
+        }
+    }
+
+    #[test]
+    fn deep_path_does_not_overflow() {
+        let n = 200_000;
+        let mut adj = vec![Vec::new(); n];
+        for v in 1..n {
+            adj[v - 1].push(v);
+            adj[v].push(v - 1);
+        }
+        let tour = EulerTour::new(&adj, 0);
+        assert_eq!(tour.subtree_range(n / 2), n / 2..n);
+        assert!(tour.is_ancestor(1, n - 1));
+        assert!(!tour.is_ancestor(n - 1, 1));
+    }
+}
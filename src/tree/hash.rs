@@ -0,0 +1,268 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::collections::HashMap;
+
+/// # Rooted tree 해시
+/// 루트가 있는 트리의 모양(자식 순서는 무시)에 대한 64비트 해시입니다. 같은 모양이면 항상 같은 값입니다.
+///
+/// * 정점의 해시는 자식 해시들을 정렬한 뒤 splitmix64로 섞어서 만듭니다.
+/// * 다른 모양이 같은 값을 가질 수 있습니다(충돌). 무작위 입력에서는 사실상 일어나지 않지만, 정확한 답이 필요하면
+/// 충돌이 없는 [`TreeCanonizer`]를 사용합니다.
+/// * BFS 순서를 거꾸로 처리하므로 깊은 트리에서도 스택 오버플로가 나지 않습니다.
+/// ## 시간복잡도
+/// O(n log n)
+pub fn tree_hash(adj: &[Vec<usize>], root: usize) -> u64 {
+    let (order, parent) = bfs_order(adj, root);
+    let mut hash = vec![0u64; adj.len()];
+    let mut children = Vec::new();
+    for &v in order.iter().rev() {
+        children.clear();
+        children.extend(adj[v].iter().filter(|&&c| c != parent[v]).map(|&c| hash[c]));
+        children.sort_unstable();
+        let mut acc = 0x243F_6A88_85A3_08D3u64;
+        for &c in &children {
+            acc = splitmix64(acc.wrapping_add(c));
+        }
+        hash[v] = splitmix64(acc ^ children.len() as u64);
+    }
+    hash[root]
+}
+
+/// # 트리 정규형 번호 매기기
+/// 정렬한 자식 번호 목록마다 처음 보는 순서대로 번호를 붙여서, 트리 모양마다 서로 다른 번호를 줍니다.
+/// 같은 `TreeCanonizer`로 구한 번호끼리는 같을 때와 모양이 같을 때가 정확히 일치합니다(충돌 없음).
+///
+/// 여러 트리의 서브트리 종류를 셀 때는 하나의 `TreeCanonizer`를 계속 사용합니다.
+#[derive(Default)]
+pub struct TreeCanonizer {
+    ids: HashMap<Vec<usize>, usize>,
+}
+
+impl TreeCanonizer {
+    /// 비어 있는 번호 표
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 지금까지 나온 서로 다른 rooted 모양의 수
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// 아직 아무 모양도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// root를 루트로 했을 때 모든 정점의 서브트리 번호
+    pub fn subtree_ids(&mut self, adj: &[Vec<usize>], root: usize) -> Vec<usize> {
+        let (order, parent) = bfs_order(adj, root);
+        let mut id = vec![0usize; adj.len()];
+        for &v in order.iter().rev() {
+            let mut key: Vec<usize> = adj[v]
+                .iter()
+                .filter(|&&c| c != parent[v])
+                .map(|&c| id[c])
+                .collect();
+            key.sort_unstable();
+            let next = self.ids.len();
+            id[v] = *self.ids.entry(key).or_insert(next);
+        }
+        id
+    }
+
+    /// root를 루트로 하는 트리의 번호
+    pub fn rooted(&mut self, adj: &[Vec<usize>], root: usize) -> usize {
+        self.subtree_ids(adj, root)[root]
+    }
+
+    /// 루트가 없는 트리의 번호. centroid에서 구한 번호 중 작은 것이고, 두 트리가 동형일 때만 같습니다.
+    /// 정점이 없는 트리는 `usize::MAX`입니다.
+    pub fn unrooted(&mut self, adj: &[Vec<usize>]) -> usize {
+        centroids(adj)
+            .into_iter()
+            .map(|c| self.rooted(adj, c))
+            .min()
+            .unwrap_or(usize::MAX)
+    }
+}
+
+/// # 트리 동형 판정
+/// 루트가 없는 두 트리가 같은 모양인지 정확하게 판정합니다.
+/// 한 트리의 centroid는 한 개 또는 인접한 두 개이므로, 각 centroid를 루트로 한 정규형을 비교합니다.
+pub fn trees_isomorphic(adj1: &[Vec<usize>], adj2: &[Vec<usize>]) -> bool {
+    if adj1.len() != adj2.len() {
+        return false;
+    }
+    let mut canonizer = TreeCanonizer::new();
+    canonizer.unrooted(adj1) == canonizer.unrooted(adj2)
+}
+
+/// 제거했을 때 남는 조각이 모두 n/2 이하가 되는 정점들 (오름차순, 한 개 또는 두 개)
+fn centroids(adj: &[Vec<usize>]) -> Vec<usize> {
+    let n = adj.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let (order, parent) = bfs_order(adj, 0);
+    let mut size = vec![1usize; n];
+    let mut heaviest = vec![0usize; n];
+    for &v in order.iter().rev() {
+        if v != 0 {
+            size[parent[v]] += size[v];
+            heaviest[parent[v]] = heaviest[parent[v]].max(size[v]);
+        }
+    }
+    (0..n)
+        .filter(|&v| heaviest[v].max(n - size[v]) * 2 <= n)
+        .collect()
+}
+
+/// root에서의 BFS 순서와 부모 (루트의 부모는 usize::MAX)
+fn bfs_order(adj: &[Vec<usize>], root: usize) -> (Vec<usize>, Vec<usize>) {
+    let n = adj.len();
+    assert!(root < n, "root {root} out of range for {n} vertices");
+    let mut parent = vec![usize::MAX; n];
+    let mut visited = vec![false; n];
+    let mut order = vec![root];
+    visited[root] = true;
+    let mut i = 0;
+    while i < order.len() {
+        let u = order[i];
+        i += 1;
+        for &v in &adj[u] {
+            if !visited[v] {
+                visited[v] = true;
+                parent[v] = u;
+                order.push(v);
+            }
+        }
+    }
+    (order, parent)
+}
+
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_tree};
+    use std::collections::HashSet;
+
+    fn from_parents(parent: &[usize]) -> Vec<Vec<usize>> {
+        let mut adj = vec![Vec::new(); parent.len()];
+        for (v, &p) in parent.iter().enumerate().skip(1) {
+            adj[p].push(v);
+            adj[v].push(p);
+        }
+        adj
+    }
+
+    /// parent[v] < v인 모든 부모 배열. 모든 rooted tree 모양(루트 0)이 적어도 한 번 나옵니다.
+    fn all_parent_arrays(n: usize) -> Vec<Vec<usize>> {
+        let mut result = vec![vec![0usize]];
+        for v in 1..n {
+            result = result
+                .into_iter()
+                .flat_map(|p| {
+                    (0..v).map(move |q| {
+                        let mut p = p.clone();
+                        p.push(q);
+                        p
+                    })
+                })
+                .collect();
+        }
+        result
+    }
+
+    fn relabel(adj: &[Vec<usize>], perm: &[usize]) -> Vec<Vec<usize>> {
+        let mut out = vec![Vec::new(); adj.len()];
+        for (u, list) in adj.iter().enumerate() {
+            for &v in list {
+                out[perm[u]].push(perm[v]);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn counts_all_small_trees() {
+        // 정점 n개짜리 rooted tree / unrooted tree의 개수 (OEIS A000081, A000055)
+        let rooted = [1, 1, 2, 4, 9, 20, 48, 115];
+        let unrooted = [1, 1, 1, 2, 3, 6, 11, 23];
+        for n in 1..=8 {
+            let mut canonizer = TreeCanonizer::new();
+            let mut rooted_ids = HashSet::new();
+            let mut unrooted_ids = HashSet::new();
+            let mut hashes = HashMap::new();
+            for parent in all_parent_arrays(n) {
+                let adj = from_parents(&parent);
+                let id = canonizer.rooted(&adj, 0);
+                rooted_ids.insert(id);
+                unrooted_ids.insert(canonizer.unrooted(&adj));
+                // 해시와 정규형 번호가 일대일로 대응
+                assert_eq!(*hashes.entry(tree_hash(&adj, 0)).or_insert(id), id);
+            }
+            assert_eq!(rooted_ids.len(), rooted[n - 1]);
+            assert_eq!(unrooted_ids.len(), unrooted[n - 1]);
+            assert_eq!(hashes.len(), rooted[n - 1]);
+        }
+    }
+
+    #[test]
+    fn relabelings_are_isomorphic() {
+        let mut rng = XorShift::new(122);
+        for _ in 0..100 {
+            let n = 1 + rng.index(60);
+            let adj = random_tree(&mut rng, n);
+            let mut perm: Vec<usize> = (0..n).collect();
+            for i in (1..n).rev() {
+                perm.swap(i, rng.index(i + 1));
+            }
+            let other = relabel(&adj, &perm);
+            assert!(trees_isomorphic(&adj, &other));
+            let root = rng.index(n);
+            assert_eq!(tree_hash(&adj, root), tree_hash(&other, perm[root]));
+
+            // 다른 무작위 트리: 정확한 판정과 centroid 해시 비교가 일치해야 함
+            let other = random_tree(&mut rng, n);
+            let unrooted_hash =
+                |adj: &[Vec<usize>]| centroids(adj).into_iter().map(|c| tree_hash(adj, c)).min();
+            assert_eq!(
+                trees_isomorphic(&adj, &other),
+                unrooted_hash(&adj) == unrooted_hash(&other)
+            );
+        }
+    }
+
+    #[test]
+    fn two_centroids() {
+        // 경로 0-1-2-3: centroid 1, 2
+        assert_eq!(centroids(&from_parents(&[0, 0, 1, 2])), vec![1, 2]);
+        // 같은 모양의 두 서브트리를 간선으로 이은 트리와, 다른 모양을 이은 트리
+        // a: (0: 1, 2) - (3: 4, 5)   b: (0: 1 - 2) - (3: 4, 5)
+        let a = from_parents(&[0, 0, 0, 0, 3, 3]);
+        let b = from_parents(&[0, 0, 1, 0, 3, 3]);
+        assert_eq!(centroids(&a), vec![0, 3]);
+        assert!(!trees_isomorphic(&a, &b));
+        let a2 = relabel(&a, &[3, 4, 5, 0, 1, 2]);
+        assert!(trees_isomorphic(&a, &a2));
+        assert!(!trees_isomorphic(&a, &from_parents(&[0, 0])));
+        assert!(trees_isomorphic(&[], &[]));
+    }
+
+    #[test]
+    fn deep_path_does_not_overflow() {
+        let n = 200_000;
+        let parent: Vec<usize> = (0..n).map(|v: usize| v.saturating_sub(1)).collect();
+        let adj = from_parents(&parent);
+        let reversed = relabel(&adj, &(0..n).rev().collect::<Vec<_>>());
+        assert!(trees_isomorphic(&adj, &reversed));
+        assert_eq!(tree_hash(&adj, 0), tree_hash(&reversed, n - 1));
+    }
+}
@@ -0,0 +1,445 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::collections::VecDeque;
+
+/// # Heavy-light decomposition
+/// 루트가 있는 트리를 체인으로 나눠서, 임의의 경로를 O(log n)개의 연속 구간으로 바꿉니다.
+///
+/// * 각 정점의 자식 중 서브트리가 가장 큰 자식(heavy child)을 같은 체인으로 잇고, 체인이 연속된 위치를 갖도록 번호를 붙입니다.
+/// * 체인 번호는 DFS 전위 순회 순서이므로 서브트리도 하나의 연속 구간 `pos[v]..pos[v] + size[v]`입니다.
+/// * 구간은 모두 `(l, r)` 반열린 구간이고, 그대로 세그먼트 트리 같은 구간 자료구조에 넘기면 됩니다.
+/// * 탐색은 BFS와 명시적인 스택으로 하므로 깊은 트리에서도 스택 오버플로가 나지 않습니다.
+///
+/// 점 갱신과 경로 합/최댓값만 필요하면 [`Hld::with_segtree`]를 사용합니다.
+///
+/// > 트리는 연결되어 있어야 합니다.
+/// ## 시간복잡도
+/// 전처리 O(n), 경로 분해 O(log n)
+pub struct Hld {
+    parent: Vec<usize>,
+    depth: Vec<usize>,
+    size: Vec<usize>,
+    head: Vec<usize>,
+    pos: Vec<usize>,
+}
+
+/// # 경로 값의 위치
+/// 값이 정점에 있는지, 간선에 있는지 정합니다.
+///
+/// * `Vertex`: 경로 위의 모든 정점(양 끝 포함)을 봅니다.
+/// * `Edge`: 경로 위의 간선만 봅니다. 간선 (parent(v), v)의 값은 자식 쪽 정점 v의 위치에 저장하고,
+/// 경로 질의에서는 LCA의 위치를 빼서 LCA 위쪽 간선이 섞이지 않게 합니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathValues {
+    Vertex,
+    Edge,
+}
+
+impl Hld {
+    /// 무방향 인접 리스트와 루트로 생성
+    pub fn new(adj: &[Vec<usize>], root: usize) -> Self {
+        let n = adj.len();
+        assert!(root < n, "root {root} out of range for {n} vertices");
+        let mut parent = vec![usize::MAX; n];
+        let mut depth = vec![0usize; n];
+        let mut order = Vec::with_capacity(n);
+        parent[root] = root;
+        let mut queue = VecDeque::from([root]);
+        while let Some(u) = queue.pop_front() {
+            order.push(u);
+            for &v in &adj[u] {
+                if parent[v] == usize::MAX {
+                    parent[v] = u;
+                    depth[v] = depth[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        let mut size = vec![1usize; n];
+        let mut heavy = vec![usize::MAX; n];
+        for &v in order.iter().rev() {
+            if v == root {
+                continue;
+            }
+            let p = parent[v];
+            size[p] += size[v];
+            if heavy[p] == usize::MAX || size[v] > size[heavy[p]] {
+                heavy[p] = v;
+            }
+        }
+
+        // heavy child를 마지막에 넣어서 바로 다음에 꺼내도록 함
+        let mut head = vec![root; n];
+        let mut pos = vec![0usize; n];
+        let mut next = 0;
+        let mut stack = vec![root];
+        while let Some(u) = stack.pop() {
+            pos[u] = next;
+            next += 1;
+            for &v in &adj[u] {
+                if v != parent[u] && v != heavy[u] {
+                    head[v] = v;
+                    stack.push(v);
+                }
+            }
+            if heavy[u] != usize::MAX {
+                head[heavy[u]] = head[u];
+                stack.push(heavy[u]);
+            }
+        }
+        Hld {
+            parent,
+            depth,
+            size,
+            head,
+            pos,
+        }
+    }
+
+    /// 정점 개수
+    pub fn len(&self) -> usize {
+        self.pos.len()
+    }
+
+    /// 정점이 하나도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.pos.is_empty()
+    }
+
+    /// 구간 자료구조에서 v가 차지하는 위치
+    pub fn pos(&self, v: usize) -> usize {
+        self.pos[v]
+    }
+
+    /// 부모. 루트면 None
+    pub fn parent(&self, v: usize) -> Option<usize> {
+        (self.parent[v] != v).then_some(self.parent[v])
+    }
+
+    /// 루트로부터의 깊이 (루트는 0)
+    pub fn depth(&self, v: usize) -> usize {
+        self.depth[v]
+    }
+
+    /// u와 v의 최소 공통 조상
+    pub fn lca(&self, mut u: usize, mut v: usize) -> usize {
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            u = self.parent[self.head[u]];
+        }
+        if self.depth[u] < self.depth[v] { u } else { v }
+    }
+
+    /// u-v 경로를 덮는 위치 구간들. 구간의 순서는 경로 순서와 관계없으므로 교환법칙이 성립하는 연산에 사용합니다.
+    pub fn path_segments(&self, u: usize, v: usize, values: PathValues) -> Vec<(usize, usize)> {
+        let (mut u, mut v) = (u, v);
+        let mut segments = Vec::new();
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+            segments.push((self.pos[self.head[u]], self.pos[u] + 1));
+            u = self.parent[self.head[u]];
+        }
+        let (a, b) = if self.pos[u] < self.pos[v] {
+            (self.pos[u], self.pos[v])
+        } else {
+            (self.pos[v], self.pos[u])
+        };
+        // a는 LCA의 위치
+        let l = match values {
+            PathValues::Vertex => a,
+            PathValues::Edge => a + 1,
+        };
+        if l <= b {
+            segments.push((l, b + 1));
+        }
+        segments
+    }
+
+    /// v의 서브트리가 차지하는 위치 구간. 간선 값이라면 `(l + 1, r)`가 서브트리 안쪽 간선들입니다.
+    pub fn subtree_segment(&self, v: usize) -> (usize, usize) {
+        (self.pos[v], self.pos[v] + self.size[v])
+    }
+
+    /// # 경로 합/최댓값 세그먼트 트리와 묶기
+    /// `initial[v]`를 각 정점의 초기값으로 두고 점 갱신, 경로 합, 경로 최댓값을 지원하는 [`HldSegTree`]를 만듭니다.
+    /// `PathValues::Edge`이면 `initial[v]`는 간선 (parent(v), v)의 값이고 루트의 값은 무시합니다.
+    pub fn with_segtree(self, initial: &[i64], values: PathValues) -> HldSegTree {
+        let n = self.len();
+        assert_eq!(initial.len(), n, "need one initial value per vertex");
+        let mut sum = vec![0i64; 2 * n];
+        let mut max = vec![i64::MIN; 2 * n];
+        for (v, &x) in initial.iter().enumerate() {
+            if values == PathValues::Edge && self.parent[v] == v {
+                continue;
+            }
+            sum[n + self.pos[v]] = x;
+            max[n + self.pos[v]] = x;
+        }
+        for i in (1..n).rev() {
+            sum[i] = sum[2 * i] + sum[2 * i + 1];
+            max[i] = max[2 * i].max(max[2 * i + 1]);
+        }
+        HldSegTree {
+            hld: self,
+            values,
+            sum,
+            max,
+        }
+    }
+}
+
+/// # HLD + 세그먼트 트리
+/// [`Hld::with_segtree`]로 만듭니다. 점 갱신 O(log n), 경로 질의 O(log² n)
+pub struct HldSegTree {
+    hld: Hld,
+    values: PathValues,
+    sum: Vec<i64>,
+    max: Vec<i64>,
+}
+
+impl HldSegTree {
+    /// 내부의 [`Hld`]
+    pub fn hld(&self) -> &Hld {
+        &self.hld
+    }
+
+    /// 정점 v의 값을 x로 바꿈 (`PathValues::Vertex`)
+    pub fn set_vertex(&mut self, v: usize, x: i64) {
+        assert_eq!(self.values, PathValues::Vertex, "values are on edges");
+        self.set_pos(self.hld.pos[v], x);
+    }
+
+    /// 간선 u-v의 값을 x로 바꿈 (`PathValues::Edge`). u와 v는 인접해야 합니다.
+    pub fn set_edge(&mut self, u: usize, v: usize, x: i64) {
+        assert_eq!(self.values, PathValues::Edge, "values are on vertices");
+        let child = if self.hld.parent[v] == u && u != v {
+            v
+        } else {
+            assert!(
+                self.hld.parent[u] == v && u != v,
+                "({u}, {v}) is not a tree edge"
+            );
+            u
+        };
+        self.set_pos(self.hld.pos[child], x);
+    }
+
+    fn set_pos(&mut self, p: usize, x: i64) {
+        let n = self.hld.len();
+        let mut i = p + n;
+        self.sum[i] = x;
+        self.max[i] = x;
+        while i > 1 {
+            i /= 2;
+            self.sum[i] = self.sum[2 * i] + self.sum[2 * i + 1];
+            self.max[i] = self.max[2 * i].max(self.max[2 * i + 1]);
+        }
+    }
+
+    /// 위치 구간 l..r의 (합, 최댓값)
+    fn range(&self, l: usize, r: usize) -> (i64, i64) {
+        let n = self.hld.len();
+        let (mut l, mut r) = (l + n, r + n);
+        let (mut sum, mut max) = (0, i64::MIN);
+        while l < r {
+            if l & 1 == 1 {
+                sum += self.sum[l];
+                max = max.max(self.max[l]);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                sum += self.sum[r];
+                max = max.max(self.max[r]);
+            }
+            l /= 2;
+            r /= 2;
+        }
+        (sum, max)
+    }
+
+    /// u-v 경로 위 값의 합. 경로가 비어 있으면 (간선 값이고 u == v) 0
+    pub fn path_sum(&self, u: usize, v: usize) -> i64 {
+        self.hld
+            .path_segments(u, v, self.values)
+            .into_iter()
+            .map(|(l, r)| self.range(l, r).0)
+            .sum()
+    }
+
+    /// u-v 경로 위 값의 최댓값. 경로가 비어 있으면 (간선 값이고 u == v) None
+    pub fn path_max(&self, u: usize, v: usize) -> Option<i64> {
+        self.hld
+            .path_segments(u, v, self.values)
+            .into_iter()
+            .map(|(l, r)| self.range(l, r).1)
+            .max()
+    }
+
+    /// v의 서브트리 값의 합. 간선 값이면 서브트리 안쪽 간선만 셉니다.
+    pub fn subtree_sum(&self, v: usize) -> i64 {
+        let (l, r) = self.hld.subtree_segment(v);
+        match self.values {
+            PathValues::Vertex => self.range(l, r).0,
+            PathValues::Edge => self.range(l + 1, r).0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_tree};
+
+    /// 부모를 따라 올라가며 u-v 경로의 정점을 모음
+    fn naive_path(hld: &Hld, mut u: usize, mut v: usize) -> Vec<usize> {
+        let mut path = Vec::new();
+        while hld.depth(u) > hld.depth(v) {
+            path.push(u);
+            u = hld.parent(u).unwrap();
+        }
+        while hld.depth(v) > hld.depth(u) {
+            path.push(v);
+            v = hld.parent(v).unwrap();
+        }
+        while u != v {
+            path.push(u);
+            path.push(v);
+            u = hld.parent(u).unwrap();
+            v = hld.parent(v).unwrap();
+        }
+        path.push(u);
+        path
+    }
+
+    fn path_graph(n: usize) -> Vec<Vec<usize>> {
+        let mut adj = vec![Vec::new(); n];
+        for v in 1..n {
+            adj[v - 1].push(v);
+            adj[v].push(v - 1);
+        }
+        adj
+    }
+
+    #[test]
+    fn decomposition_is_consistent() {
+        let mut rng = XorShift::new(113);
+        for _ in 0..50 {
+            let n = 1 + rng.index(100);
+            let adj = random_tree(&mut rng, n);
+            let hld = Hld::new(&adj, rng.index(n));
+            // pos는 순열
+            let mut seen = vec![false; n];
+            for v in 0..n {
+                assert!(!seen[hld.pos(v)]);
+                seen[hld.pos(v)] = true;
+            }
+            for _ in 0..100 {
+                let (u, v) = (rng.index(n), rng.index(n));
+                let path = naive_path(&hld, u, v);
+                let w = *path.last().unwrap();
+                assert_eq!(hld.lca(u, v), w);
+                // 구간들이 경로의 정점 위치를 정확히 한 번씩 덮음
+                let mut covered: Vec<usize> = hld
+                    .path_segments(u, v, PathValues::Vertex)
+                    .into_iter()
+                    .flat_map(|(l, r)| l..r)
+                    .collect();
+                covered.sort_unstable();
+                let mut expected: Vec<usize> = path.iter().map(|&x| hld.pos(x)).collect();
+                expected.sort_unstable();
+                assert_eq!(covered, expected);
+                // 각 쪽에서 light edge는 log2(n)개 이하
+                let log = (usize::BITS - n.leading_zeros()) as usize;
+                assert!(hld.path_segments(u, v, PathValues::Vertex).len() <= 2 * log + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn random_updates_against_path_walk() {
+        let mut rng = XorShift::new(1130);
+        for round in 0..60 {
+            let n = 1 + rng.index(80);
+            let adj = if round % 4 == 0 {
+                path_graph(n)
+            } else {
+                random_tree(&mut rng, n)
+            };
+            let root = rng.index(n);
+            let values = if round % 2 == 0 {
+                PathValues::Vertex
+            } else {
+                PathValues::Edge
+            };
+            let mut a: Vec<i64> = (0..n).map(|_| rng.range_i64(-100, 100)).collect();
+            let mut st = Hld::new(&adj, root).with_segtree(&a, values);
+            for _ in 0..300 {
+                if rng.below(3) == 0 {
+                    let v = rng.index(n);
+                    let x = rng.range_i64(-100, 100);
+                    a[v] = x;
+                    match values {
+                        PathValues::Vertex => st.set_vertex(v, x),
+                        PathValues::Edge => match st.hld().parent(v) {
+                            // 간선을 양쪽 순서로 지정해 봄
+                            Some(p) if rng.below(2) == 0 => st.set_edge(p, v, x),
+                            Some(p) => st.set_edge(v, p, x),
+                            None => a[v] = 0,
+                        },
+                    }
+                    continue;
+                }
+                let u = rng.index(n);
+                // 같은 정점, 인접한 정점도 자주 나오게 함
+                let v = match rng.below(4) {
+                    0 => u,
+                    1 => adj[u].first().copied().unwrap_or(u),
+                    _ => rng.index(n),
+                };
+                let path = naive_path(st.hld(), u, v);
+                let w = *path.last().unwrap();
+                let on_path: Vec<i64> = path
+                    .iter()
+                    .filter(|&&x| values == PathValues::Vertex || x != w)
+                    .map(|&x| a[x])
+                    .collect();
+                assert_eq!(st.path_sum(u, v), on_path.iter().sum::<i64>());
+                assert_eq!(st.path_max(u, v), on_path.iter().copied().max());
+            }
+        }
+    }
+
+    #[test]
+    fn subtree_sums() {
+        // 0 - 1 - 2, 1 - 3, 0 - 4
+        let adj = vec![vec![1, 4], vec![0, 2, 3], vec![1], vec![1], vec![0]];
+        let hld = Hld::new(&adj, 0);
+        let (l, r) = hld.subtree_segment(1);
+        assert_eq!(r - l, 3);
+        let st = hld.with_segtree(&[1, 2, 4, 8, 16], PathValues::Vertex);
+        assert_eq!(st.subtree_sum(1), 14);
+        assert_eq!(st.subtree_sum(0), 31);
+        // 간선 값: 루트 값 1은 무시
+        let st = Hld::new(&adj, 0).with_segtree(&[1, 2, 4, 8, 16], PathValues::Edge);
+        assert_eq!(st.subtree_sum(1), 12);
+        assert_eq!(st.subtree_sum(0), 30);
+        assert_eq!(st.path_sum(2, 4), 4 + 2 + 16);
+        assert_eq!(st.path_max(3, 3), None);
+    }
+
+    #[test]
+    fn deep_path_does_not_overflow() {
+        let n = 200_000;
+        let hld = Hld::new(&path_graph(n), 0);
+        assert_eq!(hld.lca(n - 1, n / 2), n / 2);
+        assert_eq!(
+            hld.path_segments(0, n - 1, PathValues::Vertex),
+            vec![(0, n)]
+        );
+    }
+}
@@ -0,0 +1,307 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::collections::VecDeque;
+
+/// # LCA (binary lifting)
+/// 루트가 있는 트리에서 두 정점의 최소 공통 조상(Lowest Common Ancestor)을 구합니다.
+///
+/// * `up[k][v]`는 v의 2^k번째 조상입니다. 루트의 조상은 루트 자신으로 둡니다.
+/// * 전처리 O(n log n), 질의 O(log n)
+/// * 깊이와 부모는 BFS로 구하므로 깊은 트리에서도 스택 오버플로가 나지 않습니다.
+///
+/// > 트리는 연결되어 있어야 합니다. 루트에서 도달할 수 없는 정점에 대한 질의 결과는 의미가 없습니다.
+pub struct Lca {
+    up: Vec<Vec<usize>>,
+    depth: Vec<usize>,
+}
+
+impl Lca {
+    /// 무방향 인접 리스트와 루트로 생성
+    pub fn new(adj: &[Vec<usize>], root: usize) -> Self {
+        let n = adj.len();
+        assert!(root < n, "root {root} out of range for {n} vertices");
+        let mut parent = vec![root; n];
+        let mut depth = vec![0usize; n];
+        let mut visited = vec![false; n];
+        let mut queue = VecDeque::new();
+        visited[root] = true;
+        queue.push_back(root);
+        while let Some(u) = queue.pop_front() {
+            for &v in &adj[u] {
+                if !visited[v] {
+                    visited[v] = true;
+                    parent[v] = u;
+                    depth[v] = depth[u] + 1;
+                    queue.push_back(v);
+                }
+            }
+        }
+        Self::build(parent, depth)
+    }
+
+    /// 부모 배열로 생성. `parent[root]`의 값은 무시합니다.
+    pub fn from_parents(parent: &[usize], root: usize) -> Self {
+        let n = parent.len();
+        assert!(root < n, "root {root} out of range for {n} vertices");
+        let mut children = vec![Vec::new(); n];
+        for (v, &p) in parent.iter().enumerate() {
+            if v != root {
+                assert!(p < n, "parent {p} of {v} out of range");
+                children[p].push(v);
+            }
+        }
+        let mut parent = parent.to_vec();
+        parent[root] = root;
+        let mut depth = vec![0usize; n];
+        let mut queue = VecDeque::from([root]);
+        while let Some(u) = queue.pop_front() {
+            for &v in &children[u] {
+                depth[v] = depth[u] + 1;
+                queue.push_back(v);
+            }
+        }
+        Self::build(parent, depth)
+    }
+
+    fn build(parent: Vec<usize>, depth: Vec<usize>) -> Self {
+        let n = parent.len();
+        let levels = ((usize::BITS - n.leading_zeros()) as usize).max(1);
+        let mut up = vec![parent];
+        for k in 1..levels {
+            let prev = &up[k - 1];
+            let next: Vec<usize> = (0..n).map(|v| prev[prev[v]]).collect();
+            up.push(next);
+        }
+        Lca { up, depth }
+    }
+
+    /// 정점 개수
+    pub fn len(&self) -> usize {
+        self.depth.len()
+    }
+
+    /// 정점이 하나도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.depth.is_empty()
+    }
+
+    /// 루트로부터의 깊이 (루트는 0)
+    pub fn depth(&self, v: usize) -> usize {
+        self.depth[v]
+    }
+
+    /// v의 k번째 조상. k = 0이면 v 자신이고, 루트를 넘어가면 None입니다.
+    pub fn kth_ancestor(&self, v: usize, k: usize) -> Option<usize> {
+        if k > self.depth[v] {
+            return None;
+        }
+        let mut v = v;
+        let mut k = k;
+        let mut level = 0;
+        while k > 0 {
+            if k & 1 == 1 {
+                v = self.up[level][v];
+            }
+            k >>= 1;
+            level += 1;
+        }
+        Some(v)
+    }
+
+    /// u와 v의 최소 공통 조상
+    pub fn lca(&self, u: usize, v: usize) -> usize {
+        let (mut u, mut v) = if self.depth[u] < self.depth[v] {
+            (v, u)
+        } else {
+            (u, v)
+        };
+        u = self.kth_ancestor(u, self.depth[u] - self.depth[v]).unwrap();
+        if u == v {
+            return u;
+        }
+        for k in (0..self.up.len()).rev() {
+            if self.up[k][u] != self.up[k][v] {
+                u = self.up[k][u];
+                v = self.up[k][v];
+            }
+        }
+        self.up[0][u]
+    }
+
+    /// u와 v 사이 경로의 간선 수
+    pub fn dist(&self, u: usize, v: usize) -> usize {
+        self.depth[u] + self.depth[v] - 2 * self.depth[self.lca(u, v)]
+    }
+
+    /// u에서 v로 가는 경로의 k번째 정점 (k = 0이면 u). k가 경로 길이보다 크면 None입니다.
+    pub fn jump_on_path(&self, u: usize, v: usize, k: usize) -> Option<usize> {
+        let w = self.lca(u, v);
+        let up_len = self.depth[u] - self.depth[w];
+        let down_len = self.depth[v] - self.depth[w];
+        if k <= up_len {
+            self.kth_ancestor(u, k)
+        } else if k <= up_len + down_len {
+            self.kth_ancestor(v, up_len + down_len - k)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_tree};
+
+    struct Naive {
+        parent: Vec<usize>,
+        depth: Vec<usize>,
+    }
+
+    impl Naive {
+        fn new(adj: &[Vec<usize>], root: usize) -> Self {
+            let n = adj.len();
+            let mut parent = vec![usize::MAX; n];
+            let mut depth = vec![0; n];
+            let mut stack = vec![root];
+            parent[root] = root;
+            while let Some(u) = stack.pop() {
+                for &v in &adj[u] {
+                    if parent[v] == usize::MAX {
+                        parent[v] = u;
+                        depth[v] = depth[u] + 1;
+                        stack.push(v);
+                    }
+                }
+            }
+            Naive { parent, depth }
+        }
+
+        fn lca(&self, mut u: usize, mut v: usize) -> usize {
+            while self.depth[u] > self.depth[v] {
+                u = self.parent[u];
+            }
+            while self.depth[v] > self.depth[u] {
+                v = self.parent[v];
+            }
+            while u != v {
+                u = self.parent[u];
+                v = self.parent[v];
+            }
+            u
+        }
+
+        fn path(&self, u: usize, v: usize) -> Vec<usize> {
+            let w = self.lca(u, v);
+            let mut left = vec![u];
+            let mut x = u;
+            while x != w {
+                x = self.parent[x];
+                left.push(x);
+            }
+            let mut right = Vec::new();
+            let mut y = v;
+            while y != w {
+                right.push(y);
+                y = self.parent[y];
+            }
+            right.reverse();
+            left.extend(right);
+            left
+        }
+    }
+
+    #[test]
+    fn path_and_star() {
+        // 0 - 1 - 2 - 3 - 4
+        let adj = vec![vec![1], vec![0, 2], vec![1, 3], vec![2, 4], vec![3]];
+        let lca = Lca::new(&adj, 0);
+        assert_eq!(lca.lca(4, 2), 2);
+        assert_eq!(lca.dist(1, 4), 3);
+        assert_eq!(lca.kth_ancestor(4, 4), Some(0));
+        assert_eq!(lca.kth_ancestor(4, 5), None);
+        assert_eq!(lca.jump_on_path(4, 0, 1), Some(3));
+
+        // 루트를 중간으로 바꿔도 같은 거리
+        let lca = Lca::new(&adj, 2);
+        assert_eq!(lca.lca(0, 4), 2);
+        assert_eq!(lca.dist(0, 4), 4);
+        assert_eq!(lca.jump_on_path(0, 4, 3), Some(3));
+        assert_eq!(lca.jump_on_path(0, 4, 5), None);
+
+        // 별: 0이 중심
+        let mut adj = vec![Vec::new(); 6];
+        for v in 1..6 {
+            adj[0].push(v);
+            adj[v].push(0);
+        }
+        let lca = Lca::new(&adj, 0);
+        assert_eq!(lca.lca(3, 5), 0);
+        assert_eq!(lca.dist(3, 5), 2);
+        assert_eq!(lca.jump_on_path(3, 5, 1), Some(0));
+        assert_eq!(lca.kth_ancestor(0, 1), None);
+
+        // 정점 하나
+        let lca = Lca::new(&[vec![]], 0);
+        assert_eq!(lca.lca(0, 0), 0);
+        assert_eq!(lca.kth_ancestor(0, 0), Some(0));
+    }
+
+    #[test]
+    fn random_trees_against_naive() {
+        let mut rng = XorShift::new(110);
+        for _ in 0..50 {
+            let n = 1 + rng.index(200);
+            let adj = random_tree(&mut rng, n);
+            let root = rng.index(n);
+            let lca = Lca::new(&adj, root);
+            let naive = Naive::new(&adj, root);
+            for _ in 0..200 {
+                let (u, v) = (rng.index(n), rng.index(n));
+                assert_eq!(lca.lca(u, v), naive.lca(u, v));
+                let path = naive.path(u, v);
+                assert_eq!(lca.dist(u, v), path.len() - 1);
+                for (k, &x) in path.iter().enumerate() {
+                    assert_eq!(lca.jump_on_path(u, v, k), Some(x));
+                }
+                assert_eq!(lca.jump_on_path(u, v, path.len()), None);
+                let k = rng.index(n + 1);
+                let expected =
+                    (k <= naive.depth[u]).then(|| (0..k).fold(u, |x, _| naive.parent[x]));
+                assert_eq!(lca.kth_ancestor(u, k), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn from_parents_matches_adjacency() {
+        let mut rng = XorShift::new(1100);
+        let n = 300;
+        let mut parent = vec![0usize; n];
+        let mut adj = vec![Vec::new(); n];
+        for v in 1..n {
+            parent[v] = rng.index(v);
+            adj[parent[v]].push(v);
+            adj[v].push(parent[v]);
+        }
+        let a = Lca::from_parents(&parent, 0);
+        let b = Lca::new(&adj, 0);
+        for _ in 0..1000 {
+            let (u, v) = (rng.index(n), rng.index(n));
+            assert_eq!(a.lca(u, v), b.lca(u, v));
+            assert_eq!(a.depth(u), b.depth(u));
+        }
+    }
+
+    #[test]
+    fn deep_path_does_not_overflow() {
+        let n = 200_000;
+        let mut adj = vec![Vec::new(); n];
+        for v in 1..n {
+            adj[v - 1].push(v);
+            adj[v].push(v - 1);
+        }
+        let lca = Lca::new(&adj, 0);
+        assert_eq!(lca.lca(n - 1, n / 2), n / 2);
+        assert_eq!(lca.kth_ancestor(n - 1, n - 1), Some(0));
+    }
+}
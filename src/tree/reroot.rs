@@ -0,0 +1,190 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # Rerooting (모든 루트에 대한 트리 DP)
+/// 각 정점을 루트로 했을 때의 트리 DP 값을 모든 정점에 대해 구합니다.
+///
+/// 루트가 정해진 트리에서 정점 v의 값은 `finalize(merge(add_child(dp[c1]), add_child(dp[c2]), ...), v)`로 정의합니다.
+/// * `merge`: 자식들의 기여를 합치는 연산. 결합법칙과 교환법칙을 만족하고 `identity`가 항등원이어야 합니다.
+/// * `add_child`: 자식 서브트리의 값을 부모 쪽으로 간선 하나를 올릴 때의 기여로 바꿉니다.
+/// * `finalize`: 합친 기여에 정점 v 자신을 더해서 v를 루트로 하는 서브트리의 값을 만듭니다.
+///
+/// 예를 들어 모든 정점까지의 거리 합은 `T = (정점 수, 거리 합)`으로 두고
+/// `add_child = (s, d) → (s, d + s)`, `finalize = (s, d) → (s + 1, d)`로 구할 수 있습니다.
+///
+/// 먼저 정점 0을 루트로 아래쪽 값을 구하고, 각 정점에서 이웃들의 기여를 prefix/suffix로 합쳐서
+/// 자식마다 "그 자식을 뺀 나머지"를 O(1)에 넘겨줍니다. BFS 순서로 처리하므로 재귀가 없습니다.
+/// 간선에 값이 없는 트리만 지원합니다. 포레스트면 컴포넌트마다 따로 계산합니다.
+/// ## 시간복잡도
+/// O(n) 번의 `merge`, `add_child`, `finalize`
+pub fn reroot<T, M, F, G>(
+    adj: &[Vec<usize>],
+    identity: T,
+    merge: M,
+    add_child: F,
+    finalize: G,
+) -> Vec<T>
+where
+    T: Clone,
+    M: Fn(&T, &T) -> T,
+    F: Fn(&T) -> T,
+    G: Fn(&T, usize) -> T,
+{
+    let n = adj.len();
+    let mut parent = vec![usize::MAX; n];
+    let mut order = Vec::with_capacity(n);
+    let mut visited = vec![false; n];
+    for root in 0..n {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let start = order.len();
+        order.push(root);
+        let mut i = start;
+        while i < order.len() {
+            let u = order[i];
+            i += 1;
+            for &v in &adj[u] {
+                if !visited[v] {
+                    visited[v] = true;
+                    parent[v] = u;
+                    order.push(v);
+                }
+            }
+        }
+    }
+
+    // down[v]: 정점 0 쪽을 루트로 했을 때 v 서브트리의 값
+    let mut down = vec![identity.clone(); n];
+    for &v in order.iter().rev() {
+        let mut acc = identity.clone();
+        for &c in &adj[v] {
+            if c != parent[v] {
+                acc = merge(&acc, &add_child(&down[c]));
+            }
+        }
+        down[v] = finalize(&acc, v);
+    }
+
+    // up[v]: v의 부모 쪽 부분이 v의 자식처럼 v에 주는 기여
+    let mut up = vec![identity.clone(); n];
+    let mut answer = vec![identity.clone(); n];
+    for &v in &order {
+        let contributions: Vec<T> = adj[v]
+            .iter()
+            .map(|&w| {
+                if w == parent[v] {
+                    up[v].clone()
+                } else {
+                    add_child(&down[w])
+                }
+            })
+            .collect();
+        let k = contributions.len();
+        let mut suffix = vec![identity.clone(); k + 1];
+        for i in (0..k).rev() {
+            suffix[i] = merge(&contributions[i], &suffix[i + 1]);
+        }
+        answer[v] = finalize(&suffix[0], v);
+        let mut prefix = identity.clone();
+        for (i, &w) in adj[v].iter().enumerate() {
+            if w != parent[v] {
+                let rest = merge(&prefix, &suffix[i + 1]);
+                up[w] = add_child(&finalize(&rest, v));
+            }
+            prefix = merge(&prefix, &contributions[i]);
+        }
+    }
+    answer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{XorShift, random_tree};
+
+    fn bfs(adj: &[Vec<usize>], s: usize) -> Vec<usize> {
+        let mut dist = vec![usize::MAX; adj.len()];
+        dist[s] = 0;
+        let mut queue = vec![s];
+        let mut i = 0;
+        while i < queue.len() {
+            let u = queue[i];
+            i += 1;
+            for &v in &adj[u] {
+                if dist[v] == usize::MAX {
+                    dist[v] = dist[u] + 1;
+                    queue.push(v);
+                }
+            }
+        }
+        dist
+    }
+
+    fn sum_of_distances(adj: &[Vec<usize>]) -> Vec<u64> {
+        reroot(
+            adj,
+            (0u64, 0u64),
+            |a, b| (a.0 + b.0, a.1 + b.1),
+            |&(size, sum)| (size, sum + size),
+            |&(size, sum), _| (size + 1, sum),
+        )
+        .into_iter()
+        .map(|(_, sum)| sum)
+        .collect()
+    }
+
+    /// 각 정점에서 가장 먼 정점까지의 간선 수. 자식이 없으면 0
+    fn max_depth(adj: &[Vec<usize>]) -> Vec<usize> {
+        reroot(
+            adj,
+            None,
+            |a: &Option<usize>, b: &Option<usize>| (*a).max(*b),
+            |a| Some(a.map_or(1, |d| d + 1)),
+            |a, _| *a,
+        )
+        .into_iter()
+        .map(|d| d.unwrap_or(0))
+        .collect()
+    }
+
+    #[test]
+    fn random_trees_against_bfs() {
+        let mut rng = XorShift::new(118);
+        for _ in 0..100 {
+            let n = 1 + rng.index(60);
+            let adj = random_tree(&mut rng, n);
+            let dist: Vec<Vec<usize>> = (0..n).map(|s| bfs(&adj, s)).collect();
+            let sums = sum_of_distances(&adj);
+            let depths = max_depth(&adj);
+            for v in 0..n {
+                assert_eq!(sums[v], dist[v].iter().sum::<usize>() as u64);
+                assert_eq!(depths[v], *dist[v].iter().max().unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn vertex_dependent_finalize_and_forest() {
+        // 두 개짜리 포레스트: 0 - 1 - 2, 3 - 4. 각 루트에서 서브트리 정점 번호 합 = 컴포넌트 합
+        let adj = vec![vec![1], vec![0, 2], vec![1], vec![4], vec![3]];
+        let total = reroot(&adj, 0usize, |a, b| a + b, |&a| a, |&a, v| a + v);
+        assert_eq!(total, vec![3, 3, 3, 7, 7]);
+    }
+
+    #[test]
+    fn deep_path_does_not_overflow() {
+        let n = 200_000;
+        let mut adj = vec![Vec::new(); n];
+        for v in 1..n {
+            adj[v - 1].push(v);
+            adj[v].push(v - 1);
+        }
+        let sums = sum_of_distances(&adj);
+        let m = n as u64;
+        assert_eq!(sums[0], m * (m - 1) / 2);
+        assert_eq!(sums[n - 1], m * (m - 1) / 2);
+        let depths = max_depth(&adj);
+        assert_eq!(depths[n / 2], n / 2);
+    }
+}
@@ -0,0 +1,223 @@
+#![allow(clippy::doc_lazy_continuation)]
+use std::ops::{Range, RangeInclusive};
+
+/// # Wavelet tree
+/// 값이 바뀌지 않는 배열에서 구간 `l..r`의 k번째로 작은 값, x 이하인 값의 개수, 값이 `lo..=hi`에 드는 원소의 개수를
+/// 값의 종류 수 σ에 대해 O(log σ)에 구합니다. [`PersistentSegTree`](crate::segtree::PersistentSegTree)로도
+/// 풀 수 있는 질의지만 상수가 작고 메모리가 적습니다.
+///
+/// * 값을 좌표 압축한 순위(`0..σ`)로 바꾸고, 가장 높은 비트부터 단계마다 "이 비트가 0인 원소를 앞으로, 1인 원소를
+/// 뒤로" 안정 정렬한 배열의 비트를 저장합니다(wavelet matrix). 단계마다 비트의 앞부분 합을 두어 구간을 다음 단계로
+/// 옮깁니다.
+/// * 구간은 0-based 반열림이고, k는 0-based입니다.
+/// ## 시간복잡도
+/// 생성 O(n log σ), 질의 O(log σ) (값을 받는 질의는 압축 순위를 찾는 O(log σ)가 더해짐)
+#[derive(Clone, Debug)]
+pub struct WaveletTree {
+    n: usize,
+    /// 정렬 후 중복을 없앤 값
+    values: Vec<u64>,
+    /// `ones[b][i]`: 높은 비트부터 b번째 단계에서 앞의 i개 중 비트가 1인 개수
+    ones: Vec<Vec<u32>>,
+    /// 단계별 비트가 0인 원소 개수
+    zeros: Vec<usize>,
+}
+
+impl WaveletTree {
+    /// 배열로 생성
+    pub fn new(values: &[u64]) -> Self {
+        let n = values.len();
+        assert!(n < u32::MAX as usize, "too many values for u32 counts");
+        let mut sorted = values.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        let mut cur: Vec<usize> = values
+            .iter()
+            .map(|x| sorted.binary_search(x).unwrap())
+            .collect();
+        let levels = (usize::BITS - sorted.len().leading_zeros()).max(1) as usize;
+        let mut ones = Vec::with_capacity(levels);
+        let mut zeros = Vec::with_capacity(levels);
+        for level in 0..levels {
+            let bit = levels - 1 - level;
+            let mut prefix = vec![0u32; n + 1];
+            for (i, &v) in cur.iter().enumerate() {
+                prefix[i + 1] = prefix[i] + ((v >> bit) & 1) as u32;
+            }
+            let (mut next, high): (Vec<usize>, Vec<usize>) =
+                cur.iter().partition(|&&v| (v >> bit) & 1 == 0);
+            zeros.push(next.len());
+            next.extend(high);
+            ones.push(prefix);
+            cur = next;
+        }
+        WaveletTree {
+            n,
+            values: sorted,
+            ones,
+            zeros,
+        }
+    }
+
+    /// 배열의 길이
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// 길이가 0인지
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// `values[l..r]`에서 k번째(0-based)로 작은 값. `k >= r - l`이면 None
+    pub fn kth_smallest(&self, range: Range<usize>, k: usize) -> Option<u64> {
+        let (mut l, mut r) = self.check(range);
+        if k >= r - l {
+            return None;
+        }
+        let mut k = k;
+        let mut rank = 0usize;
+        for (level, ones) in self.ones.iter().enumerate() {
+            let (ones_l, ones_r) = (ones[l] as usize, ones[r] as usize);
+            let zero_count = (r - l) - (ones_r - ones_l);
+            rank <<= 1;
+            if k < zero_count {
+                (l, r) = (l - ones_l, r - ones_r);
+            } else {
+                k -= zero_count;
+                rank |= 1;
+                (l, r) = (self.zeros[level] + ones_l, self.zeros[level] + ones_r);
+            }
+        }
+        Some(self.values[rank])
+    }
+
+    /// `values[l..r]`에서 x 이하인 값의 개수
+    pub fn count_leq(&self, range: Range<usize>, x: u64) -> usize {
+        let (l, r) = self.check(range);
+        self.count_rank_less(l, r, self.values.partition_point(|&v| v <= x))
+    }
+
+    /// `values[l..r]`에서 x와 같은 값의 개수
+    pub fn count_eq(&self, range: Range<usize>, x: u64) -> usize {
+        let (l, r) = self.check(range);
+        match self.values.binary_search(&x) {
+            Ok(rank) => self.count_rank_less(l, r, rank + 1) - self.count_rank_less(l, r, rank),
+            Err(_) => 0,
+        }
+    }
+
+    /// `values[l..r]`에서 값이 `lo..=hi`에 드는 원소의 개수
+    pub fn range_count(&self, range: Range<usize>, values: RangeInclusive<u64>) -> usize {
+        let (l, r) = self.check(range);
+        let (lo, hi) = (*values.start(), *values.end());
+        if lo > hi {
+            return 0;
+        }
+        let below_lo = self.values.partition_point(|&v| v < lo);
+        let up_to_hi = self.values.partition_point(|&v| v <= hi);
+        self.count_rank_less(l, r, up_to_hi) - self.count_rank_less(l, r, below_lo)
+    }
+
+    fn check(&self, range: Range<usize>) -> (usize, usize) {
+        let Range { start: l, end: r } = range;
+        assert!(
+            l <= r && r <= self.n,
+            "invalid range {l}..{r} for length {}",
+            self.n
+        );
+        (l, r)
+    }
+
+    /// `l..r`에서 압축 순위가 c보다 작은 원소의 개수
+    fn count_rank_less(&self, mut l: usize, mut r: usize, c: usize) -> usize {
+        if c >= self.values.len() {
+            return r - l;
+        }
+        let levels = self.ones.len();
+        let mut count = 0;
+        for (level, ones) in self.ones.iter().enumerate() {
+            let (ones_l, ones_r) = (ones[l] as usize, ones[r] as usize);
+            if (c >> (levels - 1 - level)) & 1 == 1 {
+                count += (r - l) - (ones_r - ones_l);
+                (l, r) = (self.zeros[level] + ones_l, self.zeros[level] + ones_r);
+            } else {
+                (l, r) = (l - ones_l, r - ones_r);
+            }
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(149);
+        for round in 0..200 {
+            let n = rng.index(60);
+            // 중복이 많은 배열과 아주 큰 값이 섞인 배열
+            let values: Vec<u64> = (0..n)
+                .map(|_| match round % 3 {
+                    0 => rng.below(4),
+                    1 => rng.below(50),
+                    _ => u64::MAX - rng.below(3) * (u64::MAX / 2),
+                })
+                .collect();
+            let tree = WaveletTree::new(&values);
+            for _ in 0..100 {
+                let l = rng.index(n + 1);
+                let r = l + rng.index(n + 1 - l);
+                let mut sorted = values[l..r].to_vec();
+                sorted.sort_unstable();
+                let k = rng.index(r - l + 2);
+                assert_eq!(tree.kth_smallest(l..r, k), sorted.get(k).copied());
+                let x = if n > 0 && rng.below(2) == 0 {
+                    values[rng.index(n)]
+                } else {
+                    rng.below(60)
+                };
+                assert_eq!(
+                    tree.count_leq(l..r, x),
+                    sorted.iter().filter(|&&v| v <= x).count()
+                );
+                assert_eq!(
+                    tree.count_eq(l..r, x),
+                    sorted.iter().filter(|&&v| v == x).count()
+                );
+                let (lo, hi) = (rng.below(60), rng.below(60));
+                assert_eq!(
+                    tree.range_count(l..r, lo..=hi),
+                    sorted.iter().filter(|&&v| lo <= v && v <= hi).count()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn single_elements_and_edges() {
+        let values = [7, 3, 3, 9, 0, 3];
+        let tree = WaveletTree::new(&values);
+        for (i, &v) in values.iter().enumerate() {
+            assert_eq!(tree.kth_smallest(i..i + 1, 0), Some(v));
+            assert_eq!(tree.kth_smallest(i..i + 1, 1), None);
+            assert_eq!(tree.count_eq(i..i + 1, v), 1);
+        }
+        assert_eq!(tree.kth_smallest(0..6, 3), Some(3));
+        assert_eq!(tree.kth_smallest(0..6, 5), Some(9));
+        assert_eq!(tree.kth_smallest(2..2, 0), None);
+        assert_eq!(tree.count_leq(0..6, u64::MAX), 6);
+        assert_eq!(tree.count_eq(0..6, 3), 3);
+        assert_eq!(tree.count_eq(0..6, 4), 0);
+        assert_eq!(tree.range_count(1..5, 1..=8), 2);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = tree.range_count(0..6, 8..=4);
+        assert_eq!(reversed, 0);
+        let empty = WaveletTree::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.count_leq(0..0, 5), 0);
+    }
+}
@@ -0,0 +1,4 @@
+mod basis;
+pub use basis::XorBasis;
+mod trie;
+pub use trie::BinaryTrie;
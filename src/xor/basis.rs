@@ -0,0 +1,205 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// # XOR 기저
+/// 넣은 u64 값들의 부분집합 XOR로 만들 수 있는 값(GF(2) 위의 span)을 관리합니다.
+/// 최대 부분집합 XOR, 그래프의 사이클 공간 문제 등에 씁니다.
+///
+/// * `basis[b]`는 가장 높은 비트가 b인 기저 벡터이고, 없으면 0입니다.
+/// * 기저를 항상 기약 행 사다리꼴(reduced row echelon form)로 유지합니다. 즉 어떤 기저 벡터의 가장 높은 비트는
+/// 다른 어떤 기저 벡터에도 켜져 있지 않습니다. 그래서 만들 수 있는 값을 크기순으로 세면 k의 비트가 그대로
+/// "높은 비트 순서로 어떤 기저를 고를지"가 됩니다.
+/// * 빈 부분집합의 XOR인 0은 항상 만들 수 있습니다.
+/// ## 시간복잡도
+/// 연산마다 O(64)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XorBasis {
+    basis: [u64; 64],
+    rank: usize,
+}
+
+impl Default for XorBasis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XorBasis {
+    /// 빈 기저
+    pub fn new() -> Self {
+        XorBasis {
+            basis: [0; 64],
+            rank: 0,
+        }
+    }
+
+    /// 기저 벡터의 개수. 만들 수 있는 값은 `2^rank`개입니다.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// x를 넣음. x를 이미 만들 수 있어서 기저가 커지지 않았으면 false
+    pub fn insert(&mut self, x: u64) -> bool {
+        let x = self.reduce(x);
+        if x == 0 {
+            return false;
+        }
+        let top = 63 - x.leading_zeros() as usize;
+        // 다른 기저 벡터에서 top 비트를 지워서 기약 형태를 유지
+        for v in self.basis.iter_mut() {
+            if (*v >> top) & 1 == 1 {
+                *v ^= x;
+            }
+        }
+        self.basis[top] = x;
+        self.rank += 1;
+        true
+    }
+
+    /// x를 만들 수 있는지
+    pub fn can_represent(&self, x: u64) -> bool {
+        self.reduce(x) == 0
+    }
+
+    /// 만들 수 있는 가장 큰 값
+    pub fn max_xor(&self) -> u64 {
+        self.basis.iter().fold(0, |acc, &v| acc ^ v)
+    }
+
+    /// 만들 수 있는 0이 아닌 가장 작은 값. 기저가 비었으면 None
+    pub fn min_xor_nonzero(&self) -> Option<u64> {
+        self.basis.iter().copied().find(|&v| v != 0)
+    }
+
+    /// 만들 수 있는 값 중 k번째(0-based)로 작은 값. 0번째는 0이고, `k >= 2^rank`이면 None
+    pub fn kth_smallest_xor(&self, k: u64) -> Option<u64> {
+        if self.rank < 64 && k >> self.rank != 0 {
+            return None;
+        }
+        let mut result = 0;
+        for (bit, &v) in self.basis.iter().filter(|&&v| v != 0).enumerate() {
+            if (k >> bit) & 1 == 1 {
+                result ^= v;
+            }
+        }
+        Some(result)
+    }
+
+    /// other의 기저를 모두 넣음
+    pub fn merge(&mut self, other: &XorBasis) {
+        for &v in other.basis.iter().filter(|&&v| v != 0) {
+            self.insert(v);
+        }
+    }
+
+    /// x에서 기저 벡터를 빼서 남은 값
+    fn reduce(&self, mut x: u64) -> u64 {
+        for b in (0..64).rev() {
+            if (x >> b) & 1 == 1 {
+                x ^= self.basis[b];
+            }
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    /// 모든 부분집합의 XOR (정렬, 중복 제거)
+    fn span(values: &[u64]) -> Vec<u64> {
+        let mut all: Vec<u64> = (0..1u32 << values.len())
+            .map(|mask| {
+                values
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| (mask >> i) & 1 == 1)
+                    .fold(0, |acc, (_, &v)| acc ^ v)
+            })
+            .collect();
+        all.sort_unstable();
+        all.dedup();
+        all
+    }
+
+    #[test]
+    fn random_against_subset_enumeration() {
+        let mut rng = XorShift::new(153);
+        for round in 0..300 {
+            let n = rng.index(16);
+            // 작은 값(종속이 많음), 0, 중복, 큰 값을 섞음
+            let values: Vec<u64> = (0..n)
+                .map(|_| match round % 3 {
+                    0 => rng.below(16),
+                    1 => rng.below(1 << 10),
+                    _ => rng.next_u64(),
+                })
+                .collect();
+            let mut basis = XorBasis::new();
+            let mut inserted = Vec::new();
+            for &x in &values {
+                let grew = basis.insert(x);
+                assert_eq!(grew, !span(&inserted).contains(&x));
+                inserted.push(x);
+            }
+            let all = span(&values);
+            assert_eq!(1usize << basis.rank(), all.len());
+            assert_eq!(basis.max_xor(), *all.last().unwrap());
+            assert_eq!(basis.min_xor_nonzero(), all.get(1).copied());
+            for (k, &x) in all.iter().enumerate() {
+                assert_eq!(basis.kth_smallest_xor(k as u64), Some(x));
+                assert!(basis.can_represent(x));
+            }
+            assert_eq!(basis.kth_smallest_xor(all.len() as u64), None);
+            for _ in 0..20 {
+                let x = if round % 3 == 2 {
+                    rng.next_u64()
+                } else {
+                    rng.below(1 << 10)
+                };
+                assert_eq!(basis.can_represent(x), all.binary_search(&x).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn merge_matches_inserting_everything() {
+        let mut rng = XorShift::new(1530);
+        for _ in 0..100 {
+            let a: Vec<u64> = (0..rng.index(8)).map(|_| rng.below(256)).collect();
+            let b: Vec<u64> = (0..rng.index(8)).map(|_| rng.below(256)).collect();
+            let (mut left, mut right, mut both) =
+                (XorBasis::new(), XorBasis::new(), XorBasis::new());
+            a.iter().for_each(|&x| _ = left.insert(x));
+            b.iter().for_each(|&x| _ = right.insert(x));
+            a.iter().chain(&b).for_each(|&x| _ = both.insert(x));
+            left.merge(&right);
+            // 기약 형태는 유일하므로 기저가 완전히 같음
+            assert_eq!(left, both);
+        }
+    }
+
+    #[test]
+    fn zeros_duplicates_and_full_rank() {
+        let mut basis = XorBasis::new();
+        assert!(!basis.insert(0));
+        assert_eq!(basis.min_xor_nonzero(), None);
+        assert_eq!(basis.kth_smallest_xor(0), Some(0));
+        assert_eq!(basis.kth_smallest_xor(1), None);
+        assert!(basis.insert(6));
+        assert!(!basis.insert(6));
+        assert!(basis.insert(3));
+        assert!(!basis.insert(5));
+        assert_eq!(basis.rank(), 2);
+        assert_eq!(basis.max_xor(), 6);
+        let mut full = XorBasis::new();
+        for b in 0..64 {
+            assert!(full.insert(u64::MAX >> b));
+        }
+        assert_eq!(full.rank(), 64);
+        assert_eq!(full.max_xor(), u64::MAX);
+        assert_eq!(full.kth_smallest_xor(u64::MAX), Some(u64::MAX));
+        assert_eq!(full.kth_smallest_xor(12345), Some(12345));
+    }
+}
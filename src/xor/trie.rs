@@ -0,0 +1,249 @@
+#![allow(clippy::doc_lazy_continuation)]
+
+/// 자식이 없음을 나타내는 번호. 0번 노드는 루트라서 자식이 될 수 없습니다.
+const NONE: u32 = 0;
+
+/// # Binary trie
+/// `bits`비트 정수의 multiset을 높은 비트부터 나눈 트라이로 관리하면서, 질의 x와 XOR한 값이 가장 큰/작은 원소와
+/// `x ⊕ y < limit`인 원소 y의 개수를 구합니다.
+///
+/// * 깊이가 `bits`로 고정된 노드를 배열에 두고, 노드마다 그 아래에 있는 원소의 개수를 셉니다. 그래서 같은 값을
+/// 여러 번 넣고 하나씩 뺄 수 있고, 슬라이딩 윈도우처럼 넣고 빼기를 섞어 써도 됩니다.
+/// * 개수가 0이 된 노드는 지우지 않고 남겨 둡니다. 노드 수는 넣은 횟수 × `bits` 이하입니다.
+/// * u32 값은 `BinaryTrie::new(32)`, u64 값은 `BinaryTrie::new(64)`로 씁니다. 값은 `2^bits`보다 작아야 합니다.
+/// ## 시간복잡도
+/// 연산마다 O(bits)
+#[derive(Clone, Debug)]
+pub struct BinaryTrie {
+    bits: u32,
+    child: Vec<[u32; 2]>,
+    count: Vec<u32>,
+}
+
+impl BinaryTrie {
+    /// `0..2^bits` 범위의 값을 담는 빈 트라이 (`1 <= bits <= 64`)
+    pub fn new(bits: u32) -> Self {
+        assert!(
+            (1..=64).contains(&bits),
+            "bits must be in 1..=64, got {bits}"
+        );
+        BinaryTrie {
+            bits,
+            child: vec![[NONE; 2]],
+            count: vec![0],
+        }
+    }
+
+    /// 원소의 개수
+    pub fn len(&self) -> usize {
+        self.count[0] as usize
+    }
+
+    /// 비었는지
+    pub fn is_empty(&self) -> bool {
+        self.count[0] == 0
+    }
+
+    /// x의 개수
+    pub fn count(&self, x: u64) -> usize {
+        self.check(x);
+        let mut v = 0;
+        for b in (0..self.bits).rev() {
+            v = self.child[v][((x >> b) & 1) as usize] as usize;
+            if v == NONE as usize {
+                return 0;
+            }
+        }
+        self.count[v] as usize
+    }
+
+    /// x를 하나 넣음
+    pub fn insert(&mut self, x: u64) {
+        self.check(x);
+        let mut v = 0;
+        self.count[0] += 1;
+        for b in (0..self.bits).rev() {
+            let c = ((x >> b) & 1) as usize;
+            if self.child[v][c] == NONE {
+                let id = u32::try_from(self.child.len()).expect("too many nodes for u32 indices");
+                self.child.push([NONE; 2]);
+                self.count.push(0);
+                self.child[v][c] = id;
+            }
+            v = self.child[v][c] as usize;
+            self.count[v] += 1;
+        }
+    }
+
+    /// x를 하나 뺌. x가 없으면 false
+    pub fn remove_one(&mut self, x: u64) -> bool {
+        if self.count(x) == 0 {
+            return false;
+        }
+        let mut v = 0;
+        self.count[0] -= 1;
+        for b in (0..self.bits).rev() {
+            v = self.child[v][((x >> b) & 1) as usize] as usize;
+            self.count[v] -= 1;
+        }
+        true
+    }
+
+    /// 원소 y 중 `x ⊕ y`의 최댓값. 비었으면 None
+    pub fn max_xor_with(&self, x: u64) -> Option<u64> {
+        self.min_xor_with(x ^ self.mask()).map(|v| v ^ self.mask())
+    }
+
+    /// 원소 y 중 `x ⊕ y`의 최솟값. 비었으면 None
+    pub fn min_xor_with(&self, x: u64) -> Option<u64> {
+        self.check(x);
+        if self.is_empty() {
+            return None;
+        }
+        let mut v = 0;
+        let mut result = 0;
+        for b in (0..self.bits).rev() {
+            let c = ((x >> b) & 1) as usize;
+            // 같은 비트 쪽으로 갈 수 있으면 그 비트의 XOR은 0
+            let same = self.child[v][c] as usize;
+            if same != NONE as usize && self.count[same] > 0 {
+                v = same;
+            } else {
+                result |= 1 << b;
+                v = self.child[v][c ^ 1] as usize;
+            }
+        }
+        Some(result)
+    }
+
+    /// `x ⊕ y < limit`인 원소 y의 개수
+    pub fn count_less_xor(&self, x: u64, limit: u64) -> usize {
+        self.check(x);
+        if self.bits < 64 && limit >> self.bits != 0 {
+            return self.len();
+        }
+        let mut total = 0;
+        let mut v = 0;
+        for b in (0..self.bits).rev() {
+            let c = ((x >> b) & 1) as usize;
+            if (limit >> b) & 1 == 1 {
+                // 이 비트의 XOR이 0인 쪽은 모두 limit보다 작음
+                let same = self.child[v][c] as usize;
+                if same != NONE as usize {
+                    total += self.count[same] as usize;
+                }
+                v = self.child[v][c ^ 1] as usize;
+            } else {
+                v = self.child[v][c] as usize;
+            }
+            if v == NONE as usize {
+                break;
+            }
+        }
+        total
+    }
+
+    fn mask(&self) -> u64 {
+        u64::MAX >> (64 - self.bits)
+    }
+
+    fn check(&self, x: u64) {
+        assert!(
+            x <= self.mask(),
+            "value {x} does not fit in {} bits",
+            self.bits
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::XorShift;
+
+    #[test]
+    fn random_against_brute_force() {
+        let mut rng = XorShift::new(154);
+        for round in 0..100 {
+            let bits = [3, 10, 32, 64][round % 4];
+            let mask = u64::MAX >> (64 - bits);
+            // 작은 범위에서 뽑아 중복이 생기도록 함
+            let draw = |rng: &mut XorShift| {
+                if round % 2 == 0 {
+                    rng.next_u64() & mask & 15
+                } else {
+                    rng.next_u64() & mask
+                }
+            };
+            let mut trie = BinaryTrie::new(bits);
+            let mut model: Vec<u64> = Vec::new();
+            for _ in 0..300 {
+                let x = draw(&mut rng);
+                match rng.below(3) {
+                    0 => {
+                        trie.insert(x);
+                        model.push(x);
+                    }
+                    1 => {
+                        // 있는 값을 지우는 경우가 많도록 고름
+                        let y = match model.get(rng.index(model.len() + 1)) {
+                            Some(&y) => y,
+                            None => x,
+                        };
+                        let expected = model.iter().position(|&v| v == y).map(|i| model.remove(i));
+                        assert_eq!(trie.remove_one(y), expected.is_some());
+                    }
+                    _ => {
+                        let limit = if rng.below(2) == 0 {
+                            draw(&mut rng)
+                        } else {
+                            rng.next_u64()
+                        };
+                        assert_eq!(trie.max_xor_with(x), model.iter().map(|&y| x ^ y).max());
+                        assert_eq!(trie.min_xor_with(x), model.iter().map(|&y| x ^ y).min());
+                        assert_eq!(
+                            trie.count_less_xor(x, limit),
+                            model.iter().filter(|&&y| x ^ y < limit).count()
+                        );
+                        assert_eq!(trie.count(x), model.iter().filter(|&&y| y == x).count());
+                    }
+                }
+                assert_eq!(trie.len(), model.len());
+            }
+        }
+    }
+
+    #[test]
+    fn removal_and_empty_trie() {
+        let mut trie = BinaryTrie::new(32);
+        assert!(trie.is_empty());
+        assert_eq!(trie.max_xor_with(5), None);
+        assert_eq!(trie.min_xor_with(5), None);
+        assert_eq!(trie.count_less_xor(5, u64::MAX), 0);
+        assert!(!trie.remove_one(5));
+        trie.insert(5);
+        trie.insert(5);
+        trie.insert(u32::MAX as u64);
+        assert_eq!(trie.max_xor_with(0), Some(u32::MAX as u64));
+        assert!(trie.remove_one(u32::MAX as u64));
+        assert_eq!(trie.max_xor_with(0), Some(5));
+        assert!(trie.remove_one(5));
+        assert_eq!(trie.count(5), 1);
+        assert_eq!(trie.min_xor_with(4), Some(1));
+        // 한도가 2^32 이상이면 모두 셈
+        assert_eq!(trie.count_less_xor(0, 1 << 32), 1);
+        assert!(trie.remove_one(5));
+        assert_eq!(trie.max_xor_with(0), None);
+        let mut wide = BinaryTrie::new(64);
+        wide.insert(u64::MAX);
+        assert_eq!(wide.max_xor_with(0), Some(u64::MAX));
+        assert_eq!(wide.count_less_xor(0, u64::MAX), 0);
+        assert_eq!(wide.count_less_xor(1, u64::MAX), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn value_too_wide_panics() {
+        BinaryTrie::new(8).insert(256);
+    }
+}